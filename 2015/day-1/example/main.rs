@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2015_day_1;
+    "The instructions take Santa to floor {}" => first_part,
+    "Santa enters the basement at position {}" or "Santa never enters the basement" => second_part,
+);
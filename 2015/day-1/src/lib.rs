@@ -0,0 +1,77 @@
+use aoc_utils::trace;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2015,
+    day: 1,
+    title: "Not Quite Lisp",
+    example_answers: [Some("0"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (lowest, highest) = floors(input)
+        .into_iter()
+        .fold((0, 0), |(lowest, highest), floor| {
+            (floor.min(lowest), floor.max(highest))
+        });
+    Some(format!("floors {lowest} to {highest}"))
+}
+
+pub fn first_part(input: &str) -> i64 {
+    floors(input).last().copied().unwrap_or(0)
+}
+
+pub fn second_part(input: &str) -> Option<usize> {
+    floors(input)
+        .iter()
+        .position(|&floor| floor < 0)
+        .map(|index| index + 1)
+}
+
+/// Lists the floors Santa is on after each instruction.
+///
+/// Characters other than parentheses are ignored.
+pub fn floors(input: &str) -> Vec<i64> {
+    let _span = trace::parse();
+    input
+        .chars()
+        .filter_map(|c| match c {
+            '(' => Some(1),
+            ')' => Some(-1),
+            _ => None,
+        })
+        .scan(0, |floor, step| {
+            *floor += step;
+            Some(*floor)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part("(())"), 0);
+        assert_eq!(first_part("()()"), 0);
+        assert_eq!(first_part("((("), 3);
+        assert_eq!(first_part("(()(()("), 3);
+        assert_eq!(first_part("))((((("), 3);
+        assert_eq!(first_part("())"), -1);
+        assert_eq!(first_part("))("), -1);
+        assert_eq!(first_part(")))"), -3);
+        assert_eq!(first_part(")())())"), -3);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(")"), Some(1));
+        assert_eq!(second_part("()())"), Some(5));
+        assert_eq!(second_part("(()"), None);
+    }
+}
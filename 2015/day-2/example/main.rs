@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2015_day_2;
+    "The elves need {} square feet of wrapping paper" => first_part,
+    "The elves need {} feet of ribbon" => second_part,
+);
@@ -0,0 +1,123 @@
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2015,
+    day: 2,
+    title: "I Was Told There Would Be No Math",
+    example_answers: [Some("58"), Some("34")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let presents = parse_presents(input).ok()?;
+    Some(format!("{} presents", presents.len()))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    parse_presents(input)
+        .expect("invalid input")
+        .iter()
+        .map(Present::paper)
+        .sum()
+}
+
+pub fn second_part(input: &str) -> u64 {
+    parse_presents(input)
+        .expect("invalid input")
+        .iter()
+        .map(Present::ribbon)
+        .sum()
+}
+
+/// The dimensions of a present, sorted in ascending order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Present {
+    pub dimensions: [u64; 3],
+}
+
+impl Present {
+    pub fn new(length: u64, width: u64, height: u64) -> Self {
+        let mut dimensions = [length, width, height];
+        dimensions.sort_unstable();
+        Self { dimensions }
+    }
+
+    /// Gets the wrapping paper needed: the surface area plus the area of the smallest side.
+    pub fn paper(&self) -> u64 {
+        let [a, b, c] = self.dimensions;
+        2 * (a * b + b * c + c * a) + a * b
+    }
+
+    /// Gets the ribbon needed: the smallest perimeter of any side plus the volume for the bow.
+    pub fn ribbon(&self) -> u64 {
+        let [a, b, c] = self.dimensions;
+        2 * (a + b) + a * b * c
+    }
+}
+
+/// Parses one present per line.
+pub fn parse_presents(input: &str) -> Result<Vec<Present>, ParsePresentError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(Present::from_str)
+        .collect()
+}
+
+impl FromStr for Present {
+    type Err = ParsePresentError;
+
+    /// Parses dimensions such as `2x3x4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dimensions = s
+            .split('x')
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| ParsePresentError("invalid number"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match dimensions[..] {
+            [length, width, height] => Ok(Self::new(length, width, height)),
+            _ => Err(ParsePresentError("expected three dimensions")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParsePresentError(&'static str);
+
+impl Display for ParsePresentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse present: {}", self.0)
+    }
+}
+
+impl Error for ParsePresentError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part("2x3x4"), 58);
+        assert_eq!(first_part("1x1x10"), 43);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part("2x3x4"), 34);
+        assert_eq!(second_part("1x1x10"), 14);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2015_day_3;
+    "Santa delivers to {} houses" => first_part,
+    "Santa and Robo-Santa deliver to {} houses" => second_part,
+);
@@ -0,0 +1,77 @@
+use aoc_utils::{trace, Direction, Point};
+use std::collections::HashSet;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2015,
+    day: 3,
+    title: "Perfectly Spherical Houses in a Vacuum",
+    example_answers: [Some("2"), Some("3")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    Some(format!("{} moves", parse_moves(input).len()))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let moves = parse_moves(input);
+    visit(&moves, 1).len()
+}
+
+pub fn second_part(input: &str) -> usize {
+    let moves = parse_moves(input);
+    visit(&moves, 2).len()
+}
+
+/// Collects the houses visited by the specified number of deliverers taking turns,
+/// including the starting house.
+pub fn visit(moves: &[Direction], deliverers: usize) -> HashSet<Point> {
+    let mut positions = vec![Point::new(0, 0); deliverers];
+    let mut visited: HashSet<_> = positions.iter().copied().collect();
+
+    for (i, direction) in moves.iter().enumerate() {
+        let position = &mut positions[i % deliverers];
+        *position += direction.offset();
+        visited.insert(*position);
+    }
+
+    visited
+}
+
+/// Parses the moves, ignoring any characters other than `^`, `>`, `v` and `<`.
+pub fn parse_moves(input: &str) -> Vec<Direction> {
+    let _span = trace::parse();
+    input
+        .chars()
+        .filter_map(|c| match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(">"), 2);
+        assert_eq!(first_part("^>v<"), 4);
+        assert_eq!(first_part("^v^v^v^v^v"), 2);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part("^v"), 3);
+        assert_eq!(second_part("^>v<"), 3);
+        assert_eq!(second_part("^v^v^v^v^v"), 11);
+    }
+}
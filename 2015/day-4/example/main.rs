@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2015_day_4;
+    "The lowest number for five leading zeros is {}" => first_part,
+    "The lowest number for six leading zeros is {}" => second_part,
+);
@@ -0,0 +1,69 @@
+use aoc_utils::md5;
+use aoc_utils::trace;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2015,
+    day: 4,
+    title: "The Ideal Stocking Stuffer",
+    example_answers: [Some("609043"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    Some(format!("secret key {:?}", secret_key(input)))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    mine(secret_key(input), 5)
+}
+
+pub fn second_part(input: &str) -> u64 {
+    mine(secret_key(input), 6)
+}
+
+/// Gets the secret key, which is the whole input but for surrounding whitespace.
+pub fn secret_key(input: &str) -> &str {
+    let _span = trace::parse();
+    input.trim()
+}
+
+/// Finds the lowest positive number that, appended to the key, produces an MD5 hash
+/// starting with the specified number of zeros in hexadecimal.
+pub fn mine(key: &str, zeros: usize) -> u64 {
+    (1..)
+        .find(|number| leading_zeros(&md5(format!("{key}{number}").as_bytes())) >= zeros)
+        .expect("the search is unbounded")
+}
+
+/// Counts the leading zero hexadecimal digits of the digest.
+fn leading_zeros(digest: &[u8]) -> usize {
+    let zero_bits: usize = digest
+        .iter()
+        .position(|&byte| byte != 0)
+        .map_or(digest.len() * 8, |index| {
+            index * 8 + digest[index].leading_zeros() as usize
+        });
+    zero_bits / 4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading_zeros() {
+        assert_eq!(leading_zeros(&[0x00, 0x00, 0x0f]), 5);
+        assert_eq!(leading_zeros(&[0x00, 0x00, 0x10]), 4);
+        assert_eq!(leading_zeros(&[0x00, 0x00]), 4);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part("abcdef"), 609043);
+        assert_eq!(first_part("pqrstuv"), 1048970);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2015_day_5;
+    "{} strings are nice" => first_part,
+    "{} strings are nice under the new rules" => second_part,
+);
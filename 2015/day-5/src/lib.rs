@@ -0,0 +1,117 @@
+use aoc_utils::trace;
+use std::collections::HashMap;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2015,
+    day: 5,
+    title: "Doesn't He Have Intern-Elves For This?",
+    example_answers: [Some("2"), Some("2")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let strings = strings(input);
+    let nice = strings.iter().filter(|s| is_nice(s)).count();
+    let nicer = strings.iter().filter(|s| is_nicer(s)).count();
+    Some(format!(
+        "{} strings, {nice} nice by the old rules, {nicer} by the new ones",
+        strings.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    strings(input).iter().filter(|s| is_nice(s)).count()
+}
+
+pub fn second_part(input: &str) -> usize {
+    strings(input).iter().filter(|s| is_nicer(s)).count()
+}
+
+/// Lists the strings, one per non-empty line.
+pub fn strings(input: &str) -> Vec<&str> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Checks the rules of part 1: at least three vowels, a letter appearing twice in a row,
+/// and none of the forbidden pairs.
+pub fn is_nice(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let vowels = bytes.iter().filter(|c| b"aeiou".contains(c)).count();
+    let has_double = bytes.windows(2).any(|pair| pair[0] == pair[1]);
+    let has_forbidden = bytes
+        .windows(2)
+        .any(|pair| matches!(pair, b"ab" | b"cd" | b"pq" | b"xy"));
+
+    vowels >= 3 && has_double && !has_forbidden
+}
+
+/// Checks the rules of part 2: a pair of letters appearing twice without overlapping,
+/// and a letter repeating with exactly one letter in between.
+pub fn is_nicer(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    // Remember where each pair first occurs; a later occurrence must not overlap it.
+    let mut first_seen = HashMap::new();
+    let has_repeated_pair = bytes.windows(2).enumerate().any(|(i, pair)| {
+        let first = *first_seen.entry(pair).or_insert(i);
+        i >= first + 2
+    });
+    let has_sandwich = bytes.windows(3).any(|triple| triple[0] == triple[2]);
+
+    has_repeated_pair && has_sandwich
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nice() {
+        assert!(is_nice("ugknbfddgicrmopn"));
+        assert!(is_nice("aaa"));
+        assert!(!is_nice("jchzalrnumimnmhp"));
+        assert!(!is_nice("haegwjzuvuyypxyu"));
+        assert!(!is_nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_is_nicer() {
+        assert!(is_nicer("qjhvhtzxzqqjkmpb"));
+        assert!(is_nicer("xxyxx"));
+        assert!(!is_nicer("uurcxstgmygtbstg"));
+        assert!(!is_nicer("ieodomkazucvgmuy"));
+        assert!(!is_nicer("aaa"));
+    }
+
+    #[test]
+    fn test_first_part() {
+        let input = "
+            ugknbfddgicrmopn
+            aaa
+            jchzalrnumimnmhp
+            haegwjzuvuyypxyu
+            dvszwmarrgswjxmb
+        ";
+        assert_eq!(first_part(input), 2);
+    }
+
+    #[test]
+    fn test_second_part() {
+        let input = "
+            qjhvhtzxzqqjkmpb
+            xxyxx
+            uurcxstgmygtbstg
+            ieodomkazucvgmuy
+        ";
+        assert_eq!(second_part(input), 2);
+    }
+}
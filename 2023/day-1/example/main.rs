@@ -1,9 +1,4 @@
-use aoc_2023_day_1::sum_calibration_values;
-
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() {
-    println!("2023 Day 1: Trebuchet?!");
-    let sum = sum_calibration_values(INPUT);
-    println!("The sum of all calibration values is {}", sum);
-}
+aoc_utils::aoc_main!(aoc_2023_day_1;
+    "The sum of all numeral calibration values is {}" => sum_numeral_calibration_values,
+    "The sum of all calibration values is {}" => sum_calibration_values,
+);
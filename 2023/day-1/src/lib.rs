@@ -1,30 +1,59 @@
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use aoc_utils::input::fold_lines;
+use aoc_utils::scan::{first_digit, last_digit};
+use aoc_utils::{trace, Trie};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+use std::sync::OnceLock;
 
-lazy_static! {
-    static ref DIGIT_REPLACEMENT: HashMap<&'static str, u32> = {
-        let mut map = HashMap::new();
-        map.insert("1", 1);
-        map.insert("2", 2);
-        map.insert("3", 3);
-        map.insert("4", 4);
-        map.insert("5", 5);
-        map.insert("6", 6);
-        map.insert("7", 7);
-        map.insert("8", 8);
-        map.insert("9", 9);
-        map.insert("0", 0);
-        map.insert("one", 1);
-        map.insert("two", 2);
-        map.insert("three", 3);
-        map.insert("four", 4);
-        map.insert("five", 5);
-        map.insert("six", 6);
-        map.insert("seven", 7);
-        map.insert("eight", 8);
-        map.insert("nine", 9);
-        map
-    };
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 1,
+    title: "Trebuchet?!",
+    example_answers: [Some("142"), Some("281")],
+    real_answers: Some([None, Some("53515")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let lines = input.lines().filter(|line| !line.trim().is_empty());
+    let (numerals, spelled): (Vec<_>, Vec<_>) =
+        lines.partition(|line| first_digit(line.as_bytes()).is_some());
+    Some(format!(
+        "{} lines with numerals, {} with spelled-out digits only",
+        numerals.len(),
+        spelled.len()
+    ))
+}
+
+/// The spelled-out digits and their values.
+const SPELLED_DIGITS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Gets the spelled-out digits for matching forwards, and spelled backwards for matching
+/// from the end of a line.
+fn spelled_digits() -> &'static (Trie<u32>, Trie<u32>) {
+    static DIGITS: OnceLock<(Trie<u32>, Trie<u32>)> = OnceLock::new();
+    DIGITS.get_or_init(|| {
+        let forward = SPELLED_DIGITS.into_iter().collect();
+        let backward = SPELLED_DIGITS
+            .into_iter()
+            .map(|(word, value)| (word.bytes().rev().collect::<Vec<_>>(), value))
+            .collect();
+        (forward, backward)
+    })
 }
 
 /// Sums the calibration values present in the given input string.
@@ -37,7 +66,40 @@ lazy_static! {
 ///
 /// The sum of all calibration values present in the input string.
 pub fn sum_calibration_values(input: &str) -> u32 {
-    sum_calibration_values_lines(input.lines())
+    parse_calibration_digits(input)
+        .expect("invalid input")
+        .iter()
+        .map(|(first, last)| first * 10 + last)
+        .sum()
+}
+
+/// Sums the calibration values of part 1, which only consist of numerals.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_2023_day_1::sum_numeral_calibration_values;
+///
+/// assert_eq!(sum_numeral_calibration_values("two1nine\n7pqrstsixteen\n"), 11 + 77);
+/// ```
+pub fn sum_numeral_calibration_values(input: &str) -> u32 {
+    parse_numeral_digits(input)
+        .iter()
+        .map(|(first, last)| first * 10 + last)
+        .sum()
+}
+
+/// Parses the first and last numeral of every line, skipping lines without any.
+fn parse_numeral_digits(input: &str) -> Vec<(u32, u32)> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .filter_map(|line| {
+            let first = numeral_value(line, first_digit(line.as_bytes())?);
+            let last = numeral_value(line, last_digit(line.as_bytes())?);
+            Some((first, last))
+        })
+        .collect()
 }
 
 /// Sums up the calibration values from the input lines.
@@ -77,6 +139,33 @@ pub fn sum_calibration_values_lines<'a, I: Iterator<Item = &'a str>>(input: I) -
         .fold(0, |sum, line| sum + get_calibration_value(line))
 }
 
+/// Sums up the calibration values read from the reader one line at a time.
+///
+/// # Arguments
+///
+/// * `reader` - The reader providing the input lines.
+///
+/// # Returns
+///
+/// The sum of all calibration values, or the error that occurred while reading.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_2023_day_1::sum_calibration_values_from_reader;
+///
+/// let sum = sum_calibration_values_from_reader("two1nine\n\n7pqrstsixteen\n".as_bytes());
+/// assert_eq!(sum.unwrap(), 29 + 76);
+/// ```
+pub fn sum_calibration_values_from_reader<R: BufRead>(reader: R) -> io::Result<u32> {
+    fold_lines(reader, 0, |sum, line| {
+        if line.trim().is_empty() {
+            return Ok(sum);
+        }
+        Ok(sum + get_calibration_value(line))
+    })
+}
+
 /// Extracts the calibration value from a given line.
 ///
 /// # Arguments
@@ -117,6 +206,39 @@ pub fn get_calibration_digits(line: &str) -> (u32, u32) {
     (first, last)
 }
 
+/// Parses the first and last digit of every non-empty line, counting spelled-out digits.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_2023_day_1::parse_calibration_digits;
+///
+/// assert_eq!(parse_calibration_digits("two1nine\nabcone2threexyz\n"), Ok(vec![(2, 9), (1, 3)]));
+/// assert!(parse_calibration_digits("abc").is_err());
+/// ```
+pub fn parse_calibration_digits(input: &str) -> Result<Vec<(u32, u32)>, ParseCalibrationError> {
+    let _span = trace::parse();
+    let (forward, _) = spelled_digits();
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let bytes = line.as_bytes();
+            let spelled = || {
+                (0..bytes.len()).any(|start| {
+                    forward
+                        .shortest_match(bytes[start..].iter().copied())
+                        .is_some()
+                })
+            };
+            if first_digit(bytes).is_none() && !spelled() {
+                return Err(ParseCalibrationError("line contains no digits"));
+            }
+            Ok(get_calibration_digits(line))
+        })
+        .collect()
+}
+
 /// Returns the first calibration digit found in the given line.
 ///
 /// This function searches for a specific pattern in the line and returns the corresponding
@@ -142,17 +264,23 @@ pub fn get_calibration_digits(line: &str) -> (u32, u32) {
 /// assert_eq!(result, 1);
 /// ```
 pub fn get_first_calibration_digit(line: &str) -> u32 {
-    let mut start = 0;
-    while start < line.len() {
-        for (&needle, &replacement) in DIGIT_REPLACEMENT.iter() {
-            if line[start..].starts_with(needle) {
-                return replacement;
-            }
+    // Spelled-out digits contain no numerals, so only those starting before the first
+    // numeral can precede it.
+    let numeral = first_digit(line.as_bytes());
+    let end = numeral.unwrap_or(line.len());
+    let (forward, _) = spelled_digits();
+    for start in 0..end {
+        if let Some((_, &value)) =
+            forward.shortest_match(line.as_bytes()[start..end].iter().copied())
+        {
+            return value;
         }
-        start += 1;
     }
 
-    panic!("line contained no digits");
+    match numeral {
+        Some(index) => numeral_value(line, index),
+        None => panic!("line contained no digits"),
+    }
 }
 
 /// Returns the second calibration digit from a given line.
@@ -175,19 +303,40 @@ pub fn get_first_calibration_digit(line: &str) -> u32 {
 /// assert_eq!(digit, 4);
 /// ```
 pub fn get_second_calibration_digit(line: &str) -> u32 {
-    let mut end = line.len();
-    while end > 0 {
-        for (&needle, &replacement) in DIGIT_REPLACEMENT.iter() {
-            if line[..end].ends_with(needle) {
-                return replacement;
-            }
+    // Spelled-out digits contain no numerals, so only those ending after the last
+    // numeral can follow it.
+    let numeral = last_digit(line.as_bytes());
+    let start = numeral.map_or(0, |index| index + 1);
+    let (_, backward) = spelled_digits();
+    for end in (start..=line.len()).rev() {
+        let reversed = line.as_bytes()[start..end].iter().rev().copied();
+        if let Some((_, &value)) = backward.shortest_match(reversed) {
+            return value;
         }
-        end -= 1;
     }
 
-    panic!("line contained no digits");
+    match numeral {
+        Some(index) => numeral_value(line, index),
+        None => panic!("line contained no digits"),
+    }
+}
+
+/// Gets the value of the ASCII numeral at the specified byte index.
+fn numeral_value(line: &str, index: usize) -> u32 {
+    u32::from(line.as_bytes()[index] - b'0')
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseCalibrationError(&'static str);
+
+impl Display for ParseCalibrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse calibration values: {}", self.0)
+    }
+}
+
+impl Error for ParseCalibrationError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +410,19 @@ mod tests {
         assert_eq!(sum, 142);
     }
 
+    #[test]
+    fn test_sum_numeral_calibration_values() {
+        let sum = sum_numeral_calibration_values(
+            "1abc2
+                   pqr3stu8vwx
+                   a1b2c3d4e5f
+                   treb7uchet
+                ",
+        );
+        assert_eq!(sum, 142);
+        assert_eq!(sum_numeral_calibration_values("eightwothree\n"), 0);
+    }
+
     #[test]
     fn test_sum_calibration_values_enhanced() {
         let sum = sum_calibration_values(
@@ -286,5 +448,9 @@ mod tests {
                 + get_calibration_value("(one)"))
             + get_calibration_value("cannoneer") * get_calibration_value("oneight4");
         assert_eq!(sum_calibration_values(INPUT), sum);
+        assert_eq!(
+            sum_calibration_values_from_reader(INPUT.as_bytes()).unwrap(),
+            sum
+        );
     }
 }
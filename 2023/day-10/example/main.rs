@@ -1,12 +1,73 @@
-use aoc_2023_day_10::*;
+use aoc_2023_day_10::trace_loop;
+use aoc_viz::{animate_requested, Canvas, Cell, Color, ColorMap, Screen};
+use std::io::ErrorKind;
+use std::time::Duration;
 
-const INPUT: &str = include_str!("../input.txt");
+aoc_utils::aoc_main!(aoc_2023_day_10;
+    before = animate_if_requested;
+    "The furthest number of steps from the start in either direction: {}" => part1,
+    "Number of tiles inside the loop: {}" => |input| part2(input, false),
+);
 
-fn main() {
-    println!("2023 Day 10: Pipe Maze");
-    println!(
-        "The furthest number of steps from the start in either direction: {}",
-        part1(INPUT)
-    );
-    println!("Number of tiles inside the loop: {}", part2(INPUT, true));
+/// Plays the animation instead of solving the puzzle, if requested.
+fn animate_if_requested(input: &str) -> bool {
+    if !animate_requested() {
+        return false;
+    }
+
+    match animate(input) {
+        Err(e) if e.kind() != ErrorKind::Interrupted => panic!("failed to animate: {e}"),
+        _ => true,
+    }
+}
+
+/// Traces the loop tile by tile, coloring it by the distance walked from the start.
+fn animate(input: &str) -> std::io::Result<()> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut canvas = Canvas::new(width, lines.len() + 1);
+    for (y, line) in lines.iter().enumerate() {
+        for (x, tile) in line.chars().enumerate() {
+            canvas.set(x, y, Cell::new(pipe(tile)).with_foreground(Color::Gray));
+        }
+    }
+
+    let path = trace_loop(input).path;
+    let colors = ColorMap::heat();
+    let steps_per_frame = (path.len() / 300).max(1);
+
+    let mut screen = Screen::enter(60)?;
+    for (i, chunk) in path.chunks(steps_per_frame).enumerate() {
+        for (j, &(x, y)) in chunk.iter().enumerate() {
+            let step = i * steps_per_frame + j;
+            let color = colors.map(step as f64 / path.len() as f64);
+            let tile = lines[y].as_bytes()[x] as char;
+            canvas.set(x, y, Cell::new(pipe(tile)).with_foreground(color));
+        }
+
+        let step = (i * steps_per_frame + chunk.len()).min(path.len());
+        let caption = format!("Tracing the loop: {step}/{} tiles", path.len());
+        canvas.draw_text(0, lines.len(), &caption, Color::White);
+        screen.present(&mut canvas)?;
+    }
+
+    screen.hold(Duration::from_secs(2))
+}
+
+/// Gets the box-drawing character for a pipe tile.
+fn pipe(tile: char) -> char {
+    match tile {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        '.' => ' ',
+        other => other,
+    }
 }
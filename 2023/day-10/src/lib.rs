@@ -1,6 +1,32 @@
+use aoc_utils::render::svg::Svg;
+use aoc_utils::scan::lines;
+use aoc_utils::{label_regions, trace, BitGrid, Grid, Point};
 use std::borrow::Borrow;
+use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 10,
+    title: "Pipe Maze",
+    example_answers: [Some("4"), Some("4")],
+    real_answers: Some([Some("6820"), Some("337")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let map: Map = input.parse().ok()?;
+    Some(format!(
+        "{}×{} map with a loop of {} tiles",
+        map.width,
+        map.height,
+        trace_loop(input).path.len()
+    ))
+}
 
 /// Solution for part 1.
 pub fn part1(input: &str) -> u64 {
@@ -36,6 +62,105 @@ pub fn part1(input: &str) -> u64 {
 
 /// Solution for part 2.
 pub fn part2(input: &str, print_map: bool) -> usize {
+    part2_with_strategy(input, print_map, Strategy::EdgeFlood)
+}
+
+/// The main loop of a pipe maze.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PipeLoop {
+    /// The width of the maze.
+    pub width: usize,
+    /// The height of the maze.
+    pub height: usize,
+    /// The `(x, y)` coordinates of the loop tiles in walking order, beginning with the start tile.
+    pub path: Vec<(usize, usize)>,
+}
+
+impl PipeLoop {
+    /// Renders the loop as an SVG document, marking the start tile.
+    pub fn to_svg(&self) -> String {
+        let mut svg = Svg::new(self.width, self.height, 8);
+        svg.path(self.path.iter().copied(), "steelblue", true);
+        if let Some(&(x, y)) = self.path.first() {
+            svg.point(x, y, "crimson", Some("S"));
+        }
+        svg.to_string()
+    }
+}
+
+/// Walks the loop from the start tile.
+pub fn trace_loop(input: &str) -> PipeLoop {
+    let map = parse_tiles(input);
+    let start = map.find_start();
+    let tile = map.infer_tile(&start);
+
+    let (mut current, _) = tile.expand(start);
+    let mut previous = start;
+    let mut path = vec![(start.x(), start.y())];
+    while current != start {
+        path.push((current.x(), current.y()));
+        let next = map.at(current).step(current, previous);
+        (current, previous) = (next, current);
+    }
+
+    PipeLoop {
+        width: map.width,
+        height: map.height,
+        path,
+    }
+}
+
+/// Brute-force variant of [`part1`] that walks the entire loop.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn part1_bf(input: &str) -> u64 {
+    trace_loop(input).path.len() as u64 / 2
+}
+
+/// Brute-force variant of [`part2`] that casts a ray from each tile to the left edge of the
+/// maze and counts how often it crosses the loop.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn part2_bf(input: &str) -> usize {
+    let path = trace_loop(input).path;
+    let on_loop: std::collections::HashSet<_> = path.iter().copied().collect();
+    let rows: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    // The ray runs along the upper half of each tile, crossing only pipes that connect north.
+    let (start_x, start_y) = path[0];
+    let start_north = [path[1], path[path.len() - 1]].contains(&(start_x, start_y.wrapping_sub(1)));
+    let connects_north = |x: usize, y: usize| match rows[y][x] {
+        '|' | 'L' | 'J' => true,
+        'S' => start_north,
+        _ => false,
+    };
+
+    let mut enclosed = 0;
+    for (y, row) in rows.iter().enumerate() {
+        for x in (0..row.len()).filter(|&x| !on_loop.contains(&(x, y))) {
+            let crossings = (0..x)
+                .filter(|&i| on_loop.contains(&(i, y)) && connects_north(i, y))
+                .count();
+            enclosed += crossings % 2;
+        }
+    }
+    enclosed
+}
+
+/// The approach used to tell the tiles inside the loop from the ones outside.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Strategy {
+    /// Flood-fills the corners between tiles, passing along every edge that no loop pipe crosses.
+    EdgeFlood,
+    /// Flood-fills a map widened to twice the width and height, with gaps between all tiles.
+    Widened,
+}
+
+/// Solution for part 2, using the specified strategy.
+pub fn part2_with_strategy(input: &str, print_map: bool, strategy: Strategy) -> usize {
     let mut map = parse_tiles(input);
 
     // The start lies on a tile. We assume the surrounding tiles connect to it meaningfully
@@ -48,230 +173,341 @@ pub fn part2(input: &str, print_map: bool) -> usize {
     let start_tile_index = map.to_index(start);
     map.tiles[start_tile_index] = tile;
 
+    let (loop_mask, mut outside) = match strategy {
+        Strategy::EdgeFlood => edge_flood_masks(&map, start, tile),
+        Strategy::Widened => widened_masks(&map, start, tile),
+    };
+
+    // Print the reduced map.
+    if print_map {
+        print_final_loop_map(&loop_mask, &outside);
+    }
+
+    // Count the number of remaining spots in the map.
+    outside.union_with(&loop_mask);
+    outside.width() * outside.height() - outside.count_ones()
+}
+
+/// Determines the loop and outside masks by flood-filling the corners of the tiles.
+///
+/// The corner `(x, y)` is the top-left corner of the tile `(x, y)`. Moving from one corner to
+/// the next follows the edge between two tiles, which is blocked only if a loop pipe crosses it.
+/// A tile is outside of the loop if it isn't part of the loop and its corner is reachable from
+/// the border.
+fn edge_flood_masks(map: &Map, start: Coordinate, tile: Tile) -> (BitGrid, BitGrid) {
+    let (current, _) = tile.expand(start);
+    let loop_mask = prepare_loop_mask(map.width, map.height, start, current, |c| map.at(c));
+
+    // A loop pipe crosses the edge if it connects towards the neighboring tile.
+    let crosses = |x: usize, y: usize, south: bool| {
+        loop_mask.get(x, y) && {
+            let tile = map.at(Coordinate(x, y));
+            if south {
+                tile.connects_south()
+            } else {
+                tile.connects_east()
+            }
+        }
+    };
+
+    let (width, height) = (map.width + 1, map.height + 1);
+    let mut corners = BitGrid::new(width, height);
+    let mut seeds = Vec::new();
+    for x in 0..width {
+        seeds.push(Coordinate(x, 0));
+        seeds.push(Coordinate(x, height - 1));
+    }
+    for y in 1..height - 1 {
+        seeds.push(Coordinate(0, y));
+        seeds.push(Coordinate(width - 1, y));
+    }
+
+    while let Some(corner) = seeds.pop() {
+        let Coordinate(x, y) = corner;
+        if !corners.insert(x, y) {
+            continue;
+        }
+
+        // Horizontal edges run between the tiles north and south of them.
+        let blocked_horizontally = |x: usize| y > 0 && y < map.height && crosses(x, y - 1, true);
+        if x + 1 < width && !blocked_horizontally(x) {
+            seeds.push(Coordinate(x + 1, y));
+        }
+        if x > 0 && !blocked_horizontally(x - 1) {
+            seeds.push(Coordinate(x - 1, y));
+        }
+
+        // Vertical edges run between the tiles west and east of them.
+        let blocked_vertically = |y: usize| x > 0 && x < map.width && crosses(x - 1, y, false);
+        if y + 1 < height && !blocked_vertically(y) {
+            seeds.push(Coordinate(x, y + 1));
+        }
+        if y > 0 && !blocked_vertically(y - 1) {
+            seeds.push(Coordinate(x, y - 1));
+        }
+    }
+
+    let mut outside = BitGrid::new(map.width, map.height);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if corners.get(x, y) && !loop_mask.get(x, y) {
+                outside.set(x, y, true);
+            }
+        }
+    }
+
+    (loop_mask, outside)
+}
+
+/// Determines the loop and outside masks by flood-filling a widened copy of the map.
+fn widened_masks(map: &Map, start: Coordinate, tile: Tile) -> (BitGrid, BitGrid) {
     // Widen the map.
     let map = map.widen();
 
     // Obtain the start position in the widened map.
-    let start = Coordinate(start.x() * 2, start.y() * 2);
+    let start = start.widen();
 
     // Get a starting direction.
     let (current, _) = tile.expand(start);
-    let mut loop_map = prepare_loop_map(&map, start, current);
+    let loop_mask = prepare_loop_mask(map.width, map.height, start, current, |c| map.at(c));
 
     // Flood-fill the outside
-    flood_fill_outside(&map, &mut loop_map);
-
-    // Reduce the map again.
-    let small_loop_map = shrink_loop_map(&map, &loop_map);
-
-    // Print the reduced map.
-    if print_map {
-        print_final_loop_map(&map, &small_loop_map);
-    }
-
-    // Count the number of remaining spots in the map.
-    let num_in_loop = small_loop_map
-        .iter()
-        .filter(|&state| *state == MapState::None)
-        .count();
+    let outside = flood_fill_outside(&loop_mask);
 
-    num_in_loop
+    // Reduce the masks again.
+    (shrink_mask(&loop_mask), shrink_mask(&outside))
 }
 
-fn prepare_loop_map(map: &WidenedMap, start: Coordinate, mut current: Coordinate) -> Vec<MapState> {
+/// Walks the loop of a map of the given size, reading its tiles with `at`.
+fn prepare_loop_mask<C, F>(width: usize, height: usize, start: C, mut current: C, at: F) -> BitGrid
+where
+    C: Position,
+    F: Fn(C) -> Tile,
+{
     let mut previous = start;
 
-    // Create a map of all tiles that are on the loop.
-    // We will later color it in such that all tiles inside the loop are marked.
-    let mut loop_map: Vec<_> = map
-        .tiles
-        .iter()
-        .map(|&tile| match tile {
-            Tile::Widened => MapState::Widened,
-            _ => MapState::None,
-        })
-        .collect();
+    // Create a mask of all tiles that are on the loop.
+    let mut loop_mask = BitGrid::new(width, height);
 
-    // Walk the loop, filling in the loop outline on the map.
-    loop_map[map.to_index(start)] = MapState::Loop;
+    // Walk the loop, filling in the loop outline on the mask.
+    let (x, y) = start.xy();
+    loop_mask.set(x, y, true);
     while current != start {
-        loop_map[map.to_index(current)] = MapState::Loop;
-        let next = map.at(current).step(current, previous);
+        let (x, y) = current.xy();
+        loop_mask.set(x, y, true);
+        let next = at(current).step(current, previous);
         (current, previous) = (next, current);
     }
-    loop_map
+    loop_mask
 }
 
-fn flood_fill_outside(map: &WidenedMap, loop_map: &mut [MapState]) {
-    let mut seeds = Vec::new();
-    for x in 0..map.width {
-        // Top row.
-        let coordinate = Coordinate(x, 0);
-        let tile = map.at(coordinate);
-        if tile == Tile::None || tile == Tile::Widened {
-            loop_map[map.to_index(coordinate)] = MapState::Outside;
-            seeds.push(coordinate);
-        }
-
-        // Bottom row.
-        let coordinate = Coordinate(x, map.height - 1);
-        let tile = map.at(coordinate);
-        if tile == Tile::None || tile == Tile::Widened {
-            loop_map[map.to_index(coordinate)] = MapState::Outside;
-            seeds.push(coordinate);
-        }
-    }
-
-    for y in 1..map.height {
-        // Top column.
-        let coordinate = Coordinate(0, y);
-        let tile = map.at(coordinate);
-        if tile == Tile::None || tile == Tile::Widened {
-            loop_map[map.to_index(coordinate)] = MapState::Outside;
-            seeds.push(coordinate);
-        }
-
-        // Right column.
-        let coordinate = Coordinate(map.width - 1, y);
-        let tile = map.at(coordinate);
-        if tile == Tile::None || tile == Tile::Widened {
-            loop_map[map.to_index(coordinate)] = MapState::Outside;
-            seeds.push(coordinate);
-        }
-    }
-
-    seeds.reverse();
-    while let Some(seed) = seeds.pop() {
-        // Check north side.
-        if let Some(coordinate) = seed.maybe_north() {
-            let tile = &mut loop_map[map.to_index(coordinate)];
-            if *tile == MapState::None || *tile == MapState::Widened {
-                *tile = MapState::Outside;
-                seeds.push(coordinate);
-            }
-        } else {
-            let thingy = loop_map[map.to_index(seed)];
-            debug_assert_eq!(thingy, MapState::Outside);
-        }
+/// Marks the tiles that aren't on the loop and whose region reaches the edge of the map.
+fn flood_fill_outside(loop_mask: &BitGrid) -> BitGrid {
+    let mut on_loop = Grid::new(loop_mask.width(), loop_mask.height(), false);
+    for (x, y) in loop_mask.iter_ones() {
+        on_loop[Point::new(x as i64, y as i64)] = true;
+    }
 
-        // Check east side.
-        if let Some(coordinate) = seed.maybe_east(map) {
-            let tile = &mut loop_map[map.to_index(coordinate)];
-            if *tile == MapState::None || *tile == MapState::Widened {
-                *tile = MapState::Outside;
-                seeds.push(coordinate);
-            }
+    let mut outside = BitGrid::new(loop_mask.width(), loop_mask.height());
+    let regions = label_regions(&on_loop, |a, b| a == b);
+    for region in regions.iter().filter(|region| region.touches_border()) {
+        if on_loop[region.cells()[0]] {
+            continue;
         }
-
-        // Check south side.
-        if let Some(coordinate) = seed.maybe_south(map) {
-            let tile = &mut loop_map[map.to_index(coordinate)];
-            if *tile == MapState::None || *tile == MapState::Widened {
-                *tile = MapState::Outside;
-                seeds.push(coordinate);
-            }
-        }
-
-        // Check west side.
-        if let Some(coordinate) = seed.maybe_west() {
-            let tile = &mut loop_map[map.to_index(coordinate)];
-            if *tile == MapState::None || *tile == MapState::Widened {
-                *tile = MapState::Outside;
-                seeds.push(coordinate);
-            }
-        } else {
-            let thingy = loop_map[map.to_index(seed)];
-            debug_assert_eq!(thingy, MapState::Outside);
+        for cell in region.cells() {
+            outside.set(cell.x as usize, cell.y as usize, true);
         }
     }
-}
 
-fn shrink_loop_map(map: &WidenedMap, loop_map: &[MapState]) -> Vec<MapState> {
-    let mut small_loop_map = vec![MapState::None; loop_map.len() / 4];
-    for y in (0..map.height).step_by(2) {
-        for x in (0..map.width).step_by(2) {
-            let index = x + y * map.width;
-            let state = loop_map[index];
+    outside
+}
 
-            let index = (x / 2) + (y * map.width) / 4;
-            small_loop_map[index] = state;
+/// Keeps only the tiles of the original map, i.e. every second row and column.
+fn shrink_mask(mask: &BitGrid) -> BitGrid {
+    let mut small_mask = BitGrid::new(mask.width() / 2, mask.height() / 2);
+    for (x, y) in mask.iter_ones() {
+        if let Some(Coordinate(x, y)) = WideCoordinate(x, y).narrow() {
+            small_mask.set(x, y, true);
         }
     }
-    small_loop_map
+    small_mask
 }
 
-fn print_final_loop_map(map: &Map, small_loop_map: &[MapState]) {
+fn print_final_loop_map(loop_mask: &BitGrid, outside: &BitGrid) {
     let mut out = String::new();
-    for l in 0..(map.height / 2) {
-        let line = &small_loop_map[l * (map.width / 2)..(l + 1) * (map.width / 2)];
-        let str = String::from_iter(line.iter().map(|&state| match state {
-            MapState::None => 'I',
-            MapState::Loop => '*',
-            MapState::Outside => 'O',
-            MapState::Widened => unreachable!(),
+    for y in 0..loop_mask.height() {
+        out.extend((0..loop_mask.width()).map(|x| {
+            if loop_mask.get(x, y) {
+                '*'
+            } else if outside.get(x, y) {
+                'O'
+            } else {
+                'I'
+            }
         }));
-        out.push_str(&str);
         out.push('\n');
     }
     println!("{out}");
 }
 
+/// The `x` and `y` position of a tile in the original maze.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum MapState {
-    None,
-    Loop,
-    Outside,
-    Widened,
-}
+pub struct Coordinate(pub usize, pub usize);
 
-/// A 2D coordinate of x an y.
+/// The `x` and `y` position of a tile in the [`WidenedMap`].
+///
+/// The tile `(x, y)` of the original maze lies at `(2x, 2y)`; the positions with an odd
+/// coordinate are the gaps between the original tiles.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Coordinate(usize, usize);
+pub struct WideCoordinate(pub usize, pub usize);
+
+/// Moves between neighboring tiles of either the original or the widened maze.
+trait Position: Copy + Eq {
+    fn new(x: usize, y: usize) -> Self;
+
+    fn xy(&self) -> (usize, usize);
+
+    fn is_north_of(&self, other: &Self) -> bool {
+        self.xy().1 < other.xy().1
+    }
+
+    fn is_south_of(&self, other: &Self) -> bool {
+        self.xy().1 > other.xy().1
+    }
+
+    fn is_west_of(&self, other: &Self) -> bool {
+        self.xy().0 < other.xy().0
+    }
+
+    fn is_east_of(&self, other: &Self) -> bool {
+        self.xy().0 > other.xy().0
+    }
+
+    fn north(&self) -> Self {
+        let (x, y) = self.xy();
+        Self::new(x, y - 1)
+    }
+
+    fn south(&self) -> Self {
+        let (x, y) = self.xy();
+        Self::new(x, y + 1)
+    }
 
+    fn west(&self) -> Self {
+        let (x, y) = self.xy();
+        Self::new(x - 1, y)
+    }
+
+    fn east(&self) -> Self {
+        let (x, y) = self.xy();
+        Self::new(x + 1, y)
+    }
+
+    fn southeast(&self) -> Self {
+        let (x, y) = self.xy();
+        Self::new(x + 1, y + 1)
+    }
+}
+
+/// A tile of the maze.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Tile {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Tile {
+    /// Ground without a pipe, `.`.
     None,
+    /// The start, `S`, whose pipe has to be inferred from its neighbors.
     Start,
+    /// A vertical pipe, `|`.
     NorthSouth,
+    /// A horizontal pipe, `-`.
     WestEast,
+    /// A bend connecting north and east, `L`.
     NorthEast,
+    /// A bend connecting north and west, `J`.
     NorthWest,
+    /// A bend connecting south and west, `7`.
     SouthWest,
+    /// A bend connecting south and east, `F`.
     SouthEast,
+    /// A gap between tiles of the [`WidenedMap`] that no pipe passes through.
     Widened,
 }
 
-struct Map {
+/// A pipe maze.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Map {
     tiles: Vec<Tile>,
     width: usize,
     height: usize,
 }
 
-struct WidenedMap(Map);
+/// A pipe maze at twice the resolution, in which the gaps between tiles are tiles of their own.
+///
+/// Pipes that connect two tiles continue through the gap between them, so the ground enclosed
+/// by the loop is cut off from the outside even where pipes squeeze past each other.
+pub struct WidenedMap {
+    tiles: Vec<Tile>,
+    width: usize,
+    height: usize,
+}
 
+/// Parses the maze, panicking on malformed input.
 fn parse_tiles(input: &str) -> Map {
-    let mut tiles = Vec::with_capacity(input.len());
-    let mut num_lines = 0;
-    for line in input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|&line| !line.is_empty())
-    {
-        tiles.extend(line.chars().map(Tile::from));
-        num_lines += 1;
+    input.parse().expect("failed to parse the maze")
+}
+
+impl FromStr for Map {
+    type Err = ParseMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        // The input length bounds the number of tiles, so the grid is allocated exactly once.
+        let mut tiles = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+        let mut starts = 0;
+        for line in lines(s.as_bytes())
+            .map(<[u8]>::trim_ascii)
+            .filter(|&line| !line.is_empty())
+        {
+            for &byte in line {
+                let tile = Tile::try_from(byte)?;
+                starts += usize::from(tile == Tile::Start);
+                tiles.push(tile);
+            }
+
+            // Ensure proper line format.
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(ParseMapError("lines differ in length"));
+            }
+            height += 1;
+        }
+
+        match starts {
+            0 => return Err(ParseMapError("missing start")),
+            1 => {}
+            _ => return Err(ParseMapError("multiple starts")),
+        }
+
+        Ok(Map {
+            tiles,
+            width: width.unwrap_or(0),
+            height,
+        })
     }
+}
 
-    // Ensure proper line format.
-    let width = tiles.len() / num_lines;
-    assert_eq!(width * num_lines, tiles.len());
+#[derive(Debug)]
+pub struct ParseMapError(&'static str);
 
-    Map {
-        tiles,
-        width,
-        height: num_lines,
+impl Display for ParseMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse map: {}", self.0)
     }
 }
 
+impl Error for ParseMapError {}
+
 impl Map {
     fn find_start(&self) -> Coordinate {
         let pos = self
@@ -291,51 +527,52 @@ impl Map {
     }
 
     fn infer_tile(&self, position: &Coordinate) -> Tile {
-        if position.has_north() && self.at(position.north()).connects_south() {
-            if self.at(position.south()).connects_north() {
-                return Tile::NorthSouth;
-            }
-
-            if position.has_west() && self.at(position.west()).connects_east() {
-                return Tile::NorthWest;
-            }
-
-            if self.at(position.east()).connects_west() {
-                return Tile::NorthEast;
-            }
-        }
-
-        if self.at(position.south()).connects_north() {
-            if position.has_west() && self.at(position.west()).connects_east() {
-                return Tile::SouthWest;
-            }
-
-            if self.at(position.east()).connects_west() {
-                return Tile::SouthEast;
-            }
-        }
-
-        if position.has_west()
-            && self.at(position.west()).connects_east()
-            && self.at(position.east()).connects_west()
-        {
-            return Tile::WestEast;
+        // Neighbors beyond the edges of the map can't connect to the start.
+        let north = position.has_north() && self.at(position.north()).connects_south();
+        let south = position.has_south(self) && self.at(position.south()).connects_north();
+        let west = position.has_west() && self.at(position.west()).connects_east();
+        let east = position.has_east(self) && self.at(position.east()).connects_west();
+
+        match (north, south, west, east) {
+            (true, true, _, _) => return Tile::NorthSouth,
+            (true, _, true, _) => return Tile::NorthWest,
+            (true, _, _, true) => return Tile::NorthEast,
+            (_, true, true, _) => return Tile::SouthWest,
+            (_, true, _, true) => return Tile::SouthEast,
+            (_, _, true, true) => return Tile::WestEast,
+            _ => {}
         }
 
         panic!("Unexpected combination of tiles")
     }
 
-    fn widen(&self) -> WidenedMap {
+    /// Creates the widened copy of the maze, with the start replaced by the pipe it connects.
+    pub fn widen(&self) -> WidenedMap {
         self.into()
     }
 }
 
 impl WidenedMap {
-    fn to_index(&self, coordinate: Coordinate) -> usize {
-        coordinate.x() + coordinate.y() * self.width
+    /// Gets the number of columns, twice that of the original maze.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of rows, twice that of the original maze.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the tile at the position.
+    pub fn at(&self, position: WideCoordinate) -> Tile {
+        self.tiles[self.to_index(position)]
+    }
+
+    fn to_index(&self, coordinate: WideCoordinate) -> usize {
+        coordinate.0 + coordinate.1 * self.width
     }
 
-    fn upgrade(&mut self, coordinate: Coordinate, new: Tile) {
+    fn upgrade(&mut self, coordinate: WideCoordinate, new: Tile) {
         let index = self.to_index(coordinate);
         let tile = &mut self.tiles[index];
         if *tile == Tile::Widened {
@@ -343,43 +580,43 @@ impl WidenedMap {
         }
     }
 
-    fn connects_north(&self, coordinate: Coordinate) -> bool {
+    fn connects_north(&self, coordinate: WideCoordinate) -> bool {
         if coordinate.1 < 2 {
             return false;
         }
 
         let tile = self.tiles[self.to_index(coordinate)];
-        let other = self.tiles[self.to_index(Coordinate(coordinate.0, coordinate.1 - 2))];
+        let other = self.tiles[self.to_index(WideCoordinate(coordinate.0, coordinate.1 - 2))];
         tile.connects_north() && other.connects_south()
     }
 
-    fn connects_west(&self, coordinate: Coordinate) -> bool {
+    fn connects_west(&self, coordinate: WideCoordinate) -> bool {
         if coordinate.0 < 2 {
             return false;
         }
 
         let tile = self.tiles[self.to_index(coordinate)];
-        let other = self.tiles[self.to_index(Coordinate(coordinate.0 - 2, coordinate.1))];
+        let other = self.tiles[self.to_index(WideCoordinate(coordinate.0 - 2, coordinate.1))];
         tile.connects_west() && other.connects_east()
     }
 
-    fn connects_south(&self, coordinate: Coordinate) -> bool {
+    fn connects_south(&self, coordinate: WideCoordinate) -> bool {
         if coordinate.1 >= self.height - 2 {
             return false;
         }
 
         let tile = self.tiles[self.to_index(coordinate)];
-        let other = self.tiles[self.to_index(Coordinate(coordinate.0, coordinate.1 + 2))];
+        let other = self.tiles[self.to_index(WideCoordinate(coordinate.0, coordinate.1 + 2))];
         tile.connects_south() && other.connects_north()
     }
 
-    fn connects_east(&self, coordinate: Coordinate) -> bool {
+    fn connects_east(&self, coordinate: WideCoordinate) -> bool {
         if coordinate.0 >= self.width - 2 {
             return false;
         }
 
         let tile = self.tiles[self.to_index(coordinate)];
-        let other = self.tiles[self.to_index(Coordinate(coordinate.0 + 2, coordinate.1))];
+        let other = self.tiles[self.to_index(WideCoordinate(coordinate.0 + 2, coordinate.1))];
         tile.connects_east() && other.connects_west()
     }
 }
@@ -409,78 +646,42 @@ impl Coordinate {
         self.0 < map.width - 1
     }
 
-    pub fn maybe_north(&self) -> Option<Coordinate> {
-        if self.has_north() {
-            Some(self.north())
-        } else {
-            None
-        }
-    }
-
-    pub fn maybe_west(&self) -> Option<Coordinate> {
-        if self.has_west() {
-            Some(self.west())
-        } else {
-            None
-        }
-    }
-
-    pub fn maybe_east(&self, map: &Map) -> Option<Coordinate> {
-        if self.has_east(map) {
-            Some(self.east())
-        } else {
-            None
-        }
-    }
-
-    pub fn maybe_south(&self, map: &Map) -> Option<Coordinate> {
-        if self.has_south(map) {
-            Some(self.south())
-        } else {
-            None
-        }
-    }
-
-    pub fn is_north_of(&self, other: &Coordinate) -> bool {
-        self.1 < other.1
-    }
-
-    pub fn is_south_of(&self, other: &Coordinate) -> bool {
-        self.1 > other.1
-    }
-
-    pub fn is_west_of(&self, other: &Coordinate) -> bool {
-        self.0 < other.0
-    }
-
-    pub fn is_east_of(&self, other: &Coordinate) -> bool {
-        self.0 > other.0
+    /// Gets the position of the tile in the [`WidenedMap`].
+    pub fn widen(self) -> WideCoordinate {
+        WideCoordinate(self.0 * 2, self.1 * 2)
     }
+}
 
-    pub fn north(&self) -> Coordinate {
-        Coordinate(self.0, self.1 - 1)
+impl WideCoordinate {
+    /// Gets the position of the tile in the original maze, or `None` for a gap between tiles.
+    pub fn narrow(self) -> Option<Coordinate> {
+        let (x, y) = (self.0, self.1);
+        (x % 2 == 0 && y % 2 == 0).then_some(Coordinate(x / 2, y / 2))
     }
+}
 
-    pub fn south(&self) -> Coordinate {
-        Coordinate(self.0, self.1 + 1)
+impl Position for Coordinate {
+    fn new(x: usize, y: usize) -> Self {
+        Coordinate(x, y)
     }
 
-    pub fn west(&self) -> Coordinate {
-        Coordinate(self.0 - 1, self.1)
+    fn xy(&self) -> (usize, usize) {
+        (self.0, self.1)
     }
+}
 
-    pub fn east(&self) -> Coordinate {
-        Coordinate(self.0 + 1, self.1)
+impl Position for WideCoordinate {
+    fn new(x: usize, y: usize) -> Self {
+        WideCoordinate(x, y)
     }
 
-    pub fn southeast(&self) -> Coordinate {
-        Coordinate(self.0 + 1, self.1 + 1)
+    fn xy(&self) -> (usize, usize) {
+        (self.0, self.1)
     }
 }
 
 impl Tile {
-    pub fn expand<C: Borrow<Coordinate>>(&self, coordinate: C) -> (Coordinate, Coordinate) {
-        let coordinate = coordinate.borrow();
+    fn expand<C: Position>(&self, coordinate: C) -> (C, C) {
         match self {
             Tile::None => panic!("Invalid call on a none-tile"),
             Tile::Widened => panic!("Invalid call on a none-tile"),
@@ -550,14 +751,8 @@ impl Tile {
         }
     }
 
-    pub fn step<C: Borrow<Coordinate>, P: Borrow<Coordinate>>(
-        &self,
-        current: C,
-        previous: P,
-    ) -> Coordinate {
-        let current = current.borrow();
-        let previous = previous.borrow();
-
+    fn step<C: Position>(&self, current: C, previous: C) -> C {
+        let (current, previous) = (&current, &previous);
         match self {
             Tile::None => panic!("can't call step on a none-tile"),
             Tile::Widened => panic!("can't call step on a none-tile"),
@@ -626,52 +821,37 @@ impl Tile {
     }
 }
 
-impl Deref for WidenedMap {
-    type Target = Map;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl DerefMut for WidenedMap {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 impl<M> From<M> for WidenedMap
 where
     M: Borrow<Map>,
 {
     fn from(value: M) -> Self {
         let value = value.borrow();
-        let mut map = WidenedMap(Map {
+        let mut map = WidenedMap {
             tiles: vec![Tile::Widened; value.tiles.len() * 4],
             width: value.width * 2,
             height: value.height * 2,
-        });
+        };
 
         // Fill in the base map.
         for y in 0..value.height {
             for x in 0..value.width {
                 let coordinate = Coordinate(x, y);
-                let tile = value.at(coordinate);
+                let tile = match value.at(coordinate) {
+                    Tile::Start => value.infer_tile(&coordinate),
+                    tile => tile,
+                };
 
-                // Place the regular tile.
-                let base_coordinate = Coordinate(x * 2, y * 2);
-                map.upgrade(base_coordinate, tile);
+                // Place the regular tile, with the start replaced by its pipe.
+                map.upgrade(coordinate.widen(), tile);
             }
         }
 
         // Fill in the gaps.
         for y in 0..value.height {
             for x in 0..value.width {
-                let coordinate = Coordinate(x, y);
-                let tile = value.at(coordinate);
-
-                let base_coordinate = Coordinate(x * 2, y * 2);
-                match tile {
+                let base_coordinate = Coordinate(x, y).widen();
+                match map.at(base_coordinate) {
                     Tile::None => {
                         // Place the tile east to it.
                         let new_coordinate = base_coordinate.east();
@@ -685,9 +865,6 @@ where
                         let new_coordinate = base_coordinate.southeast();
                         map.upgrade(new_coordinate, Tile::None);
                     }
-                    Tile::Start => {
-                        // nothing to do
-                    }
                     Tile::NorthSouth => {
                         // Place the tile north to it.
                         if map.connects_north(base_coordinate) {
@@ -754,7 +931,7 @@ where
                             map.upgrade(base_coordinate.south(), Tile::NorthSouth);
                         }
                     }
-                    Tile::Widened => unreachable!(),
+                    Tile::Start | Tile::Widened => unreachable!(),
                 };
             }
         }
@@ -763,19 +940,21 @@ where
     }
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
-        match value {
-            '|' => Self::NorthSouth,
-            '-' => Self::WestEast,
-            'L' => Self::NorthEast,
-            'J' => Self::NorthWest,
-            '7' => Self::SouthWest,
-            'F' => Self::SouthEast,
-            'S' => Self::Start,
-            '.' => Self::None,
-            _ => unreachable!(),
-        }
+impl TryFrom<u8> for Tile {
+    type Error = ParseMapError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'|' => Self::NorthSouth,
+            b'-' => Self::WestEast,
+            b'L' => Self::NorthEast,
+            b'J' => Self::NorthWest,
+            b'7' => Self::SouthWest,
+            b'F' => Self::SouthEast,
+            b'S' => Self::Start,
+            b'.' => Self::None,
+            _ => return Err(ParseMapError("invalid tile")),
+        })
     }
 }
 
@@ -793,6 +972,20 @@ impl Display for Map {
     }
 }
 
+impl Display for WidenedMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", self.at(WideCoordinate(x, y)))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Display for Tile {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -812,6 +1005,7 @@ impl Display for Tile {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::property::{assert_equivalent, check, pipe_maze};
 
     #[test]
     fn test_part1_example1() {
@@ -886,6 +1080,90 @@ mod tests {
         assert_ne!(part2(TEST, false), 357);
     }
 
+    #[test]
+    fn test_strategies_agree() {
+        const TEST: &str = ".F----7F7F7F7F-7....
+            .|F--7||||||||FJ....
+            .||.FJ||||||||L7....
+            FJL7L7LJLJ||LJ.L-7..
+            L--J.L7...LJS7F-7L7.
+            ....F-J..F7FJ|L7L7L7
+            ....L7.F7||L7|.L7L7|
+            .....|FJLJ|FJ|F7|.LJ
+            ....FJL-7.||.||||...
+            ....L---J.LJ.LJLJ...";
+        const REAL: &str = include_str!("../input.txt");
+
+        for input in [TEST, REAL] {
+            assert_eq!(
+                part2_with_strategy(input, false, Strategy::EdgeFlood),
+                part2_with_strategy(input, false, Strategy::Widened)
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1_brute_force() {
+        assert_equivalent(
+            200,
+            |input: &String| part1_bf(input),
+            |input: &String| part1(input),
+            |rng| {
+                let (width, height) = (rng.index(1..8), rng.index(1..8));
+                pipe_maze(rng, width, height).input
+            },
+        );
+    }
+
+    #[test]
+    fn test_part2_brute_force() {
+        assert_equivalent(
+            200,
+            |input: &String| part2_bf(input),
+            |input: &String| part2(input, false),
+            |rng| {
+                let (width, height) = (rng.index(1..8), rng.index(1..8));
+                pipe_maze(rng, width, height).input
+            },
+        );
+    }
+
+    #[test]
+    fn test_random_mazes() {
+        check(
+            200,
+            |rng| {
+                let (width, height) = (rng.index(1..8), rng.index(1..8));
+                pipe_maze(rng, width, height)
+            },
+            |maze| {
+                part1(&maze.input) as usize == maze.length / 2
+                    && part2_with_strategy(&maze.input, false, Strategy::EdgeFlood) == maze.enclosed
+                    && part2_with_strategy(&maze.input, false, Strategy::Widened) == maze.enclosed
+                    && trace_loop(&maze.input).path.len() == maze.length
+            },
+        );
+    }
+
+    #[test]
+    fn test_trace_loop() {
+        const TEST: &str = ".....
+            .S-7.
+            .|.|.
+            .L-J.
+            .....";
+        let pipe_loop = trace_loop(TEST);
+        assert_eq!((pipe_loop.width, pipe_loop.height), (5, 5));
+        assert_eq!(pipe_loop.path.len(), 8);
+        assert_eq!(pipe_loop.path[0], (1, 1));
+        assert!(pipe_loop.path.contains(&(3, 3)));
+
+        let svg = pipe_loop.to_svg();
+        assert!(
+            svg.contains(r#"<polygon points="12,12 12,20 12,28 20,28 28,28 28,20 28,12 20,12""#)
+        );
+    }
+
     #[test]
     fn test_parse_map() {
         const TEST1: &str = ".....
@@ -903,6 +1181,19 @@ mod tests {
             LJ...";
         let map = parse_tiles(TEST2);
         assert_eq!(map.find_start(), Coordinate(0, 2));
+
+        for (input, error) in [
+            ("", "missing start"),
+            ("S.\n...", "lines differ in length"),
+            ("S.\n.S", "multiple starts"),
+            ("S?", "invalid tile"),
+        ] {
+            let error = format!("Failed to parse map: {error}");
+            assert_eq!(
+                Map::from_str(input).err().map(|e| e.to_string()),
+                Some(error)
+            );
+        }
     }
 
     #[test]
@@ -926,6 +1217,29 @@ mod tests {
         assert_eq!(map.infer_tile(&start), Tile::SouthEast);
     }
 
+    #[test]
+    fn test_widened_map() {
+        const TEST: &str = ".....
+            .S-7.
+            .|.|.
+            .L-J.
+            .....";
+        let map = parse_tiles(TEST).widen();
+        assert_eq!((map.width(), map.height()), (10, 10));
+
+        // The pipes continue through the gaps, the start included.
+        let start = Coordinate(1, 1).widen();
+        assert_eq!(map.at(start), Tile::SouthEast);
+        assert_eq!(map.at(start.east()), Tile::WestEast);
+        assert_eq!(map.at(start.south()), Tile::NorthSouth);
+        assert_eq!(map.at(start.southeast()), Tile::Widened);
+        assert_eq!(map.at(WideCoordinate(1, 1)), Tile::None);
+
+        assert_eq!(start.narrow(), Some(Coordinate(1, 1)));
+        assert_eq!(start.east().narrow(), None);
+        assert_eq!(map.to_string().lines().nth(2), Some("..F---7*.."));
+    }
+
     #[test]
     fn test_steps() {
         let current = Coordinate(10, 10);
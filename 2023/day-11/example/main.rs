@@ -1,9 +1,56 @@
-use aoc_2023_day_11::*;
+use aoc_2023_day_11::expanded_galaxies;
+use aoc_viz::{animate_requested, Canvas, Cell, Color, ColorMap, Screen};
+use std::io::ErrorKind;
+use std::time::Duration;
 
-const INPUT: &str = include_str!("../input.txt");
+/// The largest expansion factor shown in the animation.
+const MAX_EXPANSION: usize = 4;
 
-fn main() {
-    println!("2023 Day 11: Cosmic Expansion");
-    println!("Sum of shortest pairwise distances: {}", part1(INPUT));
-    println!("Part 2: {}", part2(INPUT));
+aoc_utils::aoc_main!(aoc_2023_day_11;
+    before = animate_if_requested;
+    "Sum of shortest pairwise distances: {}" => part1,
+    "Sum of shortest pairwise distances with a million-fold expansion: {}" => part2,
+);
+
+/// Plays the animation instead of solving the puzzle, if requested.
+fn animate_if_requested(input: &str) -> bool {
+    if !animate_requested() {
+        return false;
+    }
+
+    match animate(input) {
+        Err(e) if e.kind() != ErrorKind::Interrupted => panic!("failed to animate: {e}"),
+        _ => true,
+    }
+}
+
+/// Shows the galaxies drifting apart as the empty rows and columns grow.
+fn animate(input: &str) -> std::io::Result<()> {
+    let start = expanded_galaxies(input, 1);
+    let end = expanded_galaxies(input, MAX_EXPANSION);
+    let width = end.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = end.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+
+    let frames = 60;
+    let colors = ColorMap::heat();
+    let mut canvas = Canvas::new(width, height + 1);
+    let mut screen = Screen::enter(30)?;
+    for frame in 0..=frames {
+        let t = frame as f64 / frames as f64;
+        canvas.clear();
+        for (i, (&(x0, y0), &(x1, y1))) in start.iter().zip(&end).enumerate() {
+            let x = x0 as f64 + (x1 as f64 - x0 as f64) * t;
+            let y = y0 as f64 + (y1 as f64 - y0 as f64) * t;
+            let color = colors.map(0.3 + 0.7 * i as f64 / start.len() as f64);
+            let cell = Cell::new('#').with_foreground(color);
+            canvas.set(x.round() as usize, y.round() as usize, cell);
+        }
+
+        let expansion = 1.0 + (MAX_EXPANSION - 1) as f64 * t;
+        let caption = format!("Expansion factor {expansion:.2}");
+        canvas.draw_text(0, height, &caption, Color::White);
+        screen.present(&mut canvas)?;
+    }
+
+    screen.hold(Duration::from_secs(2))
 }
@@ -1,4 +1,28 @@
-use std::collections::HashSet;
+use aoc_utils::collections::FastSet;
+use aoc_utils::render::svg::Svg;
+use aoc_utils::{compress, trace, OverflowError};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 11,
+    title: "Cosmic Expansion",
+    example_answers: [Some("374"), None],
+    real_answers: Some([Some("9312968"), Some("597714117556")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let map = GalaxyMap::expanded(input, 2);
+    Some(format!(
+        "{} galaxies, spanning {}×{} after expanding once",
+        map.galaxies.len(),
+        map.width,
+        map.height
+    ))
+}
 
 /// Solution for part 1.
 pub fn part1(input: &str) -> usize {
@@ -14,7 +38,88 @@ pub fn part2(input: &str) -> usize {
     sum_shortest_distances(galaxies)
 }
 
+/// Like [`part1`] and [`part2`] for any expansion factor, but fails instead of overflowing.
+pub fn checked_sum_of_distances(input: &str, expansion: u128) -> Result<u128, OverflowError> {
+    let (galaxies, _, _) = parse_galaxies(input);
+
+    // Moves each coordinate by the number of empty lines before it.
+    let expand = |coordinates: Vec<usize>| -> Result<Vec<u128>, OverflowError> {
+        // The rank of a coordinate among the occupied ones is the number of occupied lines
+        // before it.
+        let (_, rank) = compress(coordinates.iter().copied());
+        coordinates
+            .into_iter()
+            .map(|c| {
+                let empty = (c - rank[&c]) as u128;
+                let offset = empty.checked_mul(expansion.saturating_sub(1));
+                offset
+                    .and_then(|o| o.checked_add(c as u128))
+                    .ok_or(OverflowError)
+            })
+            .collect()
+    };
+
+    let xs = expand(galaxies.iter().map(|g| g.x).collect())?;
+    let ys = expand(galaxies.iter().map(|g| g.y).collect())?;
+
+    let mut sum = 0u128;
+    for i in 0..xs.len() {
+        for j in i + 1..xs.len() {
+            let distance = xs[i].abs_diff(xs[j]) + ys[i].abs_diff(ys[j]);
+            sum = sum.checked_add(distance).ok_or(OverflowError)?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Gets the `(x, y)` positions of the galaxies after expanding each empty row and column
+/// by the specified factor.
+pub fn expanded_galaxies(input: &str, expansion: usize) -> Vec<(usize, usize)> {
+    GalaxyMap::expanded(input, expansion).galaxies
+}
+
+/// The galaxies of an image after expanding the universe.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GalaxyMap {
+    /// The width of the expanded image.
+    pub width: usize,
+    /// The height of the expanded image.
+    pub height: usize,
+    /// The `(x, y)` positions of the galaxies, in order of their numbers.
+    pub galaxies: Vec<(usize, usize)>,
+}
+
+impl GalaxyMap {
+    /// Parses the image and expands each empty row and column by the specified factor.
+    pub fn expanded(input: &str, expansion: usize) -> Self {
+        let (galaxies, width, height) = parse_galaxies(input);
+        let galaxies: Vec<_> = expand_universe(galaxies, width, height, expansion)
+            .into_iter()
+            .map(|galaxy| (galaxy.x, galaxy.y))
+            .collect();
+
+        // The image ends at the outermost galaxies if the empty border rows and columns are ignored.
+        let width = galaxies.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = galaxies.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        Self {
+            width,
+            height,
+            galaxies,
+        }
+    }
+
+    /// Renders the galaxies as an SVG document, labeled with their numbers.
+    pub fn to_svg(&self) -> String {
+        let mut svg = Svg::new(self.width, self.height, 8);
+        for (i, &(x, y)) in self.galaxies.iter().enumerate() {
+            svg.point(x, y, "goldenrod", Some(&(i + 1).to_string()));
+        }
+        svg.to_string()
+    }
+}
+
 fn parse_galaxies(input: &str) -> (Vec<Galaxy>, usize, usize) {
+    let _span = trace::parse();
     let mut galaxies = Vec::new();
     let mut height = 0;
     let mut width = 0;
@@ -55,10 +160,10 @@ fn expand_universe(
     //               For a 10-fold increase we add 9 to the existing.
     let expansion = expansion - 1;
 
-    let rows: HashSet<usize> = HashSet::from_iter(0..height);
-    let columns: HashSet<usize> = HashSet::from_iter(0..width);
-    let observed_rows = HashSet::from_iter(galaxies.iter().map(|g| g.y));
-    let observed_columns = HashSet::from_iter(galaxies.iter().map(|g| g.x));
+    let rows: FastSet<usize> = (0..height).collect();
+    let columns: FastSet<usize> = (0..width).collect();
+    let observed_rows: FastSet<usize> = galaxies.iter().map(|g| g.y).collect();
+    let observed_columns: FastSet<usize> = galaxies.iter().map(|g| g.x).collect();
 
     // Find rows that contain no galaxies and expand their height.
     // We do this by adding the required y increment to all galaxies below it.
@@ -83,14 +188,65 @@ fn expand_universe(
     galaxies
 }
 
+/// Brute-force variant of [`part1`] that inserts the additional empty rows and columns into
+/// the image before summing up the distances between all pairs of galaxies.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn part1_bf(input: &str) -> usize {
+    sum_distances_bf(input, 2)
+}
+
+/// Brute-force variant of [`part1`] and [`part2`] for any expansion factor, inserting the
+/// additional empty rows and columns into the image.
+///
+/// The expanded image is kept in memory, so this is only feasible for small factors.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn sum_distances_bf(input: &str, expansion: usize) -> usize {
+    let mut image: Vec<Vec<char>> = Vec::new();
+    for line in input.lines().map(|line| line.trim()) {
+        let row: Vec<char> = line.chars().collect();
+        let copies = if row.contains(&'#') { 1 } else { expansion };
+        image.extend(std::iter::repeat_n(row, copies));
+    }
+
+    let width = image.first().map_or(0, |row| row.len());
+    for x in (0..width).rev() {
+        if image.iter().all(|row| row[x] != '#') {
+            for row in &mut image {
+                let copies = std::iter::repeat_n('.', expansion - 1);
+                row.splice(x..x, copies);
+            }
+        }
+    }
+
+    let galaxies: Vec<(usize, usize)> = image
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            let columns = row.iter().enumerate().filter(|(_, &c)| c == '#');
+            columns.map(move |(x, _)| (x, y))
+        })
+        .collect();
+
+    let mut sum = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            sum += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+        }
+    }
+    sum
+}
+
 fn sum_shortest_distances(galaxies: Vec<Galaxy>) -> usize {
     let mut distance_sum = 0;
     let galaxies = galaxies.as_slice();
-    for (i, galaxy) in galaxies[..galaxies.len() - 1].iter().enumerate() {
+    for (i, galaxy) in galaxies[..galaxies.len().saturating_sub(1)]
+        .iter()
+        .enumerate()
+    {
         for other in &galaxies[(i + 1)..] {
             // Calculate taxicab/Manhattan distance.
-            let dx = galaxy.x.max(other.x) - galaxy.x.min(other.x);
-            let dy = galaxy.y.max(other.y) - galaxy.y.min(other.y);
+            let dx = galaxy.x.abs_diff(other.x);
+            let dy = galaxy.y.abs_diff(other.y);
             let distance = dx + dy;
             distance_sum += distance;
         }
@@ -108,6 +264,48 @@ struct Galaxy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::property::{assert_equivalent, grid};
+
+    #[test]
+    fn test_checked_sum_of_distances() {
+        const INPUT: &str = "...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....";
+        assert_eq!(checked_sum_of_distances(INPUT, 2), Ok(374));
+        assert_eq!(checked_sum_of_distances(INPUT, 100), Ok(8410));
+        assert_eq!(
+            checked_sum_of_distances(INPUT, 1 << 125),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            checked_sum_of_distances(INPUT, 1 << 64),
+            Ok(82 * (1u128 << 64) + 374 - 2 * 82)
+        );
+    }
+
+    #[test]
+    fn test_brute_force() {
+        assert_equivalent(
+            300,
+            |(input, expansion): &(String, usize)| sum_distances_bf(input, *expansion),
+            |(input, expansion): &(String, usize)| {
+                let (galaxies, width, height) = parse_galaxies(input);
+                sum_shortest_distances(expand_universe(galaxies, width, height, *expansion))
+            },
+            |rng| {
+                let (width, height) = (rng.index(1..10), rng.index(1..10));
+                let input = grid(rng, width, height, &['.', '.', '.', '#']);
+                (input, rng.index(1..5))
+            },
+        );
+    }
 
     #[test]
     fn test_part1() {
@@ -125,6 +323,21 @@ mod tests {
         assert_eq!(part1(INPUT), 374);
     }
 
+    #[test]
+    fn test_expanded_galaxies() {
+        const INPUT: &str = "#..
+            ...
+            ..#";
+        assert_eq!(expanded_galaxies(INPUT, 1), [(0, 0), (2, 2)]);
+        assert_eq!(expanded_galaxies(INPUT, 3), [(0, 0), (4, 4)]);
+
+        let map = GalaxyMap::expanded(INPUT, 3);
+        assert_eq!((map.width, map.height), (5, 5));
+        let svg = map.to_svg();
+        assert!(svg.contains(r#"<circle cx="36" cy="36""#));
+        assert!(svg.contains(">2</text>"));
+    }
+
     #[test]
     fn test_part2() {
         const INPUT: &str = "...#......
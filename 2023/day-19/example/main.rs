@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2023_day_19;
+    "Sum of ratings of all accepted parts: {}" => part1,
+    "Number of accepted rating combinations: {}" => part2,
+);
@@ -0,0 +1,759 @@
+use aoc_utils::trace;
+use aoc_utils::RangeSet;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 19,
+    title: "Aplenty",
+    example_answers: [Some("19114"), Some("167409079868000")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (workflows, parts) = parse_input(input).ok()?;
+    let accepted = parts
+        .iter()
+        .filter(|part| workflows.accepts(part) == Ok(true))
+        .count();
+    Some(format!(
+        "{} workflows, {} parts, {accepted} of them accepted",
+        workflows.len(),
+        parts.len()
+    ))
+}
+
+/// The name of the workflow every part starts in.
+pub const START_WORKFLOW: &str = "in";
+
+/// The smallest possible rating of a category (inclusive).
+pub const MIN_RATING: u64 = 1;
+
+/// The largest possible rating of a category (inclusive).
+pub const MAX_RATING: u64 = 4000;
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> u64 {
+    let (workflows, parts) = parse_input(input).expect("invalid input");
+    parts
+        .iter()
+        .filter(|part| workflows.accepts(part).expect("invalid workflows"))
+        .map(Part::total_rating)
+        .sum()
+}
+
+/// Solution for part 2.
+pub fn part2(input: &str) -> u64 {
+    let (workflows, _) = parse_input(input).expect("invalid input");
+    workflows
+        .count_accepted(PartRanges::full())
+        .expect("invalid workflows")
+}
+
+/// Parses the workflows and the part ratings from the puzzle input.
+pub fn parse_input(input: &str) -> Result<(WorkflowGraph, Vec<Part>), ParseInputError> {
+    let _span = trace::parse();
+    let mut lines = input.lines().map(|line| line.trim());
+
+    let workflows: WorkflowGraph = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .map(Workflow::from_str)
+        .collect::<Result<_, _>>()?;
+
+    let parts = lines
+        .filter(|line| !line.is_empty())
+        .map(Part::from_str)
+        .collect::<Result<_, _>>()?;
+
+    Ok((workflows, parts))
+}
+
+/// A rating category of a [`Part`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Category {
+    /// Extremely cool looking.
+    X,
+    /// Musical (it makes a noise when you hit it).
+    M,
+    /// Aerodynamic.
+    A,
+    /// Shiny.
+    S,
+}
+
+/// A machine part with its four ratings.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Part {
+    pub x: u64,
+    pub m: u64,
+    pub a: u64,
+    pub s: u64,
+}
+
+/// The comparison operator of a [`Condition`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Comparison {
+    /// The rating must be less than the value.
+    LessThan,
+    /// The rating must be greater than the value.
+    GreaterThan,
+}
+
+/// A condition on a single category, e.g. `a<2006`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Condition {
+    pub category: Category,
+    pub comparison: Comparison,
+    pub value: u64,
+}
+
+/// Where a part is sent to after a [`Rule`] matched.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Target {
+    /// The part is accepted (`A`).
+    Accept,
+    /// The part is rejected (`R`).
+    Reject,
+    /// The part is sent to the named workflow.
+    Workflow(String),
+}
+
+/// A single rule of a [`Workflow`]. Rules without a condition always match.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Rule {
+    pub condition: Option<Condition>,
+    pub target: Target,
+}
+
+/// A named list of rules, evaluated in order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Workflow {
+    pub name: String,
+    pub rules: Vec<Rule>,
+}
+
+/// All workflows, indexed by their name. Each [`Target::Workflow`] is an edge in this graph.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WorkflowGraph {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
+    workflows: HashMap<String, Workflow>,
+}
+
+/// Serializes the workflows ordered by their name.
+#[cfg(feature = "serde")]
+fn serialize_sorted<S>(
+    workflows: &HashMap<String, Workflow>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(
+        workflows
+            .iter()
+            .collect::<std::collections::BTreeMap<_, _>>(),
+    )
+}
+
+/// The ratings of a set of parts, given as one range set per category.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartRanges {
+    pub x: RangeSet<u64>,
+    pub m: RangeSet<u64>,
+    pub a: RangeSet<u64>,
+    pub s: RangeSet<u64>,
+}
+
+impl Part {
+    /// Gets the rating of the specified category.
+    pub fn rating(&self, category: Category) -> u64 {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+
+    /// Gets the sum of all ratings.
+    pub fn total_rating(&self) -> u64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+impl Condition {
+    /// Determines whether the part satisfies this condition.
+    pub fn matches(&self, part: &Part) -> bool {
+        let rating = part.rating(self.category);
+        match self.comparison {
+            Comparison::LessThan => rating < self.value,
+            Comparison::GreaterThan => rating > self.value,
+        }
+    }
+
+    /// Splits the ranges into the subset satisfying this condition and the remainder.
+    pub fn split(&self, ranges: &PartRanges) -> (PartRanges, PartRanges) {
+        let ratings = ranges.get(self.category);
+        let (below, above) = match self.comparison {
+            Comparison::LessThan => ratings.split_at(self.value),
+            Comparison::GreaterThan => match self.value.checked_add(1) {
+                Some(value) => ratings.split_at(value),
+                // No rating is greater than the largest value.
+                None => (ratings.clone(), RangeSet::new()),
+            },
+        };
+
+        let (matching, remainder) = match self.comparison {
+            Comparison::LessThan => (below, above),
+            Comparison::GreaterThan => (above, below),
+        };
+
+        (
+            ranges.with(self.category, matching),
+            ranges.with(self.category, remainder),
+        )
+    }
+}
+
+impl Workflow {
+    /// Determines the target of the first rule matching the part.
+    pub fn target(&self, part: &Part) -> &Target {
+        self.rules
+            .iter()
+            .find(|rule| {
+                rule.condition
+                    .is_none_or(|condition| condition.matches(part))
+            })
+            .map(|rule| &rule.target)
+            .expect("workflow has no fallback rule")
+    }
+}
+
+impl WorkflowGraph {
+    /// Gets the workflow with the specified name.
+    pub fn get(&self, name: &str) -> Option<&Workflow> {
+        self.workflows.get(name)
+    }
+
+    /// Gets the number of workflows.
+    pub fn len(&self) -> usize {
+        self.workflows.len()
+    }
+
+    /// Determines whether the graph contains no workflows.
+    pub fn is_empty(&self) -> bool {
+        self.workflows.is_empty()
+    }
+
+    /// Iterates all workflows in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &Workflow> + '_ {
+        self.workflows.values()
+    }
+
+    /// Runs the part through the workflows, starting at [`START_WORKFLOW`].
+    ///
+    /// Fails if the part reaches an unknown workflow, or one it already passed through.
+    pub fn accepts(&self, part: &Part) -> Result<bool, WorkflowError> {
+        let mut visited = HashSet::new();
+        let mut name = START_WORKFLOW;
+        loop {
+            if !visited.insert(name) {
+                return Err(WorkflowError::Cycle(name.to_string()));
+            }
+            match self.workflow(name)?.target(part) {
+                Target::Accept => return Ok(true),
+                Target::Reject => return Ok(false),
+                Target::Workflow(next) => name = next,
+            }
+        }
+    }
+
+    /// Counts all rating combinations within the ranges that end up accepted.
+    ///
+    /// Instead of evaluating each part individually, the ranges are pushed through the
+    /// rule tree as a whole: every condition splits the current ranges into a matching
+    /// and a non-matching subset, which are then followed independently.
+    ///
+    /// Fails if any of the parts reaches an unknown workflow, or one it already passed through.
+    pub fn count_accepted(&self, ranges: PartRanges) -> Result<u64, WorkflowError> {
+        self.count_accepted_from(START_WORKFLOW, ranges, &mut Vec::new())
+    }
+
+    /// Counts the accepted combinations, with `path` holding the workflows that led here.
+    fn count_accepted_from<'a>(
+        &'a self,
+        name: &'a str,
+        mut ranges: PartRanges,
+        path: &mut Vec<&'a str>,
+    ) -> Result<u64, WorkflowError> {
+        if ranges.is_empty() {
+            return Ok(0);
+        }
+        if path.contains(&name) {
+            return Err(WorkflowError::Cycle(name.to_string()));
+        }
+        let workflow = self.workflow(name)?;
+        path.push(name);
+
+        let mut count = 0;
+        for rule in &workflow.rules {
+            if ranges.is_empty() {
+                break;
+            }
+
+            let matching = match rule.condition {
+                None => std::mem::replace(&mut ranges, PartRanges::empty()),
+                Some(condition) => {
+                    let (matching, remainder) = condition.split(&ranges);
+                    ranges = remainder;
+                    matching
+                }
+            };
+
+            count += match &rule.target {
+                Target::Accept => matching.combinations(),
+                Target::Reject => 0,
+                Target::Workflow(next) => self.count_accepted_from(next, matching, path)?,
+            };
+        }
+
+        path.pop();
+        Ok(count)
+    }
+
+    fn workflow(&self, name: &str) -> Result<&Workflow, WorkflowError> {
+        self.get(name)
+            .ok_or_else(|| WorkflowError::UnknownWorkflow(name.to_string()))
+    }
+}
+
+impl PartRanges {
+    /// All ratings from [`MIN_RATING`] to [`MAX_RATING`] in every category.
+    pub fn full() -> Self {
+        let range = RangeSet::from(MIN_RATING..MAX_RATING + 1);
+        Self {
+            x: range.clone(),
+            m: range.clone(),
+            a: range.clone(),
+            s: range,
+        }
+    }
+
+    /// No ratings at all.
+    pub fn empty() -> Self {
+        Self {
+            x: RangeSet::new(),
+            m: RangeSet::new(),
+            a: RangeSet::new(),
+            s: RangeSet::new(),
+        }
+    }
+
+    /// Gets the ratings of the specified category.
+    pub fn get(&self, category: Category) -> &RangeSet<u64> {
+        match category {
+            Category::X => &self.x,
+            Category::M => &self.m,
+            Category::A => &self.a,
+            Category::S => &self.s,
+        }
+    }
+
+    /// Returns a copy of these ranges with the specified category replaced.
+    pub fn with(&self, category: Category, ratings: RangeSet<u64>) -> Self {
+        let mut ranges = self.clone();
+        match category {
+            Category::X => ranges.x = ratings,
+            Category::M => ranges.m = ratings,
+            Category::A => ranges.a = ratings,
+            Category::S => ranges.s = ratings,
+        }
+        ranges
+    }
+
+    /// Determines whether any category is empty, i.e. there are no combinations.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.m.is_empty() || self.a.is_empty() || self.s.is_empty()
+    }
+
+    /// Gets the number of distinct rating combinations.
+    pub fn combinations(&self) -> u64 {
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+    }
+}
+
+impl FromIterator<Workflow> for WorkflowGraph {
+    fn from_iter<T: IntoIterator<Item = Workflow>>(iter: T) -> Self {
+        Self {
+            workflows: iter
+                .into_iter()
+                .map(|workflow| (workflow.name.clone(), workflow))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = ParseWorkflowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, rules) = s
+            .strip_suffix('}')
+            .and_then(|s| s.split_once('{'))
+            .ok_or(ParseWorkflowError("Invalid workflow format"))?;
+
+        if name.is_empty() {
+            return Err(ParseWorkflowError("Missing workflow name"));
+        }
+
+        let rules: Vec<Rule> = rules
+            .split(',')
+            .map(Rule::from_str)
+            .collect::<Result<_, _>>()?;
+        match rules.last() {
+            Some(Rule {
+                condition: None, ..
+            }) => {}
+            _ => return Err(ParseWorkflowError("Missing fallback rule")),
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            rules,
+        })
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseWorkflowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((condition, target)) = s.split_once(':') else {
+            return Ok(Self {
+                condition: None,
+                target: Target::from_str(s)?,
+            });
+        };
+
+        Ok(Self {
+            condition: Some(Condition::from_str(condition)?),
+            target: Target::from_str(target)?,
+        })
+    }
+}
+
+impl FromStr for Condition {
+    type Err = ParseWorkflowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let category = chars
+            .next()
+            .ok_or(ParseWorkflowError("Empty condition"))
+            .and_then(Category::try_from)?;
+
+        let comparison = match chars.next() {
+            Some('<') => Comparison::LessThan,
+            Some('>') => Comparison::GreaterThan,
+            _ => return Err(ParseWorkflowError("Invalid comparison operator")),
+        };
+
+        let value = chars
+            .as_str()
+            .parse()
+            .map_err(|_| ParseWorkflowError("Invalid condition value"))?;
+
+        Ok(Self {
+            category,
+            comparison,
+            value,
+        })
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseWorkflowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Self::Accept),
+            "R" => Ok(Self::Reject),
+            "" => Err(ParseWorkflowError("Empty target")),
+            name => Ok(Self::Workflow(name.to_string())),
+        }
+    }
+}
+
+impl TryFrom<char> for Category {
+    type Error = ParseWorkflowError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Self::X),
+            'm' => Ok(Self::M),
+            'a' => Ok(Self::A),
+            's' => Ok(Self::S),
+            _ => Err(ParseWorkflowError("Invalid category")),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ratings = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or(ParsePartError("Invalid part format"))?;
+
+        let mut part = Part {
+            x: 0,
+            m: 0,
+            a: 0,
+            s: 0,
+        };
+
+        for rating in ratings.split(',') {
+            let (category, value) = rating
+                .split_once('=')
+                .ok_or(ParsePartError("Invalid rating format"))?;
+            let value = value
+                .parse()
+                .map_err(|_| ParsePartError("Invalid rating value"))?;
+            match category {
+                "x" => part.x = value,
+                "m" => part.m = value,
+                "a" => part.a = value,
+                "s" => part.s = value,
+                _ => return Err(ParsePartError("Invalid category")),
+            }
+        }
+
+        Ok(part)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseWorkflowError(&'static str);
+
+impl Display for ParseWorkflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse workflow: {}", self.0)
+    }
+}
+
+impl Error for ParseWorkflowError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParsePartError(&'static str);
+
+impl Display for ParsePartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse part: {}", self.0)
+    }
+}
+
+impl Error for ParsePartError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseInputError {
+    Workflow(ParseWorkflowError),
+    Part(ParsePartError),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseInputError::Workflow(e) => write!(f, "{e}"),
+            ParseInputError::Part(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+/// An error running parts through the workflows.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WorkflowError {
+    /// A rule sends parts to a workflow that doesn't exist.
+    UnknownWorkflow(String),
+    /// Parts are sent back to a workflow they already passed through, and would never leave.
+    Cycle(String),
+}
+
+impl Display for WorkflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkflowError::UnknownWorkflow(name) => write!(f, "Unknown workflow {name}"),
+            WorkflowError::Cycle(name) => write!(f, "Parts return to workflow {name}"),
+        }
+    }
+}
+
+impl Error for WorkflowError {}
+
+impl From<ParseWorkflowError> for ParseInputError {
+    fn from(value: ParseWorkflowError) -> Self {
+        Self::Workflow(value)
+    }
+}
+
+impl From<ParsePartError> for ParseInputError {
+    fn from(value: ParsePartError) -> Self {
+        Self::Part(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
+        pv{a>1716:R,A}
+        lnx{m>1548:A,A}
+        rfg{s<537:gd,x>2440:R,A}
+        qs{s>3448:A,lnx}
+        qkq{x<1416:A,crn}
+        crn{x>2662:A,R}
+        in{s<1351:px,qqz}
+        qqz{s>2770:qs,m<1801:hdj,R}
+        gd{a>3333:R,R}
+        hdj{m>838:A,pv}
+
+        {x=787,m=2655,a=1222,s=2876}
+        {x=1679,m=44,a=2067,s=496}
+        {x=2036,m=264,a=79,s=2244}
+        {x=2461,m=1339,a=466,s=291}
+        {x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_parse_workflow() {
+        let workflow: Workflow = "px{a<2006:qkq,m>2090:A,rfg}"
+            .parse()
+            .expect("failed to parse workflow");
+        assert_eq!(workflow.name, "px");
+        assert_eq!(workflow.rules.len(), 3);
+        assert_eq!(
+            workflow.rules[0],
+            Rule {
+                condition: Some(Condition {
+                    category: Category::A,
+                    comparison: Comparison::LessThan,
+                    value: 2006
+                }),
+                target: Target::Workflow(String::from("qkq"))
+            }
+        );
+        assert_eq!(workflow.rules[1].target, Target::Accept);
+        assert_eq!(
+            workflow.rules[2],
+            Rule {
+                condition: None,
+                target: Target::Workflow(String::from("rfg"))
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_workflow_without_fallback() {
+        assert_eq!(
+            Workflow::from_str("px{a<2006:qkq}"),
+            Err(ParseWorkflowError("Missing fallback rule"))
+        );
+    }
+
+    #[test]
+    fn test_parse_part() {
+        let part: Part = "{x=787,m=2655,a=1222,s=2876}"
+            .parse()
+            .expect("failed to parse part");
+        assert_eq!(
+            part,
+            Part {
+                x: 787,
+                m: 2655,
+                a: 1222,
+                s: 2876
+            }
+        );
+        assert_eq!(part.total_rating(), 7540);
+    }
+
+    #[test]
+    fn test_accepts() {
+        let (workflows, parts) = parse_input(EXAMPLE).expect("failed to parse input");
+        assert_eq!(workflows.len(), 11);
+        let accepted: Vec<_> = parts.iter().map(|part| workflows.accepts(part)).collect();
+        assert_eq!(
+            accepted,
+            [Ok(true), Ok(false), Ok(true), Ok(false), Ok(true)]
+        );
+    }
+
+    #[test]
+    fn test_split_at_largest_value() {
+        let condition: Condition = "x>18446744073709551615".parse().expect("invalid condition");
+        let (matching, remainder) = condition.split(&PartRanges::full());
+        assert!(matching.is_empty());
+        assert_eq!(remainder, PartRanges::full());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let (workflows, parts) = parse_input("in{x<10:a,A}\na{m>5:in,R}\n\n{x=1,m=9,a=1,s=1}")
+            .expect("failed to parse input");
+        assert_eq!(
+            workflows.accepts(&parts[0]),
+            Err(WorkflowError::Cycle(String::from("in")))
+        );
+        assert_eq!(
+            workflows.count_accepted(PartRanges::full()),
+            Err(WorkflowError::Cycle(String::from("in")))
+        );
+
+        // Workflows that form a cycle no part can complete are fine.
+        let (workflows, _) =
+            parse_input("in{x<10:a,A}\na{x>20:in,R}").expect("failed to parse input");
+        assert_eq!(
+            workflows.count_accepted(PartRanges::full()),
+            Ok(3991 * 4000 * 4000 * 4000)
+        );
+        assert_eq!(
+            workflows.count_accepted(PartRanges::full().with(Category::X, RangeSet::from(1..2))),
+            Ok(0)
+        );
+        assert_eq!(
+            parse_input("in{x<10:b,A}").map(|(w, _)| w.count_accepted(PartRanges::full())),
+            Ok(Err(WorkflowError::UnknownWorkflow(String::from("b"))))
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 19114);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 167409079868000);
+    }
+}
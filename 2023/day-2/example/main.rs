@@ -1,24 +1,17 @@
-use aoc_2023_day_2::{Game, SetOfCubes};
-
-const INPUT: &str = include_str!("../input.txt");
-const GIVEN: SetOfCubes = SetOfCubes::rgb(12, 13, 14);
-
-fn main() {
-    println!("2023 Day 2: Cube Conundrum");
-
-    let games: Vec<_> = Game::iter_games(INPUT.lines())
-        .map(|g| g.expect("found invalid game"))
-        .collect();
-
-    let sum_of_possible_game_ids: u32 = Game::filter_playable_games(games.iter(), &GIVEN)
-        .map(Game::game_number)
-        .sum();
-    println!("The sum of all possible game IDs is: {sum_of_possible_game_ids}");
-
-    let power_of_smallest: u32 = games
-        .iter()
-        .map(|g| g.smallest_set_needed())
-        .map(|g| g.power())
-        .sum();
-    println!("The total power of all smallest sets is: {power_of_smallest}");
-}
+aoc_utils::aoc_main!(aoc_2023_day_2;
+    "The sum of all possible game IDs is: {}"
+        => |input| {
+            const GIVEN: SetOfCubes = SetOfCubes::rgb(12, 13, 14);
+            let games = Game::iter_games(input.lines()).map(|g| g.expect("found invalid game"));
+            Game::filter_playable_games(games, &GIVEN)
+                .map(|g| g.game_number())
+                .sum::<u32>()
+        },
+    "The total power of all smallest sets is: {}"
+        => |input| {
+            Game::iter_games(input.lines())
+                .map(|g| g.expect("found invalid game"))
+                .map(|g| g.smallest_set_needed().power())
+                .sum::<u32>()
+        },
+);
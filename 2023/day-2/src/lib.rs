@@ -1,21 +1,63 @@
+use aoc_utils::trace;
 use std::borrow::Borrow;
-use std::collections::Bound;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::ops::{Add, AddAssign, RangeBounds};
+use std::ops::{Add, AddAssign};
 use std::str::FromStr;
 
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 2,
+    title: "Cube Conundrum",
+    example_answers: [Some("8"), Some("2286")],
+    real_answers: Some([Some("2447"), Some("56322")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let games = parse_games(input).ok()?;
+    let given = SetOfCubes::rgb(12, 13, 14);
+    let possible = games.iter().filter(|game| game.is_possible(&given)).count();
+    Some(format!(
+        "{} games, {possible} of them possible with 12 red, 13 green and 14 blue cubes",
+        games.len()
+    ))
+}
+
+/// Parses the games, one per non-empty line.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseGameError> {
+    let _span = trace::parse();
+    let lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+    Game::iter_games(lines).collect()
+}
+
 /// A game.
 #[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Game {
     /// The number of the game.
     game_no: u32,
     /// The sets of cubes drawn from the bag.
-    draws: Vec<SetOfCubes>,
+    draws: Vec<Draw>,
+}
+
+/// A set of cubes drawn from the bag.
+pub type Draw = SetOfCubes;
+
+/// The color of a cube.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
 }
 
 /// A number of colored cubes drawn from the bag.
 #[derive(Debug, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SetOfCubes {
     /// The number of red cubes drawn.
     red: u32,
@@ -76,6 +118,34 @@ impl Game {
         self.game_no
     }
 
+    /// Gets the sets of cubes drawn from the bag, in the order they were drawn.
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    /// Lazily parses a list of draws, e.g. `4 blue, 3 red; 1 red, 8 green`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_2::{Color, Game};
+    ///
+    /// let mut draws = Game::iter_draws("4 blue, 3 red; 1 red, 8 green");
+    /// let draw = draws.next().unwrap().unwrap();
+    /// assert_eq!(draw.count(Color::Blue), 4);
+    /// assert_eq!(draw.count(Color::Green), 0);
+    /// assert_eq!(draws.count(), 1);
+    /// ```
+    pub fn iter_draws(draws: &str) -> impl Iterator<Item = Result<Draw, ParseGameError>> + '_ {
+        // An empty list has no draws, rather than a single empty one.
+        let draws = draws.trim();
+        (!draws.is_empty())
+            .then(|| draws.split(';'))
+            .into_iter()
+            .flatten()
+            .map(Draw::from_str)
+    }
+
     /// Checks if the given draw is available in the list of cube sets.
     ///
     /// # Arguments
@@ -132,76 +202,6 @@ impl Game {
                 )
             })
     }
-
-    /// Parses a list of cube draw sets, e.g. `4 blue, 3 red; 1 red, 8 green` into a vector of
-    /// [`SetOfCubes`] of these colors. Used by the [`FromStr`] implementation of [`Game`].
-    ///
-    /// # Arguments
-    /// * `draw_section` - The string slice that contains only the color definition.
-    ///
-    /// # Returns
-    /// A [`Vec<SetOfCubes>`] or a [`ParseGameError`] if the set definition was invalid.
-    fn parse_game_cube_draws(
-        s: &str,
-        game_separator: usize,
-    ) -> Result<Vec<SetOfCubes>, ParseGameError> {
-        let mut draws = Vec::new();
-        let mut section_begin = game_separator + 1;
-        while section_begin < s.len() {
-            let section_end = find_in_range(s, section_begin.., ';').unwrap_or(s.len());
-            let draw_section = s[section_begin..section_end].trim();
-            let draw = Self::parse_cube_set_draw(draw_section)?;
-
-            draws.push(draw);
-            section_begin = section_end + 1;
-        }
-        Ok(draws)
-    }
-
-    /// Parses a set of drawn cubes, e.g. `4 blue, 3 red` into a [`SetOfCubes`] containing
-    /// these colors. Used by [`parse_game_cube_draws`].
-    ///
-    /// # Arguments
-    /// * `draw_section` - The string slice that contains only the color definition.
-    ///
-    /// # Returns
-    /// A [`SetOfCubes`] or a [`ParseGameError`] if the draw definition was invalid.
-    fn parse_cube_set_draw(draw_section: &str) -> Result<SetOfCubes, ParseGameError> {
-        let mut draw = SetOfCubes::default();
-        let mut color_begin = 0;
-        while color_begin < draw_section.len() {
-            let color_end =
-                find_in_range(draw_section, color_begin.., ',').unwrap_or(draw_section.len());
-            let color_section = draw_section[color_begin..color_end].trim();
-            draw += Self::parse_color_section(color_section)?;
-            color_begin = color_end + 1;
-        }
-        Ok(draw)
-    }
-
-    /// Parses a color section, e.g. `3 red` into a [`SetOfCubes`] containing only
-    /// that color. Used by [`parse_cube_set_draw`].
-    ///
-    /// # Arguments
-    /// * `color_section` - The string slice that contains only the color definition.
-    ///
-    /// # Returns
-    /// A [`SetOfCubes`] or a [`ParseGameError`] if the color definition was invalid.
-    fn parse_color_section(color_section: &str) -> Result<SetOfCubes, ParseGameError> {
-        let count_end = find_in_range(color_section, 0.., ' ')
-            .ok_or(ParseGameError("invalid draw definition"))?;
-        let num_cubes_drawn: u32 = color_section[..count_end]
-            .parse()
-            .map_err(|_e| ParseGameError("invalid draw count definition"))?;
-
-        let color = match &color_section[(count_end + 1)..] {
-            "red" => SetOfCubes::rgb(num_cubes_drawn, 0, 0),
-            "green" => SetOfCubes::rgb(0, num_cubes_drawn, 0),
-            "blue" => SetOfCubes::rgb(0, 0, num_cubes_drawn),
-            _ => return Err(ParseGameError("Invalid color name")),
-        };
-        Ok(color)
-    }
 }
 
 impl SetOfCubes {
@@ -209,6 +209,15 @@ impl SetOfCubes {
         Self { red, green, blue }
     }
 
+    /// Gets the number of cubes of a color.
+    pub const fn count(&self, color: Color) -> u32 {
+        match color {
+            Color::Red => self.red,
+            Color::Green => self.green,
+            Color::Blue => self.blue,
+        }
+    }
+
     /// Calculates the power of this set, i.e. the product of all cube colors.
     pub const fn power(&self) -> u32 {
         self.red * self.green * self.blue
@@ -219,74 +228,57 @@ impl FromStr for Game {
     type Err = ParseGameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Ensure there are no multi-byte characters so we can fiddle with the bytes directly.
-        if !s.is_ascii() {
-            return Err(ParseGameError("found non-ASCII characters"));
-        }
-
-        let s = s.trim_start();
-        if &s[..5] != "Game " {
-            return Err(ParseGameError("preamble missing"));
-        }
+        let s = s
+            .trim_start()
+            .strip_prefix("Game ")
+            .ok_or(ParseGameError("preamble missing"))?;
 
         // Parse the game number.
-        let game_separator = find_in_range(s, 5.., ':').ok_or(ParseGameError("missing colon"))?;
-        let game_no: u32 = s[5..game_separator]
+        let (game_no, draws) = s.split_once(':').ok_or(ParseGameError("missing colon"))?;
+        let game_no: u32 = game_no
             .parse()
             .map_err(|_e| ParseGameError("invalid game number"))?;
 
-        let draws = Self::parse_game_cube_draws(s, game_separator)?;
-
+        let draws = Self::iter_draws(draws).collect::<Result<_, _>>()?;
         Ok(Self { game_no, draws })
     }
 }
 
-/// Finds the index of the first occurrence of a given `pattern` character in the `input` string.
-/// The search is restricted to the given `search_range` bounds, represented by a `RangeBounds<usize>` object.
-///
-/// # Arguments
-///
-/// * `input` - The input string to search in.
-/// * `search_range` - The range within which to search for the pattern.
-/// * `pattern` - The character to search for.
-///
-/// # Returns
-///
-/// * If the pattern is found within the search range, returns the index of the first occurrence of the pattern character.
-/// * If the search range is empty or the pattern is not found, returns `None`.
-///
-/// # Examples
-///
-/// ```
-/// use std::ops::Bound;
-/// use aoc_2023_day_2::find_in_range;
-///
-/// let input = "Hello, world!";
-/// let search_range = 0..5; // Search only in the first 5 characters
-/// let pattern = 'o';
-///
-/// let result = find_in_range(input, search_range, pattern);
-/// assert_eq!(result, Some(4));
-/// ```
-pub fn find_in_range<R: RangeBounds<usize>>(
-    input: &str,
-    search_range: R,
-    pattern: char,
-) -> Option<usize> {
-    let start = match search_range.start_bound() {
-        Bound::Included(x) => *x,
-        Bound::Excluded(_) => unreachable!(),
-        Bound::Unbounded => 0,
-    };
-    let end = match search_range.end_bound() {
-        Bound::Included(x) => *x,
-        Bound::Excluded(x) => *x - 1,
-        Bound::Unbounded => input.len() - 1,
-    };
-    if start >= input.len() {
-        return None;
+impl FromStr for SetOfCubes {
+    type Err = ParseGameError;
+
+    /// Parses a set of drawn cubes, e.g. `4 blue, 3 red`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .try_fold(SetOfCubes::default(), |draw, section| {
+                let (count, color) = section
+                    .trim()
+                    .split_once(' ')
+                    .ok_or(ParseGameError("invalid draw definition"))?;
+                let count: u32 = count
+                    .parse()
+                    .map_err(|_e| ParseGameError("invalid draw count definition"))?;
+                let color = match color.parse()? {
+                    Color::Red => SetOfCubes::rgb(count, 0, 0),
+                    Color::Green => SetOfCubes::rgb(0, count, 0),
+                    Color::Blue => SetOfCubes::rgb(0, 0, count),
+                };
+                Ok(draw + color)
+            })
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(ParseGameError("Invalid color name")),
+        }
     }
-    input[start..=end].find(pattern).map(|idx| idx + start)
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -453,10 +445,29 @@ mod tests {
     }
 
     #[test]
-    fn test_find_index() {
-        assert_eq!(find_in_range("abcdef", 0.., 'c'), Some(2));
-        assert_eq!(find_in_range("abcdef", 2.., 'c'), Some(2));
-        assert_eq!(find_in_range("abcdef", 3.., 'c'), None);
+    fn test_draws() {
+        let game = Game::from_str("Game 7: 3 blue, 4 red; 2 green, 1 blue, 2 green; 5 red")
+            .expect("failed to parse game");
+        assert_eq!(
+            game.draws(),
+            [
+                SetOfCubes::rgb(4, 0, 3),
+                SetOfCubes::rgb(0, 4, 1),
+                SetOfCubes::rgb(5, 0, 0)
+            ]
+        );
+        assert_eq!(game.draws()[1].count(Color::Green), 4);
+
+        let game = Game::from_str("Game 8:").expect("failed to parse game");
+        assert!(game.draws().is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_game() {
+        assert!(Game::from_str("Game").is_err());
+        assert!(Game::from_str("Game 1: , blue").is_err());
+        assert!(Game::from_str("Game 1: 3 blue; 2 yellow").is_err());
+        assert!(Game::from_str("Game 1: 3 blue;").is_err());
     }
 
     #[test]
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2023_day_20;
+    "Product of low and high pulse counts: {}" => part1,
+    "Button presses until rx receives a low pulse: {}" or "The network has no rx module" => part2,
+);
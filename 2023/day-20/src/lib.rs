@@ -0,0 +1,558 @@
+use aoc_utils::lcm_slice;
+use aoc_utils::trace;
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 20,
+    title: "Pulse Propagation",
+    example_answers: [Some("32000000"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let network: Network = input.parse().ok()?;
+    let conjunctions = network
+        .modules()
+        .filter(|(_, module)| matches!(module.kind(), ModuleKind::Conjunction { .. }))
+        .count();
+    Some(format!(
+        "{} modules, {conjunctions} of them conjunctions",
+        network.modules().count()
+    ))
+}
+
+/// The name of the module receiving the button's pulse.
+pub const BROADCASTER: &str = "broadcaster";
+
+/// The name of the module that needs to receive a low pulse in part 2.
+pub const RX: &str = "rx";
+
+/// The number of button presses within which every sub-cycle of part 2 must have completed.
+///
+/// The cycles of the puzzle inputs are all a bit below 2<sup>12</sup>.
+pub const MAX_CYCLE_PRESSES: usize = 1 << 12;
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> usize {
+    let mut network = Network::from_str(input).expect("invalid input");
+
+    let mut counts = PulseCounts::default();
+    for _ in 0..1000 {
+        counts += network.press_button();
+    }
+
+    counts.low * counts.high
+}
+
+/// Solution for part 2.
+///
+/// Returns `None` if the network has no [`RX`] module.
+pub fn part2(input: &str) -> Option<usize> {
+    let mut network = Network::from_str(input).expect("invalid input");
+    network.presses_until_low(network.id(RX)?)
+}
+
+/// The level of a pulse.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+/// The index of a module in the [`Network`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ModuleId(usize);
+
+/// A pulse travelling from one module to another.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Signal {
+    /// The sending module, or `None` for the button.
+    pub source: Option<ModuleId>,
+    /// The receiving module.
+    pub destination: ModuleId,
+    /// The pulse level.
+    pub pulse: Pulse,
+}
+
+/// The number of pulses sent during one or more button presses.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PulseCounts {
+    pub low: usize,
+    pub high: usize,
+}
+
+/// The type and state of a module.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ModuleKind {
+    /// The `broadcaster` module; forwards every pulse.
+    Broadcaster,
+    /// A flip-flop module (`%`); toggles on low pulses.
+    FlipFlop { on: bool },
+    /// A conjunction module (`&`); remembers the last pulse of each input.
+    Conjunction { memory: Vec<(ModuleId, Pulse)> },
+    /// A module that is only referenced as a destination, e.g. `output` or `rx`.
+    Sink,
+}
+
+/// A module in the [`Network`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Module {
+    name: String,
+    kind: ModuleKind,
+    inputs: Vec<ModuleId>,
+    destinations: Vec<ModuleId>,
+}
+
+/// The module network.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Network {
+    modules: Vec<Module>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ids: HashMap<String, ModuleId>,
+    broadcaster: ModuleId,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    presses: usize,
+}
+
+impl Module {
+    /// Gets the name of the module.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the type and state of the module.
+    pub fn kind(&self) -> &ModuleKind {
+        &self.kind
+    }
+
+    /// Gets the modules sending pulses to this module.
+    pub fn inputs(&self) -> &[ModuleId] {
+        &self.inputs
+    }
+
+    /// Gets the modules this module sends pulses to.
+    pub fn destinations(&self) -> &[ModuleId] {
+        &self.destinations
+    }
+
+    /// Processes a pulse and returns the pulse to send to all destinations, if any.
+    fn receive(&mut self, source: Option<ModuleId>, pulse: Pulse) -> Option<Pulse> {
+        match &mut self.kind {
+            ModuleKind::Broadcaster => Some(pulse),
+            ModuleKind::FlipFlop { .. } if pulse == Pulse::High => None,
+            ModuleKind::FlipFlop { on } => {
+                *on = !*on;
+                Some(if *on { Pulse::High } else { Pulse::Low })
+            }
+            ModuleKind::Conjunction { memory } => {
+                let source = source.expect("conjunction received a pulse from the button");
+                if let Some(entry) = memory.iter_mut().find(|(id, _)| *id == source) {
+                    entry.1 = pulse;
+                }
+
+                if memory.iter().all(|&(_, pulse)| pulse == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+            ModuleKind::Sink => None,
+        }
+    }
+
+    /// Restores the initial state.
+    fn reset(&mut self) {
+        match &mut self.kind {
+            ModuleKind::FlipFlop { on } => *on = false,
+            ModuleKind::Conjunction { memory } => {
+                for entry in memory {
+                    entry.1 = Pulse::Low;
+                }
+            }
+            ModuleKind::Broadcaster | ModuleKind::Sink => {}
+        }
+    }
+}
+
+impl Network {
+    /// Pushes the button once and processes all pulses until the network settles.
+    pub fn press_button(&mut self) -> PulseCounts {
+        self.press_button_with(|_| {})
+    }
+
+    /// Pushes the button once, calling `observe` for every pulse in the order it is processed.
+    pub fn press_button_with<F>(&mut self, mut observe: F) -> PulseCounts
+    where
+        F: FnMut(&Signal),
+    {
+        self.presses += 1;
+
+        let mut counts = PulseCounts::default();
+        let mut queue = VecDeque::from([Signal {
+            source: None,
+            destination: self.broadcaster,
+            pulse: Pulse::Low,
+        }]);
+
+        while let Some(signal) = queue.pop_front() {
+            counts.count(signal.pulse);
+            observe(&signal);
+
+            let module = &mut self.modules[signal.destination.0];
+            let Some(pulse) = module.receive(signal.source, signal.pulse) else {
+                continue;
+            };
+
+            queue.extend(module.destinations.iter().map(|&destination| Signal {
+                source: Some(signal.destination),
+                destination,
+                pulse,
+            }));
+        }
+
+        counts
+    }
+
+    /// Gets the number of button presses since construction or the last [`reset`](Network::reset).
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Restores the initial state of all modules.
+    pub fn reset(&mut self) {
+        self.presses = 0;
+        for module in &mut self.modules {
+            module.reset();
+        }
+    }
+
+    /// Gets the ID of the module with the specified name.
+    pub fn id(&self, name: &str) -> Option<ModuleId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Gets the module with the specified ID.
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id.0]
+    }
+
+    /// Iterates all modules.
+    pub fn modules(&self) -> impl Iterator<Item = (ModuleId, &Module)> + '_ {
+        self.modules
+            .iter()
+            .enumerate()
+            .map(|(id, module)| (ModuleId(id), module))
+    }
+
+    /// Determines the number of button presses (from the initial state) until the
+    /// specified module receives a low pulse.
+    ///
+    /// This relies on the structure of the puzzle input: the target is fed by a single
+    /// conjunction, whose inputs each send a high pulse periodically, once per sub-cycle.
+    /// The target receives a low pulse once all sub-cycles align, which happens after the
+    /// least common multiple of the individual cycle lengths.
+    ///
+    /// Returns `None` if the network does not have this structure, or if any of the
+    /// sub-cycles is longer than [`MAX_CYCLE_PRESSES`].
+    pub fn presses_until_low(&mut self, target: ModuleId) -> Option<usize> {
+        let &[feeder] = self.module(target).inputs() else {
+            return None;
+        };
+
+        if !matches!(self.module(feeder).kind(), ModuleKind::Conjunction { .. }) {
+            return None;
+        }
+
+        let inputs = self.module(feeder).inputs().to_vec();
+        let mut cycles: Vec<Option<usize>> = vec![None; inputs.len()];
+
+        self.reset();
+        while cycles.iter().any(Option::is_none) {
+            if self.presses >= MAX_CYCLE_PRESSES {
+                return None;
+            }
+
+            let presses = self.presses + 1;
+            self.press_button_with(|signal| {
+                if signal.destination != feeder || signal.pulse != Pulse::High {
+                    return;
+                }
+
+                let Some(source) = signal.source else {
+                    return;
+                };
+
+                if let Some(pos) = inputs.iter().position(|&id| id == source) {
+                    cycles[pos].get_or_insert(presses);
+                }
+            });
+        }
+
+        let cycles: Vec<usize> = cycles.into_iter().flatten().collect();
+        Some(lcm_slice(&cycles))
+    }
+}
+
+impl PulseCounts {
+    fn count(&mut self, pulse: Pulse) {
+        match pulse {
+            Pulse::Low => self.low += 1,
+            Pulse::High => self.high += 1,
+        }
+    }
+}
+
+impl std::ops::AddAssign for PulseCounts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.low += rhs.low;
+        self.high += rhs.high;
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut modules: Vec<Module> = Vec::new();
+        let mut ids: HashMap<String, ModuleId> = HashMap::new();
+
+        fn get_or_insert(
+            modules: &mut Vec<Module>,
+            ids: &mut HashMap<String, ModuleId>,
+            name: &str,
+        ) -> ModuleId {
+            *ids.entry(name.to_string()).or_insert_with(|| {
+                modules.push(Module {
+                    name: name.to_string(),
+                    kind: ModuleKind::Sink,
+                    inputs: Vec::new(),
+                    destinations: Vec::new(),
+                });
+                ModuleId(modules.len() - 1)
+            })
+        }
+
+        for line in s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            let (module, destinations) = line
+                .split_once(" -> ")
+                .ok_or(ParseNetworkError("Missing destination list"))?;
+
+            let (kind, name) = if let Some(name) = module.strip_prefix('%') {
+                (ModuleKind::FlipFlop { on: false }, name)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (ModuleKind::Conjunction { memory: Vec::new() }, name)
+            } else if module == BROADCASTER {
+                (ModuleKind::Broadcaster, module)
+            } else {
+                return Err(ParseNetworkError("Unknown module type"));
+            };
+
+            if name.is_empty() {
+                return Err(ParseNetworkError("Missing module name"));
+            }
+
+            let id = get_or_insert(&mut modules, &mut ids, name);
+            if modules[id.0].kind != ModuleKind::Sink {
+                return Err(ParseNetworkError("Duplicate module definition"));
+            }
+            modules[id.0].kind = kind;
+
+            for destination in destinations.split(',').map(|name| name.trim()) {
+                if destination.is_empty() {
+                    return Err(ParseNetworkError("Empty destination name"));
+                }
+
+                let destination = get_or_insert(&mut modules, &mut ids, destination);
+                modules[id.0].destinations.push(destination);
+                modules[destination.0].inputs.push(id);
+            }
+        }
+
+        // Conjunctions remember a pulse for each of their inputs.
+        for module in &mut modules {
+            if let ModuleKind::Conjunction { memory } = &mut module.kind {
+                memory.extend(module.inputs.iter().map(|&id| (id, Pulse::Low)));
+            }
+        }
+
+        let broadcaster = *ids
+            .get(BROADCASTER)
+            .ok_or(ParseNetworkError("Missing broadcaster module"))?;
+        if modules[broadcaster.0].kind != ModuleKind::Broadcaster {
+            return Err(ParseNetworkError("Missing broadcaster module"));
+        }
+
+        Ok(Self {
+            modules,
+            ids,
+            broadcaster,
+            presses: 0,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseNetworkError(&'static str);
+
+impl Display for ParseNetworkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse network: {}", self.0)
+    }
+}
+
+impl Error for ParseNetworkError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "broadcaster -> a, b, c
+        %a -> b
+        %b -> c
+        %c -> inv
+        &inv -> a";
+
+    const EXAMPLE2: &str = "broadcaster -> a
+        %a -> inv, con
+        &inv -> b
+        %b -> con
+        &con -> output";
+
+    /// Two counters with periods 3 and 5 that reset themselves, joined by a conjunction.
+    const COUNTERS: &str = "broadcaster -> a0, b0
+        %a0 -> a1, ca
+        %a1 -> ca
+        &ca -> a0, xa
+        &xa -> hub
+        %b0 -> b1, cb
+        %b1 -> b2
+        %b2 -> cb
+        &cb -> b0, b1, xb
+        &xb -> hub
+        &hub -> rx";
+
+    #[test]
+    fn test_parse_network() {
+        let network = Network::from_str(EXAMPLE2).expect("failed to parse network");
+        assert_eq!(network.modules().count(), 6);
+
+        let con = network.id("con").expect("missing module");
+        let inputs: Vec<_> = network
+            .module(con)
+            .inputs()
+            .iter()
+            .map(|&id| network.module(id).name())
+            .collect();
+        assert_eq!(inputs, ["a", "b"]);
+
+        let output = network.id("output").expect("missing module");
+        assert_eq!(network.module(output).kind(), &ModuleKind::Sink);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Network::from_str("%a -> b").unwrap_err(),
+            ParseNetworkError("Missing broadcaster module")
+        );
+        assert_eq!(
+            Network::from_str("broadcaster a").unwrap_err(),
+            ParseNetworkError("Missing destination list")
+        );
+    }
+
+    #[test]
+    fn test_press_button() {
+        let mut network = Network::from_str(EXAMPLE1).expect("failed to parse network");
+        assert_eq!(network.press_button(), PulseCounts { low: 8, high: 4 });
+        assert_eq!(network.presses(), 1);
+    }
+
+    #[test]
+    fn test_press_button_with() {
+        let mut network = Network::from_str(EXAMPLE2).expect("failed to parse network");
+
+        let mut trace = Vec::new();
+        network.press_button_with(|&signal| trace.push(signal));
+
+        let name = |id: Option<ModuleId>| id.map_or("button", |id| network.module(id).name());
+        let trace: Vec<_> = trace
+            .into_iter()
+            .map(|signal| {
+                (
+                    name(signal.source),
+                    name(Some(signal.destination)),
+                    signal.pulse,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            trace,
+            [
+                ("button", "broadcaster", Pulse::Low),
+                ("broadcaster", "a", Pulse::Low),
+                ("a", "inv", Pulse::High),
+                ("a", "con", Pulse::High),
+                ("inv", "b", Pulse::Low),
+                ("con", "output", Pulse::High),
+                ("b", "con", Pulse::High),
+                ("con", "output", Pulse::Low),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE1), 32000000);
+        assert_eq!(part1(EXAMPLE2), 11687500);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE2), None);
+        assert_eq!(part2(COUNTERS), Some(15));
+    }
+
+    #[test]
+    fn test_part2_without_cycle() {
+        // The second input of the conjunction never sends a pulse.
+        let network = "broadcaster -> hub
+            &idle -> hub
+            &hub -> rx";
+        assert_eq!(part2(network), None);
+    }
+
+    #[test]
+    fn test_part2_brute_force() {
+        let mut network = Network::from_str(COUNTERS).expect("failed to parse network");
+        let rx = network.id(RX).expect("missing module");
+
+        let mut done = false;
+        while !done {
+            network.press_button_with(|signal| {
+                done |= signal.destination == rx && signal.pulse == Pulse::Low;
+            });
+        }
+
+        assert_eq!(network.presses(), 15);
+    }
+}
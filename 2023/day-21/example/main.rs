@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2023_day_21;
+    "Garden plots reachable in 64 steps: {}" => part1,
+    "Garden plots reachable in 26501365 steps: {}" => part2,
+);
@@ -0,0 +1,263 @@
+use aoc_utils::{bfs_distances_within, extrapolate_at, trace};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 21,
+    title: "Step Counter",
+    example_answers: [Some("16"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let garden: Garden = input.parse().ok()?;
+    Some(format!(
+        "{}×{} garden, {} plots reachable in {PART1_STEPS} steps",
+        garden.width(),
+        garden.height(),
+        reachable_plots(&garden, PART1_STEPS)
+    ))
+}
+
+/// The number of steps for part 1.
+pub const PART1_STEPS: usize = 64;
+
+/// The number of steps for part 2.
+pub const PART2_STEPS: usize = 26501365;
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> usize {
+    reachable_after(input, PART1_STEPS)
+}
+
+/// Solution for part 1 with a custom number of steps, e.g. the 6 steps of the example.
+pub fn reachable_after(input: &str, steps: usize) -> usize {
+    let garden = Garden::from_str(input).expect("invalid input");
+    reachable_plots(&garden, steps)
+}
+
+/// Solution for part 2.
+pub fn part2(input: &str) -> usize {
+    let garden = Garden::from_str(input).expect("invalid input");
+    reachable_plots_extrapolated(&garden, PART2_STEPS)
+}
+
+/// A position on the (infinitely repeating) map.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Coordinate(pub i64, pub i64);
+
+/// The garden map. It repeats infinitely in every direction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Garden {
+    rocks: Vec<bool>,
+    width: usize,
+    height: usize,
+    start: Coordinate,
+}
+
+/// Counts the garden plots reachable in exactly `steps` steps on the infinitely repeating map.
+///
+/// Since the Elf can always step back and forth between two plots, a plot is reachable in
+/// exactly `steps` steps if its shortest distance is at most `steps` and has the same parity.
+pub fn reachable_plots(garden: &Garden, steps: usize) -> usize {
+    bfs_distances_within(garden.start, steps, |&coordinate| {
+        coordinate
+            .neighbors()
+            .into_iter()
+            .filter(|&neighbor| !garden.is_rock(neighbor))
+    })
+    .into_values()
+    .filter(|&distance| distance % 2 == steps % 2)
+    .count()
+}
+
+/// Counts the garden plots reachable in exactly `steps` steps by fitting a quadratic polynomial.
+///
+/// This relies on the structure of the puzzle input: the map is square, the start lies in
+/// its center, and the start's row and column as well as the map's border are free of rocks.
+/// The reachable area then grows as a diamond that crosses one additional map copy per
+/// `width` steps, so the number of plots reachable in `remainder + n * width` steps is a
+/// quadratic function of `n`. Three samples suffice to extrapolate it.
+pub fn reachable_plots_extrapolated(garden: &Garden, steps: usize) -> usize {
+    let width = garden.width;
+    let remainder = steps % width;
+
+    let samples: Vec<i64> = (0..3)
+        .map(|n| reachable_plots(garden, remainder + n * width) as i64)
+        .collect();
+
+    extrapolate_at(&samples, (steps / width) as i64) as usize
+}
+
+impl Garden {
+    /// Gets the width of the map.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the height of the map.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the starting position.
+    pub fn start(&self) -> Coordinate {
+        self.start
+    }
+
+    /// Determines whether there is a rock at the specified position of the repeating map.
+    pub fn is_rock(&self, Coordinate(x, y): Coordinate) -> bool {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        self.rocks[x + y * self.width]
+    }
+}
+
+impl Coordinate {
+    /// Gets the positions north, east, south and west of this one.
+    pub fn neighbors(&self) -> [Coordinate; 4] {
+        let Coordinate(x, y) = *self;
+        [
+            Coordinate(x, y - 1),
+            Coordinate(x + 1, y),
+            Coordinate(x, y + 1),
+            Coordinate(x - 1, y),
+        ]
+    }
+}
+
+impl FromStr for Garden {
+    type Err = ParseGardenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut rocks = Vec::with_capacity(s.len());
+        let mut start = None;
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .enumerate()
+        {
+            if height > 0 && line.len() != width {
+                return Err(ParseGardenError("Inconsistent line length"));
+            }
+
+            width = line.len();
+            height = y + 1;
+
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '.' => rocks.push(false),
+                    '#' => rocks.push(true),
+                    'S' if start.is_none() => {
+                        start = Some(Coordinate(x as i64, y as i64));
+                        rocks.push(false);
+                    }
+                    'S' => return Err(ParseGardenError("Multiple starting positions")),
+                    _ => return Err(ParseGardenError("Invalid tile")),
+                }
+            }
+        }
+
+        let start = start.ok_or(ParseGardenError("Missing starting position"))?;
+        Ok(Self {
+            rocks,
+            width,
+            height,
+            start,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseGardenError(&'static str);
+
+impl Display for ParseGardenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse garden: {}", self.0)
+    }
+}
+
+impl Error for ParseGardenError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........";
+
+    /// A map with the structural properties of the real puzzle input.
+    const OPEN_CENTER: &str = "...........
+        ...........
+        ...........
+        ....#......
+        .......#...
+        .....S.....
+        ...#.......
+        ......#....
+        ...........
+        ...........
+        ...........";
+
+    #[test]
+    fn test_parse_garden() {
+        let garden = Garden::from_str(EXAMPLE).expect("failed to parse garden");
+        assert_eq!(garden.width(), 11);
+        assert_eq!(garden.height(), 11);
+        assert_eq!(garden.start(), Coordinate(5, 5));
+        assert!(garden.is_rock(Coordinate(5, 1)));
+        assert!(garden.is_rock(Coordinate(5, 12)));
+        assert!(!garden.is_rock(Coordinate(-11, -11)));
+    }
+
+    #[test]
+    fn test_reachable_plots() {
+        let garden = Garden::from_str(EXAMPLE).expect("failed to parse garden");
+        assert_eq!(reachable_plots(&garden, 6), 16);
+        assert_eq!(reachable_plots(&garden, 10), 50);
+        assert_eq!(reachable_plots(&garden, 50), 1594);
+        assert_eq!(reachable_plots(&garden, 100), 6536);
+    }
+
+    #[test]
+    fn test_reachable_after() {
+        assert_eq!(
+            PUZZLE.example_answers[0],
+            Some(reachable_after(EXAMPLE, 6).to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn test_reachable_plots_extrapolated() {
+        let garden = Garden::from_str(OPEN_CENTER).expect("failed to parse garden");
+        for n in 3..6 {
+            let steps = 5 + n * garden.width();
+            assert_eq!(
+                reachable_plots_extrapolated(&garden, steps),
+                reachable_plots(&garden, steps)
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2023_day_22;
+    "Bricks that can be safely disintegrated: {}" => part1,
+    "Sum of bricks falling in chain reactions: {}" => part2,
+);
@@ -0,0 +1,221 @@
+use aoc_utils::{trace, BoundingBox3, ParseVec3Error, Vec3};
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 22,
+    title: "Sand Slabs",
+    example_answers: [Some("5"), Some("7")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let stack: Stack = input.parse().ok()?;
+    Some(format!(
+        "{} bricks, {} of them safe to disintegrate",
+        stack.len(),
+        stack.safe_to_disintegrate().count()
+    ))
+}
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> usize {
+    let stack = Stack::from_str(input).expect("invalid input");
+    stack.safe_to_disintegrate().count()
+}
+
+/// Solution for part 2.
+pub fn part2(input: &str) -> usize {
+    let stack = Stack::from_str(input).expect("invalid input");
+    (0..stack.len())
+        .map(|brick| stack.chain_reaction(brick))
+        .sum()
+}
+
+/// A pile of settled bricks and the graph of which bricks rest on which.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Stack {
+    /// The settled bricks, ordered by their lowest point.
+    bricks: Vec<BoundingBox3>,
+    /// For each brick, the bricks directly resting on it.
+    supports: Vec<Vec<usize>>,
+    /// For each brick, the bricks it directly rests on.
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets the bricks fall until they settle and builds the support graph.
+    pub fn settle(mut bricks: Vec<BoundingBox3>) -> Self {
+        bricks.sort_by_key(|brick| brick.min.z);
+
+        // For each (x, y) column, the height and index of the highest brick so far.
+        let mut heights: HashMap<(i64, i64), (i64, usize)> = HashMap::new();
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+
+        for (index, brick) in bricks.iter_mut().enumerate() {
+            let columns: Vec<_> = (brick.min.x..=brick.max.x)
+                .flat_map(|x| (brick.min.y..=brick.max.y).map(move |y| (x, y)))
+                .collect();
+
+            // The brick comes to rest right above the highest brick below it.
+            let floor = columns
+                .iter()
+                .filter_map(|column| heights.get(column))
+                .map(|&(height, _)| height)
+                .max()
+                .unwrap_or(0);
+
+            *brick = brick.translate(Vec3::new(0, 0, floor + 1 - brick.min.z));
+
+            for column in columns {
+                if let Some(&(height, below)) = heights.get(&column) {
+                    if height == floor && !supported_by[index].contains(&below) {
+                        supported_by[index].push(below);
+                        supports[below].push(index);
+                    }
+                }
+
+                heights.insert(column, (brick.max.z, index));
+            }
+        }
+
+        Self {
+            bricks,
+            supports,
+            supported_by,
+        }
+    }
+
+    /// Gets the number of bricks.
+    pub fn len(&self) -> usize {
+        self.bricks.len()
+    }
+
+    /// Determines whether the stack contains no bricks.
+    pub fn is_empty(&self) -> bool {
+        self.bricks.is_empty()
+    }
+
+    /// Gets the settled bricks, ordered by their lowest point.
+    pub fn bricks(&self) -> &[BoundingBox3] {
+        &self.bricks
+    }
+
+    /// Gets the bricks directly resting on the specified brick.
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+
+    /// Gets the bricks the specified brick directly rests on.
+    pub fn supported_by(&self, brick: usize) -> &[usize] {
+        &self.supported_by[brick]
+    }
+
+    /// Iterates the bricks that can be removed without any other brick falling,
+    /// i.e. every brick resting on them also rests on another brick.
+    pub fn safe_to_disintegrate(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|&brick| {
+            self.supports(brick)
+                .iter()
+                .all(|&above| self.supported_by(above).len() > 1)
+        })
+    }
+
+    /// Counts the other bricks that would fall if the specified brick was removed.
+    pub fn chain_reaction(&self, brick: usize) -> usize {
+        let mut falling = vec![false; self.len()];
+        falling[brick] = true;
+
+        let mut queue = VecDeque::from([brick]);
+        let mut count = 0;
+        while let Some(current) = queue.pop_front() {
+            for &above in self.supports(current) {
+                if falling[above] {
+                    continue;
+                }
+
+                // A brick falls once everything it rests on is falling.
+                if self.supported_by(above).iter().all(|&below| falling[below]) {
+                    falling[above] = true;
+                    queue.push_back(above);
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+impl FromStr for Stack {
+    type Err = ParseVec3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let bricks = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(BoundingBox3::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::settle(bricks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1,0,1~1,2,1
+        0,0,2~2,0,2
+        0,2,3~2,2,3
+        0,0,4~0,2,4
+        2,0,5~2,2,5
+        0,1,6~2,1,6
+        1,1,8~1,1,9";
+
+    #[test]
+    fn test_settle() {
+        let stack = Stack::from_str(EXAMPLE).expect("failed to parse bricks");
+        let lowest: Vec<_> = stack.bricks().iter().map(|brick| brick.min.z).collect();
+        assert_eq!(lowest, [1, 2, 2, 3, 3, 4, 5]);
+
+        // Brick A supports B and C; both D and E rest on B and C.
+        assert_eq!(stack.supports(0), [1, 2]);
+        assert_eq!(stack.supported_by(3), [1, 2]);
+        assert_eq!(stack.supported_by(4), [1, 2]);
+
+        // No two settled bricks overlap.
+        for (i, a) in stack.bricks().iter().enumerate() {
+            for b in &stack.bricks()[i + 1..] {
+                assert!(!a.intersects(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 5);
+    }
+
+    #[test]
+    fn test_chain_reaction() {
+        let stack = Stack::from_str(EXAMPLE).expect("failed to parse bricks");
+        assert_eq!(stack.chain_reaction(0), 6);
+        assert_eq!(stack.chain_reaction(5), 1);
+        assert_eq!(stack.chain_reaction(6), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 7);
+    }
+}
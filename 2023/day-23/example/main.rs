@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2023_day_23;
+    "Longest hike with slippery slopes: {}" => part1,
+    "Longest hike with climbable slopes: {}" => part2,
+);
@@ -0,0 +1,477 @@
+use aoc_utils::trace;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 23,
+    title: "A Long Walk",
+    example_answers: [Some("94"), Some("154")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let map: TrailMap = input.parse().ok()?;
+    let graph = JunctionGraph::contract(&map, Slopes::Climbable);
+    Some(format!(
+        "{}×{} map with {} junctions",
+        map.width(),
+        map.height(),
+        graph.len()
+    ))
+}
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> usize {
+    let map = TrailMap::from_str(input).expect("invalid input");
+    JunctionGraph::contract(&map, Slopes::Slippery)
+        .longest_path()
+        .expect("no path to the goal")
+}
+
+/// Solution for part 2.
+pub fn part2(input: &str) -> usize {
+    let map = TrailMap::from_str(input).expect("invalid input");
+    JunctionGraph::contract(&map, Slopes::Climbable)
+        .longest_path()
+        .expect("no path to the goal")
+}
+
+/// Whether or not slopes can only be walked downhill.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Slopes {
+    /// Slopes can only be walked in the direction they are pointing (for part 1).
+    Slippery,
+    /// Slopes are regular paths (for part 2).
+    Climbable,
+}
+
+/// A direction on the map.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A tile of the map.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Tile {
+    /// A path (`.`).
+    Path,
+    /// A forest (`#`); can't be entered.
+    Forest,
+    /// A steep slope (`^`, `>`, `v` or `<`).
+    Slope(Direction),
+}
+
+/// A 2D coordinate of x an y.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Coordinate(pub usize, pub usize);
+
+/// The map of hiking trails.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrailMap {
+    tiles: Vec<Tile>,
+    width: usize,
+    height: usize,
+    start: Coordinate,
+    goal: Coordinate,
+}
+
+/// A corridor between two junctions of the [`JunctionGraph`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Edge {
+    /// The index of the node the corridor leads to.
+    pub to: usize,
+    /// The number of steps along the corridor.
+    pub length: usize,
+}
+
+/// The trail map contracted into a graph of junctions.
+///
+/// The nodes are the start, the goal, and all tiles where paths fork. The edges are the
+/// corridors in between, weighted by their number of steps. With slippery slopes, edges
+/// are directed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JunctionGraph {
+    nodes: Vec<Coordinate>,
+    edges: Vec<Vec<Edge>>,
+    start: usize,
+    goal: usize,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+}
+
+impl TrailMap {
+    /// Gets the width of the map.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the height of the map.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the start position in the top row.
+    pub fn start(&self) -> Coordinate {
+        self.start
+    }
+
+    /// Gets the goal position in the bottom row.
+    pub fn goal(&self) -> Coordinate {
+        self.goal
+    }
+
+    /// Gets the tile at the specified position.
+    pub fn at(&self, Coordinate(x, y): Coordinate) -> Tile {
+        self.tiles[x + y * self.width]
+    }
+
+    /// Gets the position one step into the specified direction, if it lies on the map.
+    pub fn step(&self, Coordinate(x, y): Coordinate, direction: Direction) -> Option<Coordinate> {
+        match direction {
+            Direction::North if y > 0 => Some(Coordinate(x, y - 1)),
+            Direction::East if x + 1 < self.width => Some(Coordinate(x + 1, y)),
+            Direction::South if y + 1 < self.height => Some(Coordinate(x, y + 1)),
+            Direction::West if x > 0 => Some(Coordinate(x - 1, y)),
+            _ => None,
+        }
+    }
+
+    /// Gets the positions reachable in a single step, along with the direction of the step.
+    pub fn moves(
+        &self,
+        from: Coordinate,
+        slopes: Slopes,
+    ) -> impl Iterator<Item = (Direction, Coordinate)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let to = self.step(from, direction)?;
+            let allowed = |tile: Tile| match (tile, slopes) {
+                (Tile::Forest, _) => false,
+                (Tile::Slope(slope), Slopes::Slippery) => slope == direction,
+                _ => true,
+            };
+
+            (allowed(self.at(from)) && allowed(self.at(to))).then_some((direction, to))
+        })
+    }
+
+    /// Determines whether paths fork at the specified position.
+    fn is_junction(&self, position: Coordinate) -> bool {
+        self.at(position) != Tile::Forest
+            && Direction::ALL
+                .into_iter()
+                .filter_map(|direction| self.step(position, direction))
+                .filter(|&neighbor| self.at(neighbor) != Tile::Forest)
+                .count()
+                > 2
+    }
+}
+
+impl JunctionGraph {
+    /// Contracts the trail map into a graph of junctions.
+    pub fn contract(map: &TrailMap, slopes: Slopes) -> Self {
+        let mut nodes = vec![map.start, map.goal];
+        nodes.extend(
+            (0..map.height)
+                .flat_map(|y| (0..map.width).map(move |x| Coordinate(x, y)))
+                .filter(|&position| map.is_junction(position)),
+        );
+
+        let index: HashMap<Coordinate, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &position)| (position, i))
+            .collect();
+
+        let edges = nodes
+            .iter()
+            .map(|&node| {
+                map.moves(node, slopes)
+                    .filter_map(|(_, next)| Self::follow_corridor(map, slopes, &index, node, next))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            nodes,
+            edges,
+            start: 0,
+            goal: 1,
+        }
+    }
+
+    /// Walks along a corridor until the next node is reached.
+    ///
+    /// Returns `None` if the corridor is a dead end or blocked by a slope.
+    fn follow_corridor(
+        map: &TrailMap,
+        slopes: Slopes,
+        index: &HashMap<Coordinate, usize>,
+        mut previous: Coordinate,
+        mut current: Coordinate,
+    ) -> Option<Edge> {
+        let mut length = 1;
+        loop {
+            if let Some(&to) = index.get(&current) {
+                return Some(Edge { to, length });
+            }
+
+            let (_, next) = map
+                .moves(current, slopes)
+                .find(|&(_, next)| next != previous)?;
+            (previous, current) = (current, next);
+            length += 1;
+        }
+    }
+
+    /// Gets the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Determines whether the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Gets the map position of the specified node.
+    pub fn position(&self, node: usize) -> Coordinate {
+        self.nodes[node]
+    }
+
+    /// Gets the corridors leaving the specified node.
+    pub fn edges(&self, node: usize) -> &[Edge] {
+        &self.edges[node]
+    }
+
+    /// Gets the index of the start node.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Gets the index of the goal node.
+    pub fn goal(&self) -> usize {
+        self.goal
+    }
+
+    /// Determines the length of the longest path from start to goal that visits no node twice.
+    ///
+    /// This is a depth-first search over all simple paths; the visited nodes are tracked
+    /// as a bit mask, which limits the graph to 64 nodes.
+    ///
+    /// Returns `None` if the goal is unreachable.
+    pub fn longest_path(&self) -> Option<usize> {
+        assert!(self.len() <= 64, "too many junctions for a 64-bit mask");
+
+        // If all paths to the goal pass through a single junction, we must go to the goal
+        // directly once we reach that junction; any detour would lock us out.
+        let mut predecessors = self.edges.iter().enumerate().filter_map(|(node, edges)| {
+            edges
+                .iter()
+                .find(|edge| edge.to == self.goal)
+                .map(|edge| (node, edge.length))
+        });
+        let last = match (predecessors.next(), predecessors.next()) {
+            (Some(last), None) => Some(last),
+            _ => None,
+        };
+
+        let mut best = None;
+        self.search(self.start, 1 << self.start, 0, last, &mut best);
+        best
+    }
+
+    fn search(
+        &self,
+        node: usize,
+        visited: u64,
+        length: usize,
+        last: Option<(usize, usize)>,
+        best: &mut Option<usize>,
+    ) {
+        if node == self.goal {
+            *best = Some(best.map_or(length, |best| best.max(length)));
+            return;
+        }
+
+        if let Some((last, to_goal)) = last {
+            if node == last {
+                self.search(self.goal, visited, length + to_goal, None, best);
+                return;
+            }
+        }
+
+        for edge in self.edges(node) {
+            let mask = 1 << edge.to;
+            if visited & mask != 0 {
+                continue;
+            }
+
+            self.search(edge.to, visited | mask, length + edge.length, last, best);
+        }
+    }
+}
+
+impl FromStr for TrailMap {
+    type Err = ParseTrailMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut tiles = Vec::with_capacity(s.len());
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            if height > 0 && line.len() != width {
+                return Err(ParseTrailMapError("Inconsistent line length"));
+            }
+
+            width = line.len();
+            height += 1;
+
+            for c in line.chars() {
+                tiles.push(match c {
+                    '.' => Tile::Path,
+                    '#' => Tile::Forest,
+                    '^' => Tile::Slope(Direction::North),
+                    '>' => Tile::Slope(Direction::East),
+                    'v' => Tile::Slope(Direction::South),
+                    '<' => Tile::Slope(Direction::West),
+                    _ => return Err(ParseTrailMapError("Invalid tile")),
+                });
+            }
+        }
+
+        let find_path = |y: usize| {
+            (0..width)
+                .find(|&x| tiles[x + y * width] == Tile::Path)
+                .map(|x| Coordinate(x, y))
+        };
+
+        if height < 2 {
+            return Err(ParseTrailMapError("Map is too small"));
+        }
+
+        let start = find_path(0).ok_or(ParseTrailMapError("Missing start"))?;
+        let goal = find_path(height - 1).ok_or(ParseTrailMapError("Missing goal"))?;
+
+        Ok(Self {
+            tiles,
+            width,
+            height,
+            start,
+            goal,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseTrailMapError(&'static str);
+
+impl Display for ParseTrailMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse trail map: {}", self.0)
+    }
+}
+
+impl Error for ParseTrailMapError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#.#####################
+        #.......#########...###
+        #######.#########.#.###
+        ###.....#.>.>.###.#.###
+        ###v#####.#v#.###.#.###
+        ###.>...#.#.#.....#...#
+        ###v###.#.#.#########.#
+        ###...#.#.#.......#...#
+        #####.#.#.#######.#.###
+        #.....#.#.#.......#...#
+        #.#####.#.#.#########v#
+        #.#...#...#...###...>.#
+        #.#.#v#######v###.###v#
+        #...#.>.#...>.>.#.###.#
+        #####v#.#.###v#.#.###.#
+        #.....#...#...#.#.#...#
+        #.#########.###.#.#.###
+        #...###...#...#...#.###
+        ###.###.#.###v#####v###
+        #...#...#.#.>.>.#.>.###
+        #.###.###.#.###.#.#v###
+        #.....###...###...#...#
+        #####################.#";
+
+    #[test]
+    fn test_parse_map() {
+        let map = TrailMap::from_str(EXAMPLE).expect("failed to parse map");
+        assert_eq!(map.width(), 23);
+        assert_eq!(map.height(), 23);
+        assert_eq!(map.start(), Coordinate(1, 0));
+        assert_eq!(map.goal(), Coordinate(21, 22));
+        assert_eq!(map.at(Coordinate(10, 3)), Tile::Slope(Direction::East));
+    }
+
+    #[test]
+    fn test_contract() {
+        let map = TrailMap::from_str(EXAMPLE).expect("failed to parse map");
+        let graph = JunctionGraph::contract(&map, Slopes::Climbable);
+
+        // Start, goal and seven junctions.
+        assert_eq!(graph.len(), 9);
+
+        // The start leads to the first junction only.
+        assert_eq!(graph.edges(graph.start()).len(), 1);
+        let first = graph.edges(graph.start())[0];
+        assert_eq!(graph.position(first.to), Coordinate(3, 5));
+        assert_eq!(first.length, 15);
+
+        // Without slopes, every corridor can be walked both ways.
+        for node in 0..graph.len() {
+            for edge in graph.edges(node) {
+                assert!(graph
+                    .edges(edge.to)
+                    .iter()
+                    .any(|back| back.to == node && back.length == edge.length));
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 94);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 154);
+    }
+}
@@ -0,0 +1,5 @@
+aoc_utils::aoc_main!(aoc_2023_day_24;
+    "Future path intersections within the test area: {}"
+        => |input| part1(input, TEST_AREA_MIN, TEST_AREA_MAX),
+    "Sum of the rock's starting coordinates: {}" => part2,
+);
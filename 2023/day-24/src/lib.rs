@@ -0,0 +1,340 @@
+use aoc_utils::{gcd_i128, trace, ParseVec3Error, Rational, Vec3};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 24,
+    title: "Never Tell Me The Odds",
+    example_answers: [Some("2"), Some("47")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let hailstones = parse_hailstones(input).ok()?;
+    Some(format!("{} hailstones", hailstones.len()))
+}
+
+/// The lower bound (inclusive) of the part 1 test area.
+pub const TEST_AREA_MIN: i64 = 200000000000000;
+
+/// The upper bound (inclusive) of the part 1 test area.
+pub const TEST_AREA_MAX: i64 = 400000000000000;
+
+/// Solution for part 1.
+pub fn part1(input: &str, min: i64, max: i64) -> usize {
+    let hailstones = parse_hailstones(input).expect("invalid input");
+    count_intersections_xy(&hailstones, min, max)
+}
+
+/// Solution for part 2.
+pub fn part2(input: &str) -> i128 {
+    let hailstones = parse_hailstones(input).expect("invalid input");
+    let rock = throw_rock(&hailstones).expect("no rock trajectory hits all hailstones");
+    rock.position.x as i128 + rock.position.y as i128 + rock.position.z as i128
+}
+
+/// A hailstone (or rock) with its position at time `0` and its velocity.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Hailstone {
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+/// An `i128` vector, used where `i64` cross products would overflow.
+type Wide = [i128; 3];
+
+/// Parses one hailstone per line.
+pub fn parse_hailstones(input: &str) -> Result<Vec<Hailstone>, ParseHailstoneError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(Hailstone::from_str)
+        .collect()
+}
+
+/// Counts the pairs of hailstones whose future paths cross within the test area,
+/// ignoring the `Z` axis.
+pub fn count_intersections_xy(hailstones: &[Hailstone], min: i64, max: i64) -> usize {
+    let area = Rational::from(min)..=Rational::from(max);
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| hailstones[i + 1..].iter().map(move |b| (a, b)))
+        .filter_map(|(a, b)| a.intersect_xy(b))
+        .filter(|(x, y)| area.contains(x) && area.contains(y))
+        .count()
+}
+
+/// Determines the rock trajectory that collides with every hailstone.
+///
+/// Instead of solving the (non-linear) system for all hailstones at once, this works in the
+/// reference frame of the first hailstone, in which it stands still at the origin. The
+/// rock's path must pass through the origin; it must also hit every other hailstone, so it
+/// lies in the plane spanned by the origin and each other hailstone's path. The rock's
+/// direction is the intersection of two such planes, i.e. the cross product of their normals.
+/// Knowing the direction, the collision times with two hailstones give the rock's velocity
+/// and starting position. All arithmetic is exact, using `i128` and [`Rational`].
+///
+/// Returns `None` if there is no integer trajectory.
+pub fn throw_rock(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    let (origin, others) = hailstones.split_first()?;
+    let relative: Vec<(Wide, Wide)> = others
+        .iter()
+        .map(|stone| {
+            (
+                widen(stone.position - origin.position),
+                widen(stone.velocity - origin.velocity),
+            )
+        })
+        .collect();
+
+    // The normals of the planes containing the origin and each hailstone's path.
+    let normals: Vec<Wide> = relative
+        .iter()
+        .map(|&(position, velocity)| cross(position, velocity))
+        .collect();
+
+    // The rock's direction, relative to the first hailstone.
+    let direction = normals
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| normals[i + 1..].iter().map(move |&b| cross(a, b)))
+        .find(|&direction| direction != [0; 3])
+        .map(reduce)?;
+
+    // The collision times with hailstones whose paths aren't parallel to the rock's.
+    let mut collisions = relative.iter().filter_map(|&(position, velocity)| {
+        let time = collision_time(position, velocity, direction)?;
+        Some((time, position, velocity))
+    });
+
+    let (t1, p1, v1) = collisions.next()?;
+    let (t2, p2, v2) = collisions.find(|&(time, _, _)| time != t1)?;
+
+    // The positions of the rock at both collision times.
+    let at = |position: Wide, velocity: Wide, time: Rational| -> [Rational; 3] {
+        [0, 1, 2].map(|i| Rational::from(position[i]) + Rational::from(velocity[i]) * time)
+    };
+    let q1 = at(p1, v1, t1);
+    let q2 = at(p2, v2, t2);
+
+    let velocity = [0, 1, 2].map(|i| (q2[i] - q1[i]) / (t2 - t1));
+    let position = [0, 1, 2].map(|i| q1[i] - velocity[i] * t1);
+
+    // Convert back into the original reference frame.
+    let integer = |value: Rational| value.to_integer().and_then(|v| i64::try_from(v).ok());
+    let velocity = Vec3::new(
+        integer(velocity[0])?,
+        integer(velocity[1])?,
+        integer(velocity[2])?,
+    ) + origin.velocity;
+    let position = Vec3::new(
+        integer(position[0])?,
+        integer(position[1])?,
+        integer(position[2])?,
+    ) + origin.position;
+
+    Some(Hailstone { position, velocity })
+}
+
+impl Hailstone {
+    /// Determines where the future paths of two hailstones cross, ignoring the `Z` axis.
+    ///
+    /// Returns `None` if the paths are parallel or crossed in the past for either hailstone.
+    pub fn intersect_xy(&self, other: &Hailstone) -> Option<(Rational, Rational)> {
+        let (p1, v1) = (self.position, self.velocity);
+        let (p2, v2) = (other.position, other.velocity);
+
+        // Solve p1 + t * v1 = p2 + s * v2 using Cramer's rule.
+        let determinant = v1.x as i128 * v2.y as i128 - v1.y as i128 * v2.x as i128;
+        if determinant == 0 {
+            return None;
+        }
+
+        let dx = (p2.x - p1.x) as i128;
+        let dy = (p2.y - p1.y) as i128;
+        let t = Rational::new(dx * v2.y as i128 - dy * v2.x as i128, determinant);
+        let s = Rational::new(dx * v1.y as i128 - dy * v1.x as i128, determinant);
+        if t < Rational::ZERO || s < Rational::ZERO {
+            return None;
+        }
+
+        let x = Rational::from(p1.x) + Rational::from(v1.x) * t;
+        let y = Rational::from(p1.y) + Rational::from(v1.y) * t;
+        Some((x, y))
+    }
+
+    /// Gets the position at the specified time.
+    pub fn at(&self, time: i64) -> Vec3 {
+        let velocity = self.velocity;
+        self.position + Vec3::new(velocity.x * time, velocity.y * time, velocity.z * time)
+    }
+}
+
+/// Determines when a path `position + t * velocity` crosses the line through the origin
+/// along `direction`, or `None` if the path is parallel to it.
+fn collision_time(position: Wide, velocity: Wide, direction: Wide) -> Option<Rational> {
+    // (position + t * velocity) × direction = 0, solved for any non-zero component.
+    let a = cross(position, direction);
+    let b = cross(velocity, direction);
+    let k = (0..3).find(|&k| b[k] != 0)?;
+    Some(Rational::new(-a[k], b[k]))
+}
+
+fn widen(vec: Vec3) -> Wide {
+    [vec.x as i128, vec.y as i128, vec.z as i128]
+}
+
+fn cross(a: Wide, b: Wide) -> Wide {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Divides the vector by the greatest common divisor of its components.
+fn reduce(vec: Wide) -> Wide {
+    let divisor = gcd_i128(gcd_i128(vec[0], vec[1]), vec[2]);
+    vec.map(|component| component / divisor)
+}
+
+impl FromStr for Hailstone {
+    type Err = ParseHailstoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s
+            .split_once('@')
+            .ok_or(ParseHailstoneError::MissingVelocity)?;
+        Ok(Self {
+            position: position.parse()?,
+            velocity: velocity.parse()?,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseHailstoneError {
+    MissingVelocity,
+    InvalidVector(ParseVec3Error),
+}
+
+impl Display for ParseHailstoneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHailstoneError::MissingVelocity => {
+                write!(f, "Failed to parse hailstone: missing velocity")
+            }
+            ParseHailstoneError::InvalidVector(e) => write!(f, "Failed to parse hailstone: {e}"),
+        }
+    }
+}
+
+impl Error for ParseHailstoneError {}
+
+impl From<ParseVec3Error> for ParseHailstoneError {
+    fn from(value: ParseVec3Error) -> Self {
+        ParseHailstoneError::InvalidVector(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::solve_linear_system;
+
+    const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
+        18, 19, 22 @ -1, -1, -2
+        20, 25, 34 @ -2, -2, -4
+        12, 31, 28 @ -1, -2, -1
+        20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn test_parse_hailstone() {
+        let stone: Hailstone = "20, 19, 15 @  1, -5, -3"
+            .parse()
+            .expect("failed to parse hailstone");
+        assert_eq!(stone.position, Vec3::new(20, 19, 15));
+        assert_eq!(stone.velocity, Vec3::new(1, -5, -3));
+    }
+
+    #[test]
+    fn test_intersect_xy() {
+        let stones = parse_hailstones(EXAMPLE).expect("failed to parse hailstones");
+
+        // Hailstone A and B cross inside the test area.
+        let (x, y) = stones[0]
+            .intersect_xy(&stones[1])
+            .expect("paths should cross");
+        assert_eq!(x, Rational::new(43, 3));
+        assert_eq!(y, Rational::new(46, 3));
+
+        // Hailstone B and C are parallel.
+        assert_eq!(stones[1].intersect_xy(&stones[2]), None);
+
+        // Hailstone A and E crossed in the past for A.
+        assert_eq!(stones[0].intersect_xy(&stones[4]), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE, 7, 27), 2);
+    }
+
+    #[test]
+    fn test_throw_rock() {
+        let stones = parse_hailstones(EXAMPLE).expect("failed to parse hailstones");
+        let rock = throw_rock(&stones).expect("failed to find a trajectory");
+        assert_eq!(rock.position, Vec3::new(24, 13, 10));
+        assert_eq!(rock.velocity, Vec3::new(-3, 1, 2));
+
+        // The rock hits every hailstone at an integer time.
+        for stone in &stones {
+            let dx = stone.position.x - rock.position.x;
+            let dvx = rock.velocity.x - stone.velocity.x;
+            let time = dx / dvx;
+            assert_eq!(rock.at(time), stone.at(time));
+        }
+    }
+
+    /// Cross-checks the geometric solution against the linearized system
+    /// `P × (v_i - v_j) + (p_i - p_j) × V = p_i × v_i - p_j × v_j`.
+    #[test]
+    fn test_linear_system() {
+        let stones = parse_hailstones(EXAMPLE).expect("failed to parse hailstones");
+
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        for other in &stones[1..3] {
+            let dv = widen(stones[0].velocity - other.velocity);
+            let dp = widen(stones[0].position - other.position);
+            let c0 = cross(widen(stones[0].position), widen(stones[0].velocity));
+            let c1 = cross(widen(other.position), widen(other.velocity));
+
+            a.push([0, dv[2], -dv[1], 0, -dp[2], dp[1]]);
+            a.push([-dv[2], 0, dv[0], dp[2], 0, -dp[0]]);
+            a.push([dv[1], -dv[0], 0, -dp[1], dp[0], 0]);
+            b.extend((0..3).map(|k| c0[k] - c1[k]));
+        }
+
+        let a = a
+            .into_iter()
+            .map(|row| row.into_iter().map(Rational::from).collect())
+            .collect();
+        let b = b.into_iter().map(Rational::from).collect();
+        let x = solve_linear_system(a, b).expect("system is solvable");
+        let x: Vec<_> = x.iter().map(|v| v.to_integer().expect("integer")).collect();
+        assert_eq!(x, [24, 13, 10, -3, 1, 2]);
+
+        assert_eq!(part2(EXAMPLE), 47);
+    }
+}
@@ -0,0 +1,3 @@
+aoc_utils::aoc_main!(aoc_2023_day_25;
+    "Product of the group sizes: {}" => part1,
+);
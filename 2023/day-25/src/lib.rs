@@ -0,0 +1,174 @@
+use aoc_utils::{trace, FlowNetwork, Graph};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 25,
+    title: "Snowverload",
+    example_answers: [Some("54"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let components = Components::parse(input).ok()?;
+    Some(format!(
+        "{} components with {} connections",
+        components.graph.node_count(),
+        components.graph.edge_count()
+    ))
+}
+
+/// The number of wires that need to be disconnected.
+pub const CUT_SIZE: usize = 3;
+
+/// Solution for part 1.
+pub fn part1(input: &str) -> usize {
+    let components = Components::parse(input).expect("invalid input");
+    let cut = find_cut(&components.graph, CUT_SIZE).expect("no cut of the expected size");
+    cut.group_size * (components.graph.node_count() - cut.group_size)
+}
+
+/// The wiring diagram, with every component's name interned to a node index.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Components<'a> {
+    /// The wires between the components, whose nodes carry the components' names.
+    pub graph: Graph<&'a str>,
+}
+
+/// A minimum cut separating the graph into two groups.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cut {
+    /// The number of nodes in the group containing node `0`.
+    pub group_size: usize,
+    /// The edges crossing between both groups, as `(a, b)` with `a < b`.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl<'a> Components<'a> {
+    /// Parses lines such as `jqt: rhn xhk nvd`.
+    pub fn parse(input: &'a str) -> Result<Self, ParseComponentsError> {
+        let _span = trace::parse();
+        let mut wires = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (name, connections) = line
+                .split_once(':')
+                .ok_or(ParseComponentsError("missing colon"))?;
+            let name = name.trim();
+            wires.extend(
+                connections
+                    .split_whitespace()
+                    .map(|other| (name, other, ())),
+            );
+        }
+
+        Ok(Self {
+            graph: Graph::from_edges(wires),
+        })
+    }
+}
+
+/// Finds a cut of exactly `size` edges by repeatedly running a maximum flow from node `0`
+/// to every other node.
+///
+/// With unit capacities, the maximum flow between two nodes equals the number of edges that
+/// need to be cut to separate them. Since the graph is only weakly connected via the cut,
+/// some sink will be on the other side, and its flow will be exactly `size`. The nodes still
+/// reachable from the source in the residual graph then form the source's group.
+///
+/// Returns `None` if no such cut exists.
+pub fn find_cut<N>(graph: &Graph<N>, size: usize) -> Option<Cut> {
+    let source = graph.nodes().next()?;
+    let mut network = FlowNetwork::new(graph, |_| 1);
+    let size = size as u64;
+
+    // Stop once the flow exceeds the cut size; the sink is in the same group then.
+    let sink = graph
+        .nodes()
+        .skip(1)
+        .find(|&sink| network.max_flow(source, sink, size + 1) == size)?;
+    let group = network.source_side(source);
+    debug_assert!(!group[sink]);
+
+    let edges = graph
+        .edges()
+        .filter(|&(a, b)| group[a] != group[b])
+        .collect();
+    Some(Cut {
+        group_size: group.iter().filter(|&&side| side).count(),
+        edges,
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseComponentsError(&'static str);
+
+impl Display for ParseComponentsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse components: {}", self.0)
+    }
+}
+
+impl Error for ParseComponentsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
+        rsh: frs pzl lsr
+        xhk: hfx
+        cmg: qnr nvd lhk bvb
+        rhn: xhk bvb hfx
+        bvb: xhk hfx
+        pzl: lsr hfx nvd
+        qnr: nvd
+        ntq: jqt hfx bvb xhk
+        nvd: lhk
+        lsr: lhk
+        rzs: qnr cmg lsr rsh
+        frs: qnr lhk lsr";
+
+    #[test]
+    fn test_parse() {
+        let components = Components::parse(EXAMPLE).expect("failed to parse components");
+        assert_eq!(components.graph.node_count(), 15);
+        assert_eq!(components.graph.edge_count(), 33);
+        assert_eq!(components.graph[0], "jqt");
+    }
+
+    #[test]
+    fn test_find_cut() {
+        let components = Components::parse(EXAMPLE).expect("failed to parse components");
+        let cut = find_cut(&components.graph, CUT_SIZE).expect("failed to find cut");
+
+        let mut wires: Vec<_> = cut
+            .edges
+            .iter()
+            .map(|&(a, b)| {
+                let mut pair = [components.graph[a], components.graph[b]];
+                pair.sort();
+                pair
+            })
+            .collect();
+        wires.sort();
+        assert_eq!(wires, [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+        assert!(cut.group_size == 6 || cut.group_size == 9);
+    }
+
+    #[test]
+    fn test_no_smaller_cut() {
+        let components = Components::parse(EXAMPLE).expect("failed to parse components");
+        assert_eq!(find_cut(&components.graph, 2), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 54);
+    }
+}
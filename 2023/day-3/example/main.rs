@@ -1,12 +1,12 @@
-use aoc_2023_day_3::Schematic;
-use std::str::FromStr;
-
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() {
-    println!("2023 Day 3: Gear Ratios");
-
-    let schematic = Schematic::from_str(INPUT).expect("Failed to parse schematic");
-    println!("Sum of all part numbers: {}", schematic.sum_valid_parts());
-    println!("Sum of all gear ratios: {}", schematic.sum_gear_ratios());
-}
+aoc_utils::aoc_main!(aoc_2023_day_3;
+    "Sum of all part numbers: {}"
+        => |input| {
+            let schematic = Schematic::parse_borrowed(input).expect("Failed to parse schematic");
+            schematic.sum_valid_parts()
+        },
+    "Sum of all gear ratios: {}"
+        => |input| {
+            let schematic = Schematic::parse_borrowed(input).expect("Failed to parse schematic");
+            schematic.sum_gear_ratios()
+        },
+);
@@ -1,25 +1,70 @@
-use std::borrow::Borrow;
+use aoc_utils::render::svg::Svg;
+use aoc_utils::scan::{digit_runs, lines};
+use aoc_utils::trace;
+use std::borrow::{Borrow, Cow};
 use std::collections::Bound;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::ops::{RangeBounds, RangeInclusive};
+use std::ops::{Range, RangeBounds, RangeInclusive};
 use std::str::FromStr;
 
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 3,
+    title: "Gear Ratios",
+    example_answers: [Some("4361"), Some("467835")],
+    real_answers: Some([Some("539713"), Some("84159075")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let schematic = Schematic::parse_borrowed(input).ok()?;
+    let gears = schematic
+        .symbols_with_adjacent_parts(2)
+        .filter(|symbol| symbol.symbol() == '*')
+        .count();
+    Some(format!(
+        "{} part numbers, {} symbols, {gears} gears",
+        schematic.num_valid(),
+        schematic.symbols().len(),
+    ))
+}
+
 /// The `Schematic` struct represents a schematic with valid and invalid part numbers.
+///
+/// The cells are borrowed from the parsed input until the schematic is edited.
 #[derive(Debug)]
-#[allow(dead_code)]
-pub struct Schematic {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Schematic<'a> {
     /// A vector of `PartNumber` instances representing the valid part numbers.
     valid: Vec<PartNumber>,
     /// A vector of `PartNumber` instances representing the invalid part numbers.
     invalid: Vec<PartNumber>,
     /// The symbol map, used for gear detection.
-    symbol_map: SymbolMap,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    symbol_map: SymbolMap<'a>,
+    /// The valid part numbers next to each symbol, in reading order of the symbols.
+    symbols: Vec<SymbolParts>,
+    /// The number of symbols next to each valid part number.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    symbol_counts: Vec<usize>,
+}
+
+/// A symbol together with the valid part numbers next to it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SymbolParts {
+    position: SymbolPosition,
+    symbol: char,
+    parts: Vec<PartNumber>,
 }
 
 /// Represents a part number
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PartNumber {
     row: usize,
     pos: usize,
@@ -29,16 +74,18 @@ pub struct PartNumber {
 
 /// `SymbolMap` is a struct that represents a grid of symbols, where each symbol can be either true or false.
 /// It is used to keep track of the state of symbols in a grid, such as the state of pixels in an image.
+///
+/// The rows are read off the bytes of the input rather than copied; only an edit copies them.
 #[derive(Debug, Clone)]
-struct SymbolMap {
+struct SymbolMap<'a> {
     /// The number of lines.
     num_lines: usize,
     /// The length of each line.
     line_length: usize,
-    /// The map of symbols.
-    map: Vec<SymbolType>,
-    /// The set of potential gear symbols.
-    potential_gears: Vec<SymbolPosition>,
+    /// The bytes of the input, including line terminators and surrounding whitespace.
+    bytes: Cow<'a, [u8]>,
+    /// The offset of each (trimmed) row into the bytes.
+    rows: Vec<usize>,
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -52,6 +99,7 @@ enum SymbolType {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SymbolPosition {
     x: usize,
     y: usize,
@@ -66,18 +114,186 @@ impl SymbolType {
             SymbolType::GearCandidate => true,
         }
     }
+}
 
-    /// Determines if the current value represents a gear.
-    fn is_potential_gear(&self) -> bool {
-        match self {
-            SymbolType::None => false,
-            SymbolType::Generic => false,
-            SymbolType::GearCandidate => true,
-        }
+impl Schematic<'static> {
+    /// Parses the schematic into one that owns a copy of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_3::Schematic;
+    ///
+    /// let schematic = Schematic::parse("467..114..\n...*......").unwrap();
+    /// assert_eq!(schematic.sum_valid_parts(), 467);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseSchematicError> {
+        let schematic = Schematic::parse_borrowed(input)?;
+        Ok(Self {
+            symbol_map: schematic.symbol_map.into_owned(),
+            ..schematic
+        })
     }
 }
 
-impl Schematic {
+impl<'a> Schematic<'a> {
+    /// Parses the schematic directly off the bytes of the borrowed input.
+    ///
+    /// Lines and part numbers are scanned in place rather than copied out, so the offsets of
+    /// the rows are the only allocation for the grid, besides the lists of part numbers. The
+    /// input is only copied once a cell is changed with [`set_cell`](Self::set_cell).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_3::Schematic;
+    ///
+    /// let input = String::from("467..114..\n...*......");
+    /// let schematic = Schematic::parse_borrowed(&input).unwrap();
+    /// assert_eq!(schematic.sum_valid_parts(), 467);
+    /// ```
+    pub fn parse_borrowed(input: &'a str) -> Result<Self, ParseSchematicError> {
+        let _span = trace::parse();
+        let symbol_map = SymbolMap::parse_borrowed(input)?;
+        let num_lines = symbol_map.num_lines;
+
+        let mut schematic = Self {
+            valid: Vec::new(),
+            invalid: Vec::new(),
+            symbol_map,
+            symbols: Vec::new(),
+            symbol_counts: Vec::new(),
+        };
+        schematic.rescan(0..num_lines)?;
+        Ok(schematic)
+    }
+
+    /// Changes a single cell, e.g. to find out which edit maximizes the sum of the parts.
+    ///
+    /// Only the part numbers of the edited row and its neighbors are scanned again, together
+    /// with the symbols next to them. On error, the schematic is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_3::Schematic;
+    ///
+    /// let mut schematic = Schematic::parse("467..114..\n...*......").unwrap();
+    /// schematic.set_cell(5, 1, '#').unwrap();
+    /// assert_eq!(schematic.sum_valid_parts(), 467 + 114);
+    ///
+    /// schematic.set_cell(1, 0, '.').unwrap();
+    /// assert_eq!(schematic.sum_valid_parts(), 7 + 114);
+    /// ```
+    pub fn set_cell(&mut self, x: usize, y: usize, value: char) -> Result<(), EditSchematicError> {
+        let map = &self.symbol_map;
+        if x >= map.line_length || y >= map.num_lines {
+            return Err(EditSchematicError::InvalidAddress(InvalidAddressError(
+                x, y,
+            )));
+        }
+        let byte = u8::try_from(value)
+            .ok()
+            .filter(u8::is_ascii)
+            .ok_or(EditSchematicError::NotAscii)?;
+
+        // Joining two numbers may exceed the range of a part number.
+        let mut row = map.row(y).to_vec();
+        row[x] = byte;
+        if digit_runs(&row).any(|digits| parse_part_number(&row[digits]).is_none()) {
+            return Err(EditSchematicError::NumberTooLarge);
+        }
+
+        self.symbol_map.set(x, y, byte);
+        let rows = y.saturating_sub(1)..(y + 2).min(self.symbol_map.num_lines);
+        self.rescan(rows)
+            .expect("the rows were checked before the edit");
+        Ok(())
+    }
+
+    /// Scans the part numbers of the rows again, and re-indexes the symbols next to them.
+    ///
+    /// Part numbers outside of the rows keep their symbol counts, so the rows must include the
+    /// neighbors of every changed cell.
+    fn rescan(&mut self, rows: Range<usize>) -> Result<(), ParseSchematicError> {
+        let map = &self.symbol_map;
+
+        let mut valid = Vec::new();
+        let mut invalid = Vec::new();
+        for line_no in rows.clone() {
+            let line = map.row(line_no);
+            for digits in digit_runs(line) {
+                debug_assert!(digits.end <= map.line_length);
+
+                // Test if we are surrounded by a symbol.
+                let range = (digits.start as isize - 1)..=(digits.end as isize);
+                let next_to_symbol = map.is_next_to_symbol(range, line_no as _);
+
+                let part = PartNumber {
+                    row: line_no,
+                    pos: digits.start,
+                    len: digits.len(),
+                    number: parse_part_number(&line[digits]).ok_or(ParseSchematicError::Line(
+                        line_no,
+                        "Failed to parse part number",
+                    ))?,
+                };
+
+                if next_to_symbol {
+                    valid.push(part);
+                } else {
+                    invalid.push(part);
+                }
+            }
+        }
+
+        // Part numbers are sorted by row, so the ones of the rows form a contiguous range.
+        let in_rows = |parts: &[PartNumber]| {
+            parts.partition_point(|p| p.row < rows.start)
+                ..parts.partition_point(|p| p.row < rows.end)
+        };
+        let replaced = in_rows(&self.invalid);
+        self.invalid.splice(replaced, invalid);
+        let replaced = in_rows(&self.valid);
+        self.symbol_counts
+            .splice(replaced.clone(), valid.iter().map(|_| 0));
+        self.valid.splice(replaced, valid);
+
+        // Index the parts next to each symbol that may touch one of the rows. Only the part
+        // numbers of the symbol's and the neighboring rows need to be checked.
+        let symbol_rows = rows.start.saturating_sub(1)..(rows.end + 1).min(map.num_lines);
+        let mut symbols = Vec::new();
+        for (position, symbol) in map.symbols(symbol_rows.clone()) {
+            let lower = self.valid.partition_point(|p| p.row + 1 < position.y);
+            let upper = self.valid.partition_point(|p| p.row <= position.y + 1);
+            let mut parts = Vec::new();
+            for (index, part) in self.valid.iter().enumerate().take(upper).skip(lower) {
+                if part.is_adjacent(&position) {
+                    // Symbols outside of these rows can't touch the rescanned part numbers.
+                    if rows.contains(&part.row) {
+                        self.symbol_counts[index] += 1;
+                    }
+                    parts.push(part.clone());
+                }
+            }
+
+            symbols.push(SymbolParts {
+                position,
+                symbol,
+                parts,
+            });
+        }
+
+        let start = self
+            .symbols
+            .partition_point(|s| s.position.y < symbol_rows.start);
+        let end = self
+            .symbols
+            .partition_point(|s| s.position.y < symbol_rows.end);
+        self.symbols.splice(start..end, symbols);
+        Ok(())
+    }
+
     /// Returns the number of valid items in the collection.
     pub fn num_valid(&self) -> usize {
         self.valid.len()
@@ -90,33 +306,96 @@ impl Schematic {
 
     /// Sums up all the gear ratios.
     pub fn sum_gear_ratios(&self) -> u32 {
-        let mut sum = 0;
-        for potential_gear in self.symbol_map.potential_gears() {
-            // Select only those numbers that fall into the relevant line range.
-            let lower = self.valid.partition_point(|p| p.row < potential_gear.y - 1);
-            let upper = self
-                .valid
-                .partition_point(|p| p.row <= potential_gear.y + 1);
-
-            // Find all adjacent numbers. This is beautifully shitty as it goes through all numbers
-            // in the relevant line range, even though we could limit them by x offset.
-            let values: Vec<_> = self.valid[lower..upper]
-                .iter()
-                .filter(|&part| part.is_adjacent(potential_gear))
-                .map(|part| part.number)
-                .collect();
-
-            // "A gear is any * symbol that is adjacent to exactly two part numbers."
-            debug_assert!(values.len() <= 2);
-            if values.len() != 2 {
-                continue;
-            }
+        // "A gear is any * symbol that is adjacent to exactly two part numbers."
+        self.symbols_with_adjacent_parts(2)
+            .filter(|symbol| symbol.symbol() == '*')
+            .map(|gear| gear.parts().iter().map(PartNumber::number).product::<u32>())
+            .sum()
+    }
+
+    /// Gets every symbol together with the part numbers next to it, in reading order.
+    pub fn symbols(&self) -> &[SymbolParts] {
+        &self.symbols
+    }
+
+    /// Iterates the symbols that are next to exactly `n` part numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_3::Schematic;
+    ///
+    /// let schematic = Schematic::parse("1.2\n.*.\n3#.").unwrap();
+    /// let symbols: Vec<_> = schematic.symbols_with_adjacent_parts(3).collect();
+    /// assert_eq!(symbols.len(), 1);
+    /// assert_eq!(symbols[0].symbol(), '*');
+    /// ```
+    pub fn symbols_with_adjacent_parts(&self, n: usize) -> impl Iterator<Item = &SymbolParts> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| symbol.parts.len() == n)
+    }
 
-            let gear_ratio: u32 = values.iter().product();
-            sum += gear_ratio;
+    /// Iterates the valid part numbers that are next to at least `min_symbols` symbols.
+    ///
+    /// Every valid part number is next to at least one symbol.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_2023_day_3::Schematic;
+    ///
+    /// let schematic = Schematic::parse("1.2\n.*.\n3#.").unwrap();
+    /// let numbers: Vec<_> = schematic.parts_with_adjacency(2).map(|part| part.number()).collect();
+    /// assert_eq!(numbers, [3]);
+    /// ```
+    pub fn parts_with_adjacency(&self, min_symbols: usize) -> impl Iterator<Item = &PartNumber> {
+        self.valid
+            .iter()
+            .zip(&self.symbol_counts)
+            .filter(move |(_, &count)| count >= min_symbols)
+            .map(|(part, _)| part)
+    }
+
+    /// Renders the schematic as an SVG document. Symbols are drawn in gray and potential
+    /// gears in orange; part numbers are highlighted in green if they are valid and in red
+    /// if they are not.
+    pub fn to_svg(&self) -> String {
+        let map = &self.symbol_map;
+        let mut svg = Svg::new(map.line_length, map.num_lines, 16);
+        svg.grid(|x, y| match SymbolType::from(map.row(y)[x]) {
+            SymbolType::None => None,
+            SymbolType::Generic => Some("gray"),
+            SymbolType::GearCandidate => Some("orange"),
+        });
+
+        for (parts, color) in [(&self.valid, "green"), (&self.invalid, "red")] {
+            for part in parts {
+                svg.span(part.pos, part.row, part.len, color);
+                for (i, digit) in part.number.to_string().chars().enumerate() {
+                    svg.text(part.pos + i, part.row, &digit.to_string(), "white");
+                }
+            }
         }
 
-        sum
+        svg.to_string()
+    }
+}
+
+impl SymbolParts {
+    /// Gets the position of the symbol.
+    pub fn position(&self) -> &SymbolPosition {
+        &self.position
+    }
+
+    /// Gets the symbol character.
+    pub fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    /// Gets the part numbers next to the symbol, in reading order.
+    pub fn parts(&self) -> &[PartNumber] {
+        &self.parts
     }
 }
 
@@ -177,6 +456,11 @@ impl PartNumber {
         }
     }
 
+    /// Gets the value of the part number.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
     /// Checks if the given position is adjacent to the current symbol position.
     ///
     /// # Arguments
@@ -216,9 +500,35 @@ impl PartNumber {
     }
 }
 
-impl SymbolMap {
-    pub fn potential_gears(&self) -> std::slice::Iter<SymbolPosition> {
-        self.potential_gears.iter()
+impl SymbolMap<'_> {
+    /// Gets the characters of a row.
+    fn row(&self, y: usize) -> &[u8] {
+        &self.bytes[self.rows[y]..][..self.line_length]
+    }
+
+    /// Copies the input, if it is still borrowed.
+    fn into_owned(self) -> SymbolMap<'static> {
+        SymbolMap {
+            num_lines: self.num_lines,
+            line_length: self.line_length,
+            bytes: Cow::Owned(self.bytes.into_owned()),
+            rows: self.rows,
+        }
+    }
+
+    /// Iterates the symbols of the rows in reading order, with their characters.
+    fn symbols(&self, rows: Range<usize>) -> impl Iterator<Item = (SymbolPosition, char)> + '_ {
+        rows.flat_map(move |y| {
+            let row = self.row(y).iter().enumerate();
+            row.filter(|&(_, &byte)| SymbolType::from(byte).is_symbol())
+                .map(move |(x, &byte)| (SymbolPosition { x, y }, char::from(byte)))
+        })
+    }
+
+    /// Replaces the character of a cell, copying the input if it is still borrowed.
+    fn set(&mut self, x: usize, y: usize, byte: u8) {
+        let index = self.rows[y] + x;
+        self.bytes.to_mut()[index] = byte;
     }
 
     /// Checks if the specified address represents a symbol in the map.
@@ -241,7 +551,7 @@ impl SymbolMap {
             return Err(InvalidAddressError(x, y));
         }
 
-        Ok(self.map[y * self.line_length + x]).map(|s| s.is_symbol())
+        Ok(SymbolType::from(self.row(y)[x]).is_symbol())
     }
 
     /// Checks if there is a symbol adjacent to the given row and range of columns.
@@ -297,20 +607,18 @@ impl SymbolMap {
         let start = start as usize;
         let end = end as usize;
 
-        let line_start = row * self.line_length;
-        let line_end = line_start + self.line_length;
-        let line = &self.map[line_start..line_end];
-
-        let segment = &line[start..=end];
-        segment.iter().any(|&is_symbol| is_symbol.is_symbol())
+        let segment = &self.row(row)[start..=end];
+        segment
+            .iter()
+            .any(|&byte| SymbolType::from(byte).is_symbol())
     }
 }
 
-impl From<char> for SymbolType {
-    fn from(value: char) -> Self {
-        if value == '*' {
+impl From<u8> for SymbolType {
+    fn from(value: u8) -> Self {
+        if value == b'*' {
             Self::GearCandidate
-        } else if !value.is_ascii_digit() && value != '.' {
+        } else if !value.is_ascii_digit() && value != b'.' {
             Self::Generic
         } else {
             Self::None
@@ -318,131 +626,72 @@ impl From<char> for SymbolType {
     }
 }
 
-impl FromStr for Schematic {
+impl FromStr for Schematic<'static> {
     type Err = ParseSchematicError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let symbol_map = SymbolMap::from_str(s)?;
-        let line_len = symbol_map.line_length;
-
-        let mut valid = Vec::new();
-        let mut invalid = Vec::new();
-
-        // We trim whitespace to make test input easier.
-        'line: for (line_no, line) in s.lines().map(|l| l.trim()).enumerate() {
-            if line.is_empty() {
-                continue;
-            }
-
-            let mut start_pos = 0;
-            while start_pos < line_len {
-                // Find the position of the first digit in the line or skip to the next line.
-                let first_digit = start_pos
-                    + match line[start_pos..].bytes().position(|c| c.is_ascii_digit()) {
-                        None => continue 'line,
-                        Some(digit) => digit,
-                    };
-
-                // Find the position of the first non-digit after the specified position; if none
-                // is found, return the line length.
-                let first_non_digit = first_digit
-                    + line[first_digit..]
-                        .bytes()
-                        .position(|c| !c.is_ascii_digit())
-                        .unwrap_or(line.len() - first_digit);
-
-                // Register start position for the next number.
-                start_pos = first_non_digit;
-
-                // Extract region containing numbers.
-                debug_assert!(first_non_digit <= line_len);
-                let digit = &line[first_digit..first_non_digit];
-
-                // Test if we are surrounded by a symbol.
-                let range = (first_digit as isize - 1)..=(first_non_digit as isize);
-                let next_to_symbol = symbol_map.is_next_to_symbol(range, line_no as _);
-
-                let part = PartNumber {
-                    row: line_no,
-                    pos: first_digit,
-                    len: digit.len(),
-                    number: u32::from_str(digit).map_err(|_| {
-                        ParseSchematicError::Line(line_no, "Failed to parse part number")
-                    })?,
-                };
-
-                if next_to_symbol {
-                    valid.push(part);
-                } else {
-                    invalid.push(part);
-                }
-            }
-        }
-
-        Ok(Self {
-            valid,
-            invalid,
-            symbol_map,
-        })
+        Self::parse(s)
     }
 }
 
-impl FromStr for SymbolMap {
+impl FromStr for SymbolMap<'static> {
     type Err = ParseSchematicError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(SymbolMap::parse_borrowed(s)?.into_owned())
+    }
+}
+
+impl<'a> SymbolMap<'a> {
+    /// Parses the symbol map directly off the bytes of the borrowed input.
+    fn parse_borrowed(s: &'a str) -> Result<Self, ParseSchematicError> {
         if !s.is_ascii() {
             return Err(ParseSchematicError::NotAscii);
         }
 
-        // We trim whitespace to make test input easier.
-        let mut lines = s.lines().map(|l| l.trim()).peekable();
+        let mut lines = rows(s).peekable();
         let first_line = *lines.peek().ok_or(ParseSchematicError::InputEmpty)?;
         let line_length = first_line.len();
         if line_length >= isize::MAX as usize {
             return Err(ParseSchematicError::Line(0, "Input line too long"));
         }
 
-        // We reserve capacity for the entire input length - this is typically oversized
-        // as we do not need to keep the space for the newline characters. It is, however,
-        // a safe upper bound that's not excessively large.
-        let mut map = Vec::with_capacity(s.len());
-        let mut potential_gears = Vec::new();
-
-        let mut num_lines = 0;
+        // Every row takes at least a line terminator besides its cells, so this is a safe
+        // upper bound that means the offsets are never reallocated.
+        let bytes = s.as_bytes();
+        let mut rows = Vec::with_capacity(bytes.len() / (line_length + 1) + 1);
         for (line_no, line) in lines.enumerate() {
-            num_lines += 1;
             if line.len() != line_length {
                 return Err(ParseSchematicError::Line(line_no, "Line length mismatch"));
             }
 
-            // Convert every character into a boolean. true implies the character was a symbol,
-            // false implies it was not. Dots do not count as a character as per the problem description.
-            let symbol_detection = Vec::from_iter(line.chars().map(SymbolType::from));
-
-            // Register all potential gear positions.
-            potential_gears.extend(
-                symbol_detection
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, &sym)| sym.is_potential_gear())
-                    .map(|(x, _)| SymbolPosition { x, y: line_no }),
-            );
-
-            // Register all symbols.
-            map.extend(symbol_detection);
+            // The rows are subslices of the input, so their offset follows from their address.
+            rows.push(line.as_ptr() as usize - bytes.as_ptr() as usize);
         }
 
-        map.shrink_to_fit();
         Ok(SymbolMap {
-            num_lines,
+            num_lines: rows.len(),
             line_length,
-            map,
-            potential_gears,
+            bytes: Cow::Borrowed(bytes),
+            rows,
         })
     }
 }
 
+/// Iterates the lines of the input as byte slices.
+///
+/// We trim whitespace to make test input easier.
+fn rows(input: &str) -> impl Iterator<Item = &[u8]> {
+    lines(input.as_bytes()).map(<[u8]>::trim_ascii)
+}
+
+/// Parses a run of ASCII digits, failing if the number doesn't fit.
+fn parse_part_number(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0u32, |number, &digit| {
+        number.checked_mul(10)?.checked_add(u32::from(digit - b'0'))
+    })
+}
+
 /// Represents an error that can occur during parsing of a schematic.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ParseSchematicError {
@@ -465,6 +714,28 @@ impl Display for ParseSchematicError {
 
 impl Error for ParseSchematicError {}
 
+/// Represents an error that can occur when editing a schematic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EditSchematicError {
+    InvalidAddress(InvalidAddressError),
+    NotAscii,
+    NumberTooLarge,
+}
+
+impl Display for EditSchematicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditSchematicError::InvalidAddress(error) => write!(f, "{error}"),
+            EditSchematicError::NotAscii => write!(f, "The character is not proper ASCII"),
+            EditSchematicError::NumberTooLarge => {
+                write!(f, "The edit creates a too large part number")
+            }
+        }
+    }
+}
+
+impl Error for EditSchematicError {}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct InvalidAddressError(usize, usize);
 
@@ -483,6 +754,7 @@ impl Error for InvalidAddressError {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::property::{check, grid};
 
     #[test]
     fn test_schematic_from_string() {
@@ -557,6 +829,168 @@ mod tests {
         assert_eq!(schematic.sum_gear_ratios(), 467835 + 598 * 997);
     }
 
+    #[test]
+    fn test_symbol_adjacency() {
+        const EXAMPLE: &str = "467..114..
+                               ...*......
+                               ..35..633.
+                               ......#...
+                               617*......
+                               .....+.58.
+                               ..592.....
+                               ......755.
+                               ...$.*....
+                               .664.598..
+                               ......*997";
+        let schematic = Schematic::from_str(EXAMPLE).expect("failed to parse schematic");
+        assert_eq!(schematic.symbols().len(), 7);
+
+        let pairs: Vec<_> = schematic
+            .symbols_with_adjacent_parts(2)
+            .map(|symbol| {
+                let numbers: Vec<_> = symbol.parts().iter().map(PartNumber::number).collect();
+                (symbol.position().x(), symbol.position().y(), numbers)
+            })
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (3, 1, vec![467, 35]),
+                (5, 8, vec![755, 598]),
+                (6, 10, vec![598, 997])
+            ]
+        );
+        assert_eq!(schematic.symbols_with_adjacent_parts(1).count(), 4);
+        assert_eq!(schematic.symbols_with_adjacent_parts(3).count(), 0);
+
+        let shared: Vec<_> = schematic
+            .parts_with_adjacency(2)
+            .map(PartNumber::number)
+            .collect();
+        assert_eq!(shared, [598]);
+        assert_eq!(
+            schematic.parts_with_adjacency(1).count(),
+            schematic.num_valid()
+        );
+    }
+
+    #[test]
+    fn test_set_cell() {
+        let mut schematic = Schematic::from_str("467..114..\n...*......").expect("failed to parse");
+        assert_eq!(
+            schematic.set_cell(10, 0, '#'),
+            Err(EditSchematicError::InvalidAddress(InvalidAddressError(
+                10, 0
+            )))
+        );
+        assert_eq!(
+            schematic.set_cell(0, 0, 'ä'),
+            Err(EditSchematicError::NotAscii)
+        );
+
+        // Joining the numbers would exceed a `u32`.
+        for (x, value) in [(8, '9'), (9, '9'), (3, '5')] {
+            schematic.set_cell(x, 0, value).unwrap();
+        }
+        assert_eq!(schematic.sum_valid_parts(), 4675);
+        assert_eq!(
+            schematic.set_cell(4, 0, '1'),
+            Err(EditSchematicError::NumberTooLarge)
+        );
+        schematic.set_cell(0, 0, '.').unwrap();
+        assert_eq!(schematic.set_cell(4, 0, '1'), Ok(()));
+        assert_eq!(schematic.sum_valid_parts(), 675_111_499);
+
+        schematic.set_cell(3, 1, '.').unwrap();
+        assert_eq!(schematic.num_valid(), 0);
+        assert!(schematic.symbols().is_empty());
+    }
+
+    #[test]
+    fn test_edits_match_parsing() {
+        let symbols: Vec<char> = "........123456789*#".chars().collect();
+        check(
+            200,
+            |rng| {
+                let (width, height) = (rng.index(1..10), rng.index(1..10));
+                let input = grid(rng, width, height, &symbols);
+                let edits: Vec<_> = (0..rng.index(1..8))
+                    .map(|_| {
+                        (
+                            rng.index(0..width),
+                            rng.index(0..height),
+                            *rng.choose(&symbols),
+                        )
+                    })
+                    .collect();
+                (input, edits)
+            },
+            |(input, edits)| {
+                let mut schematic = Schematic::from_str(input).expect("failed to parse schematic");
+                let mut rows: Vec<Vec<char>> =
+                    input.lines().map(|line| line.chars().collect()).collect();
+                edits.iter().all(|&(x, y, value)| {
+                    if schematic.set_cell(x, y, value).is_err() {
+                        return true;
+                    }
+                    rows[y][x] = value;
+                    let text: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                    let parsed =
+                        Schematic::from_str(&text.join("\n")).expect("failed to parse schematic");
+
+                    let summary = |schematic: &Schematic| {
+                        let shared: Vec<u32> = schematic
+                            .parts_with_adjacency(2)
+                            .map(PartNumber::number)
+                            .collect();
+                        let symbols: Vec<_> = schematic
+                            .symbols()
+                            .iter()
+                            .map(|symbol| {
+                                (
+                                    symbol.position().x(),
+                                    symbol.position().y(),
+                                    symbol.parts().len(),
+                                )
+                            })
+                            .collect();
+                        (
+                            schematic.sum_valid_parts(),
+                            schematic.sum_gear_ratios(),
+                            shared,
+                            symbols,
+                        )
+                    };
+                    summary(&schematic) == summary(&parsed)
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn test_parse_borrowed() {
+        let input = String::from("  467..114..\r\n  ...*......\n");
+        let mut schematic = Schematic::parse_borrowed(&input).expect("failed to parse");
+        assert!(matches!(schematic.symbol_map.bytes, Cow::Borrowed(_)));
+        assert_eq!(schematic.symbol_map.row(1), b"...*......");
+        assert_eq!(schematic.sum_valid_parts(), 467);
+
+        schematic.set_cell(5, 1, '#').unwrap();
+        assert!(matches!(schematic.symbol_map.bytes, Cow::Owned(_)));
+        assert_eq!(schematic.symbol_map.row(1), b"...*.#....");
+        assert_eq!(schematic.sum_valid_parts(), 467 + 114);
+        assert_eq!(input, "  467..114..\r\n  ...*......\n");
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let schematic = Schematic::from_str("467..114..\n...*......").expect("failed to parse");
+        let svg = schematic.to_svg();
+        assert!(svg.contains(r#"<rect x="0" y="0" width="48" height="16" fill="green"/>"#));
+        assert!(svg.contains(r#"<rect x="80" y="0" width="48" height="16" fill="red"/>"#));
+        assert!(svg.contains(r#"<rect x="48" y="16" width="16" height="16" fill="orange"/>"#));
+    }
+
     #[test]
     fn test_symbol_map_from_string_single_line() {
         let map = SymbolMap::from_str("...$.*....").expect("failed to parse input");
@@ -611,7 +1045,12 @@ mod tests {
         assert_eq!(map.is_symbol(10, 0), Err(InvalidAddressError(10, 0)));
         assert_eq!(map.is_symbol(9, 2), Err(InvalidAddressError(9, 2)));
 
-        assert_eq!(map.potential_gears.len(), 1);
+        assert_eq!(
+            map.symbols(0..map.num_lines)
+                .filter(|(_, c)| *c == '*')
+                .count(),
+            1
+        );
     }
 
     #[test]
@@ -620,15 +1059,20 @@ mod tests {
         assert!(map.contains_symbol(0.., 0));
         assert!(map.contains_symbol(3..=3, 0));
         assert!(!map.contains_symbol(3..=3, 1));
-        assert_eq!(map.potential_gears.len(), 2);
+        assert_eq!(
+            map.symbols(0..map.num_lines)
+                .filter(|(_, c)| *c == '*')
+                .count(),
+            2
+        );
     }
 
     #[test]
-    fn test_symbol_type_from_char() {
-        assert_eq!(SymbolType::from('*'), SymbolType::GearCandidate);
-        assert_eq!(SymbolType::from('%'), SymbolType::Generic);
-        assert_eq!(SymbolType::from('0'), SymbolType::None);
-        assert_eq!(SymbolType::from('.'), SymbolType::None);
+    fn test_symbol_type_from_byte() {
+        assert_eq!(SymbolType::from(b'*'), SymbolType::GearCandidate);
+        assert_eq!(SymbolType::from(b'%'), SymbolType::Generic);
+        assert_eq!(SymbolType::from(b'0'), SymbolType::None);
+        assert_eq!(SymbolType::from(b'.'), SymbolType::None);
     }
 
     #[test]
@@ -654,4 +1098,78 @@ mod tests {
         assert!(!part.is_adjacent(SymbolPosition { x: 8, y: 15 }));
         assert!(!part.is_adjacent(SymbolPosition { x: 9, y: 16 }));
     }
+
+    #[test]
+    fn test_random_schematics() {
+        /// Finds the part numbers and gear ratios by checking the neighborhood of every number.
+        fn reference(input: &str) -> (u32, u32) {
+            let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+            let mut gears = std::collections::HashMap::<_, Vec<u32>>::new();
+            let mut sum = 0;
+            for (y, row) in rows.iter().enumerate() {
+                let mut x = 0;
+                while x < row.len() {
+                    let len = row[x..].iter().take_while(|b| b.is_ascii_digit()).count();
+                    if len == 0 {
+                        x += 1;
+                        continue;
+                    }
+
+                    let number: u32 = input.lines().nth(y).unwrap()[x..x + len].parse().unwrap();
+                    let mut is_part = false;
+                    let neighbors = rows.iter().enumerate().skip(y.saturating_sub(1));
+                    for (ny, neighbors) in neighbors.take(if y == 0 { 2 } else { 3 }) {
+                        let cells = neighbors.iter().enumerate().skip(x.saturating_sub(1));
+                        for (nx, &symbol) in cells.take(len + if x == 0 { 1 } else { 2 }) {
+                            if symbol != b'.' && !symbol.is_ascii_digit() {
+                                is_part = true;
+                                if symbol == b'*' {
+                                    gears.entry((nx, ny)).or_default().push(number);
+                                }
+                            }
+                        }
+                    }
+                    if is_part {
+                        sum += number;
+                    }
+                    x += len;
+                }
+            }
+
+            let ratios = gears
+                .values()
+                .filter(|numbers| numbers.len() == 2)
+                .map(|numbers| numbers[0] * numbers[1])
+                .sum();
+            (sum, ratios)
+        }
+
+        // Part numbers have no leading zeros.
+        let symbols: Vec<char> = "........123456789*#".chars().collect();
+        check(
+            300,
+            |rng| {
+                let (width, height) = (rng.index(1..12), rng.index(1..12));
+                let grid = grid(rng, width, height, &symbols);
+
+                // Part numbers have at most three digits.
+                let mut digits = 0;
+                grid.chars()
+                    .map(|c| {
+                        digits = if c.is_ascii_digit() { digits + 1 } else { 0 };
+                        if digits > 3 {
+                            digits = 0;
+                            '.'
+                        } else {
+                            c
+                        }
+                    })
+                    .collect::<String>()
+            },
+            |input| {
+                let schematic = Schematic::from_str(input).expect("failed to parse schematic");
+                (schematic.sum_valid_parts(), schematic.sum_gear_ratios()) == reference(input)
+            },
+        );
+    }
 }
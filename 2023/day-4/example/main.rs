@@ -1,14 +1,11 @@
-use aoc_2023_day_4::Card;
-
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() {
-    println!("2023 Day 4: Scratchcards");
-
-    let cards = Card::parse_all(INPUT).expect("invalid input");
-    println!("Total points: {}", Card::sum_all_scores(&cards));
-    println!(
-        "Total count of copied cards: {}",
-        Card::count_copied_cards(cards)
-    );
-}
+aoc_utils::aoc_main!(aoc_2023_day_4;
+    "Total points: {}"
+        => |input| {
+            let cards = Card::parse_all(input).expect("invalid input");
+            Card::sum_all_scores(&cards)
+        },
+    "Total count of copied cards: {}"
+        => |input| {
+            Card::count_copied_cards(Card::parse_all(input).expect("invalid input"))
+        },
+);
@@ -1,19 +1,78 @@
-use std::collections::HashSet;
+use aoc_utils::input::fold_lines;
+use aoc_utils::iter::merge_join;
+use aoc_utils::trace;
+use aoc_utils::OverflowError;
+use std::collections::VecDeque;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
 use std::str::FromStr;
 
-#[allow(dead_code)]
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 4,
+    title: "Scratchcards",
+    example_answers: [Some("13"), Some("30")],
+    real_answers: Some([Some("24706"), Some("13114317")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let cards = Card::parse_all(input).ok()?;
+    let winning = cards
+        .iter()
+        .filter(|card| card.matching_numbers().next().is_some())
+        .count();
+    Some(format!("{} cards, {winning} of them winning", cards.len()))
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Card {
     card_no: u32,
+    /// The winning numbers, sorted ascending.
     winning_numbers: Vec<u32>,
+    /// The numbers we have, sorted ascending.
     our_numbers: Vec<u32>,
 }
 
 impl Card {
+    /// Gets the number of the card.
+    pub fn card_no(&self) -> u32 {
+        self.card_no
+    }
+
+    /// Gets the winning numbers in ascending order.
+    pub fn winning_numbers(&self) -> &[u32] {
+        &self.winning_numbers
+    }
+
+    /// Gets the numbers we have in ascending order.
+    pub fn our_numbers(&self) -> &[u32] {
+        &self.our_numbers
+    }
+
+    /// Iterates the numbers we have that are winning numbers, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_2023_day_4::Card;
+    ///
+    /// let card: Card = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".parse().unwrap();
+    /// assert!(card.matching_numbers().eq([17, 48, 83, 86]));
+    /// ```
+    pub fn matching_numbers(&self) -> impl Iterator<Item = u32> + '_ {
+        merge_join(&self.winning_numbers, &self.our_numbers, |&number| number)
+            .map(|(winning, _)| winning[0])
+    }
+
     /// Parses all lines into a vector of [`Card`].
     pub fn parse_all(input: &str) -> Result<Vec<Card>, ParseCardError> {
+        let _span = trace::parse();
         input
             .lines()
             .map(|line| line.trim())
@@ -36,6 +95,55 @@ impl Card {
             .fold(0, |sum, card| sum + card.0)
     }
 
+    /// Like [`sum_all_scores`](Card::sum_all_scores), but fails instead of overflowing.
+    pub fn checked_sum_all_scores<'a, C: IntoIterator<Item = &'a Card>>(
+        cards: C,
+    ) -> Result<u128, OverflowError> {
+        cards.into_iter().try_fold(0u128, |sum, card| {
+            sum.checked_add(card.checked_score()?).ok_or(OverflowError)
+        })
+    }
+
+    /// Like [`count_copied_cards`](Card::count_copied_cards), but fails instead of overflowing.
+    pub fn checked_count_copied_cards<C: IntoIterator<Item = Card>>(
+        cards: C,
+    ) -> Result<u128, OverflowError> {
+        let wins: Vec<_> = cards.into_iter().map(|c| c.get_num_winning()).collect();
+        let mut copies = vec![1u128; wins.len()];
+        for i in 0..copies.len() {
+            let num_copies_to_make = copies[i];
+            for count in copies.iter_mut().take(i + wins[i] as usize + 1).skip(i + 1) {
+                *count = count.checked_add(num_copies_to_make).ok_or(OverflowError)?;
+            }
+        }
+
+        copies.into_iter().try_fold(0u128, |sum, count| {
+            sum.checked_add(count).ok_or(OverflowError)
+        })
+    }
+
+    /// Like [`sum_all_scores`](Card::sum_all_scores), but reads the cards one line at a time.
+    pub fn sum_scores_from_reader<R: BufRead>(reader: R) -> io::Result<u32> {
+        fold_cards(reader, 0, |sum, card| sum + card.get_score())
+    }
+
+    /// Like [`count_copied_cards`](Card::count_copied_cards), but reads the cards one line
+    /// at a time, keeping only the copies still to be made to the following cards.
+    pub fn count_copied_cards_from_reader<R: BufRead>(reader: R) -> io::Result<u32> {
+        let mut pending = VecDeque::new();
+        fold_cards(reader, 0, |sum, card| {
+            let copies = 1 + pending.pop_front().unwrap_or(0);
+            let num_rows_to_copy = card.get_num_winning() as usize;
+            if pending.len() < num_rows_to_copy {
+                pending.resize(num_rows_to_copy, 0);
+            }
+            for count in pending.iter_mut().take(num_rows_to_copy) {
+                *count += copies;
+            }
+            sum + copies
+        })
+    }
+
     /// Determines the number of copies per card.
     pub fn determine_copies<C: IntoIterator<Item = Card>>(cards: C) -> Vec<(u32, Card)> {
         let mut cards: Vec<_> = cards.into_iter().map(|c| (1, c)).collect();
@@ -52,9 +160,7 @@ impl Card {
 
     /// Returns the number of winning numbers in `our_numbers`.
     fn get_num_winning(&self) -> u32 {
-        let winning: HashSet<&u32> = HashSet::from_iter(&self.winning_numbers);
-        let ours = HashSet::from_iter(&self.our_numbers);
-        winning.intersection(&ours).count() as u32
+        self.matching_numbers().count() as u32
     }
 
     /// Calculate the score based on the number of winnings.
@@ -74,6 +180,14 @@ impl Card {
             0
         }
     }
+
+    /// Like [`get_score`](Card::get_score), but fails instead of overflowing.
+    pub fn checked_score(&self) -> Result<u128, OverflowError> {
+        match self.get_num_winning() {
+            0 => Ok(0),
+            n => 1u128.checked_shl(n - 1).ok_or(OverflowError),
+        }
+    }
 }
 
 impl FromStr for Card {
@@ -100,18 +214,22 @@ impl FromStr for Card {
         let winning_numbers = s[..bar_pos].trim();
         let our_numbers = s[bar_pos + 1..].trim();
 
-        let winning_numbers = winning_numbers
+        let mut winning_numbers: Vec<_> = winning_numbers
             .split_whitespace()
             .map(u32::from_str)
             .collect::<Result<_, _>>()
             .map_err(|_| ParseCardError("failed to parse a winning number"))?;
 
-        let our_numbers = our_numbers
+        let mut our_numbers: Vec<_> = our_numbers
             .split_whitespace()
             .map(u32::from_str)
             .collect::<Result<_, _>>()
             .map_err(|_| ParseCardError("failed to parse an owned number"))?;
 
+        // Sorting allows matching the numbers by merging instead of hashing.
+        winning_numbers.sort_unstable();
+        our_numbers.sort_unstable();
+
         Ok(Self {
             card_no,
             winning_numbers,
@@ -120,6 +238,19 @@ impl FromStr for Card {
     }
 }
 
+/// Folds the non-empty lines of the reader as cards, failing on invalid data.
+fn fold_cards<R: BufRead, T>(reader: R, init: T, mut f: impl FnMut(T, Card) -> T) -> io::Result<T> {
+    fold_lines(reader, init, |acc, line| {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(acc);
+        }
+        let card =
+            Card::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(f(acc, card))
+    })
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct ParseCardError(&'static str);
 
@@ -140,9 +271,10 @@ mod tests {
     fn test_parse_card() {
         let card = Card::from_str("Card 31:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
             .expect("invalid card");
-        assert_eq!(card.card_no, 31);
-        assert_eq!(card.winning_numbers, [1, 21, 53, 59, 44]);
-        assert_eq!(card.our_numbers, [69, 82, 63, 72, 16, 21, 14, 1]);
+        assert_eq!(card.card_no(), 31);
+        assert_eq!(card.winning_numbers(), [1, 21, 44, 53, 59]);
+        assert_eq!(card.our_numbers(), [1, 14, 16, 21, 63, 69, 72, 82]);
+        assert!(card.matching_numbers().eq([1, 21]));
     }
 
     #[rstest(
@@ -162,6 +294,28 @@ mod tests {
         assert_eq!(card.get_score(), score);
     }
 
+    #[test]
+    fn test_checked_score() {
+        let card = |wins: u32| {
+            let numbers: Vec<_> = (1..=wins).map(|n| n.to_string()).collect();
+            let numbers = numbers.join(" ");
+            Card::from_str(&format!("Card 1: {numbers} | {numbers}")).expect("invalid card")
+        };
+
+        assert_eq!(card(0).checked_score(), Ok(0));
+        assert_eq!(card(40).checked_score(), Ok(1 << 39));
+        assert_eq!(card(128).checked_score(), Ok(1 << 127));
+        assert_eq!(card(129).checked_score(), Err(OverflowError));
+        assert_eq!(
+            Card::checked_sum_all_scores(&[card(128), card(128)]),
+            Err(OverflowError)
+        );
+
+        // Every card copies all the following ones, doubling the count with each card.
+        let cards: Vec<_> = (0..40).map(|_| card(40)).collect();
+        assert_eq!(Card::checked_count_copied_cards(cards), Ok((1 << 40) - 1));
+    }
+
     #[test]
     fn test_count_copies() {
         const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
@@ -184,4 +338,21 @@ mod tests {
         let total_copies = Card::count_copied_cards(cards);
         assert_eq!(total_copies, 30);
     }
+
+    #[test]
+    fn test_from_reader() {
+        const INPUT: &str = include_str!("../input.txt");
+        let cards = Card::parse_all(INPUT).expect("invalid input");
+        assert_eq!(
+            Card::sum_scores_from_reader(INPUT.as_bytes()).unwrap(),
+            Card::sum_all_scores(&cards)
+        );
+        assert_eq!(
+            Card::count_copied_cards_from_reader(INPUT.as_bytes()).unwrap(),
+            Card::count_copied_cards(cards)
+        );
+
+        let error = Card::sum_scores_from_reader("Card 1: x | 2".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
 }
@@ -1,12 +1,40 @@
 use aoc_utils::parse_whitespace_delimited;
+use aoc_utils::trace;
 use itertools::Itertools;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Range, Sub};
 use std::str::FromStr;
 
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 mod macros;
 
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 5,
+    title: "If You Give A Seed A Fertilizer",
+    example_answers: [Some("35"), Some("46")],
+    real_answers: Some([Some("31599214"), Some("20358599")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let almanac: Almanac = input.parse().ok()?;
+    let (seed, _) = almanac.map_smallest_from_seeds()?;
+    let trace = almanac
+        .trace_seed(seed)
+        .into_iter()
+        .map(|(category, value)| format!("{category} {value}"))
+        .collect::<Vec<_>>()
+        .join(" → ");
+    Some(format!("{almanac}\nclosest seed: {trace}"))
+}
+
 pub trait AlmanacType:
     Copy
     + Clone
@@ -24,6 +52,8 @@ pub trait AlmanacType:
 {
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Almanac {
     seeds: Vec<Seed>,
     seed_to_soil: MapRangeSet<Soil, Seed>,
@@ -35,7 +65,10 @@ pub struct Almanac {
     humidity_to_location: MapRangeSet<Location, Humidity>,
 }
 
-struct MapRange<To, From> {
+/// A contiguous range of source values mapped to a destination range of the same length.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MapRange<To, From> {
     /// The length of the range.
     length: usize,
     /// The destination range.
@@ -44,6 +77,19 @@ struct MapRange<To, From> {
     source: Range<From>,
 }
 
+/// The categories of an almanac, in the order in which it maps them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Category {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
 create_type!(Seed);
 create_type!(Soil);
 create_type!(Fertilizer);
@@ -89,6 +135,31 @@ impl Almanac {
     ///   seed range using [`map_seed`](Almanac::map_seed).
     /// - The smallest location for each of these is the winner.
     pub fn map_smallest_from_seed_ranges(&self) -> Option<(Seed, Location)> {
+        // Iterate through all the seed ranges. The start index corresponds to the smallest
+        // possible location.
+        let mut best: Option<(Seed, Location)> = None;
+        for seed in self.sliced_seed_ranges() {
+            let better = self.map_seed(seed.start);
+
+            if let Some((_, location)) = best {
+                if better >= location {
+                    continue;
+                }
+            }
+
+            best = Some((seed.start, better));
+
+            // Sanity check that the end of the sliced seeds is not a smaller location.
+            let last = self.map_seed(Seed::from(seed.end.value() - 1));
+            debug_assert!(last >= better);
+        }
+
+        best
+    }
+
+    /// Treats each pair of seeds as a range and slices these at the boundaries of the
+    /// `seed-to-soil` map, so that the locations grow along each slice.
+    fn sliced_seed_ranges(&self) -> Vec<Range<Seed>> {
         let mut seeds = Vec::new();
         for pair in &self.seeds.iter().chunks(2) {
             let pair = pair.collect::<Vec<_>>();
@@ -97,61 +168,65 @@ impl Almanac {
         }
         seeds.sort_by_key(|range| range.start);
 
-        // Slice the seeds according to the first map.
-        let mut extra_slices = Vec::new();
-        for range in &self.seed_to_soil.ranges {
-            // If there is a seed range that contains a boundary, slice it.
-            let positions: Vec<_> = seeds
+        // Slice the seeds at the boundaries of the first map.
+        let boundaries: Vec<_> = self.seed_to_soil.boundaries().collect();
+        let mut sliced_seeds = Vec::with_capacity(seeds.len());
+        for seed in seeds {
+            let mut start = seed.start;
+            for &boundary in boundaries
                 .iter()
-                // find overlapping slices
-                .filter(|seed| range.source.start < seed.end && seed.start < range.source.end)
-                .enumerate()
-                .map(|(pos, _)| pos)
-                .collect();
-
-            for pos in positions {
-                let seed_range = &seeds[pos];
-
-                // Don't slice direct matches.
-                if seed_range.start == range.source.start {
-                    continue;
-                }
-
-                let updated_range = seed_range.start..range.source.start;
-                let sliced_range = range.source.start..seed_range.end;
-                seeds[pos] = updated_range;
-                extra_slices.push(sliced_range);
+                .filter(|&&boundary| seed.start < boundary && boundary < seed.end)
+            {
+                sliced_seeds.push(start..boundary);
+                start = boundary;
             }
+            sliced_seeds.push(start..seed.end);
         }
+        sliced_seeds
+    }
 
-        seeds.extend(extra_slices);
-        seeds.sort_by_key(|seed| seed.start);
-
-        // Now iterate through all the seed ranges. The start index corresponds to the smallest
-        // possible location.
-        let mut best_location: Option<Location> = None;
-        let mut best_seed: Option<Seed> = None;
-        for seed in seeds {
-            let better = self.map_seed(seed.start);
-
-            if let Some(location) = best_location {
-                if better >= location {
-                    continue;
-                }
-            }
+    /// Brute-force variant of [`map_smallest_from_seed_ranges`](Almanac::map_smallest_from_seed_ranges)
+    /// that maps every single seed of the ranges.
+    #[cfg(any(test, feature = "reference-impls"))]
+    pub fn map_smallest_from_seed_ranges_bf(&self) -> Option<Location> {
+        self.seeds
+            .chunks(2)
+            .flat_map(|pair| pair[0].value()..pair[0].value() + pair[1].value())
+            .map(|seed| self.map_seed(Seed(seed)))
+            .min()
+    }
 
-            best_location = Some(better);
-            best_seed = Some(seed.start);
+    /// Maps a seed through every category, returning each intermediate value.
+    pub fn trace_seed(&self, seed: Seed) -> Vec<(Category, u64)> {
+        std::iter::successors(
+            Some((Category::Seed, seed.value())),
+            |&(category, value)| self.map_category(category, value),
+        )
+        .collect()
+    }
 
-            // Sanity check that the end of the sliced seeds is indeed a larger location.
-            let last = self.map_seed(Seed::from(seed.end.value() - 1));
-            debug_assert!(last > better);
-        }
+    /// Maps a value of the category to the next category, or returns `None` for a location.
+    fn map_category(&self, category: Category, value: u64) -> Option<(Category, u64)> {
+        let mapped = match category {
+            Category::Seed => self.seed_to_soil.map(Seed(value)).value(),
+            Category::Soil => self.soil_to_fertilizer.map(Soil(value)).value(),
+            Category::Fertilizer => self.fertilizer_to_water.map(Fertilizer(value)).value(),
+            Category::Water => self.water_to_light.map(Water(value)).value(),
+            Category::Light => self.light_to_temperature.map(Light(value)).value(),
+            Category::Temperature => self.temperature_to_humidity.map(Temperature(value)).value(),
+            Category::Humidity => self.humidity_to_location.map(Humidity(value)).value(),
+            Category::Location => return None,
+        };
+        Some((category.next()?, mapped))
+    }
 
-        Some((
-            best_seed.expect("found no location"),
-            best_location.expect("found no location"),
-        ))
+    /// Maps a value of the category all the way to its location.
+    fn locate(&self, category: Category, value: u64) -> u64 {
+        std::iter::successors(Some((category, value)), |&(category, value)| {
+            self.map_category(category, value)
+        })
+        .last()
+        .map_or(value, |(_, location)| location)
     }
 
     fn map_seed(&self, seed: Seed) -> Location {
@@ -189,56 +264,46 @@ impl Almanac {
 
     /// Patches the almanac, ensuring that the optimal
     fn optimize_after_construction(&mut self) {
-        self.humidity_to_location.sort();
-
         // For the last map (humidity to location), the lowest possible location for
-        // each entry is the destination itself.
-        for entry in &mut self.humidity_to_location.ranges {
-            // While we iterate, we can create slices in the map right before us, the
-            // temperature to humidity map.
-            self.temperature_to_humidity.slice(entry.source.start);
-            // self.temperature_to_humidity.slice(entry.source.end);
+        // each entry is the destination itself. Wherever the mapping changes, including the
+        // end of the explicit ranges, we create slices in the map right before it.
+        for boundary in self.humidity_to_location.boundaries() {
+            self.temperature_to_humidity.slice(boundary);
         }
-        self.temperature_to_humidity.sort();
 
         // Slice the light to temperature map.
-        for entry in &self.temperature_to_humidity.ranges {
-            self.light_to_temperature.slice(entry.source.start);
-            // self.light_to_temperature.slice(entry.source.end);
+        for boundary in self.temperature_to_humidity.boundaries() {
+            self.light_to_temperature.slice(boundary);
         }
-        self.light_to_temperature.sort();
 
         // Slice the water to light map.
-        for entry in &self.light_to_temperature.ranges {
-            self.water_to_light.slice(entry.source.start);
-            // self.water_to_light.slice(entry.source.end);
+        for boundary in self.light_to_temperature.boundaries() {
+            self.water_to_light.slice(boundary);
         }
-        self.water_to_light.sort();
 
         // Slice the fertilizer to water map.
-        for entry in &self.water_to_light.ranges {
-            self.fertilizer_to_water.slice(entry.source.start);
-            // self.fertilizer_to_water.slice(entry.source.end);
+        for boundary in self.water_to_light.boundaries() {
+            self.fertilizer_to_water.slice(boundary);
         }
-        self.fertilizer_to_water.sort();
 
         // Slice the soil to fertilizer map.
-        for entry in &self.fertilizer_to_water.ranges {
-            self.soil_to_fertilizer.slice(entry.source.start);
-            // self.soil_to_fertilizer.slice(entry.source.end);
+        for boundary in self.fertilizer_to_water.boundaries() {
+            self.soil_to_fertilizer.slice(boundary);
         }
-        self.soil_to_fertilizer.sort();
 
         // Slice the seed to soil map.
-        for entry in &self.soil_to_fertilizer.ranges {
-            self.seed_to_soil.slice(entry.source.start);
-            // self.seed_to_soil.slice(entry.source.end);
+        for boundary in self.soil_to_fertilizer.boundaries() {
+            self.seed_to_soil.slice(boundary);
         }
-        self.seed_to_soil.sort();
     }
 }
 
-struct MapRangeSet<Destination, Source> {
+/// A set of [`MapRange`] entries covering the entire source value space.
+///
+/// The ranges are kept sorted by their source start, allowing lookups by binary search.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MapRangeSet<Destination, Source> {
     ranges: Vec<MapRange<Destination, Source>>,
 }
 
@@ -247,48 +312,103 @@ where
     Destination: AlmanacType,
     Source: AlmanacType,
 {
-    #[allow(dead_code)]
-    fn len(&self) -> usize {
+    /// Gets the number of ranges, including the ones plugging the gaps between the mapped ranges.
+    pub fn len(&self) -> usize {
         self.ranges.len()
     }
 
-    fn map(&self, source: Source) -> Destination {
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Gets the ranges, sorted by their source start.
+    pub fn ranges(&self) -> &[MapRange<Destination, Source>] {
+        &self.ranges
+    }
+
+    /// Gets the source value at which the explicit ranges end. All values from here on
+    /// are mapped to themselves.
+    pub fn end(&self) -> Source {
+        self.ranges
+            .last()
+            .map_or(Source::from(0), |range| range.source.end)
+    }
+
+    /// Gets the source values at which the mapping changes, i.e. the start of each range
+    /// followed by the [`end`](MapRangeSet::end) of the explicit ranges.
+    pub fn boundaries(&self) -> impl Iterator<Item = Source> + '_ {
         self.ranges
             .iter()
-            .filter(|&map| map.source.start <= source)
-            .filter(|&map| map.source.end > source)
-            .find_map(|map| map.map(source))
-            .expect("not all ranges are covered")
+            .map(|range| range.source.start)
+            .chain(std::iter::once(self.end()))
     }
 
-    /// Sorts the set, e.g. after a call to [`slice`](MapRangeSet::slice).
-    fn sort(&mut self) {
-        self.ranges.sort_by_key(|r| r.source.start);
+    /// Maps a source value to its destination value using the explicit ranges.
+    ///
+    /// Returns `None` if the value lies beyond the [`end`](MapRangeSet::end) of the ranges.
+    pub fn get(&self, source: Source) -> Option<Destination> {
+        let pos = self
+            .ranges
+            .partition_point(|range| range.source.start <= source);
+        pos.checked_sub(1)
+            .and_then(|pos| self.ranges[pos].map(source))
+    }
+
+    /// Maps a source value to its destination value.
+    ///
+    /// Values not covered by an explicit range are mapped to themselves.
+    pub fn map(&self, source: Source) -> Destination {
+        self.get(source)
+            .unwrap_or_else(|| Destination::from(source.into()))
     }
 
-    /// Slices the map set so that the [`MapRange`] containing the destination index is split
+    /// Slices the map set so that every [`MapRange`] containing the destination index is split
     /// across that index, such that the left part does not contain it and the right part start
     /// with it.
     ///
-    /// After this, the segment list is unsorted and should be [sorted](MapRangeSet::sort) again for proper use.
+    /// If the index lies beyond the [`end`](MapRangeSet::end) of the ranges, the implicit identity
+    /// mapping is made explicit up to the index, so that a new range starts there.
+    ///
+    /// The right parts are inserted right after the left ones, keeping the set sorted by source.
     fn slice(&mut self, index: Destination) {
-        let pos = match self
+        let positions: Vec<_> = self
             .ranges
-            .iter_mut()
-            .position(|map| map.destination.start <= index && map.destination.end > index)
-        {
-            // It's possible that the destination range is unmapped in the current set.
-            None => return,
-            Some(pos) => pos,
-        };
+            .iter()
+            .enumerate()
+            .filter(|(_, range)| range.destination.start < index && range.destination.end > index)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        for pos in positions.into_iter().rev() {
+            let sliced_range = self.ranges[pos].slice(index);
+            self.ranges.insert(pos + 1, sliced_range);
+        }
 
-        // Don't slice if it's an exact boundary.
-        if self.ranges[pos].destination.start == index {
-            return;
+        let end: u64 = self.end().into();
+        let index: u64 = index.into();
+        if index > end {
+            self.ranges.push(MapRange::new(
+                Destination::from(end),
+                Source::from(end),
+                (index - end) as usize,
+            ));
         }
+    }
+}
 
-        let sliced_range = self.ranges[pos].slice(index);
-        self.ranges.push(sliced_range);
+impl Category {
+    /// Gets the category that this one maps to, or `None` for a location.
+    pub fn next(self) -> Option<Category> {
+        match self {
+            Category::Seed => Some(Category::Soil),
+            Category::Soil => Some(Category::Fertilizer),
+            Category::Fertilizer => Some(Category::Water),
+            Category::Water => Some(Category::Light),
+            Category::Light => Some(Category::Temperature),
+            Category::Temperature => Some(Category::Humidity),
+            Category::Humidity => Some(Category::Location),
+            Category::Location => None,
+        }
     }
 }
 
@@ -305,11 +425,24 @@ impl<To, From> MapRange<To, From> {
         }
     }
 
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.length
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Gets the destination range.
+    pub fn destination(&self) -> &Range<To> {
+        &self.destination
+    }
+
+    /// Gets the source range.
+    pub fn source(&self) -> &Range<From> {
+        &self.source
+    }
+
     pub fn map(&self, source: From) -> Option<To>
     where
         From: AlmanacType,
@@ -323,11 +456,8 @@ impl<To, From> MapRange<To, From> {
         Some(self.destination.start + offset)
     }
 
-    /// Slices the map set so that the [`MapRange`] containing the destination index is split
-    /// across that index, such that the left part does not contain it and the right part start
-    /// with it.
-    ///
-    /// After this, the segment list is unsorted and should be sorted again for proper use.
+    /// Slices the range at the destination index, such that this range ends right before
+    /// the index and the returned range starts with it.
     fn slice(&mut self, index: To) -> MapRange<To, From>
     where
         To: AlmanacType,
@@ -366,7 +496,7 @@ where
     fn from(mut ranges: Vec<MapRange<Destination, Source>>) -> Self {
         ranges.sort_by_key(|r| r.source.start);
 
-        // Find holes and plug them. This provides full coverage of the entire value space.
+        // Find holes and plug them. This provides full coverage up to the end of the last range.
         let mut next_start = 0;
         let mut plugs = Vec::new();
         for range in &ranges {
@@ -384,23 +514,12 @@ where
             next_start = range.source.end.into();
         }
 
-        // Merge and sort.
+        // Merge and sort. Values beyond the last range are implicitly mapped to themselves.
         if !plugs.is_empty() {
             ranges.extend(plugs);
             ranges.sort_by_key(|r| r.source.start);
         }
 
-        // Fill in the last range. We do this after sorting because it always goes last anyway.
-        let last_range = &ranges[ranges.len() - 1];
-        debug_assert!(last_range.source.end > 0.into());
-
-        let last_range_start = last_range.source.end.into();
-        ranges.push(MapRange {
-            source: Source::from(last_range_start)..Source::from(u64::MAX),
-            destination: Destination::from(next_start)..Destination::from(u64::MAX),
-            length: (u64::MAX - last_range_start) as usize,
-        });
-
         Self { ranges }
     }
 }
@@ -421,6 +540,7 @@ impl FromStr for Almanac {
     type Err = ParseAlmanacError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
         let mut sections = s
             .split_terminator("\n\n")
             .map(|line| line.trim())
@@ -509,6 +629,148 @@ impl FromStr for Almanac {
     }
 }
 
+impl Display for Category {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Category::Seed => "seed",
+            Category::Soil => "soil",
+            Category::Fertilizer => "fertilizer",
+            Category::Water => "water",
+            Category::Light => "light",
+            Category::Temperature => "temperature",
+            Category::Humidity => "humidity",
+            Category::Location => "location",
+        };
+        f.write_str(name)
+    }
+}
+
+impl<To, From> Display for MapRange<To, From>
+where
+    To: Display,
+    From: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}..{} → {}..{}, length {}",
+            self.source.start,
+            self.source.end,
+            self.destination.start,
+            self.destination.end,
+            self.length
+        )
+    }
+}
+
+impl<Destination, Source> Display for MapRangeSet<Destination, Source>
+where
+    Destination: AlmanacType + Display,
+    Source: AlmanacType + Display,
+{
+    /// Prints the source and destination range and the length of each range, in aligned
+    /// columns.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_ranges(f, &self.ranges, None::<fn(&_) -> u64>)
+    }
+}
+
+impl Display for Almanac {
+    /// Prints the seeds and the ranges of every map like [`MapRangeSet`] does, adding the
+    /// location that the start of each range ends up at. After slicing, this is the smallest
+    /// location of the range.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {seed}")?;
+        }
+        writeln!(f)?;
+
+        write_stage(f, self, Category::Seed, &self.seed_to_soil)?;
+        write_stage(f, self, Category::Soil, &self.soil_to_fertilizer)?;
+        write_stage(f, self, Category::Fertilizer, &self.fertilizer_to_water)?;
+        write_stage(f, self, Category::Water, &self.water_to_light)?;
+        write_stage(f, self, Category::Light, &self.light_to_temperature)?;
+        write_stage(
+            f,
+            self,
+            Category::Temperature,
+            &self.temperature_to_humidity,
+        )?;
+        write_stage(f, self, Category::Humidity, &self.humidity_to_location)
+    }
+}
+
+/// Writes the heading and ranges of a map, with the location of each range's destination start.
+fn write_stage<Destination, Source>(
+    f: &mut Formatter<'_>,
+    almanac: &Almanac,
+    source: Category,
+    set: &MapRangeSet<Destination, Source>,
+) -> std::fmt::Result
+where
+    Destination: AlmanacType + Display,
+    Source: AlmanacType + Display,
+{
+    let destination = source.next().expect("locations aren't mapped");
+    writeln!(f, "\n{source}-to-{destination} map:")?;
+    write_ranges(
+        f,
+        &set.ranges,
+        Some(|range: &MapRange<Destination, Source>| {
+            almanac.locate(destination, range.destination.start.into())
+        }),
+    )
+}
+
+/// Writes one row per range, aligning the source and destination ranges, the lengths and the
+/// optional locations in columns below a header.
+fn write_ranges<Destination, Source, L>(
+    f: &mut Formatter<'_>,
+    ranges: &[MapRange<Destination, Source>],
+    location: Option<L>,
+) -> std::fmt::Result
+where
+    Destination: Display,
+    Source: Display,
+    L: Fn(&MapRange<Destination, Source>) -> u64,
+{
+    let header = ["source", "destination", "length", "location"].map(String::from);
+    let rows: Vec<[String; 4]> = std::iter::once(header)
+        .chain(ranges.iter().map(|range| {
+            [
+                format!("{}..{}", range.source.start, range.source.end),
+                format!("{}..{}", range.destination.start, range.destination.end),
+                range.length.to_string(),
+                location
+                    .as_ref()
+                    .map_or(String::new(), |l| l(range).to_string()),
+            ]
+        }))
+        .collect();
+
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    for (i, [source, destination, length, location_cell]) in rows.iter().enumerate() {
+        let arrow = if i == 0 { ' ' } else { '→' };
+        write!(
+            f,
+            "{source:>0$} {arrow} {destination:>1$}  {length:>2$}",
+            widths[0], widths[1], widths[2]
+        )?;
+        if location.is_some() {
+            write!(f, "  {location_cell:>0$}", widths[3])?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
 impl<To, From> FromStr for MapRange<To, From>
 where
     From: AlmanacType,
@@ -550,7 +812,7 @@ where
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct ParseMapRangeError(&'static str);
+pub struct ParseMapRangeError(&'static str);
 
 impl Display for ParseMapRangeError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -581,6 +843,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::almanac;
+    use aoc_utils::property::{assert_equivalent, check};
 
     #[test]
     fn test_parse_seeds() {
@@ -668,6 +932,51 @@ mod tests {
         assert_eq!(almanac.map_seed(Seed(14)), Location(43));
         assert_eq!(almanac.map_seed(Seed(55)), Location(86));
         assert_eq!(almanac.map_seed(Seed(13)), Location(35));
+
+        assert_eq!(
+            almanac.trace_seed(Seed(79)),
+            [
+                (Category::Seed, 79),
+                (Category::Soil, 81),
+                (Category::Fertilizer, 81),
+                (Category::Water, 81),
+                (Category::Light, 74),
+                (Category::Temperature, 78),
+                (Category::Humidity, 78),
+                (Category::Location, 82)
+            ]
+        );
+
+        let display = almanac.to_string();
+        assert!(display.starts_with("seeds: 79 14 55 13\n\nseed-to-soil map:\n"));
+        assert!(display.contains(
+            "humidity-to-location map:
+source   destination  length  location
+ 0..56 →       0..56      56         0
+56..93 →      60..97      37        60
+93..97 →      56..60       4        56
+"
+        ));
+    }
+
+    #[test]
+    fn test_display_range_set() {
+        let set: MapRangeSet<Soil, Seed> = [
+            MapRange::new(Soil(50), Seed(98), 2),
+            MapRange::new(Soil(52), Seed(50), 48),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.ranges[2].to_string(), "98..100 → 50..52, length 2");
+        assert_eq!(
+            set.to_string(),
+            " source   destination  length
+  0..50 →       0..50      50
+ 50..98 →     52..100      48
+98..100 →      50..52       2
+"
+        );
     }
 
     #[test]
@@ -702,7 +1011,7 @@ mod tests {
             MapRange::<Soil, Seed>::from_str("52 50 48").expect("failed to parse range"),
         ]);
 
-        assert_eq!(set.len(), 4);
+        assert_eq!(set.len(), 3);
         assert_eq!(set.ranges[0].source.start, Seed(0));
         assert_eq!(set.ranges[0].destination.start, Soil(0));
 
@@ -712,13 +1021,11 @@ mod tests {
         assert_eq!(set.ranges[2].source.start, Seed(98));
         assert_eq!(set.ranges[2].destination.start, Soil(50));
 
-        assert_eq!(set.ranges[3].source.start, Seed(101));
-        assert_eq!(set.ranges[3].destination.start, Soil(101));
+        assert_eq!(set.end(), Seed(101));
 
         set.slice(Soil(51));
-        set.sort();
 
-        assert_eq!(set.len(), 5);
+        assert_eq!(set.len(), 4);
         assert_eq!(set.ranges[0].source.start, Seed(0));
         assert_eq!(set.ranges[0].destination.start, Soil(0));
 
@@ -731,8 +1038,46 @@ mod tests {
         assert_eq!(set.ranges[3].source.start, Seed(99)); // the sliced one
         assert_eq!(set.ranges[3].destination.start, Soil(51));
 
-        assert_eq!(set.ranges[4].source.start, Seed(101));
-        assert_eq!(set.ranges[4].destination.start, Soil(101));
+        assert_eq!(set.end(), Seed(101));
+    }
+
+    #[test]
+    fn test_map_range_set() {
+        let set: MapRangeSet<Soil, Seed> = [
+            MapRange::new(Soil(50), Seed(98), 2),
+            MapRange::new(Soil(52), Seed(50), 48),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.map(Seed(0)), Soil(0));
+        assert_eq!(set.map(Seed(49)), Soil(49));
+        assert_eq!(set.map(Seed(50)), Soil(52));
+        assert_eq!(set.map(Seed(97)), Soil(99));
+        assert_eq!(set.map(Seed(98)), Soil(50));
+        assert_eq!(set.map(Seed(99)), Soil(51));
+        assert_eq!(set.map(Seed(100)), Soil(100));
+        assert_eq!(set.map(Seed(1 << 40)), Soil(1 << 40));
+        assert_eq!(set.map(Seed(u64::MAX)), Soil(u64::MAX));
+        assert_eq!(set.get(Seed(99)), Some(Soil(51)));
+        assert_eq!(set.get(Seed(100)), None);
+
+        let starts: Vec<_> = set.ranges().iter().map(|r| r.source().start).collect();
+        assert_eq!(starts, [Seed(0), Seed(50), Seed(98)]);
+    }
+
+    #[test]
+    fn test_slice_beyond_end() {
+        let mut set = MapRangeSet::from(vec![MapRange::new(Soil(50), Seed(98), 2)]);
+        assert_eq!(set.end(), Seed(100));
+
+        set.slice(Soil(120));
+        assert_eq!(set.end(), Seed(120));
+        assert_eq!(
+            set.boundaries().collect::<Vec<_>>(),
+            [Seed(0), Seed(98), Seed(100), Seed(120)]
+        );
+        assert_eq!(set.map(Seed(110)), Soil(110));
     }
 
     #[test]
@@ -742,20 +1087,82 @@ mod tests {
             MapRange::<Soil, Seed>::from_str("52 50 48").expect("failed to parse range"),
         ]);
 
-        assert_eq!(set.len(), 4);
+        assert_eq!(set.len(), 3);
         assert_eq!(set.ranges[0].source.start, Seed(0));
         assert_eq!(set.ranges[1].source.start, Seed(50));
         assert_eq!(set.ranges[2].source.start, Seed(98));
-        assert_eq!(set.ranges[3].source.start, Seed(101));
+        assert_eq!(set.end(), Seed(101));
 
         // This slice should be no-op because it's on an exact destination boundary.
         set.slice(Soil(50));
-        set.sort();
 
-        assert_eq!(set.len(), 4);
+        assert_eq!(set.len(), 3);
         assert_eq!(set.ranges[0].source.start, Seed(0));
         assert_eq!(set.ranges[1].source.start, Seed(50));
         assert_eq!(set.ranges[2].source.start, Seed(98));
-        assert_eq!(set.ranges[3].source.start, Seed(101));
+        assert_eq!(set.end(), Seed(101));
+    }
+
+    #[test]
+    fn test_seed_ranges_brute_force() {
+        assert_equivalent(
+            300,
+            |almanac: &Almanac| almanac.map_smallest_from_seed_ranges_bf(),
+            |almanac: &Almanac| {
+                let (_, location) = almanac.map_smallest_from_seed_ranges()?;
+                Some(location)
+            },
+            |rng| {
+                let ranges = rng.index(1..4);
+                almanac(rng, ranges, 40)
+            },
+        );
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let almanac = almanac(&mut aoc_utils::property::Rng::new(5), 0, 40);
+        assert_eq!(almanac.map_smallest_from_seeds(), None);
+        assert_eq!(almanac.map_smallest_from_seed_ranges(), None);
+    }
+
+    #[test]
+    fn test_smallest_seed_is_in_range() {
+        check(
+            300,
+            |rng| {
+                let ranges = rng.index(1..6);
+                almanac(rng, ranges, 200)
+            },
+            |almanac| {
+                let (seed, location) = almanac
+                    .map_smallest_from_seed_ranges()
+                    .expect("found no location");
+                let in_range = almanac
+                    .seeds
+                    .chunks(2)
+                    .any(|pair| (pair[0]..pair[0] + pair[1].value()).contains(&seed));
+                in_range && almanac.map_seed(seed) == location
+            },
+        );
+    }
+
+    #[test]
+    fn test_locations_grow_along_slices() {
+        check(
+            300,
+            |rng| {
+                let ranges = rng.index(1..4);
+                almanac(rng, ranges, 60)
+            },
+            |almanac| {
+                almanac.sliced_seed_ranges().into_iter().all(|range| {
+                    let start = almanac.map_seed(range.start).value();
+                    (range.start.value()..range.end.value()).all(|seed| {
+                        almanac.map_seed(Seed(seed)).value() == start + seed - range.start.value()
+                    })
+                })
+            },
+        );
     }
 }
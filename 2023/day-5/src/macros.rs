@@ -3,6 +3,7 @@ macro_rules! create_type {
     ($type_name:ident) => {
         paste::paste! {
             #[derive(Debug, Copy, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
             pub struct $type_name(u64);
 
             impl $type_name {
@@ -45,7 +46,7 @@ macro_rules! create_type {
 
             impl PartialOrd for $type_name {
                 fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
-                    Some(self.0.cmp(&other.0))
+                    Some(self.cmp(other))
                 }
 
                 fn lt(&self, other: &Self) -> bool {
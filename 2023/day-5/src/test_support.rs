@@ -0,0 +1,26 @@
+//! Generators for randomized tests of the almanac, available with the `test-support` feature.
+//!
+//! ```
+//! use aoc_2023_day_5::test_support::almanac;
+//! use aoc_2023_day_5::Almanac;
+//! use aoc_utils::property::assert_equivalent;
+//!
+//! assert_equivalent(
+//!     20,
+//!     |almanac: &Almanac| almanac.map_smallest_from_seed_ranges_bf(),
+//!     |almanac: &Almanac| almanac.map_smallest_from_seed_ranges().map(|(_, location)| location),
+//!     |rng| almanac(rng, 2, 30),
+//! );
+//! ```
+
+use crate::Almanac;
+use aoc_utils::property::{self, Rng};
+use std::str::FromStr;
+
+/// Generates an almanac with the specified number of seed ranges, with all values below `limit`.
+///
+/// Every map has up to four ranges, which may leave gaps and overlap in their destinations.
+pub fn almanac(rng: &mut Rng, seed_ranges: usize, limit: u64) -> Almanac {
+    Almanac::from_str(&property::almanac(rng, seed_ranges, limit))
+        .expect("generated an invalid almanac")
+}
@@ -1,82 +1,243 @@
 use aoc_utils::parse_whitespace_delimited;
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::ops::RangeInclusive;
+use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-struct RaceDuration(u64);
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 6,
+    title: "Wait for It",
+    example_answers: [Some("288"), Some("71503")],
+    real_answers: Some([Some("1108800"), Some("36919753")]),
+};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-struct ChargeTime(u64);
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let races: Races = input.parse().ok()?;
+    let ways: Vec<_> = races
+        .races()
+        .iter()
+        .map(|race| race.num_winning_conditions().to_string())
+        .collect();
+    Some(format!(
+        "{} races, won in {} ways",
+        races.len(),
+        ways.join(", ")
+    ))
+}
+
+/// The total duration of a race, in milliseconds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RaceDuration(u64);
+
+/// The time the button of a boat is held at the start of a race, in milliseconds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ChargeTime(u64);
+
+/// The distance a boat travels during a race, in millimeters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BoatDistance(u64);
+
+/// A single race, along with the record distance to beat.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Race {
+    pub duration: RaceDuration,
+    pub record: BoatDistance,
+}
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-struct BoatDistance(u64);
+/// The races listed on the sheet of paper, i.e. the race durations and the record distances.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Races {
+    races: Vec<Race>,
+}
+
+macro_rules! impl_value {
+    ($type_name:ident) => {
+        impl $type_name {
+            pub const fn new(value: u64) -> Self {
+                Self(value)
+            }
+
+            pub const fn value(&self) -> u64 {
+                self.0
+            }
+        }
+    };
+}
+
+impl_value!(RaceDuration);
+impl_value!(ChargeTime);
+impl_value!(BoatDistance);
 
 /// Solution to part 1.
-pub fn product_of_winning_conditions_with_spaces(input: &str) -> u64 {
-    let mut lines = input.lines();
+pub fn product_of_winning_conditions_with_spaces(input: &str) -> Result<u64, ParseRacesError> {
+    Ok(Races::from_str(input)?.product_of_winning_conditions())
+}
 
-    let first_line = lines.next().expect("input is empty");
-    if &first_line[..5] != "Time:" {
-        panic!("Invalid input: Missing time")
+/// Solution to part 2.
+pub fn product_of_winning_conditions_without_spaces(input: &str) -> Result<u64, ParseRacesError> {
+    Ok(Races::parse_without_spaces(input)?.product_of_winning_conditions())
+}
+
+/// Brute-force variant of [`product_of_winning_conditions_with_spaces`] that tries every
+/// charge time of each race.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn product_of_winning_conditions_with_spaces_bf(input: &str) -> Result<u64, ParseRacesError> {
+    Ok(Races::from_str(input)?.product_of_winning_conditions_bf())
+}
+
+/// Brute-force variant of [`product_of_winning_conditions_without_spaces`] that tries every
+/// charge time of the race.
+#[cfg(any(test, feature = "reference-impls"))]
+pub fn product_of_winning_conditions_without_spaces_bf(
+    input: &str,
+) -> Result<u64, ParseRacesError> {
+    Ok(Races::parse_without_spaces(input)?.product_of_winning_conditions_bf())
+}
+
+impl Races {
+    /// Parses the sheet as a single race, ignoring the spaces between the digits.
+    pub fn parse_without_spaces(input: &str) -> Result<Self, ParseRacesError> {
+        Self::parse(input, true)
     }
-    let first_line = first_line[5..].trim();
-    let times: Vec<u64> = parse_whitespace_delimited(first_line).expect("unable to parse times");
 
-    let second_line = lines.next().expect("input is toos hort");
-    if &second_line[..9] != "Distance:" {
-        panic!("Invalid input: Missing distnances")
+    /// Gets the races.
+    pub fn races(&self) -> &[Race] {
+        &self.races
     }
-    let second_line = second_line[9..].trim();
-    let distances: Vec<u64> =
-        parse_whitespace_delimited(second_line).expect("unable to parse distances");
 
-    product_of_winning_conditions(times, distances)
-}
+    /// Gets the number of races.
+    pub fn len(&self) -> usize {
+        self.races.len()
+    }
 
-/// Solution to part 2.
-pub fn product_of_winning_conditions_without_spaces(input: &str) -> u64 {
-    let mut lines = input.lines();
+    pub fn is_empty(&self) -> bool {
+        self.races.is_empty()
+    }
 
-    let first_line = lines.next().expect("input is empty");
-    if &first_line[..5] != "Time:" {
-        panic!("Invalid input: Missing time")
+    /// Determines the product of the number of winning conditions of all races.
+    pub fn product_of_winning_conditions(&self) -> u64 {
+        self.races
+            .iter()
+            .map(Race::num_winning_conditions)
+            .product()
     }
-    let first_line = first_line[5..].trim().replace(' ', "");
-    let times: Vec<u64> = parse_whitespace_delimited(&first_line).expect("unable to parse times");
 
-    let second_line = lines.next().expect("input is toos hort");
-    if &second_line[..9] != "Distance:" {
-        panic!("Invalid input: Missing distnances")
+    /// Brute-force variant of [`product_of_winning_conditions`](Races::product_of_winning_conditions).
+    #[cfg(any(test, feature = "reference-impls"))]
+    pub fn product_of_winning_conditions_bf(&self) -> u64 {
+        self.races
+            .iter()
+            .map(Race::num_winning_conditions_bf)
+            .product()
+    }
+
+    /// Parses the race durations and records, optionally ignoring the spaces between the digits.
+    fn parse(input: &str, ignore_spaces: bool) -> Result<Self, ParseRacesError> {
+        let _span = trace::parse();
+        let parse_line = |line: Option<&str>, header: &str, error| {
+            let values = line
+                .map(str::trim)
+                .and_then(|line| line.strip_prefix(header))
+                .ok_or(ParseRacesError(error))?;
+            let values = if ignore_spaces {
+                values.replace(' ', "")
+            } else {
+                values.to_string()
+            };
+            parse_whitespace_delimited::<u64>(&values)
+                .map_err(|_| ParseRacesError("invalid number"))
+        };
+
+        let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+        let times = parse_line(lines.next(), "Time:", "missing times")?;
+        let distances = parse_line(lines.next(), "Distance:", "missing distances")?;
+        if times.len() != distances.len() {
+            return Err(ParseRacesError("number of times and distances differ"));
+        }
+
+        Ok(Self {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|(time, distance)| Race::new(RaceDuration(time), BoatDistance(distance)))
+                .collect(),
+        })
     }
-    let second_line = second_line[9..].trim().replace(' ', "");
-    let distances: Vec<u64> =
-        parse_whitespace_delimited(&second_line).expect("unable to parse distances");
+}
 
-    product_of_winning_conditions(times, distances)
+impl FromStr for Races {
+    type Err = ParseRacesError;
+
+    /// Parses the sheet of races, with the numbers separated by spaces.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, false)
+    }
 }
 
-/// Determines the product of all winning conditions fo all games.
-fn product_of_winning_conditions(times: Vec<u64>, distances: Vec<u64>) -> u64 {
-    times
-        .into_iter()
-        .zip(distances)
-        .map(|(time, distance)| (RaceDuration(time), BoatDistance(distance)))
-        .map(|(time, distance)| num_winning_conditions(time, distance))
-        .product()
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseRacesError(&'static str);
+
+impl Display for ParseRacesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse races: {}", self.0)
+    }
 }
 
-/// Determines the number of winning conditions.
-fn num_winning_conditions(race_duration: RaceDuration, best_distance: BoatDistance) -> u64 {
-    let range =
-        winning_condition(race_duration, best_distance).expect("found no winning conditions");
+impl Error for ParseRacesError {}
+
+impl Race {
+    pub const fn new(duration: RaceDuration, record: BoatDistance) -> Self {
+        Self { duration, record }
+    }
+
+    /// Calculates the distance the boat travels when charged for the specified time.
+    pub fn distance(&self, charge_time: ChargeTime) -> BoatDistance {
+        boat_distance(charge_time, self.duration)
+    }
+
+    /// Gets the range of charge times beating the record, if any.
+    pub fn winning_charge_times(&self) -> Option<RangeInclusive<ChargeTime>> {
+        winning_condition(self.duration, self.record)
+    }
+
+    /// Gets the number of charge times beating the record.
+    pub fn num_winning_conditions(&self) -> u64 {
+        num_winning_conditions(self.duration, self.record)
+    }
+
+    /// Brute-force variant of [`num_winning_conditions`](Race::num_winning_conditions) that
+    /// tries every charge time.
+    #[cfg(any(test, feature = "reference-impls"))]
+    pub fn num_winning_conditions_bf(&self) -> u64 {
+        winning_condition_bf(self.duration, self.record)
+            .map_or(0, |range| range.end().0 - range.start().0 + 1)
+    }
+}
 
+/// Determines the number of winning conditions, i.e. the number of charge times with which
+/// the boat travels farther than the best distance.
+pub fn num_winning_conditions(race_duration: RaceDuration, best_distance: BoatDistance) -> u64 {
     // The number of winnings conditions is the range length (plus one since the end is inclusive).
-    range.end().0 - range.start().0 + 1
+    winning_condition(race_duration, best_distance)
+        .map_or(0, |range| range.end().0 - range.start().0 + 1)
 }
 
 /// Checks for the winning condition based on race duration and best distance.
 ///
-/// Unlike [`winning_condition_bf`], this function uses analysis to obtain the conditions directly.
-/// Uses [`find_quadratic_roots`] to solve the quadratic equation.
+/// Unlike [`winning_condition_bf`], this function doesn't scan all charge times. The distances
+/// grow up to the middle of the race and then shrink symmetrically, so the first winning charge
+/// time is found by a binary search on the first half; the last one mirrors it. All arithmetic
+/// is exact, regardless of the magnitude of the race duration.
 ///
 /// # Arguments
 ///
@@ -90,6 +251,50 @@ fn num_winning_conditions(race_duration: RaceDuration, best_distance: BoatDistan
 fn winning_condition(
     RaceDuration(race_duration): RaceDuration,
     BoatDistance(best_distance): BoatDistance,
+) -> Option<RangeInclusive<ChargeTime>> {
+    // Products of two values up to the race duration don't fit into 64 bits in general.
+    let wins = |charge_time: u64| {
+        charge_time as u128 * (race_duration - charge_time) as u128 > best_distance as u128
+    };
+
+    // The longest distance is reached when charging for half of the race.
+    let middle = race_duration / 2;
+    if !wins(middle) {
+        return None;
+    }
+
+    // Find the first winning charge time, knowing that it lies in `0..=middle`.
+    let (mut low, mut high) = (0, middle);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if wins(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Some(ChargeTime(low)..=ChargeTime(race_duration - low))
+}
+
+/// Checks for the winning condition based on race duration and best distance.
+///
+/// Like [`winning_condition`], but uses [`find_quadratic_roots`] to solve the quadratic equation
+/// in floating point. This may misround for race durations near 2<sup>53</sup>.
+///
+/// # Arguments
+///
+/// * `race_duration` - The duration of the race.
+/// * `best_distance` - The best distance achieved by the boat.
+///
+/// # Returns
+///
+/// An `Option` containing the range of `ChargeTime` values that satisfy the winning condition.
+/// If no range is found, `None` is returned.
+#[cfg(test)]
+fn winning_condition_float(
+    RaceDuration(race_duration): RaceDuration,
+    BoatDistance(best_distance): BoatDistance,
 ) -> Option<RangeInclusive<ChargeTime>> {
     // Find the winning conditions using analysis. We add `0.5` to the best distance to account
     // for the fact that we want to exclude the winning condition itself; since the actual solutions
@@ -116,14 +321,12 @@ fn winning_condition(
 /// # Returns
 ///
 /// The distance the boat can travel during the race.
-#[cfg(test)]
 fn boat_distance(
     ChargeTime(charge_time): ChargeTime,
     RaceDuration(race_duration): RaceDuration,
 ) -> BoatDistance {
-    debug_assert!(charge_time <= race_duration);
-    // Charging takes time during the race.
-    let travel_time = race_duration - charge_time;
+    // Charging takes time during the race; charging for the entire race leaves no time to move.
+    let travel_time = race_duration.saturating_sub(charge_time);
     // The travel speed is equal to the charge time.
     let travel_speed = charge_time;
     // Travel distance is trivial then.
@@ -148,7 +351,7 @@ fn boat_distance(
 ///
 /// An `Option` containing the range of `ChargeTime` values that satisfy the winning condition.
 /// If no range is found, `None` is returned.
-#[cfg(test)]
+#[cfg(any(test, feature = "reference-impls"))]
 fn winning_condition_bf(
     race_duration: RaceDuration,
     best_distance: BoatDistance,
@@ -161,11 +364,7 @@ fn winning_condition_bf(
         .map(|t| (t, boat_distance(t, race_duration)))
         .filter(|(_, d)| *d > best_distance)
         .map(|(t, _)| t)
-        .next();
-    let start_condition = match start_condition {
-        None => return None,
-        Some(duration) => duration,
-    };
+        .next()?;
 
     // Find the first non-winning condition after the known start condition.
     // When a non-winning condition is found, the time before that must be the last
@@ -188,6 +387,7 @@ fn winning_condition_bf(
 /// - `c` is our charge time,
 /// - `d` is the race duration and
 /// - `b` is the best game we want to beat.
+#[cfg(test)]
 fn find_quadratic_roots(duration: f64, best: f64) -> (f64, f64) {
     let discriminant = duration.powi(2) + 4.0 * (-best);
     if discriminant >= 0.0 {
@@ -204,6 +404,7 @@ fn find_quadratic_roots(duration: f64, best: f64) -> (f64, f64) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::property::assert_equivalent;
 
     #[test]
     fn test_boat_distance() {
@@ -287,4 +488,129 @@ mod tests {
             9
         );
     }
+
+    #[test]
+    fn test_winning_condition_equivalence() {
+        /// Counts the charge times, treating a missing range like an empty one.
+        fn count(range: Option<RangeInclusive<ChargeTime>>) -> u64 {
+            range.map_or(0, |range| {
+                (range.end().0 + 1).saturating_sub(range.start().0)
+            })
+        }
+
+        assert_equivalent(
+            1000,
+            |&(duration, best)| count(winning_condition_bf(duration, best)),
+            |&(duration, best)| count(winning_condition(duration, best)),
+            |rng| {
+                let duration = rng.range(1..80);
+                let best = rng.below(duration * duration / 4 + 3);
+                (RaceDuration(duration), BoatDistance(best))
+            },
+        );
+    }
+
+    #[test]
+    fn test_brute_force_entry_points() {
+        const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!(product_of_winning_conditions_with_spaces(INPUT), Ok(288));
+        assert_eq!(product_of_winning_conditions_with_spaces_bf(INPUT), Ok(288));
+        assert_eq!(
+            product_of_winning_conditions_without_spaces(INPUT),
+            Ok(71503)
+        );
+        assert_eq!(
+            product_of_winning_conditions_without_spaces_bf(INPUT),
+            Ok(71503)
+        );
+    }
+
+    #[test]
+    fn test_race() {
+        let race = Race::new(RaceDuration::new(7), BoatDistance::new(9));
+        assert_eq!(race.distance(ChargeTime::new(3)), BoatDistance::new(12));
+        assert_eq!(race.distance(ChargeTime::new(8)), BoatDistance::new(0));
+        assert_eq!(
+            race.winning_charge_times(),
+            Some(ChargeTime::new(2)..=ChargeTime::new(5))
+        );
+        assert_eq!(race.num_winning_conditions(), 4);
+        assert_eq!(race.num_winning_conditions_bf(), 4);
+
+        let race = Race::new(RaceDuration::new(4), BoatDistance::new(4));
+        assert_eq!(race.winning_charge_times(), None);
+        assert_eq!(race.num_winning_conditions(), 0);
+    }
+
+    #[test]
+    fn test_parse_races() {
+        let races: Races = "Time:      7  15   30\nDistance:  9  40  200"
+            .parse()
+            .expect("failed to parse races");
+        assert_eq!(races.len(), 3);
+        assert_eq!(
+            races.races()[1],
+            Race::new(RaceDuration(15), BoatDistance(40))
+        );
+
+        let races = Races::parse_without_spaces("Time:  7  15\nDistance:  9  40")
+            .expect("failed to parse races");
+        assert_eq!(
+            races.races(),
+            [Race::new(RaceDuration::new(715), BoatDistance::new(940))]
+        );
+
+        for (input, error) in [
+            ("", "missing times"),
+            ("Distance: 9", "missing times"),
+            ("Time: 7", "missing distances"),
+            ("Time: 7\nDistance: x", "invalid number"),
+            (
+                "Time: 7 15\nDistance: 9",
+                "number of times and distances differ",
+            ),
+        ] {
+            let error = format!("Failed to parse races: {error}");
+            assert_eq!(
+                Races::from_str(input).map_err(|e| e.to_string()),
+                Err(error)
+            );
+        }
+    }
+
+    #[test]
+    fn test_winning_condition_float() {
+        assert_equivalent(
+            1000,
+            |&(duration, best)| winning_condition_float(duration, best),
+            |&(duration, best)| winning_condition(duration, best),
+            |rng| {
+                let duration = rng.range(2..10_000);
+                let best = rng.below(duration * duration / 4);
+                (RaceDuration(duration), BoatDistance(best))
+            },
+        );
+    }
+
+    #[test]
+    fn test_winning_condition_large() {
+        // The record is held by charging for exactly `charge` units of time.
+        let duration = (1u64 << 53) + 3;
+        let charge = 1000;
+        let record = BoatDistance(charge * (duration - charge));
+
+        let range = winning_condition(RaceDuration(duration), record).expect("no winning range");
+        assert_eq!(
+            range,
+            ChargeTime(charge + 1)..=ChargeTime(duration - charge - 1)
+        );
+
+        // The largest possible race has a single winning charge time beyond the previous record.
+        let duration = u64::MAX - 1;
+        let middle = duration / 2;
+        let record = BoatDistance(u64::MAX);
+        let range = winning_condition(RaceDuration(duration), record).expect("no winning range");
+        assert!(range.contains(&ChargeTime(middle)));
+    }
 }
@@ -1,36 +1,87 @@
+use aoc_utils::iter::run_lengths;
+use aoc_utils::trace;
+use aoc_utils::OverflowError;
 use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 7,
+    title: "Camel Cards",
+    example_answers: [Some("6440"), Some("5905")],
+    real_answers: Some([Some("253603890"), Some("253630098")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let games = parse_games(input, Jokers::Disallowed).ok()?;
+    let strongest = games.iter().map(|game| game.hand().hand_type()).max()?;
+    Some(format!(
+        "{} hands, the strongest a {strongest:?}",
+        games.len()
+    ))
+}
+
 /// A marker used for jokers in part 2.
 const JOKER_MARKER: char = '*';
 
 /// Solution for part 1 and 2.
 pub fn total_winnings(input: &str, jokers: Jokers) -> u64 {
-    let mut games: Vec<_> = input
-        .lines()
-        .map(|line| Game::from_str(line, jokers).expect("invalid input"))
-        .collect();
-    games.sort_by(|lhs, rhs| lhs.hand().cmp(rhs.hand()));
-
-    games
+    ranked_games(input, jokers)
         .into_iter()
         .enumerate()
         .map(|(i, game)| (i as u64 + 1) * game.bid().0)
         .sum()
 }
 
+/// Like [`total_winnings`], but fails instead of overflowing.
+pub fn checked_total_winnings(input: &str, jokers: Jokers) -> Result<u128, OverflowError> {
+    ranked_games(input, jokers)
+        .into_iter()
+        .enumerate()
+        .try_fold(0u128, |sum, (i, game)| {
+            // The product of two 64-bit values always fits into 128 bits.
+            let winnings = (i as u128 + 1) * game.bid().0 as u128;
+            sum.checked_add(winnings).ok_or(OverflowError)
+        })
+}
+
+/// Parses the games, one per non-empty line.
+pub fn parse_games(input: &str, jokers: Jokers) -> Result<Vec<Game>, ParseGameError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| Game::from_str(line, jokers))
+        .collect()
+}
+
+/// Parses the games and sorts them by rank, weakest first.
+fn ranked_games(input: &str, jokers: Jokers) -> Vec<Game> {
+    let mut games = parse_games(input, jokers).expect("invalid input");
+    games.sort();
+    games
+}
+
 /// A game consisting of a [`Hand`] and a [`Bid`].
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Game(Hand, Bid);
 
 /// A bid.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Bid(u64);
 
 /// A hand of cards.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Hand([Card; 5]);
 
 /// Whether or not to allow jokers.
@@ -44,6 +95,7 @@ pub enum Jokers {
 
 /// A card.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Card {
     /// Card value `1` (for part 2).
     Joker,
@@ -93,6 +145,26 @@ pub enum HandType {
     FiveOfAKind,
 }
 
+/// The outcome of comparing two hands, along with the rule that decided it.
+///
+/// See [`Hand::cmp_detailed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HandComparison {
+    pub ordering: Ordering,
+    pub rule: ComparisonRule,
+}
+
+/// The rule that decided a comparison of two hands.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ComparisonRule {
+    /// The hands are of different types.
+    HandType(HandType, HandType),
+    /// The hands are of the same type and first differ in the card at the given index.
+    Card { index: usize, lhs: Card, rhs: Card },
+    /// The hands consist of the same cards.
+    Identical,
+}
+
 impl Game {
     pub fn hand(&self) -> &Hand {
         &self.0
@@ -129,6 +201,52 @@ impl Hand {
         Self::hand_from_card_count(self.count_cards())
     }
 
+    /// Compares two hands like [`Ord::cmp`], but also reports which rule decided the outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_2023_day_7::{ComparisonRule, Card, Game, Jokers};
+    /// use std::cmp::Ordering;
+    ///
+    /// let lhs = Game::from_str("KK677 28", Jokers::Disallowed).unwrap();
+    /// let rhs = Game::from_str("KTJJT 220", Jokers::Disallowed).unwrap();
+    ///
+    /// let comparison = lhs.hand().cmp_detailed(rhs.hand());
+    /// assert_eq!(comparison.ordering, Ordering::Greater);
+    /// assert_eq!(
+    ///     comparison.rule,
+    ///     ComparisonRule::Card { index: 1, lhs: Card::K, rhs: Card::T }
+    /// );
+    /// ```
+    pub fn cmp_detailed(&self, other: &Self) -> HandComparison {
+        // First rule: The higher hand type wins.
+        let (lhs_type, rhs_type) = (self.hand_type(), other.hand_type());
+        if lhs_type != rhs_type {
+            return HandComparison {
+                ordering: lhs_type.cmp(&rhs_type),
+                rule: ComparisonRule::HandType(lhs_type, rhs_type),
+            };
+        }
+
+        // Second rule: For identical hands, the first larger card determines the outcome.
+        self.0
+            .iter()
+            .zip(other.0)
+            .enumerate()
+            .find(|&(_, (&lhs, rhs))| lhs != rhs)
+            .map_or(
+                HandComparison {
+                    ordering: Ordering::Equal,
+                    rule: ComparisonRule::Identical,
+                },
+                |(index, (&lhs, rhs))| HandComparison {
+                    ordering: lhs.cmp(&rhs),
+                    rule: ComparisonRule::Card { index, lhs, rhs },
+                },
+            )
+    }
+
     fn from_str(s: &str, jokers: Jokers) -> Result<Self, ParseHandError> {
         let s = s.trim();
         if s.len() != 5 {
@@ -155,23 +273,10 @@ impl Hand {
     }
 
     fn count_cards(&self) -> Vec<(Card, usize)> {
-        let mut counts = [0_usize; Card::NUM_CARDS];
-        for card in &self.0 {
-            counts[card.index()] += 1;
-        }
-
-        // There are at most five different cards per hand.
-        let mut counted = Vec::with_capacity(5);
-
-        for (card, count) in counts
-            .into_iter()
-            .enumerate()
-            .rev()
-            .filter(|(_, count)| *count > 0)
-            .map(|(index, count)| (Card::from_index(index), count))
-        {
-            counted.push((card, count));
-        }
+        // Sorting puts equal cards next to each other, the highest ones first.
+        let mut cards = self.0;
+        cards.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+        let mut counted: Vec<_> = run_lengths(cards).collect();
 
         // Sort by count in descending order.
         counted.sort_by_key(|(_, count)| 5 - *count);
@@ -235,52 +340,6 @@ impl Hand {
     }
 }
 
-impl Card {
-    const NUM_CARDS: usize = 14;
-
-    const CARDS: [Card; Self::NUM_CARDS] = [
-        Card::Joker,
-        Card::Two,
-        Card::Three,
-        Card::Four,
-        Card::Five,
-        Card::Six,
-        Card::Seven,
-        Card::Eight,
-        Card::Nine,
-        Card::T,
-        Card::J,
-        Card::Q,
-        Card::K,
-        Card::A,
-    ];
-
-    /// Returns an index corresponding to each card value.
-    fn index(&self) -> usize {
-        match self {
-            Card::Joker => 0,
-            Card::Two => 1,
-            Card::Three => 2,
-            Card::Four => 3,
-            Card::Five => 4,
-            Card::Six => 5,
-            Card::Seven => 6,
-            Card::Eight => 7,
-            Card::Nine => 8,
-            Card::T => 9,
-            Card::J => 10,
-            Card::Q => 11,
-            Card::K => 12,
-            Card::A => 13,
-        }
-    }
-
-    /// Returns a card corresponding to its index.
-    fn from_index(index: usize) -> Card {
-        Self::CARDS[index]
-    }
-}
-
 impl From<u64> for Bid {
     fn from(value: u64) -> Self {
         Self(value)
@@ -319,19 +378,7 @@ impl TryFrom<char> for Card {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        // First rule: The higher hand type wins.
-        let hand = self.hand_type().cmp(&other.hand_type());
-        if hand != Ordering::Equal {
-            return hand;
-        }
-
-        // Second rule: For identical hands, the first larger card determines the outcome.
-        self.0
-            .iter()
-            .zip(other.0)
-            .map(|(lhs, rhs)| lhs.cmp(&rhs))
-            .find(|&ordering| ordering != Ordering::Equal)
-            .unwrap_or(Ordering::Equal)
+        self.cmp_detailed(other).ordering
     }
 }
 
@@ -341,6 +388,34 @@ impl PartialOrd for Hand {
     }
 }
 
+impl Ord for Game {
+    /// Orders games by their hands, falling back to the bids for identical hands so that
+    /// ranking is deterministic.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand()
+            .cmp(other.hand())
+            .then_with(|| self.bid().cmp(&other.bid()))
+    }
+}
+
+impl PartialOrd for Game {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for ComparisonRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComparisonRule::HandType(lhs, rhs) => write!(f, "hand type ({lhs:?} vs {rhs:?})"),
+            ComparisonRule::Card { index, lhs, rhs } => {
+                write!(f, "card {} ({lhs:?} vs {rhs:?})", index + 1)
+            }
+            ComparisonRule::Identical => write!(f, "identical hands"),
+        }
+    }
+}
+
 impl FromStr for Card {
     type Err = ParseCardError;
 
@@ -623,6 +698,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cmp_detailed() {
+        let parse = |s| Hand::from_str(s, Jokers::Allowed).expect("failed to parse hand");
+
+        assert_eq!(
+            parse("QQQJA").cmp_detailed(&parse("T55J5")),
+            HandComparison {
+                ordering: Ordering::Greater,
+                rule: ComparisonRule::Card {
+                    index: 0,
+                    lhs: Card::Q,
+                    rhs: Card::T
+                }
+            }
+        );
+        assert_eq!(
+            parse("JKKK2").cmp_detailed(&parse("QQQQ2")).rule,
+            ComparisonRule::Card {
+                index: 0,
+                lhs: Card::Joker,
+                rhs: Card::Q
+            }
+        );
+        assert_eq!(
+            parse("32T3K").cmp_detailed(&parse("KTJJT")),
+            HandComparison {
+                ordering: Ordering::Less,
+                rule: ComparisonRule::HandType(HandType::OnePair, HandType::FourOfAKind)
+            }
+        );
+        assert_eq!(
+            parse("32T3K").cmp_detailed(&parse("32T3K")),
+            HandComparison {
+                ordering: Ordering::Equal,
+                rule: ComparisonRule::Identical
+            }
+        );
+        assert_eq!(
+            ComparisonRule::Card {
+                index: 2,
+                lhs: Card::K,
+                rhs: Card::Q
+            }
+            .to_string(),
+            "card 3 (K vs Q)"
+        );
+    }
+
+    #[test]
+    fn test_game_ordering() {
+        let parse = |s| Game::from_str(s, Jokers::Disallowed).expect("failed to parse game");
+
+        // The hand decides first, the bid only breaks ties.
+        assert!(parse("AAAAA 1") > parse("KKKKK 2"));
+        assert!(parse("AAAAA 1") < parse("AAAAA 2"));
+        assert_eq!(parse("AAAAA 1").cmp(&parse("AAAAA 1")), Ordering::Equal);
+    }
+
     #[test]
     fn test_hand_type_with_jokers() {
         assert_eq!(
@@ -646,4 +779,23 @@ mod tests {
             HandType::FourOfAKind
         );
     }
+
+    #[test]
+    fn test_checked_total_winnings() {
+        const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        assert_eq!(total_winnings(INPUT, Jokers::Disallowed), 6440);
+        assert_eq!(checked_total_winnings(INPUT, Jokers::Disallowed), Ok(6440));
+        assert_eq!(checked_total_winnings(INPUT, Jokers::Allowed), Ok(5905));
+
+        // The second-ranked bid alone exceeds 64 bits.
+        let input = format!("22222 {max}\nAAAAA {max}", max = u64::MAX);
+        assert_eq!(
+            checked_total_winnings(&input, Jokers::Disallowed),
+            Ok(3 * u64::MAX as u128)
+        );
+    }
 }
@@ -1,15 +1,4 @@
-use aoc_2023_day_8::*;
-
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() {
-    println!("2023 Day 8: Haunted Wasteland");
-    println!(
-        "The total number of steps required from AAA to ZZZ is: {}",
-        count_steps_to_destination(INPUT)
-    );
-    println!(
-        "The total number of steps required from ..A to ..Z is: {}",
-        count_ghost_steps_to_destination(INPUT)
-    );
-}
+aoc_utils::aoc_main!(aoc_2023_day_8;
+    "The total number of steps required from AAA to ZZZ is: {}" => count_steps_to_destination,
+    "The total number of steps required from ..A to ..Z is: {}" => count_ghost_steps_to_destination,
+);
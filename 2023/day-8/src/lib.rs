@@ -1,208 +1,182 @@
-use std::collections::HashMap;
+use aoc_utils::intern::Interner;
+use aoc_utils::{checked_lcm_slice, trace, OverflowError};
+pub use aoc_utils::{gcd, lcm, lcm_slice};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
-struct NodeId([char; 3], u16);
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 8,
+    title: "Haunted Wasteland",
+    example_answers: [Some("2"), Some("6")],
+    real_answers: Some([Some("22199"), Some("13334102464297")]),
+};
 
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let network: Network = input.parse().ok()?;
+    let ghosts = network.nodes().filter(NodeId::is_ghost_start).count();
+    Some(format!(
+        "{} nodes, {ghosts} of them ghost starts",
+        network.nodes().count()
+    ))
+}
+
+/// The three-letter name of a node.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub struct NodeId([char; 3]);
+
+/// A node of the network, along with its left and right neighbors.
 #[derive(Debug, Copy, Clone)]
-struct Node {
+pub struct Node {
     id: NodeId,
     left: NodeId,
     right: NodeId,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 enum Direction {
     Left,
     Right,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 struct Directions(Vec<Direction>);
 
+/// The network of nodes along with the directions to follow through it.
+///
+/// The nodes are interned so that they can be looked up by index.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Network {
+    directions: Directions,
+    names: Interner<NodeId>,
+    /// The left and right neighbors of each node, by index.
+    branches: Vec<[u32; 2]>,
+}
+
+/// An endless walk through the network, following the directions over and over again.
+#[derive(Debug, Clone)]
+pub struct Walk<'a> {
+    network: &'a Network,
+    index: u32,
+    step: usize,
+}
+
 pub fn count_steps_to_destination(input: &str) -> usize {
-    let (directions, nodes) = parse_input(input);
-    count_until(&directions, &nodes, NodeId::START, NodeId::GOAL, 0)
+    let network = parse_network(input);
+    network
+        .steps_between(NodeId::START, NodeId::GOAL)
+        .expect("the goal is unreachable")
 }
 
 pub fn count_ghost_steps_to_destination(input: &str) -> usize {
-    let (directions, nodes) = parse_input(input);
+    lcm_slice(&ghost_loop_lengths(input))
+}
 
-    let node_ids: Vec<_> = nodes
-        .keys()
-        .filter(|id| id.is_ghost_start())
-        .copied()
+/// Like [`count_ghost_steps_to_destination`], but fails instead of overflowing.
+pub fn checked_count_ghost_steps_to_destination(input: &str) -> Result<u128, OverflowError> {
+    let loop_lengths: Vec<u128> = ghost_loop_lengths(input)
+        .into_iter()
+        .map(|length| length as u128)
         .collect();
+    checked_lcm_slice(&loop_lengths)
+}
 
-    let loop_lengths: Vec<usize> = node_ids
-        .iter()
-        .map(|&id| count_until_ghost_goal(&directions, &nodes, id))
-        .collect();
+/// Determines the number of steps each ghost takes to reach its first goal.
+fn ghost_loop_lengths(input: &str) -> Vec<usize> {
+    let network = parse_network(input);
+    network
+        .nodes()
+        .filter(NodeId::is_ghost_start)
+        .map(|start| {
+            network
+                .walk(start)
+                .take(network.num_states())
+                .position(|id| id.is_ghost_goal())
+                .expect("a ghost never reaches a goal")
+        })
+        .collect()
+}
 
-    lcm_slice(&loop_lengths)
+fn parse_network(input: &str) -> Network {
+    input.parse().expect("invalid input")
 }
 
-fn count_until(
-    directions: &Directions,
-    nodes: &HashMap<NodeId, Node>,
-    mut node_id: NodeId,
-    goal: NodeId,
-    min_steps: usize,
-) -> usize {
-    for (steps_taken, direction) in directions.iter().enumerate() {
-        if node_id == goal && steps_taken >= min_steps {
-            return steps_taken;
-        }
+impl Network {
+    /// Marks the branches of nodes that are referenced, but not yet defined.
+    const UNDEFINED: [u32; 2] = [u32::MAX; 2];
 
-        node_id = nodes[&node_id].branch(direction);
+    /// Iterates the names of all nodes.
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.names.iter().map(|(_, &id)| id)
     }
 
-    unreachable!();
-}
-
-fn count_until_ghost_goal(
-    directions: &Directions,
-    nodes: &HashMap<NodeId, Node>,
-    mut node_id: NodeId,
-) -> usize {
-    for (steps_taken, direction) in directions.iter().enumerate() {
-        if node_id.is_ghost_goal() {
-            return steps_taken;
+    /// Walks the network from the start node, beginning with the start node itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start node isn't part of the network.
+    pub fn walk(&self, start: NodeId) -> Walk<'_> {
+        let index = self.names.get(&start).expect("unknown start node");
+        Walk {
+            network: self,
+            index,
+            step: 0,
         }
-
-        node_id = nodes[&node_id].branch(direction);
     }
 
-    unreachable!();
-}
-
-/// Calculate the greatest common divisor (GCD) of two numbers.
-///
-/// The GCD is the largest positive integer that divides both `a` and `b` without remainder.
-/// This function uses the Euclidean algorithm to calculate the GCD.
-///
-/// # Arguments
-///
-/// * `a` - The first number.
-/// * `b` - The second number.
-///
-/// # Returns
-///
-/// The GCD of `a` and `b`.
-///
-/// # Examples
-///
-/// ```
-/// use aoc_2023_day_8::gcd;
-///
-/// let result = gcd(10, 15);
-/// assert_eq!(result, 5);
-///
-/// let result = gcd(24, 36);
-/// assert_eq!(result, 12);
-/// ```
-///
-pub fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
+    /// Counts the steps taken from the start node until the goal node is first reached.
+    ///
+    /// At least one step is taken, so if the start is the goal, this is the length of the
+    /// walk's first return to it. Returns `None` if either node isn't part of the network
+    /// or the goal is never reached.
+    pub fn steps_between(&self, start: NodeId, goal: NodeId) -> Option<usize> {
+        self.names.get(&start)?;
+        self.names.get(&goal)?;
+        self.walk(start)
+            .take(self.num_states() + 1)
+            .skip(1)
+            .position(|id| id == goal)
+            .map(|steps| steps + 1)
     }
-}
 
-/// Calculates the least common multiple (LCM) of two numbers.
-///
-/// # Arguments
-///
-/// * `a` - A positive integer number.
-/// * `b` - Another positive integer number.
-///
-/// # Returns
-///
-/// The LCM of `a` and `b`.
-///
-/// # Examples
-///
-/// ```
-/// use aoc_2023_day_8::lcm;
-///
-/// let result = lcm(12, 18);
-/// assert_eq!(result, 36);
-/// ```
-pub fn lcm(a: usize, b: usize) -> usize {
-    a / gcd(a, b) * b
-}
+    /// The number of distinct states of a walk, after which it necessarily repeats itself.
+    fn num_states(&self) -> usize {
+        self.branches.len() * self.directions.0.len()
+    }
 
-/// Calculates the least common multiple (LCM) of a vector of numbers.
-///
-/// The function takes a vector of `usize` numbers and returns their
-/// least common multiple (LCM).
-///
-/// # Arguments
-///
-/// * `numbers` - A vector of `usize` numbers.
-///
-/// # Returns
-///
-/// The LCM of the given numbers.
-///
-/// # Panics
-///
-/// The function will panic if called with an empty vector.
-///
-/// # Examples
-///
-/// ```
-/// use std::iter::FromIterator;
-/// use aoc_2023_day_8::lcm_slice;
-///
-/// let numbers = Vec::from_iter([2, 3, 4, 5]);
-/// let lcm = lcm_slice(&numbers);
-/// assert_eq!(lcm, 60);
-/// ```
-pub fn lcm_slice(numbers: &[usize]) -> usize {
-    let mut iter = numbers.iter();
-    let &first = iter.next().unwrap();
-    iter.fold(first, |a, &b| lcm(a, b))
+    /// Gets the index of the node reached from a node in a direction.
+    fn branch(&self, index: u32, direction: Direction) -> u32 {
+        let [left, right] = self.branches[index as usize];
+        match direction {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
 }
 
-fn parse_input(input: &str) -> (Directions, HashMap<NodeId, Node>) {
-    let mut lines = input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty());
-
-    let directions: Directions = lines
-        .next()
-        .expect("empty input")
-        .parse()
-        .expect("failed to parse directions");
-
-    let nodes: Vec<Node> = lines
-        .map(Node::from_str)
-        .collect::<Result<_, _>>()
-        .expect("failed to parse nodes");
-
-    let map: HashMap<NodeId, Node> =
-        HashMap::from_iter(nodes.into_iter().map(|node| (node.id, node)));
+impl Iterator for Walk<'_> {
+    type Item = NodeId;
 
-    (directions, map)
-}
-
-impl Node {
-    pub fn branch(&self, direction: Direction) -> NodeId {
-        match direction {
-            Direction::Left => self.left,
-            Direction::Right => self.right,
-        }
+    fn next(&mut self) -> Option<Self::Item> {
+        let directions = &self.network.directions.0;
+        let id = self.network.names[self.index];
+        self.index = self.network.branch(self.index, directions[self.step]);
+        self.step = (self.step + 1) % directions.len();
+        Some(id)
     }
 }
 
 impl Directions {
-    pub fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
+    #[cfg(test)]
+    fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
         self.0.iter().copied().cycle()
     }
 
@@ -214,17 +188,13 @@ impl Directions {
 
 impl NodeId {
     /// Marks a start node according to part 1.
-    pub const START: NodeId = NodeId(['A', 'A', 'A'], 0);
+    pub const START: NodeId = NodeId(['A', 'A', 'A']);
 
     /// Marks a goal node according to part 1.
-    pub const GOAL: NodeId = NodeId(['Z', 'Z', 'Z'], 25 * 100 + 25 * 10 + 25);
+    pub const GOAL: NodeId = NodeId(['Z', 'Z', 'Z']);
 
     pub fn new(first: char, second: char, third: char) -> Self {
-        let hash = (first as usize - 'A' as usize) * 100
-            + (second as usize - 'A' as usize) * 10
-            + (third as usize - 'A' as usize);
-        let hash = hash as u16;
-        Self([first, second, third], hash)
+        Self([first, second, third])
     }
 
     /// Identifies a start node according to part 2.
@@ -238,9 +208,57 @@ impl NodeId {
     }
 }
 
-impl Hash for NodeId {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.1.hash(state)
+impl Display for NodeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [first, second, third] = self.0;
+        write!(f, "{first}{second}{third}")
+    }
+}
+
+/// Serializes the name as a string, such as `"AAA"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NodeId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut lines = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty());
+
+        let directions: Directions = lines
+            .next()
+            .ok_or(ParseNetworkError::Empty)?
+            .parse()
+            .map_err(ParseNetworkError::Directions)?;
+
+        let mut names = Interner::new();
+        let mut branches = Vec::new();
+        for line in lines {
+            let node = Node::from_str(line).map_err(ParseNetworkError::Node)?;
+            let index = names.intern(node.id) as usize;
+            let left = names.intern(node.left);
+            let right = names.intern(node.right);
+            branches.resize(names.len(), Self::UNDEFINED);
+            branches[index] = [left, right];
+        }
+
+        if let Some(index) = branches.iter().position(|&b| b == Self::UNDEFINED) {
+            return Err(ParseNetworkError::UndefinedNode(names[index as u32]));
+        }
+
+        Ok(Self {
+            directions,
+            names,
+            branches,
+        })
     }
 }
 
@@ -249,10 +267,14 @@ impl FromStr for Node {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim();
-        if s.len() != 16 {
+        if !s.is_ascii() || s.len() != 16 {
             return Err(ParseNodeError("Invalid length"));
         }
 
+        if &s[3..7] != " = (" || &s[10..12] != ", " || &s[15..] != ")" {
+            return Err(ParseNodeError("Invalid format"));
+        }
+
         let id = NodeId::from_str(&s[..3]).map_err(|_| ParseNodeError("Invalid node ID"))?;
         let left = NodeId::from_str(&s[7..10]).map_err(|_| ParseNodeError("Invalid node ID"))?;
         let right = NodeId::from_str(&s[12..15]).map_err(|_| ParseNodeError("Invalid node ID"))?;
@@ -270,6 +292,13 @@ impl FromStr for NodeId {
             return Err(ParseNodeIdError("Invalid length"));
         }
 
+        if !s
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        {
+            return Err(ParseNodeIdError("Invalid character"));
+        }
+
         let mut chars = s.chars();
         Ok(Self::new(
             chars.next().expect("invalid iterator"),
@@ -301,6 +330,31 @@ impl FromStr for Directions {
     }
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseNetworkError {
+    /// The input has no directions.
+    Empty,
+    Directions(ParseDirectionsError),
+    Node(ParseNodeError),
+    /// A node is referenced as a neighbor, but never defined.
+    UndefinedNode(NodeId),
+}
+
+impl Display for ParseNetworkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseNetworkError::Empty => write!(f, "Failed to parse network: Empty input"),
+            ParseNetworkError::Directions(e) => write!(f, "{e}"),
+            ParseNetworkError::Node(e) => write!(f, "{e}"),
+            ParseNetworkError::UndefinedNode(id) => {
+                write!(f, "Failed to parse network: Undefined node {id}")
+            }
+        }
+    }
+}
+
+impl Error for ParseNetworkError {}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct ParseDirectionsError(&'static str);
 
@@ -343,7 +397,13 @@ mod tests {
     #[test]
     fn test_parse_node_id() {
         let id: NodeId = "ABC".parse().expect("failed to parse node ID");
-        assert_eq!(id, NodeId::new('A', 'B', 'C'))
+        assert_eq!(id, NodeId::new('A', 'B', 'C'));
+        assert_eq!(
+            "11A".parse::<NodeId>().map(|id| id.is_ghost_start()),
+            Ok(true)
+        );
+        assert!("AéB".parse::<NodeId>().is_err());
+        assert!("A*B".parse::<NodeId>().is_err());
     }
 
     #[test]
@@ -352,6 +412,9 @@ mod tests {
         assert_eq!(node.id, NodeId::new('A', 'A', 'A'));
         assert_eq!(node.left, NodeId::new('B', 'B', 'B'));
         assert_eq!(node.right, NodeId::new('C', 'C', 'C'));
+
+        assert!("AAA = (BBB,.CCC)".parse::<Node>().is_err());
+        assert!("AAA = ñBB, CCC)".parse::<Node>().is_err());
     }
 
     #[test]
@@ -379,16 +442,52 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_input() {
+    fn test_parse_network() {
         const INPUT: &str = "LLR
 
             AAA = (BBB, BBB)
             BBB = (AAA, ZZZ)
             ZZZ = (ZZZ, ZZZ)";
 
-        let (directions, nodes) = parse_input(INPUT);
-        assert_eq!(directions.0.len(), 3);
-        assert_eq!(nodes.len(), 3);
+        let network: Network = INPUT.parse().expect("failed to parse network");
+        assert_eq!(network.directions.len(), 3);
+        assert_eq!(network.nodes().count(), 3);
+        assert_eq!(network.names.get(&NodeId::START), Some(0));
+        assert_eq!(network.branch(1, Direction::Right), 2);
+
+        assert_eq!("".parse::<Network>().err(), Some(ParseNetworkError::Empty));
+        assert_eq!(
+            "L\nAAA = (BBB, AAA)".parse::<Network>().err(),
+            Some(ParseNetworkError::UndefinedNode(NodeId::new('B', 'B', 'B')))
+        );
+    }
+
+    #[test]
+    fn test_walk() {
+        const INPUT: &str = "LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)";
+
+        let network: Network = INPUT.parse().expect("failed to parse network");
+        let walk: Vec<String> = network
+            .walk(NodeId::START)
+            .take(8)
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(
+            walk,
+            ["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ", "ZZZ"]
+        );
+
+        assert_eq!(network.steps_between(NodeId::START, NodeId::GOAL), Some(6));
+        assert_eq!(network.steps_between(NodeId::GOAL, NodeId::GOAL), Some(1));
+        assert_eq!(network.steps_between(NodeId::GOAL, NodeId::START), None);
+        assert_eq!(
+            network.steps_between(NodeId::new('X', 'X', 'X'), NodeId::GOAL),
+            None
+        );
     }
 
     #[test]
@@ -421,25 +520,27 @@ mod tests {
             XXX = (XXX, XXX)";
 
         assert_eq!(count_ghost_steps_to_destination(INPUT), 6);
+        assert_eq!(checked_count_ghost_steps_to_destination(INPUT), Ok(6));
     }
 
     #[test]
     fn test_loop_from_start() {
-        let (directions, nodes) = parse_input(INPUT);
+        let network: Network = INPUT.parse().expect("failed to parse network");
 
         // Find all start nodes.
-        let mut node_ids: Vec<_> = nodes
-            .keys()
-            .filter(|id| id.is_ghost_start())
-            .copied()
-            .collect();
-        node_ids.sort();
-        assert_eq!(node_ids.len(), 6);
+        let mut starts: Vec<_> = network.nodes().filter(NodeId::is_ghost_start).collect();
+        starts.sort();
+        assert_eq!(starts.len(), 6);
 
         // Determine the length of a cycle from a goal node to its next occurrence.
-        let cycle_lengths: Vec<usize> = node_ids
+        let cycle_lengths: Vec<usize> = starts
             .iter()
-            .map(|&id| count_until_ghost_goal(&directions, &nodes, id))
+            .map(|&start| {
+                network
+                    .walk(start)
+                    .position(|id| id.is_ghost_goal())
+                    .expect("no goal reached")
+            })
             .collect();
 
         assert_eq!(cycle_lengths[0], 22199); // AAA -> ZZZ
@@ -450,7 +551,7 @@ mod tests {
         assert_eq!(cycle_lengths[5], 16579); // VXA -> TNZ
 
         // Path lengths are evenly divisible by the direction length.
-        let direction_length = directions.len();
+        let direction_length = network.directions.len();
         assert_eq!(cycle_lengths[0] % direction_length, 0);
         assert_eq!(cycle_lengths[1] % direction_length, 0);
         assert_eq!(cycle_lengths[2] % direction_length, 0);
@@ -463,21 +564,17 @@ mod tests {
 
     #[test]
     fn test_loop_from_goal() {
-        let (directions, nodes) = parse_input(INPUT);
+        let network: Network = INPUT.parse().expect("failed to parse network");
 
         // Find all goal nodes.
-        let mut node_ids: Vec<_> = nodes
-            .keys()
-            .filter(|id| id.is_ghost_goal())
-            .copied()
-            .collect();
-        node_ids.sort();
-        assert_eq!(node_ids.len(), 6);
+        let mut goals: Vec<_> = network.nodes().filter(NodeId::is_ghost_goal).collect();
+        goals.sort();
+        assert_eq!(goals.len(), 6);
 
         // Determine the length of a cycle from a goal node to its next occurrence.
-        let cycle_lengths: Vec<usize> = node_ids
+        let cycle_lengths: Vec<usize> = goals
             .iter()
-            .map(|&id| count_until(&directions, &nodes, id, id, 1))
+            .map(|&goal| network.steps_between(goal, goal).expect("no cycle found"))
             .collect();
 
         assert_eq!(cycle_lengths[0], 18827); // FRZ -> FRZ
@@ -1,75 +1,90 @@
-use aoc_utils::parse_whitespace_delimited;
-use itertools::Itertools;
+use aoc_utils::input::fold_lines;
+use aoc_utils::par::map_lines;
+use aoc_utils::{extrapolate_next, extrapolate_previous, parse_whitespace_delimited, trace};
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2023,
+    day: 9,
+    title: "Mirage Maintenance",
+    example_answers: [Some("114"), Some("2")],
+    real_answers: Some([Some("1877825184"), Some("1108")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let histories = parse_histories(input).ok()?;
+    let longest = histories.iter().map(Vec::len).max()?;
+    Some(format!(
+        "{} histories of up to {longest} values",
+        histories.len()
+    ))
+}
+
+/// Parses the histories of values, one per non-empty line.
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<i64>>, ParseIntError> {
+    let _span = trace::parse();
+    map_lines(input, parse_whitespace_delimited)
+        .into_iter()
+        .collect()
+}
 
 /// Solution for part 1.
 pub fn part1(input: &str) -> i64 {
-    input
-        .lines()
-        .filter(|&line| !line.is_empty())
-        .map(parse_whitespace_delimited::<i64>)
-        .map(|result| result.expect("invalid input"))
-        .map(predict_part1)
-        .sum()
+    let histories = parse_histories(input).expect("invalid input");
+    histories.into_iter().map(predict_part1).sum()
 }
 
 /// Solution for part 2.
 pub fn part2(input: &str) -> i64 {
-    input
-        .lines()
-        .filter(|&line| !line.is_empty())
-        .map(parse_whitespace_delimited::<i64>)
-        .map(|result| result.expect("invalid input"))
-        .map(predict_part2)
-        .sum()
+    let histories = parse_histories(input).expect("invalid input");
+    histories.into_iter().map(predict_part2).sum()
 }
 
-/// Obtains the new history value prediction for part 1.
-fn predict_part1(mut history: Vec<i64>) -> i64 {
-    let mut last_values = vec![*history.last().expect("history has zero length")];
-
-    while !all_zero(&history) {
-        history = differentiate(&history);
-        last_values.push(*history.last().expect("history has zero length"));
-    }
-
-    last_values.into_iter().sum()
+/// Like [`part1`], but reads the histories one line at a time.
+pub fn part1_from_reader<R: BufRead>(reader: R) -> io::Result<i64> {
+    sum_predictions(reader, predict_part1)
 }
 
-/// Obtains the new history value prediction for part 2.
-fn predict_part2(mut history: Vec<i64>) -> i64 {
-    let mut last_values = vec![*history.first().expect("history has zero length")];
+/// Like [`part2`], but reads the histories one line at a time.
+pub fn part2_from_reader<R: BufRead>(reader: R) -> io::Result<i64> {
+    sum_predictions(reader, predict_part2)
+}
 
-    while !all_zero(&history) {
-        history = differentiate(&history);
-        last_values.push(*history.first().expect("history has zero length"));
-    }
+/// Sums the predictions of all histories read from the reader.
+fn sum_predictions<R: BufRead>(reader: R, predict: fn(Vec<i64>) -> i64) -> io::Result<i64> {
+    fold_lines(reader, 0, |sum, line| {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(sum);
+        }
+        Ok(sum + predict(parse_history(line)))
+    })
+}
 
-    last_values
-        .into_iter()
-        .rev()
-        .fold(0, |sum, current| current - sum)
+/// Parses a single line of history values.
+fn parse_history(line: &str) -> Vec<i64> {
+    parse_whitespace_delimited::<i64>(line).expect("invalid input")
 }
 
-/// Determines whether all input values are zero.
-fn all_zero<H: AsRef<[i64]>>(values: H) -> bool {
-    values.as_ref().iter().all(|&value| value == 0)
+/// Obtains the new history value prediction for part 1.
+fn predict_part1(history: Vec<i64>) -> i64 {
+    extrapolate_next(&history)
 }
 
-/// Obtains the difference of values and returns a vector of differences.
-fn differentiate<H: AsRef<[i64]>>(values: H) -> Vec<i64> {
-    values
-        .as_ref()
-        .iter()
-        .tuple_windows()
-        .map(|(a, b)| b - a)
-        .collect()
+/// Obtains the new history value prediction for part 2.
+fn predict_part2(history: Vec<i64>) -> i64 {
+    extrapolate_previous(&history)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[allow(dead_code)]
     const INPUT: &str = include_str!("../input.txt");
 
     #[test]
@@ -100,4 +115,10 @@ mod tests {
 
         assert_eq!(part2(TEST), 2);
     }
+
+    #[test]
+    fn test_from_reader() {
+        assert_eq!(part1_from_reader(INPUT.as_bytes()).unwrap(), part1(INPUT));
+        assert_eq!(part2_from_reader(INPUT.as_bytes()).unwrap(), part2(INPUT));
+    }
 }
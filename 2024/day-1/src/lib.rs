@@ -1,4 +1,42 @@
+use aoc_utils::input::fold_lines;
+use aoc_utils::iter::merge_join;
+use aoc_utils::num::abs_diff;
 use aoc_utils::parse_whitespace_delimited;
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 1,
+    title: "Historian Hysteria",
+    example_answers: [Some("11"), Some("31")],
+    real_answers: Some([Some("1889772"), Some("23228917")]),
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (lhs, rhs) = parse_lists(input).ok()?;
+    let shared = lhs.iter().filter(|id| rhs.contains(id)).count();
+    Some(format!(
+        "{} location IDs per list, {shared} on the left also on the right",
+        lhs.len()
+    ))
+}
+
+/// Parses the left and right list of location IDs, in the order they are listed.
+pub fn parse_lists(input: &str) -> Result<(Vec<i128>, Vec<i128>), ParseListsError> {
+    let _span = trace::parse();
+    let mut lists = (Vec::new(), Vec::new());
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        push_values(&mut lists, line)?;
+    }
+    Ok(lists)
+}
 
 pub fn first_part(input: &str) -> i128 {
     let (lhs, rhs) = split_values(input);
@@ -10,92 +48,76 @@ pub fn second_part(input: &str) -> i128 {
     sum_scores(&lhs, &rhs)
 }
 
+/// Like [`first_part`], but reads the lists one line at a time.
+pub fn first_part_from_reader<R: BufRead>(reader: R) -> io::Result<i128> {
+    let (lhs, rhs) = read_values(reader)?;
+    Ok(sum_distances(&lhs, &rhs))
+}
+
+/// Like [`second_part`], but reads the lists one line at a time.
+pub fn second_part_from_reader<R: BufRead>(reader: R) -> io::Result<i128> {
+    let (lhs, rhs) = read_values(reader)?;
+    Ok(sum_scores(&lhs, &rhs))
+}
+
 fn split_values(input: &str) -> (Vec<i128>, Vec<i128>) {
-    let mut lhs = Vec::new();
-    let mut rhs = Vec::new();
-
-    for line in input.trim().lines() {
-        let nums = parse_whitespace_delimited::<i128>(line)
-            .expect("expect all lines to contain exactly two numbers");
-        lhs.push(nums[0]);
-        rhs.push(nums[1]);
-    }
+    let lists = parse_lists(input).expect("expect all lines to contain exactly two numbers");
+    sort_lists(lists)
+}
 
+fn read_values<R: BufRead>(reader: R) -> io::Result<(Vec<i128>, Vec<i128>)> {
+    let _span = trace::parse();
+    let lists = fold_lines(reader, (Vec::new(), Vec::new()), |mut lists, line| {
+        if !line.trim().is_empty() {
+            push_values(&mut lists, line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        Ok(lists)
+    })?;
+    Ok(sort_lists(lists))
+}
+
+/// Parses the pair of location IDs on a line onto the lists.
+fn push_values((lhs, rhs): &mut (Vec<i128>, Vec<i128>), line: &str) -> Result<(), ParseListsError> {
+    let nums = parse_whitespace_delimited::<i128>(line)
+        .map_err(|_| ParseListsError("Invalid location ID"))?;
+    let &[left, right] = nums.as_slice() else {
+        return Err(ParseListsError("Expected two location IDs per line"));
+    };
+    lhs.push(left);
+    rhs.push(right);
+    Ok(())
+}
+
+fn sort_lists((mut lhs, mut rhs): (Vec<i128>, Vec<i128>)) -> (Vec<i128>, Vec<i128>) {
     lhs.sort_unstable();
     rhs.sort_unstable();
-
     (lhs, rhs)
 }
 
 fn sum_distances(lhs: &[i128], rhs: &[i128]) -> i128 {
     lhs.iter()
         .zip(rhs)
-        .map(|(&a, &b)| (a.max(b), a.min(b)))
-        .map(|(a, b)| a - b)
+        .map(|(&a, &b)| abs_diff(a, b) as i128)
         .sum()
 }
 
 fn sum_scores(sorted_lhs: &[i128], sorted_rhs: &[i128]) -> i128 {
-    let lhs_counts = count_occurrences(sorted_lhs);
-    let rhs_counts = count_occurrences(sorted_rhs);
-
-    // Iterate through both counts in parallel
-    let mut lhs_iter = lhs_counts.into_iter();
-    let mut rhs_iter = rhs_counts.into_iter();
-    let mut lhs_entry = lhs_iter.next();
-    let mut rhs_entry = rhs_iter.next();
-    let mut total_sum = 0;
-
-    while let (Some(lhs_item), Some(rhs_item)) = (lhs_entry.as_ref(), rhs_entry.as_ref()) {
-        match (lhs_item, rhs_item) {
-            (lhs, rhs) if lhs == rhs => {
-                total_sum += lhs.value * (lhs.count as i128) * (rhs.count as i128);
-                lhs_entry = lhs_iter.next();
-                rhs_entry = rhs_iter.next();
-            }
-            (lhs, rhs) if lhs < rhs => {
-                lhs_entry = lhs_iter.next();
-            }
-            (lhs, rhs) if lhs > rhs => {
-                rhs_entry = rhs_iter.next();
-            }
-            _ => unreachable!(),
-        }
-    }
-
-    total_sum
+    merge_join(sorted_lhs, sorted_rhs, |&value| value)
+        .map(|(lhs, rhs)| lhs[0] * lhs.len() as i128 * rhs.len() as i128)
+        .sum()
 }
 
-struct Item {
-    value: i128,
-    count: usize,
-}
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseListsError(&'static str);
 
-impl PartialEq for Item {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+impl Display for ParseListsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse lists: {}", self.0)
     }
 }
 
-impl PartialOrd for Item {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.value.partial_cmp(&other.value)
-    }
-}
-
-fn count_occurrences(sorted_lhs: &[i128]) -> Vec<Item> {
-    let mut counts = Vec::new();
-    let mut current_value = sorted_lhs.first().cloned();
-    for &value in sorted_lhs {
-        if Some(value) != current_value || counts.is_empty() {
-            counts.push(Item { value, count: 1 });
-            current_value = Some(value);
-        } else if let Some(last) = counts.last_mut() {
-            last.count += 1;
-        }
-    }
-    counts
-}
+impl Error for ParseListsError {}
 
 #[cfg(test)]
 mod tests {
@@ -110,8 +132,30 @@ mod tests {
         3    3
     ";
 
+    #[test]
+    fn test_parse_lists() {
+        let (lhs, rhs) = parse_lists(INPUT).unwrap();
+        assert_eq!(lhs, [3, 4, 2, 1, 3, 3]);
+        assert_eq!(rhs, [4, 3, 5, 3, 9, 3]);
+        assert!(parse_lists("1 2 3").is_err());
+    }
+
     #[test]
     fn test_second_part() {
         assert_eq!(second_part(INPUT), 31);
     }
+
+    #[test]
+    fn test_from_reader() {
+        assert_eq!(first_part_from_reader(INPUT.as_bytes()).unwrap(), 11);
+        assert_eq!(second_part_from_reader(INPUT.as_bytes()).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_from_reader_invalid() {
+        let error = first_part_from_reader("3 4\n1 2 3\n".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = second_part_from_reader("3 4\nx 2\n".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
 }
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_10;
+    "The sum of the trailhead scores is {}" => first_part,
+    "The sum of the trailhead ratings is {}" => second_part,
+);
@@ -0,0 +1,133 @@
+use aoc_utils::{bfs_distances, count_paths, trace, Grid, ParseGridError, Point};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 10,
+    title: "Hoof It",
+    example_answers: [Some("36"), Some("81")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let map = TopographicMap::parse(input).ok()?;
+    Some(format!(
+        "{}×{} map with {} trailheads",
+        map.heights.width(),
+        map.heights.height(),
+        map.trailheads().count()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let map = TopographicMap::parse(input).expect("invalid input");
+    map.trailheads().map(|start| map.score(start)).sum()
+}
+
+pub fn second_part(input: &str) -> usize {
+    let map = TopographicMap::parse(input).expect("invalid input");
+    map.trailheads().map(|start| map.rating(start)).sum()
+}
+
+/// The height of the trail's end.
+const SUMMIT: u8 = 9;
+
+/// The heights of the surrounding area.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TopographicMap {
+    pub heights: Grid<u8>,
+}
+
+impl TopographicMap {
+    /// Parses a map with one digit per position.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        let _span = trace::parse();
+        let heights = Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { heights })
+    }
+
+    /// Iterates the positions of height `0`.
+    pub fn trailheads(&self) -> impl Iterator<Item = Point> + '_ {
+        self.heights
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(point, _)| point)
+    }
+
+    /// Gets the number of summits reachable from the trailhead.
+    pub fn score(&self, trailhead: Point) -> usize {
+        bfs_distances(trailhead, |&point| self.uphill(point))
+            .keys()
+            .filter(|&&point| self.heights[point] == SUMMIT)
+            .count()
+    }
+
+    /// Gets the number of distinct hiking trails starting at the trailhead.
+    pub fn rating(&self, trailhead: Point) -> usize {
+        count_paths(
+            trailhead,
+            |&point| self.uphill(point),
+            |&point| self.heights[point] == SUMMIT,
+        )
+    }
+
+    /// Gets the neighboring positions exactly one step higher.
+    fn uphill(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.heights[point];
+        point
+            .neighbors()
+            .into_iter()
+            .filter(move |&next| self.heights.get(next) == Some(&(height + 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        89010123
+        78121874
+        87430965
+        96549874
+        45678903
+        32019012
+        01329801
+        10456732
+    ";
+
+    #[test]
+    fn test_scores() {
+        let map = TopographicMap::parse(INPUT).expect("failed to parse map");
+        let scores: Vec<_> = map.trailheads().map(|start| map.score(start)).collect();
+        assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+    }
+
+    #[test]
+    fn test_rating() {
+        let map = TopographicMap::parse(
+            "
+            0123
+            1234
+            8765
+            9876
+            ",
+        )
+        .expect("failed to parse map");
+        assert_eq!(map.rating(Point::new(0, 0)), 16);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 36);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 81);
+    }
+}
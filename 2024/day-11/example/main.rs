@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_11;
+    "After blinking 25 times, there are {} stones" => first_part,
+    "After blinking 75 times, there are {} stones" => second_part,
+);
@@ -0,0 +1,123 @@
+use aoc_utils::num::digit_count;
+use aoc_utils::parse_whitespace_delimited;
+use aoc_utils::trace;
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 11,
+    title: "Plutonian Pebbles",
+    example_answers: [Some("55312"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let stones = parse_stones(input).ok()?;
+    Some(format!(
+        "{} stones, {} distinct ones after 25 blinks",
+        stones.len(),
+        blink_n(&stones, 25).len()
+    ))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    count_stones(input, 25)
+}
+
+pub fn second_part(input: &str) -> u64 {
+    count_stones(input, 75)
+}
+
+/// Parses the numbers engraved on the stones, in the order they are lined up.
+pub fn parse_stones(input: &str) -> Result<Vec<u64>, ParseIntError> {
+    let _span = trace::parse();
+    parse_whitespace_delimited(input)
+}
+
+fn count_stones(input: &str, blinks: usize) -> u64 {
+    let stones = parse_stones(input).expect("invalid input");
+    blink_n(&stones, blinks).values().sum()
+}
+
+/// Blinks `n` times and returns the number of stones per engraved number.
+///
+/// Since the order of the stones does not affect how they change, only the counts
+/// are tracked; equal stones are handled at once.
+pub fn blink_n(stones: &[u64], n: usize) -> HashMap<u64, u64> {
+    let mut counts = HashMap::new();
+    for &stone in stones {
+        *counts.entry(stone).or_default() += 1;
+    }
+
+    for _ in 0..n {
+        counts = blink(&counts);
+    }
+
+    counts
+}
+
+/// Blinks once, changing every stone at the same time.
+pub fn blink(counts: &HashMap<u64, u64>) -> HashMap<u64, u64> {
+    let mut next = HashMap::with_capacity(counts.len());
+    for (&stone, &count) in counts {
+        let (first, second) = change(stone);
+        *next.entry(first).or_default() += count;
+        if let Some(second) = second {
+            *next.entry(second).or_default() += count;
+        }
+    }
+    next
+}
+
+/// Changes a single stone into one or two stones.
+pub fn change(stone: u64) -> (u64, Option<u64>) {
+    if stone == 0 {
+        return (1, None);
+    }
+
+    let digits = digit_count(stone);
+    if digits.is_multiple_of(2) {
+        let factor = 10u64.pow(digits / 2);
+        (stone / factor, Some(stone % factor))
+    } else {
+        (stone * 2024, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "125 17";
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(0), (1, None));
+        assert_eq!(change(1), (2024, None));
+        assert_eq!(change(1000), (10, Some(0)));
+        assert_eq!(change(253000), (253, Some(0)));
+    }
+
+    #[test]
+    fn test_blink_n() {
+        let stones = blink_n(&[125, 17], 6);
+        assert_eq!(stones.values().sum::<u64>(), 22);
+        assert_eq!(stones[&2], 4);
+        assert_eq!(stones[&4048], 1);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 55312);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 65601038650482);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_12;
+    "The total price of fencing all regions is {}" => first_part,
+    "The total price with the bulk discount is {}" => second_part,
+);
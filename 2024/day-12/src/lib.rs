@@ -0,0 +1,150 @@
+use aoc_utils::{label_regions, trace, Grid, ParseGridError, Regions};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 12,
+    title: "Garden Groups",
+    example_answers: [Some("1930"), Some("1206")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let garden = Garden::parse(input).ok()?;
+    let regions = garden.regions();
+    let largest = regions.iter().map(|region| region.area()).max()?;
+    Some(format!(
+        "{} regions, the largest with {largest} plots",
+        regions.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let garden = Garden::parse(input).expect("invalid input");
+    garden
+        .regions()
+        .iter()
+        .map(|region| region.area() * region.perimeter())
+        .sum()
+}
+
+pub fn second_part(input: &str) -> usize {
+    let garden = Garden::parse(input).expect("invalid input");
+    garden
+        .regions()
+        .iter()
+        .map(|region| region.area() * region.corners())
+        .sum()
+}
+
+/// The garden plots and their plant types.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Garden {
+    pub plants: Grid<char>,
+}
+
+impl Garden {
+    /// Parses a map with one letter per garden plot.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        let _span = trace::parse();
+        let plants = Grid::parse(input, |c| c.is_ascii_alphabetic().then_some(c))?;
+        Ok(Self { plants })
+    }
+
+    /// Decomposes the garden into its regions of connected plots growing the same plant.
+    ///
+    /// A region's number of straight fence sections equals its number of corners.
+    pub fn regions(&self) -> Regions {
+        label_regions(&self.plants, |a, b| a == b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        RRRRIICCFF
+        RRRRIICCCF
+        VVRRRCCFFF
+        VVRCCCJFFF
+        VVVVCJJCFE
+        VVIVCCJJEE
+        VVIIICJJEE
+        MIIIIIJJEE
+        MIIISIJEEE
+        MMMISSJEEE
+    ";
+
+    const SMALL: &str = "
+        AAAA
+        BBCD
+        BBCC
+        EEEC
+    ";
+
+    #[test]
+    fn test_regions() {
+        let garden = Garden::parse(SMALL).expect("failed to parse garden");
+        let mut regions: Vec<_> = garden
+            .regions()
+            .iter()
+            .map(|region| {
+                (
+                    garden.plants[region.cells()[0]],
+                    region.area(),
+                    region.perimeter(),
+                    region.corners(),
+                )
+            })
+            .collect();
+        regions.sort();
+        assert_eq!(
+            regions,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sides_with_holes() {
+        let garden = Garden::parse(
+            "
+            AAAAAA
+            AAABBA
+            AAABBA
+            ABBAAA
+            ABBAAA
+            AAAAAA
+            ",
+        )
+        .expect("failed to parse garden");
+        let price: usize = garden
+            .regions()
+            .iter()
+            .map(|region| region.area() * region.corners())
+            .sum();
+        assert_eq!(price, 368);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(SMALL), 140);
+        assert_eq!(first_part(INPUT), 1930);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(SMALL), 80);
+        assert_eq!(second_part(INPUT), 1206);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_13;
+    "Winning all possible prizes takes {} tokens" => first_part,
+    "With the corrected prize positions, it takes {} tokens" => second_part,
+);
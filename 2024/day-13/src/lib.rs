@@ -0,0 +1,232 @@
+use aoc_utils::{solve_integer_2x2, trace, Point};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 13,
+    title: "Claw Contraption",
+    example_answers: [Some("480"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let machines = parse_machines(input).ok()?;
+    let winnable = machines
+        .iter()
+        .filter(|machine| machine.presses().is_some())
+        .count();
+    Some(format!(
+        "{} claw machines, {winnable} of them winnable",
+        machines.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> i64 {
+    parse_machines(input)
+        .expect("invalid input")
+        .iter()
+        .filter_map(|machine| machine.presses())
+        .filter(|&(a, b)| a <= PRESS_LIMIT && b <= PRESS_LIMIT)
+        .map(|(a, b)| tokens(a, b))
+        .sum()
+}
+
+pub fn second_part(input: &str) -> i64 {
+    parse_machines(input)
+        .expect("invalid input")
+        .iter()
+        .map(|machine| machine.with_prize_offset(PRIZE_OFFSET))
+        .filter_map(|machine| machine.presses())
+        .map(|(a, b)| tokens(a, b))
+        .sum()
+}
+
+/// The maximum number of presses per button in part 1.
+pub const PRESS_LIMIT: i64 = 100;
+
+/// The unit conversion error of part 2, added to both prize coordinates.
+pub const PRIZE_OFFSET: i64 = 10000000000000;
+
+/// The cost of pressing the `A` button.
+const A_COST: i64 = 3;
+
+/// The cost of pressing the `B` button.
+const B_COST: i64 = 1;
+
+/// A claw machine.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Machine {
+    /// The claw movement when pressing `A`.
+    pub a: Point,
+    /// The claw movement when pressing `B`.
+    pub b: Point,
+    /// The position of the prize.
+    pub prize: Point,
+}
+
+/// Gets the number of tokens needed for the button presses.
+pub fn tokens(a: i64, b: i64) -> i64 {
+    a * A_COST + b * B_COST
+}
+
+impl Machine {
+    /// Determines how often `A` and `B` need to be pressed to win the prize.
+    ///
+    /// The presses solve `a · A + b · B = prize`, two linear equations in two unknowns.
+    /// Returns `None` if there is no non-negative integer solution.
+    ///
+    /// # Panics
+    ///
+    /// Both buttons moving along the same line would allow multiple solutions;
+    /// this is never the case for the puzzle input and not supported.
+    pub fn presses(&self) -> Option<(i64, i64)> {
+        assert_ne!(
+            self.a.x * self.b.y,
+            self.a.y * self.b.x,
+            "buttons must not be collinear"
+        );
+
+        let [a, b] = solve_integer_2x2(
+            [[self.a.x, self.b.x], [self.a.y, self.b.y]],
+            [self.prize.x, self.prize.y],
+        )?;
+        (a >= 0 && b >= 0).then_some((a, b))
+    }
+
+    /// Returns the machine with the prize moved by the offset along both axes.
+    pub fn with_prize_offset(&self, offset: i64) -> Self {
+        Self {
+            prize: self.prize + Point::new(offset, offset),
+            ..*self
+        }
+    }
+}
+
+/// Parses the machines, separated by empty lines.
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseMachineError> {
+    let _span = trace::parse();
+    let lines: Vec<_> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    lines
+        .chunks(3)
+        .map(|chunk| Machine::from_str(&chunk.join("\n")))
+        .collect()
+}
+
+impl FromStr for Machine {
+    type Err = ParseMachineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+        let mut next = |prefix: &str, separator: char| {
+            let line = lines.next().ok_or(ParseMachineError("missing line"))?;
+            let rest = line
+                .strip_prefix(prefix)
+                .ok_or(ParseMachineError("unexpected line"))?;
+            parse_point(rest, separator)
+        };
+
+        Ok(Self {
+            a: next("Button A:", '+')?,
+            b: next("Button B:", '+')?,
+            prize: next("Prize:", '=')?,
+        })
+    }
+}
+
+/// Parses coordinates such as `X+94, Y+34` or `X=8400, Y=5400`.
+fn parse_point(s: &str, separator: char) -> Result<Point, ParseMachineError> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or(ParseMachineError("missing coordinate"))?;
+    let coordinate = |value: &str, axis: char| {
+        value
+            .trim()
+            .strip_prefix(axis)
+            .and_then(|value| value.strip_prefix(separator))
+            .and_then(|value| value.parse().ok())
+            .ok_or(ParseMachineError("invalid coordinate"))
+    };
+    Ok(Point::new(coordinate(x, 'X')?, coordinate(y, 'Y')?))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseMachineError(&'static str);
+
+impl Display for ParseMachineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse machine: {}", self.0)
+    }
+}
+
+impl Error for ParseMachineError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Button A: X+94, Y+34
+        Button B: X+22, Y+67
+        Prize: X=8400, Y=5400
+
+        Button A: X+26, Y+66
+        Button B: X+67, Y+21
+        Prize: X=12748, Y=12176
+
+        Button A: X+17, Y+86
+        Button B: X+84, Y+37
+        Prize: X=7870, Y=6450
+
+        Button A: X+69, Y+23
+        Button B: X+27, Y+71
+        Prize: X=18641, Y=10279
+    ";
+
+    #[test]
+    fn test_parse_machines() {
+        let machines = parse_machines(INPUT).expect("failed to parse machines");
+        assert_eq!(machines.len(), 4);
+        assert_eq!(
+            machines[0],
+            Machine {
+                a: Point::new(94, 34),
+                b: Point::new(22, 67),
+                prize: Point::new(8400, 5400),
+            }
+        );
+    }
+
+    #[test]
+    fn test_presses() {
+        let machines = parse_machines(INPUT).expect("failed to parse machines");
+        let presses: Vec<_> = machines.iter().map(Machine::presses).collect();
+        assert_eq!(presses, [Some((80, 40)), None, Some((38, 86)), None]);
+
+        let winnable: Vec<_> = machines
+            .iter()
+            .map(|machine| machine.with_prize_offset(PRIZE_OFFSET).presses().is_some())
+            .collect();
+        assert_eq!(winnable, [false, true, false, true]);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 480);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 875318608908);
+    }
+}
@@ -0,0 +1,5 @@
+aoc_utils::aoc_main!(aoc_2024_day_14;
+    "The safety factor after 100 seconds is {}" => |input| first_part(input, WIDTH, HEIGHT),
+    "The robots display the Easter egg after {} seconds"
+        => |input| second_part(input, WIDTH, HEIGHT),
+);
@@ -0,0 +1,254 @@
+use aoc_utils::trace;
+use aoc_utils::Point;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 14,
+    title: "Restroom Redoubt",
+    example_answers: [Some("12"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let robots = parse_robots(input).ok()?;
+    Some(format!("{} robots", robots.len()))
+}
+
+pub fn first_part(input: &str, width: i64, height: i64) -> usize {
+    let robots = parse_robots(input).expect("invalid input");
+    let positions: Vec<_> = robots
+        .iter()
+        .map(|robot| robot.position_at(100, width, height))
+        .collect();
+    safety_factor(&positions, width, height)
+}
+
+pub fn second_part(input: &str, width: i64, height: i64) -> i64 {
+    let robots = parse_robots(input).expect("invalid input");
+    find_easter_egg(&robots, width, height)
+}
+
+/// The width of the space the robots patrol.
+pub const WIDTH: i64 = 101;
+
+/// The height of the space the robots patrol.
+pub const HEIGHT: i64 = 103;
+
+/// A robot's starting position and velocity per second.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Robot {
+    pub position: Point,
+    pub velocity: Point,
+}
+
+impl Robot {
+    /// Gets the position after the specified number of seconds, wrapping around the edges.
+    pub fn position_at(&self, seconds: i64, width: i64, height: i64) -> Point {
+        let moved = self.position + self.velocity * seconds;
+        Point::new(moved.x.rem_euclid(width), moved.y.rem_euclid(height))
+    }
+}
+
+/// Multiplies the numbers of robots in each quadrant, ignoring robots on the middle lines.
+pub fn safety_factor(positions: &[Point], width: i64, height: i64) -> usize {
+    let (mid_x, mid_y) = (width / 2, height / 2);
+    let mut quadrants = [0; 4];
+    for position in positions {
+        if position.x == mid_x || position.y == mid_y {
+            continue;
+        }
+
+        let index = usize::from(position.x > mid_x) + 2 * usize::from(position.y > mid_y);
+        quadrants[index] += 1;
+    }
+    quadrants.iter().product()
+}
+
+/// Finds the first time at which the robots cluster into a picture.
+///
+/// Since the `X` coordinates repeat every `width` seconds and the `Y` coordinates every
+/// `height` seconds, each axis is searched independently for the time its coordinates have
+/// the smallest variance. Both times are then combined with the Chinese remainder theorem,
+/// which requires `width` and `height` to be coprime.
+pub fn find_easter_egg(robots: &[Robot], width: i64, height: i64) -> i64 {
+    let variance_at = |period: i64, axis: fn(&Point) -> i64| {
+        (0..period)
+            .min_by_key(|&seconds| {
+                let values = robots.iter().map(|robot| {
+                    let position = robot.position_at(seconds, width, height);
+                    axis(&position)
+                });
+                variance(values)
+            })
+            .expect("period must not be zero")
+    };
+
+    let x = variance_at(width, |point| point.x);
+    let y = variance_at(height, |point| point.y);
+
+    // Find t ≡ x (mod width) with t ≡ y (mod height).
+    (0..height)
+        .map(|k| x + k * width)
+        .find(|t| t % height == y)
+        .expect("width and height must be coprime")
+}
+
+/// Calculates the variance of the values, scaled by the squared count to stay in integers.
+fn variance(values: impl Iterator<Item = i64>) -> i64 {
+    let (count, sum, squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        (count + 1, sum + value, squares + value * value)
+    });
+    count * squares - sum * sum
+}
+
+/// Renders the robot positions, showing the number of robots per tile or `.` for none.
+pub fn render(positions: &[Point], width: i64, height: i64) -> String {
+    let mut counts = vec![0u32; (width * height) as usize];
+    for position in positions {
+        counts[(position.y * width + position.x) as usize] += 1;
+    }
+
+    counts
+        .chunks(width as usize)
+        .map(|row| {
+            row.iter()
+                .map(|&count| match count {
+                    0 => '.',
+                    n => char::from_digit(n.min(9), 10).expect("single digit"),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses one robot per line.
+pub fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseRobotError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(Robot::from_str)
+        .collect()
+}
+
+impl FromStr for Robot {
+    type Err = ParseRobotError;
+
+    /// Parses a robot such as `p=0,4 v=3,-3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s
+            .split_once(' ')
+            .ok_or(ParseRobotError("missing velocity"))?;
+        let point = |value: &str, prefix: &str| {
+            let (x, y) = value
+                .strip_prefix(prefix)
+                .and_then(|value| value.split_once(','))
+                .ok_or(ParseRobotError("invalid vector"))?;
+            match (x.parse(), y.parse()) {
+                (Ok(x), Ok(y)) => Ok(Point::new(x, y)),
+                _ => Err(ParseRobotError("invalid coordinate")),
+            }
+        };
+
+        Ok(Self {
+            position: point(position, "p=")?,
+            velocity: point(velocity.trim(), "v=")?,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseRobotError(&'static str);
+
+impl Display for ParseRobotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse robot: {}", self.0)
+    }
+}
+
+impl Error for ParseRobotError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        p=0,4 v=3,-3
+        p=6,3 v=-1,-3
+        p=10,3 v=-1,2
+        p=2,0 v=2,-1
+        p=0,0 v=1,3
+        p=3,0 v=-2,-2
+        p=7,6 v=-1,-3
+        p=3,0 v=-1,-2
+        p=9,3 v=2,3
+        p=7,3 v=-1,2
+        p=2,4 v=2,-3
+        p=9,5 v=-3,-3
+    ";
+
+    #[test]
+    fn test_position_at() {
+        let robot: Robot = "p=2,4 v=2,-3".parse().expect("failed to parse robot");
+        assert_eq!(robot.position_at(1, 11, 7), Point::new(4, 1));
+        assert_eq!(robot.position_at(5, 11, 7), Point::new(1, 3));
+    }
+
+    #[test]
+    fn test_render() {
+        let robots = parse_robots(INPUT).expect("failed to parse robots");
+        let positions: Vec<_> = robots
+            .iter()
+            .map(|robot| robot.position_at(100, 11, 7))
+            .collect();
+        assert_eq!(
+            render(&positions, 11, 7),
+            "......2..1.\n...........\n1..........\n.11........\n.....1.....\n...12......\n.1....1...."
+        );
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT, 11, 7), 12);
+    }
+
+    #[test]
+    fn test_find_easter_egg() {
+        // Robots that all gather in a small square after a known number of seconds.
+        const SECONDS: i64 = 6789;
+        let mut seed = 12345u64;
+        let mut random = |modulus: i64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % modulus as u64) as i64
+        };
+
+        let robots: Vec<_> = (0..200)
+            .map(|_| {
+                let target = Point::new(40 + random(10), 50 + random(10));
+                let velocity = Point::new(random(201) - 100, random(201) - 100);
+                let start = Robot {
+                    position: target,
+                    velocity: -velocity,
+                };
+                Robot {
+                    position: start.position_at(SECONDS, WIDTH, HEIGHT),
+                    velocity,
+                }
+            })
+            .collect();
+
+        assert_eq!(find_easter_egg(&robots, WIDTH, HEIGHT), SECONDS);
+    }
+}
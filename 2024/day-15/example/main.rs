@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_15;
+    "The sum of all boxes' GPS coordinates is {}" => first_part,
+    "The sum of all boxes' GPS coordinates in the wide warehouse is {}" => second_part,
+);
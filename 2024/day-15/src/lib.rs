@@ -0,0 +1,357 @@
+use aoc_utils::{trace, Direction, Grid, ParseGridError, Point};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 15,
+    title: "Warehouse Woes",
+    example_answers: [Some("10092"), Some("9021")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (warehouse, moves) = parse_input(input).ok()?;
+    let boxes = warehouse
+        .tiles
+        .iter()
+        .filter(|(_, &tile)| tile == Tile::Box)
+        .count();
+    Some(format!(
+        "{}×{} warehouse with {boxes} boxes, the robot starting at {} for {} moves",
+        warehouse.tiles.width(),
+        warehouse.tiles.height(),
+        warehouse.robot,
+        moves.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> i64 {
+    let (mut warehouse, moves) = parse_input(input).expect("invalid input");
+    warehouse.run(&moves);
+    warehouse.gps_sum()
+}
+
+pub fn second_part(input: &str) -> i64 {
+    let (warehouse, moves) = parse_input(input).expect("invalid input");
+    let mut warehouse = warehouse.widen();
+    warehouse.run(&moves);
+    warehouse.gps_sum()
+}
+
+/// The contents of a warehouse tile.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Tile {
+    Empty,
+    Wall,
+    /// A box one tile wide.
+    Box,
+    /// The left half of a box two tiles wide.
+    BoxLeft,
+    /// The right half of a box two tiles wide.
+    BoxRight,
+}
+
+/// The warehouse with the robot's position.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Warehouse {
+    pub tiles: Grid<Tile>,
+    pub robot: Point,
+}
+
+impl Warehouse {
+    /// Parses the warehouse map, where `#` is a wall, `O` a box and `@` the robot.
+    pub fn parse(input: &str) -> Result<Self, ParseInputError> {
+        let cells = Grid::parse(input, |c| match c {
+            '.' | '#' | 'O' | '[' | ']' | '@' => Some(c),
+            _ => None,
+        })
+        .map_err(ParseInputError::InvalidMap)?;
+        let robot = cells
+            .find(|&c| c == '@')
+            .ok_or(ParseInputError::MissingRobot)?;
+
+        let tiles = cells.map(|&c| match c {
+            '#' => Tile::Wall,
+            'O' => Tile::Box,
+            '[' => Tile::BoxLeft,
+            ']' => Tile::BoxRight,
+            _ => Tile::Empty,
+        });
+        Ok(Self { tiles, robot })
+    }
+
+    /// Creates the twice-as-wide warehouse of part 2.
+    pub fn widen(&self) -> Self {
+        let mut tiles = Grid::new(self.tiles.width() * 2, self.tiles.height(), Tile::Empty);
+        for (point, &tile) in self.tiles.iter() {
+            let (left, right) = match tile {
+                Tile::Box => (Tile::BoxLeft, Tile::BoxRight),
+                Tile::Wall => (Tile::Wall, Tile::Wall),
+                _ => (Tile::Empty, Tile::Empty),
+            };
+            tiles[Point::new(point.x * 2, point.y)] = left;
+            tiles[Point::new(point.x * 2 + 1, point.y)] = right;
+        }
+
+        Self {
+            tiles,
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    /// Attempts all moves in order.
+    pub fn run(&mut self, moves: &[Direction]) {
+        for &direction in moves {
+            self.step(direction);
+        }
+    }
+
+    /// Attempts to move the robot, pushing any boxes in the way.
+    ///
+    /// Returns `false` if the robot or a box would run into a wall, in which case nothing moves.
+    pub fn step(&mut self, direction: Direction) -> bool {
+        let offset = direction.offset();
+        let Some(pushed) = self.pushed_tiles(self.robot + offset, direction) else {
+            return false;
+        };
+
+        // Move the tiles furthest along the direction first so no tile is overwritten.
+        let mut pushed: Vec<_> = pushed.into_iter().collect();
+        pushed.sort_by_key(|point| -(point.x * offset.x + point.y * offset.y));
+        for point in pushed {
+            self.tiles[point + offset] = self.tiles[point];
+            self.tiles[point] = Tile::Empty;
+        }
+
+        self.robot += offset;
+        true
+    }
+
+    /// Collects all box tiles that need to move when pushing into `start`,
+    /// or `None` if any of them is blocked by a wall.
+    fn pushed_tiles(&self, start: Point, direction: Direction) -> Option<HashSet<Point>> {
+        let mut pushed = HashSet::new();
+        let mut pending = vec![start];
+
+        while let Some(point) = pending.pop() {
+            if pushed.contains(&point) {
+                continue;
+            }
+
+            let partner = match self.tiles[point] {
+                Tile::Wall => return None,
+                Tile::Empty => continue,
+                Tile::Box => None,
+                Tile::BoxLeft => Some(point + Direction::Right.offset()),
+                Tile::BoxRight => Some(point + Direction::Left.offset()),
+            };
+
+            pushed.insert(point);
+            pending.push(point + direction.offset());
+            if let Some(partner) = partner {
+                pending.push(partner);
+            }
+        }
+
+        Some(pushed)
+    }
+
+    /// Gets the sum of the GPS coordinates of all boxes, measured at their left edge.
+    pub fn gps_sum(&self) -> i64 {
+        self.tiles
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Box || tile == Tile::BoxLeft)
+            .map(|(point, _)| 100 * point.y + point.x)
+            .sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.tiles.height() as i64 {
+            for x in 0..self.tiles.width() as i64 {
+                let point = Point::new(x, y);
+                let c = match self.tiles[point] {
+                    _ if point == self.robot => '@',
+                    Tile::Empty => '.',
+                    Tile::Wall => '#',
+                    Tile::Box => 'O',
+                    Tile::BoxLeft => '[',
+                    Tile::BoxRight => ']',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the warehouse map and the moves, separated by an empty line.
+pub fn parse_input(input: &str) -> Result<(Warehouse, Vec<Direction>), ParseInputError> {
+    let _span = trace::parse();
+    let mut lines = input
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty());
+    let map: Vec<_> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let moves: String = lines.collect();
+
+    let moves = moves
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            _ => Err(ParseInputError::InvalidMove),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((Warehouse::parse(&map.join("\n"))?, moves))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseInputError {
+    InvalidMap(ParseGridError),
+    MissingRobot,
+    InvalidMove,
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseInputError::InvalidMap(e) => write!(f, "Failed to parse input: {e}"),
+            ParseInputError::MissingRobot => write!(f, "Failed to parse input: missing robot"),
+            ParseInputError::InvalidMove => write!(f, "Failed to parse input: invalid move"),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ##########
+        #..O..O.O#
+        #......O.#
+        #.OO..O.O#
+        #..O@..O.#
+        #O#..O...#
+        #O..O..O.#
+        #.OO.O.OO#
+        #....O...#
+        ##########
+
+        <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+        vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+        ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+        <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+        ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+        ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+        >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+        <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+        ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+        v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+    ";
+
+    const SMALL: &str = "
+        ########
+        #..O.O.#
+        ##@.O..#
+        #...O..#
+        #.#.O..#
+        #...O..#
+        #......#
+        ########
+
+        <^^>>>vv<v>>v<<
+    ";
+
+    fn trim_lines(s: &str) -> String {
+        s.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+
+    #[test]
+    fn test_small() {
+        let (mut warehouse, moves) = parse_input(SMALL).expect("failed to parse input");
+        assert_eq!(moves.len(), 15);
+        warehouse.run(&moves);
+        assert_eq!(
+            warehouse.to_string(),
+            trim_lines(
+                "
+                ########
+                #....OO#
+                ##.....#
+                #.....O#
+                #.#O@..#
+                #...O..#
+                #...O..#
+                ########
+                "
+            )
+        );
+        assert_eq!(warehouse.gps_sum(), 2028);
+    }
+
+    #[test]
+    fn test_wide_push() {
+        let (warehouse, moves) = parse_input(
+            "
+            #######
+            #...#.#
+            #.....#
+            #..OO@#
+            #..O..#
+            #.....#
+            #######
+
+            <vv<<^^<<^^
+            ",
+        )
+        .expect("failed to parse input");
+        let mut warehouse = warehouse.widen();
+        warehouse.run(&moves);
+        assert_eq!(
+            warehouse.to_string(),
+            trim_lines(
+                "
+                ##############
+                ##...[].##..##
+                ##...@.[]...##
+                ##....[]....##
+                ##..........##
+                ##..........##
+                ##############
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 10092);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 9021);
+    }
+}
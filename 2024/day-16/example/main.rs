@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_16;
+    "The lowest possible score is {}" => first_part,
+    "{} tiles are part of at least one of the best paths" => second_part,
+);
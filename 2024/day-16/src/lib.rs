@@ -0,0 +1,236 @@
+use aoc_utils::{dijkstra_distances, trace, Direction, Grid, ParseGridError, Point};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 16,
+    title: "Reindeer Maze",
+    example_answers: [Some("7036"), Some("45")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let maze = Maze::parse(input).ok()?;
+    Some(format!(
+        "{}×{} maze from {} to {}",
+        maze.walls.width(),
+        maze.walls.height(),
+        maze.start,
+        maze.end
+    ))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    let maze = Maze::parse(input).expect("invalid input");
+    maze.lowest_score().expect("the end is unreachable")
+}
+
+pub fn second_part(input: &str) -> usize {
+    let maze = Maze::parse(input).expect("invalid input");
+    maze.best_path_tiles().len()
+}
+
+/// The cost of moving forward one tile.
+const STEP_COST: u64 = 1;
+
+/// The cost of rotating by 90 degrees.
+const TURN_COST: u64 = 1000;
+
+/// A position and the direction the Reindeer faces.
+pub type State = (Point, Direction);
+
+/// The maze with its start and end tiles.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Maze {
+    /// `true` for wall tiles.
+    pub walls: Grid<bool>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Maze {
+    /// Parses the maze, where `#` is a wall, `S` the start and `E` the end.
+    pub fn parse(input: &str) -> Result<Self, ParseMazeError> {
+        let _span = trace::parse();
+        let cells = Grid::parse(input, |c| match c {
+            '.' | '#' | 'S' | 'E' => Some(c),
+            _ => None,
+        })
+        .map_err(ParseMazeError::InvalidGrid)?;
+        let start = cells
+            .find(|&c| c == 'S')
+            .ok_or(ParseMazeError::MissingTile("start"))?;
+        let end = cells
+            .find(|&c| c == 'E')
+            .ok_or(ParseMazeError::MissingTile("end"))?;
+
+        Ok(Self {
+            walls: cells.map(|&c| c == '#'),
+            start,
+            end,
+        })
+    }
+
+    /// Gets the lowest score of any path from the start to the end.
+    pub fn lowest_score(&self) -> Option<u64> {
+        let distances = self.distances_from_start();
+        self.end_states()
+            .filter_map(|state| distances.get(&state).copied())
+            .min()
+    }
+
+    /// Gets all tiles that are part of at least one best path.
+    ///
+    /// A state lies on a best path if its distance from the start plus its distance to the end
+    /// equals the lowest score. The distances to the end are found by a backward search from
+    /// all end states along reversed moves.
+    pub fn best_path_tiles(&self) -> HashSet<Point> {
+        let forward = self.distances_from_start();
+        let Some(best) = self
+            .end_states()
+            .filter_map(|state| forward.get(&state).copied())
+            .min()
+        else {
+            return HashSet::new();
+        };
+
+        let backward = dijkstra_distances(self.end_states(), |&(position, direction)| {
+            self.moves((position, direction), direction.opposite())
+        });
+
+        forward
+            .iter()
+            .filter(|(state, &distance)| {
+                backward
+                    .get(state)
+                    .is_some_and(|&remaining| distance + remaining == best)
+            })
+            .map(|(&(position, _), _)| position)
+            .collect()
+    }
+
+    fn distances_from_start(&self) -> HashMap<State, u64> {
+        dijkstra_distances([(self.start, Direction::Right)], |&state| {
+            self.moves(state, state.1)
+        })
+    }
+
+    fn end_states(&self) -> impl Iterator<Item = State> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| (self.end, direction))
+    }
+
+    /// Gets the states reachable from a state by stepping along `heading` or by turning.
+    ///
+    /// Stepping along the facing direction explores the maze forwards; stepping against it
+    /// undoes a move, which is used to search backwards from the end.
+    fn moves(&self, (position, direction): State, heading: Direction) -> Vec<(State, u64)> {
+        let mut moves = vec![
+            ((position, direction.turn_left()), TURN_COST),
+            ((position, direction.turn_right()), TURN_COST),
+        ];
+
+        let next = position + heading.offset();
+        if self.walls.get(next) == Some(&false) {
+            moves.push(((next, direction), STEP_COST));
+        }
+
+        moves
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseMazeError {
+    InvalidGrid(ParseGridError),
+    MissingTile(&'static str),
+}
+
+impl Display for ParseMazeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMazeError::InvalidGrid(e) => write!(f, "Failed to parse maze: {e}"),
+            ParseMazeError::MissingTile(tile) => {
+                write!(f, "Failed to parse maze: missing {tile} tile")
+            }
+        }
+    }
+}
+
+impl Error for ParseMazeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ###############
+        #.......#....E#
+        #.#.###.#.###.#
+        #.....#.#...#.#
+        #.###.#####.#.#
+        #.#.#.......#.#
+        #.#.#####.###.#
+        #...........#.#
+        ###.#.#####.#.#
+        #...#.....#.#.#
+        #.#.#.###.#.#.#
+        #.....#...#.#.#
+        #.###.#.#.#.#.#
+        #S..#.....#...#
+        ###############
+    ";
+
+    const SECOND: &str = "
+        #################
+        #...#...#...#..E#
+        #.#.#.#.#.#.#.#.#
+        #.#.#.#...#...#.#
+        #.#.#.#.###.#.#.#
+        #...#.#.#.....#.#
+        #.#.#.#.#.#####.#
+        #.#...#.#.#.....#
+        #.#.#####.#.###.#
+        #.#.#.......#...#
+        #.#.###.#####.###
+        #.#.#...#.....#.#
+        #.#.#.#####.###.#
+        #.#.#.........#.#
+        #.#.#.#########.#
+        #S#.............#
+        #################
+    ";
+
+    #[test]
+    fn test_unreachable() {
+        let maze = Maze::parse(
+            "
+            #####
+            #S#E#
+            #####
+            ",
+        )
+        .expect("failed to parse maze");
+        assert_eq!(maze.lowest_score(), None);
+        assert!(maze.best_path_tiles().is_empty());
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 7036);
+        assert_eq!(first_part(SECOND), 11048);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 45);
+        assert_eq!(second_part(SECOND), 64);
+    }
+}
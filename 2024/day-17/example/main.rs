@@ -0,0 +1,5 @@
+aoc_utils::aoc_main!(aoc_2024_day_17;
+    "The program outputs {}" => first_part,
+    "The program outputs a copy of itself for A = {}" or "The program never outputs a copy of itself"
+        => second_part,
+);
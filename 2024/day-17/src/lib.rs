@@ -0,0 +1,337 @@
+use aoc_utils::trace;
+use aoc_utils::vm::{Flow, Machine, MachineBuilder, State, Status, VmError};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 17,
+    title: "Chronospatial Computer",
+    example_answers: [Some("4,6,3,5,6,3,5,2,1,0"), Some("117440")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let computer: Computer = input.parse().ok()?;
+    Some(format!(
+        "registers {:?}, program of {} numbers",
+        computer.registers(),
+        computer.program().len()
+    ))
+}
+
+pub fn first_part(input: &str) -> String {
+    let mut computer: Computer = input.parse().expect("invalid input");
+    let output = computer.run();
+    output
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn second_part(input: &str) -> Option<u64> {
+    let computer: Computer = input.parse().expect("invalid input");
+    find_quine(&computer)
+}
+
+/// The outcome of executing a single instruction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Step {
+    /// The instruction was executed without producing output.
+    Continue,
+    /// The instruction output a value.
+    Output(u8),
+    /// The instruction pointer ran past the end of the program.
+    Halt,
+}
+
+/// A 3-bit computer with three registers.
+#[derive(Debug, Clone)]
+pub struct Computer {
+    machine: Machine<u64>,
+}
+
+const A: usize = 0;
+const B: usize = 1;
+const C: usize = 2;
+
+impl Computer {
+    /// Creates a computer with the instruction pointer at the start of the program.
+    pub fn new(registers: [u64; 3], program: Vec<u8>) -> Self {
+        let program = program.into_iter().map(u64::from).collect();
+        let mut machine = instruction_set().build(program);
+        machine.state_mut().registers = registers.to_vec();
+        Self { machine }
+    }
+
+    /// Gets the registers `A`, `B` and `C`.
+    pub fn registers(&self) -> &[u64] {
+        &self.machine.state().registers
+    }
+
+    /// Sets the value of register `A` (`0`), `B` (`1`) or `C` (`2`).
+    pub fn set_register(&mut self, register: usize, value: u64) {
+        self.machine.state_mut().registers[register] = value;
+    }
+
+    /// Gets the program as a list of 3-bit numbers.
+    pub fn program(&self) -> Vec<u8> {
+        let program = &self.machine.state().program;
+        program.iter().map(|&value| value as u8).collect()
+    }
+
+    /// Runs the program until it halts and returns its output.
+    pub fn run(&mut self) -> Vec<u8> {
+        let mut output = Vec::new();
+        loop {
+            match self.step() {
+                Step::Continue => {}
+                Step::Output(value) => output.push(value),
+                Step::Halt => return output,
+            }
+        }
+    }
+
+    /// Executes the instruction at the instruction pointer.
+    ///
+    /// # Panics
+    ///
+    /// Panics on invalid opcodes or combo operands.
+    pub fn step(&mut self) -> Step {
+        // An opcode without its operand ends the program, like running past its end.
+        let status = match self.machine.step() {
+            Ok(status) => status,
+            Err(VmError::TruncatedInstruction { .. }) => Status::Halted,
+            Err(e) => panic!("{e}"),
+        };
+
+        if let Some(value) = self.machine.take_output().pop() {
+            return Step::Output(value as u8);
+        }
+
+        match status {
+            Status::Halted => Step::Halt,
+            _ => Step::Continue,
+        }
+    }
+}
+
+/// Serializes the registers and the program, leaving out the instruction pointer.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Computer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut computer = serializer.serialize_struct("Computer", 2)?;
+        computer.serialize_field("registers", self.registers())?;
+        computer.serialize_field("program", &self.program())?;
+        computer.end()
+    }
+}
+
+/// Defines the eight instructions of the computer.
+fn instruction_set() -> MachineBuilder<u64> {
+    MachineBuilder::new(3)
+        .opcode(0, "adv", 1, |state, operands| {
+            state.registers[A] = divide(state, operands[0]);
+            Flow::Next
+        })
+        .opcode(1, "bxl", 1, |state, operands| {
+            state.registers[B] ^= operands[0];
+            Flow::Next
+        })
+        .opcode(2, "bst", 1, |state, operands| {
+            state.registers[B] = combo(state, operands[0]) % 8;
+            Flow::Next
+        })
+        .opcode(3, "jnz", 1, |state, operands| {
+            if state.registers[A] != 0 {
+                Flow::Jump(operands[0] as usize)
+            } else {
+                Flow::Next
+            }
+        })
+        // The operand of bxc is ignored.
+        .opcode(4, "bxc", 1, |state, _| {
+            state.registers[B] ^= state.registers[C];
+            Flow::Next
+        })
+        .opcode(5, "out", 1, |state, operands| {
+            let value = combo(state, operands[0]) % 8;
+            state.output.push(value);
+            Flow::Next
+        })
+        .opcode(6, "bdv", 1, |state, operands| {
+            state.registers[B] = divide(state, operands[0]);
+            Flow::Next
+        })
+        .opcode(7, "cdv", 1, |state, operands| {
+            state.registers[C] = divide(state, operands[0]);
+            Flow::Next
+        })
+}
+
+/// Divides register `A` by two to the power of the combo operand, truncating the result.
+fn divide(state: &State<u64>, operand: u64) -> u64 {
+    let exponent = combo(state, operand);
+    state.registers[A]
+        .checked_shr(exponent.try_into().unwrap_or(u32::MAX))
+        .unwrap_or(0)
+}
+
+/// Gets the value of a combo operand.
+fn combo(state: &State<u64>, operand: u64) -> u64 {
+    match operand {
+        0..=3 => operand,
+        4 => state.registers[A],
+        5 => state.registers[B],
+        6 => state.registers[C],
+        _ => panic!("invalid combo operand {operand}"),
+    }
+}
+
+/// Finds the lowest positive initial value of register `A` for which the program
+/// outputs a copy of itself.
+///
+/// This assumes the program's structure: it loops, shifting `A` right by three bits each
+/// iteration, and each output only depends on the remaining bits of `A`. The value is
+/// therefore built one octal digit at a time, starting with the most significant digit
+/// that produces the last output value. Every digit keeping the output equal to the
+/// program's tail remains a candidate, as later digits may rule it out.
+pub fn find_quine(computer: &Computer) -> Option<u64> {
+    let program = computer.program();
+    let registers = computer.registers();
+    let run = |a: u64| Computer::new([a, registers[B], registers[C]], program.clone()).run();
+
+    let mut candidates = vec![0u64];
+    for length in 1..=program.len() {
+        let tail = &program[program.len() - length..];
+        candidates = candidates
+            .iter()
+            .flat_map(|&prefix| (0..8).map(move |digit| prefix * 8 + digit))
+            .filter(|&a| run(a) == tail)
+            .collect();
+    }
+
+    candidates.into_iter().filter(|&a| a > 0).min()
+}
+
+impl FromStr for Computer {
+    type Err = ParseComputerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut registers = [0; 3];
+        let mut program = None;
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or(ParseComputerError("missing colon"))?;
+            let value = value.trim();
+
+            let register = match name {
+                "Register A" => A,
+                "Register B" => B,
+                "Register C" => C,
+                "Program" => {
+                    let values = value
+                        .split(',')
+                        .map(|v| v.trim().parse::<u8>().ok().filter(|&v| v < 8))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or(ParseComputerError("invalid program"))?;
+                    program = Some(values);
+                    continue;
+                }
+                _ => return Err(ParseComputerError("unknown line")),
+            };
+
+            registers[register] = value
+                .parse()
+                .map_err(|_| ParseComputerError("invalid register value"))?;
+        }
+
+        let program = program.ok_or(ParseComputerError("missing program"))?;
+        Ok(Self::new(registers, program))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseComputerError(&'static str);
+
+impl Display for ParseComputerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse computer: {}", self.0)
+    }
+}
+
+impl Error for ParseComputerError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Register A: 729
+        Register B: 0
+        Register C: 0
+
+        Program: 0,1,5,4,3,0
+    ";
+
+    const QUINE: &str = "
+        Register A: 2024
+        Register B: 0
+        Register C: 0
+
+        Program: 0,3,5,4,3,0
+    ";
+
+    #[test]
+    fn test_instructions() {
+        let mut computer = Computer::new([0, 0, 9], vec![2, 6]);
+        computer.run();
+        assert_eq!(computer.registers()[B], 1);
+
+        let mut computer = Computer::new([10, 0, 0], vec![5, 0, 5, 1, 5, 4]);
+        assert_eq!(computer.run(), [0, 1, 2]);
+
+        let mut computer = Computer::new([2024, 0, 0], vec![0, 1, 5, 4, 3, 0]);
+        assert_eq!(computer.run(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.registers()[A], 0);
+
+        let mut computer = Computer::new([0, 29, 0], vec![1, 7]);
+        computer.run();
+        assert_eq!(computer.registers()[B], 26);
+
+        let mut computer = Computer::new([0, 2024, 43690], vec![4, 0]);
+        computer.run();
+        assert_eq!(computer.registers()[B], 44354);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut computer = Computer::new([10, 0, 0], vec![5, 4]);
+        assert_eq!(computer.step(), Step::Output(2));
+        assert_eq!(computer.step(), Step::Halt);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(QUINE), Some(117440));
+
+        let mut computer: Computer = QUINE.parse().expect("failed to parse computer");
+        computer.set_register(A, 117440);
+        assert_eq!(computer.run(), computer.program());
+    }
+}
@@ -0,0 +1,6 @@
+aoc_utils::aoc_main!(aoc_2024_day_18;
+    "The exit can be reached in {} steps" or "The exit cannot be reached"
+        => |input| first_part(input, SIZE, BYTES),
+    "The first byte cutting off the exit is {}" or "No byte cuts off the exit"
+        => |input| second_part(input, SIZE),
+);
@@ -0,0 +1,232 @@
+use aoc_utils::{bfs_distances, trace, DisjointSet, Grid, Point};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 18,
+    title: "RAM Run",
+    example_answers: [Some("22"), Some("6,1")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let bytes = parse_bytes(input).ok()?;
+    Some(format!("{} falling bytes", bytes.len()))
+}
+
+pub fn first_part(input: &str, size: i64, bytes: usize) -> Option<usize> {
+    let falling = parse_bytes(input).expect("invalid input");
+    let mut memory = MemorySpace::new(size);
+    for &byte in falling.iter().take(bytes) {
+        memory.corrupt(byte);
+    }
+    memory.shortest_path()
+}
+
+pub fn second_part(input: &str, size: i64) -> Option<Point> {
+    let falling = parse_bytes(input).expect("invalid input");
+    MemorySpace::new(size).first_blocking_byte(&falling)
+}
+
+/// The width and height of the memory space.
+pub const SIZE: i64 = 71;
+
+/// The number of bytes that have fallen in part 1.
+pub const BYTES: usize = 1024;
+
+/// A square memory space with the start in the top left and the exit in the bottom right corner.
+#[derive(Debug, Clone)]
+pub struct MemorySpace {
+    /// `true` for corrupted positions.
+    pub corrupted: Grid<bool>,
+}
+
+impl MemorySpace {
+    /// Creates an uncorrupted memory space of the specified width and height.
+    pub fn new(size: i64) -> Self {
+        Self {
+            corrupted: Grid::new(size as usize, size as usize, false),
+        }
+    }
+
+    /// Gets the starting position.
+    pub fn start(&self) -> Point {
+        Point::new(0, 0)
+    }
+
+    /// Gets the position of the exit.
+    pub fn exit(&self) -> Point {
+        Point::new(
+            self.corrupted.width() as i64 - 1,
+            self.corrupted.height() as i64 - 1,
+        )
+    }
+
+    /// Marks the position as corrupted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position lies outside the memory space.
+    pub fn corrupt(&mut self, position: Point) {
+        self.corrupted[position] = true;
+    }
+
+    /// Gets the minimum number of steps from the start to the exit.
+    pub fn shortest_path(&self) -> Option<usize> {
+        if self.corrupted[self.start()] {
+            return None;
+        }
+
+        let distances = bfs_distances(self.start(), |&position| self.free_neighbors(position));
+        distances.get(&self.exit()).copied()
+    }
+
+    /// Finds the first of the falling bytes that cuts off the exit from the start.
+    ///
+    /// All bytes are dropped at once and the remaining free positions are joined into
+    /// connected regions. The bytes are then removed in reverse order, joining each freed
+    /// position with its free neighbors, until the start and the exit become connected;
+    /// the byte removed last is the one that blocked the path.
+    pub fn first_blocking_byte(&self, bytes: &[Point]) -> Option<Point> {
+        let mut memory = self.clone();
+        for &byte in bytes {
+            memory.corrupt(byte);
+        }
+
+        let mut regions = DisjointSet::new(self.corrupted.width() * self.corrupted.height());
+        for (position, &corrupted) in memory.corrupted.iter() {
+            if !corrupted {
+                memory.join(&mut regions, position);
+            }
+        }
+
+        let (start, exit) = (self.index(self.start()), self.index(self.exit()));
+        if regions.connected(start, exit) {
+            return None;
+        }
+
+        for &byte in bytes.iter().rev() {
+            memory.corrupted[byte] = self.corrupted[byte];
+            if memory.corrupted[byte] {
+                continue;
+            }
+
+            memory.join(&mut regions, byte);
+            if regions.connected(start, exit) {
+                return Some(byte);
+            }
+        }
+
+        None
+    }
+
+    /// Joins a free position with the regions of its free neighbors.
+    fn join(&self, regions: &mut DisjointSet, position: Point) {
+        for neighbor in self.free_neighbors(position) {
+            regions.union(self.index(position), self.index(neighbor));
+        }
+    }
+
+    fn free_neighbors(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        position
+            .neighbors()
+            .into_iter()
+            .filter(|&neighbor| self.corrupted.get(neighbor) == Some(&false))
+    }
+
+    fn index(&self, position: Point) -> usize {
+        position.y as usize * self.corrupted.width() + position.x as usize
+    }
+}
+
+/// Parses the falling bytes' `X,Y` positions, one per line.
+pub fn parse_bytes(input: &str) -> Result<Vec<Point>, ParseByteError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or(ParseByteError("missing comma"))?;
+            match (x.parse(), y.parse()) {
+                (Ok(x), Ok(y)) => Ok(Point::new(x, y)),
+                _ => Err(ParseByteError("invalid coordinate")),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseByteError(&'static str);
+
+impl Display for ParseByteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse byte: {}", self.0)
+    }
+}
+
+impl Error for ParseByteError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0
+    ";
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT, 7, 12), Some(22));
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT, 7), Some(Point::new(6, 1)));
+    }
+
+    #[test]
+    fn test_first_blocking_byte_matches_search() {
+        let bytes = parse_bytes(INPUT).expect("failed to parse bytes");
+        let mut memory = MemorySpace::new(7);
+        let blocking = bytes
+            .iter()
+            .find(|&&byte| {
+                memory.corrupt(byte);
+                memory.shortest_path().is_none()
+            })
+            .copied();
+        assert_eq!(MemorySpace::new(7).first_blocking_byte(&bytes), blocking);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_19;
+    "{} designs are possible" => first_part,
+    "There are {} ways to arrange the towels for all designs" => second_part,
+);
@@ -0,0 +1,170 @@
+use aoc_utils::trace;
+use aoc_utils::Trie;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 19,
+    title: "Linen Layout",
+    example_answers: [Some("6"), Some("16")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (towels, designs) = parse_input(input).ok()?;
+    let possible = designs
+        .iter()
+        .filter(|design| towels.arrangements(design) > 0)
+        .count();
+    Some(format!(
+        "{} designs, {possible} of them possible",
+        designs.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let (towels, designs) = parse_input(input).expect("invalid input");
+    designs
+        .iter()
+        .filter(|design| towels.arrangements(design) > 0)
+        .count()
+}
+
+pub fn second_part(input: &str) -> u64 {
+    let (towels, designs) = parse_input(input).expect("invalid input");
+    designs
+        .iter()
+        .map(|design| towels.arrangements(design))
+        .sum()
+}
+
+/// The available towel patterns.
+#[derive(Debug, Clone)]
+pub struct Towels {
+    patterns: Trie,
+}
+
+impl Towels {
+    /// Creates the set of available patterns.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let patterns = patterns.into_iter().map(|pattern| (pattern, ())).collect();
+        Self { patterns }
+    }
+
+    /// Counts the number of ways the design can be composed from the patterns.
+    ///
+    /// `ways[end]` holds the number of ways to compose the first `end` stripes; each composable
+    /// prefix contributes its count to every longer prefix it can be extended to by one pattern.
+    pub fn arrangements(&self, design: &str) -> u64 {
+        let mut ways = vec![0u64; design.len() + 1];
+        ways[0] = 1;
+
+        for start in 0..design.len() {
+            if ways[start] == 0 {
+                continue;
+            }
+
+            for (len, ()) in self.patterns.prefixes(design[start..].bytes()) {
+                ways[start + len] += ways[start];
+            }
+        }
+
+        ways[design.len()]
+    }
+}
+
+/// Serializes the patterns as a list of strings, in byte order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Towels {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.patterns
+                .keys()
+                .map(|pattern| String::from_utf8_lossy(&pattern).into_owned()),
+        )
+    }
+}
+
+/// Parses the comma-separated towel patterns and the designs, separated by an empty line.
+pub fn parse_input(input: &str) -> Result<(Towels, Vec<String>), ParseInputError> {
+    let _span = trace::parse();
+    let mut lines = input
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty());
+    let patterns = lines.next().ok_or(ParseInputError("missing patterns"))?;
+    let patterns: Vec<_> = patterns.split(',').map(str::trim).collect();
+
+    let is_stripes = |s: &str| !s.is_empty() && s.chars().all(|c| "wubrg".contains(c));
+    if !patterns.iter().all(|pattern| is_stripes(pattern)) {
+        return Err(ParseInputError("invalid pattern"));
+    }
+
+    let designs: Vec<_> = lines
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+    if !designs.iter().all(|design| is_stripes(design)) {
+        return Err(ParseInputError("invalid design"));
+    }
+
+    Ok((Towels::new(patterns), designs))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseInputError(&'static str);
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse input: {}", self.0)
+    }
+}
+
+impl Error for ParseInputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        r, wr, b, g, bwu, rb, gb, br
+
+        brwrr
+        bggr
+        gbbr
+        rrbgbr
+        ubwu
+        bwurrg
+        brgr
+        bbrgwb
+    ";
+
+    #[test]
+    fn test_arrangements() {
+        let (towels, designs) = parse_input(INPUT).expect("failed to parse input");
+        let arrangements: Vec<_> = designs
+            .iter()
+            .map(|design| towels.arrangements(design))
+            .collect();
+        assert_eq!(arrangements, [2, 1, 4, 6, 0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 6);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 16);
+    }
+}
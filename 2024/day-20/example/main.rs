@@ -0,0 +1,6 @@
+aoc_utils::aoc_main!(aoc_2024_day_20;
+    "{} two-picosecond cheats save at least 100 picoseconds"
+        => |input| first_part(input, MIN_SAVING),
+    "{} twenty-picosecond cheats save at least 100 picoseconds"
+        => |input| second_part(input, MIN_SAVING),
+);
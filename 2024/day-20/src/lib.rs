@@ -0,0 +1,186 @@
+use aoc_utils::{trace, Grid, ParseGridError, Point};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 20,
+    title: "Race Condition",
+    example_answers: [Some("44"), Some("285")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let track = Racetrack::parse(input).ok()?;
+    Some(format!(
+        "a path of {} picoseconds",
+        track.path.len().checked_sub(1)?
+    ))
+}
+
+pub fn first_part(input: &str, min_saving: usize) -> usize {
+    let track = Racetrack::parse(input).expect("invalid input");
+    count_cheats(&track.path, SHORT_CHEAT, min_saving)
+}
+
+pub fn second_part(input: &str, min_saving: usize) -> usize {
+    let track = Racetrack::parse(input).expect("invalid input");
+    count_cheats(&track.path, LONG_CHEAT, min_saving)
+}
+
+/// The minimum number of picoseconds a cheat needs to save to be counted.
+pub const MIN_SAVING: usize = 100;
+
+/// The maximum cheat duration of part 1.
+pub const SHORT_CHEAT: usize = 2;
+
+/// The maximum cheat duration of part 2.
+pub const LONG_CHEAT: usize = 20;
+
+/// The racetrack with its single path from the start to the end.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Racetrack {
+    /// The track positions in the order they are visited, from the start to the end.
+    pub path: Vec<Point>,
+}
+
+impl Racetrack {
+    /// Parses the racetrack, where `#` is a wall, `S` the start and `E` the end.
+    ///
+    /// The track must not branch.
+    pub fn parse(input: &str) -> Result<Self, ParseRacetrackError> {
+        let _span = trace::parse();
+        let cells = Grid::parse(input, |c| match c {
+            '.' | '#' | 'S' | 'E' => Some(c),
+            _ => None,
+        })
+        .map_err(ParseRacetrackError::InvalidGrid)?;
+        let start = cells
+            .find(|&c| c == 'S')
+            .ok_or(ParseRacetrackError::MissingTile("start"))?;
+        let end = cells
+            .find(|&c| c == 'E')
+            .ok_or(ParseRacetrackError::MissingTile("end"))?;
+
+        let mut path = vec![start];
+        let mut previous = None;
+        let mut current = start;
+        while current != end {
+            let mut next = current.neighbors().into_iter().filter(|&neighbor| {
+                Some(neighbor) != previous && cells.get(neighbor).is_some_and(|&c| c != '#')
+            });
+            let step = next.next().ok_or(ParseRacetrackError::InvalidTrack)?;
+            if next.next().is_some() {
+                return Err(ParseRacetrackError::InvalidTrack);
+            }
+
+            previous = Some(current);
+            current = step;
+            path.push(current);
+        }
+
+        Ok(Self { path })
+    }
+}
+
+/// Counts the cheats lasting at most `max_duration` picoseconds that save at least `min_saving`.
+///
+/// A cheat from `path[i]` to `path[j]` takes their Manhattan distance `d` and saves `j - i - d`
+/// picoseconds, so only positions at least `min_saving + d` steps ahead need to be checked.
+/// As the distance changes by at most one per step along the path, a position `d` away with
+/// `d > max_duration` allows skipping the next `d - max_duration - 1` positions.
+pub fn count_cheats(path: &[Point], max_duration: usize, min_saving: usize) -> usize {
+    let mut count = 0;
+    for (i, &from) in path.iter().enumerate() {
+        let mut j = i + min_saving + 1;
+        while j < path.len() {
+            let distance = from.manhattan(path[j]) as usize;
+            if distance > max_duration {
+                j += distance - max_duration;
+                continue;
+            }
+
+            if j - i >= min_saving + distance {
+                count += 1;
+            }
+            j += 1;
+        }
+    }
+    count
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseRacetrackError {
+    InvalidGrid(ParseGridError),
+    MissingTile(&'static str),
+    /// The track branches or does not connect the start to the end.
+    InvalidTrack,
+}
+
+impl Display for ParseRacetrackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRacetrackError::InvalidGrid(e) => write!(f, "Failed to parse racetrack: {e}"),
+            ParseRacetrackError::MissingTile(tile) => {
+                write!(f, "Failed to parse racetrack: missing {tile} tile")
+            }
+            ParseRacetrackError::InvalidTrack => {
+                write!(
+                    f,
+                    "Failed to parse racetrack: the track is not a single path"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseRacetrackError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ###############
+        #...#...#.....#
+        #.#.#.#.#.###.#
+        #S#...#.#.#...#
+        #######.#.#.###
+        #######.#.#...#
+        #######.#.###.#
+        ###..E#...#...#
+        ###.#######.###
+        #...###...#...#
+        #.#####.#.###.#
+        #.#...#.#.#...#
+        #.#.#.#.#.#.###
+        #...#...#...###
+        ###############
+    ";
+
+    #[test]
+    fn test_parse() {
+        let track = Racetrack::parse(INPUT).expect("failed to parse racetrack");
+        assert_eq!(track.path.len(), 85);
+        assert_eq!(track.path[0], Point::new(1, 3));
+        assert_eq!(track.path[84], Point::new(5, 7));
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT, 1), 44);
+        assert_eq!(first_part(INPUT, 20), 5);
+        assert_eq!(first_part(INPUT, 64), 1);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT, 50), 285);
+        assert_eq!(second_part(INPUT, 76), 3);
+    }
+}
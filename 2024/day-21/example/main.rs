@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_21;
+    "The sum of complexities with 2 robots is {}" => first_part,
+    "The sum of complexities with 25 robots is {}" => second_part,
+);
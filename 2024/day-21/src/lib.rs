@@ -0,0 +1,239 @@
+use aoc_utils::{trace, Point};
+use std::collections::HashMap;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 21,
+    title: "Keypad Conundrum",
+    example_answers: [Some("126384"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let codes = codes(input);
+    Some(format!("{} codes: {}", codes.len(), codes.join(", ")))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    codes(input)
+        .iter()
+        .map(|code| complexity(code, SHORT_CHAIN))
+        .sum()
+}
+
+pub fn second_part(input: &str) -> u64 {
+    codes(input)
+        .iter()
+        .map(|code| complexity(code, LONG_CHAIN))
+        .sum()
+}
+
+/// The number of robot-operated directional keypads in part 1.
+pub const SHORT_CHAIN: usize = 2;
+
+/// The number of robot-operated directional keypads in part 2.
+pub const LONG_CHAIN: usize = 25;
+
+/// The layout of the door's numeric keypad; the space marks the gap.
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+
+/// The layout of a directional keypad; the space marks the gap.
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+/// Lists the codes, one per line.
+pub fn codes(input: &str) -> Vec<&str> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Multiplies the number of presses needed for a code with its numeric part.
+///
+/// # Panics
+///
+/// Panics if the code contains keys not found on the numeric keypad.
+pub fn complexity(code: &str, layers: usize) -> u64 {
+    let value: u64 = code
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0);
+    min_presses(code, layers) * value
+}
+
+/// Gets the fewest button presses needed to type the code on the numeric keypad through
+/// `layers` robot-operated directional keypads.
+///
+/// # Panics
+///
+/// Panics if the code contains keys not found on the numeric keypad.
+pub fn min_presses(code: &str, layers: usize) -> u64 {
+    let numeric = Keypad::new(&NUMERIC);
+    let mut chain = Chain::new();
+    chain.sequence_cost(&numeric, code, layers)
+}
+
+/// A keypad layout.
+struct Keypad {
+    keys: HashMap<char, Point>,
+    gap: Point,
+}
+
+impl Keypad {
+    fn new(rows: &[&str]) -> Self {
+        let mut keys = HashMap::new();
+        let mut gap = None;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                let position = Point::new(x as i64, y as i64);
+                match key {
+                    ' ' => gap = Some(position),
+                    _ => {
+                        keys.insert(key, position);
+                    }
+                }
+            }
+        }
+
+        Self {
+            keys,
+            gap: gap.expect("keypad must have a gap"),
+        }
+    }
+
+    /// Gets the directional key sequences moving the arm from one key to another and pressing it.
+    ///
+    /// Only the sequences making all horizontal moves before or after all vertical moves are
+    /// considered, as alternating between directions never takes fewer presses further up the
+    /// chain. Sequences that would point the arm at the gap are skipped.
+    fn paths(&self, from: char, to: char) -> Vec<String> {
+        let (from, to) = (self.keys[&from], self.keys[&to]);
+        let delta = to - from;
+        let horizontal =
+            if delta.x < 0 { "<" } else { ">" }.repeat(delta.x.unsigned_abs() as usize);
+        let vertical = if delta.y < 0 { "^" } else { "v" }.repeat(delta.y.unsigned_abs() as usize);
+
+        let mut paths = Vec::with_capacity(2);
+        if Point::new(to.x, from.y) != self.gap {
+            paths.push(format!("{horizontal}{vertical}A"));
+        }
+        if Point::new(from.x, to.y) != self.gap {
+            let path = format!("{vertical}{horizontal}A");
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+    }
+}
+
+/// Memoizes the press costs for a chain of directional keypads.
+struct Chain {
+    directional: Keypad,
+    /// The cost of moving between two directional keys and pressing the second one,
+    /// by the number of keypads above.
+    cache: HashMap<(char, char, usize), u64>,
+}
+
+impl Chain {
+    fn new() -> Self {
+        Self {
+            directional: Keypad::new(&DIRECTIONAL),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Gets the presses needed to type the sequence on the keypad through `layers`
+    /// directional keypads, with every arm starting at `A`.
+    fn sequence_cost(&mut self, keypad: &Keypad, sequence: &str, layers: usize) -> u64 {
+        let mut from = 'A';
+        let mut total = 0;
+        for to in sequence.chars() {
+            total += keypad
+                .paths(from, to)
+                .iter()
+                .map(|path| self.directional_cost(path, layers))
+                .min()
+                .expect("keys must be connected");
+            from = to;
+        }
+        total
+    }
+
+    /// Gets the presses needed to type the sequence on a directional keypad through
+    /// `layers` further directional keypads; without any, it is typed directly.
+    fn directional_cost(&mut self, sequence: &str, layers: usize) -> u64 {
+        if layers == 0 {
+            return sequence.len() as u64;
+        }
+
+        let mut from = 'A';
+        let mut total = 0;
+        for to in sequence.chars() {
+            total += self.move_cost(from, to, layers - 1);
+            from = to;
+        }
+        total
+    }
+
+    fn move_cost(&mut self, from: char, to: char, layers: usize) -> u64 {
+        if let Some(&cost) = self.cache.get(&(from, to, layers)) {
+            return cost;
+        }
+
+        let cost = self
+            .directional
+            .paths(from, to)
+            .iter()
+            .map(|path| self.directional_cost(path, layers))
+            .min()
+            .expect("keys must be connected");
+        self.cache.insert((from, to, layers), cost);
+        cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        029A
+        980A
+        179A
+        456A
+        379A
+    ";
+
+    #[test]
+    fn test_min_presses() {
+        assert_eq!(min_presses("029A", 0), "<A^A>^^AvvvA".len() as u64);
+        assert_eq!(
+            min_presses("029A", 1),
+            "v<<A>>^A<A>AvA<^AA>A<vAAA>^A".len() as u64
+        );
+        assert_eq!(min_presses("029A", 2), 68);
+        assert_eq!(min_presses("980A", 2), 60);
+        assert_eq!(min_presses("179A", 2), 68);
+        assert_eq!(min_presses("456A", 2), 64);
+        assert_eq!(min_presses("379A", 2), 64);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 126384);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 154115708116294);
+    }
+}
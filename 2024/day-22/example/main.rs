@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_22;
+    "The sum of the 2000th secret numbers is {}" => first_part,
+    "The best sequence of changes gets {} bananas" => second_part,
+);
@@ -0,0 +1,201 @@
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 22,
+    title: "Monkey Market",
+    example_answers: [Some("37327623"), Some("23")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let secrets = parse_secrets(input).ok()?;
+    Some(format!("{} buyers", secrets.len()))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    parse_secrets(input)
+        .expect("invalid input")
+        .iter()
+        .map(|&secret| nth_secret(secret, ROUNDS))
+        .sum()
+}
+
+pub fn second_part(input: &str) -> u32 {
+    let secrets = parse_secrets(input).expect("invalid input");
+    best_sequence(&secrets, ROUNDS)
+}
+
+/// The number of secret numbers each buyer generates.
+pub const ROUNDS: usize = 2000;
+
+/// The modulus keeping secret numbers within 24 bits.
+const PRUNE: u64 = 16777216;
+
+/// The number of distinct price changes, from `-9` to `9`.
+const CHANGES: usize = 19;
+
+/// The number of distinct sequences of four price changes.
+const SEQUENCES: usize = CHANGES.pow(4);
+
+/// Generates the next secret number.
+pub fn next_secret(secret: u64) -> u64 {
+    let secret = ((secret * 64) ^ secret) % PRUNE;
+    let secret = ((secret / 32) ^ secret) % PRUNE;
+    ((secret * 2048) ^ secret) % PRUNE
+}
+
+/// Generates the `n`th secret number after the initial one.
+pub fn nth_secret(secret: u64, n: usize) -> u64 {
+    (0..n).fold(secret, |secret, _| next_secret(secret))
+}
+
+/// Iterates the prices for the initial secret and the `n` secrets generated from it.
+pub fn prices(secret: u64, n: usize) -> impl Iterator<Item = u8> {
+    std::iter::successors(Some(secret), |&secret| Some(next_secret(secret)))
+        .take(n + 1)
+        .map(|secret| (secret % 10) as u8)
+}
+
+/// Finds the most bananas any sequence of four price changes gets across all buyers.
+pub fn best_sequence(secrets: &[u64], rounds: usize) -> u32 {
+    let totals = banana_totals(secrets, rounds);
+    totals.into_iter().max().unwrap_or(0)
+}
+
+/// Accumulates the bananas per sequence of price changes in a flat array.
+///
+/// With the `rayon` feature, the buyers are spread across the thread pool.
+fn banana_totals(secrets: &[u64], rounds: usize) -> Vec<u32> {
+    let bananas = aoc_utils::par::fold_reduce(
+        secrets,
+        Bananas::new,
+        |mut bananas, (buyer, &secret)| {
+            bananas.add_buyer(buyer, secret, rounds);
+            bananas
+        },
+        Bananas::merge,
+    );
+    bananas.totals
+}
+
+/// The bananas per sequence of price changes.
+struct Bananas {
+    totals: Vec<u32>,
+    /// The last buyer (plus one) that has seen each sequence, so only the first
+    /// occurrence per buyer is counted without clearing the array in between.
+    seen_by: Vec<u32>,
+}
+
+impl Bananas {
+    fn new() -> Self {
+        Self {
+            totals: vec![0; SEQUENCES],
+            seen_by: vec![0; SEQUENCES],
+        }
+    }
+
+    fn add_buyer(&mut self, buyer: usize, secret: u64, rounds: usize) {
+        let marker = buyer as u32 + 1;
+        let mut sequence = 0;
+        let mut previous = None;
+
+        for (i, price) in prices(secret, rounds).enumerate() {
+            if let Some(previous) = previous {
+                let change = (9 + price - previous) as usize;
+                sequence = (sequence * CHANGES + change) % SEQUENCES;
+            }
+            previous = Some(price);
+
+            // The first sequence is complete after four changes, i.e. at the fifth price.
+            if i >= 4 && self.seen_by[sequence] != marker {
+                self.seen_by[sequence] = marker;
+                self.totals[sequence] += u32::from(price);
+            }
+        }
+    }
+
+    /// Adds up the totals of two disjoint sets of buyers.
+    fn merge(mut self, other: Self) -> Self {
+        let totals = self.totals.iter_mut().zip(other.totals);
+        totals.for_each(|(lhs, rhs)| *lhs += rhs);
+        self
+    }
+}
+
+/// Parses the initial secret numbers, one per line.
+pub fn parse_secrets(input: &str) -> Result<Vec<u64>, ParseSecretError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().map_err(|_| ParseSecretError))
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseSecretError;
+
+impl Display for ParseSecretError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse secret: invalid number")
+    }
+}
+
+impl Error for ParseSecretError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        1
+        10
+        100
+        2024
+    ";
+
+    #[test]
+    fn test_next_secret() {
+        let secrets: Vec<_> = std::iter::successors(Some(123), |&secret| Some(next_secret(secret)))
+            .skip(1)
+            .take(10)
+            .collect();
+        assert_eq!(
+            secrets,
+            [
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prices() {
+        let prices: Vec<_> = prices(123, 9).collect();
+        assert_eq!(prices, [3, 0, 6, 5, 4, 4, 6, 4, 4, 2]);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 37327623);
+    }
+
+    #[test]
+    fn test_second_part() {
+        let input = "
+            1
+            2
+            3
+            2024
+        ";
+        assert_eq!(second_part(input), 23);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_23;
+    "{} sets of three computers contain a computer starting with t" => first_part,
+    "The password to get into the LAN party is {}" => second_part,
+);
@@ -0,0 +1,163 @@
+use aoc_utils::trace;
+use aoc_utils::Graph;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 23,
+    title: "LAN Party",
+    example_answers: [Some("7"), Some("co,de,ka,ta")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let network = Network::parse(input).ok()?;
+    Some(format!(
+        "{} computers with {} connections, {} triangles",
+        network.graph.node_count(),
+        network.graph.edge_count(),
+        network.triangles().count()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let network = Network::parse(input).expect("invalid input");
+    network
+        .triangles()
+        .filter(|triangle| {
+            triangle
+                .iter()
+                .any(|&node| network.graph[node].starts_with('t'))
+        })
+        .count()
+}
+
+pub fn second_part(input: &str) -> String {
+    let network = Network::parse(input).expect("invalid input");
+    network.password()
+}
+
+/// The computers and their connections.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Network<'a> {
+    /// The connections between the computers, whose nodes carry the computers' names.
+    pub graph: Graph<&'a str>,
+}
+
+impl<'a> Network<'a> {
+    /// Parses connections such as `kh-tc`, one per line.
+    pub fn parse(input: &'a str) -> Result<Self, ParseNetworkError> {
+        let _span = trace::parse();
+        let mut connections = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (a, b) = line
+                .split_once('-')
+                .ok_or(ParseNetworkError("missing dash"))?;
+            if a.is_empty() || b.is_empty() {
+                return Err(ParseNetworkError("missing computer name"));
+            }
+            connections.push((a, b, ()));
+        }
+
+        Ok(Self {
+            graph: Graph::from_edges(connections),
+        })
+    }
+
+    /// Iterates all sets of three computers that are connected to each other,
+    /// with the node indices in ascending order.
+    pub fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.graph.edges().flat_map(move |(a, b)| {
+            self.graph
+                .neighbors(b)
+                .iter()
+                .filter(move |&&c| b < c && self.graph.contains_edge(a, c))
+                .map(move |&c| [a, b, c])
+        })
+    }
+
+    /// Gets the sorted, comma-separated names of the largest set of connected computers.
+    pub fn password(&self) -> String {
+        let mut names: Vec<_> = self
+            .graph
+            .max_clique()
+            .into_iter()
+            .map(|node| self.graph[node])
+            .collect();
+        names.sort_unstable();
+        names.join(",")
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseNetworkError(&'static str);
+
+impl Display for ParseNetworkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse network: {}", self.0)
+    }
+}
+
+impl Error for ParseNetworkError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        kh-tc
+        qp-kh
+        de-cg
+        ka-co
+        yn-aq
+        qp-ub
+        cg-tb
+        vc-aq
+        tb-ka
+        wh-tc
+        yn-cg
+        kh-ub
+        ta-co
+        de-co
+        tc-td
+        tb-wq
+        wh-td
+        ta-ka
+        td-qp
+        aq-cg
+        wq-ub
+        ub-vc
+        de-ta
+        wq-aq
+        wq-vc
+        wh-yn
+        ka-de
+        kh-ta
+        co-tc
+        wh-qp
+        tb-vc
+        td-yn
+    ";
+
+    #[test]
+    fn test_triangles() {
+        let network = Network::parse(INPUT).expect("failed to parse network");
+        assert_eq!(network.triangles().count(), 12);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 7);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,5 @@
+aoc_utils::aoc_main!(aoc_2024_day_24;
+    "The wires starting with z output {}" => first_part,
+    "The swapped wires are {}" or "The circuit is not an adder with four swapped pairs"
+        => second_part,
+);
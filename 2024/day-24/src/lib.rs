@@ -0,0 +1,405 @@
+use aoc_utils::trace;
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 24,
+    title: "Crossed Wires",
+    example_answers: [Some("2024"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let circuit: Circuit = input.parse().ok()?;
+    Some(format!(
+        "{} initial wires, {} gates",
+        circuit.initial.len(),
+        circuit.gates.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    let circuit: Circuit = input.parse().expect("invalid input");
+    let values = circuit.evaluate();
+    number(&values, 'z')
+}
+
+/// The number of output pairs swapped in the puzzle's adder.
+const SWAPPED_PAIRS: usize = 4;
+
+/// Lists the swapped wires, or returns `None` unless exactly four pairs are found, as is the
+/// case for a circuit that isn't a ripple-carry adder.
+pub fn second_part(input: &str) -> Option<String> {
+    let circuit: Circuit = input.parse().expect("invalid input");
+    let wires: Vec<_> = circuit.swapped_wires().into_iter().collect();
+    (wires.len() == 2 * SWAPPED_PAIRS).then(|| wires.join(","))
+}
+
+/// The operation of a logic gate.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operation {
+    pub fn apply(&self, a: bool, b: bool) -> bool {
+        match self {
+            Operation::And => a && b,
+            Operation::Or => a || b,
+            Operation::Xor => a ^ b,
+        }
+    }
+}
+
+/// A logic gate connecting two input wires to an output wire.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Gate {
+    pub inputs: [String; 2],
+    pub operation: Operation,
+    pub output: String,
+}
+
+impl Gate {
+    /// Returns `true` if the gate is connected to the wire as an input.
+    pub fn reads(&self, wire: &str) -> bool {
+        self.inputs.iter().any(|input| input == wire)
+    }
+
+    /// Returns `true` if both inputs are bits of the `x` and `y` numbers.
+    fn reads_inputs(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input.starts_with('x') || input.starts_with('y'))
+    }
+}
+
+/// The system of gates and the initial wire values.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Circuit {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
+    pub initial: HashMap<String, bool>,
+    pub gates: Vec<Gate>,
+}
+
+/// Serializes the initial wire values ordered by wire name.
+#[cfg(feature = "serde")]
+fn serialize_sorted<S>(initial: &HashMap<String, bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(initial.iter().collect::<std::collections::BTreeMap<_, _>>())
+}
+
+impl Circuit {
+    /// Simulates the gates and gets the values of all wires.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gates form a cycle or read a wire that never gets a value.
+    pub fn evaluate(&self) -> HashMap<String, bool> {
+        let mut values = self.initial.clone();
+        let mut pending: Vec<_> = self.gates.iter().collect();
+
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|gate| {
+                let [a, b] = &gate.inputs;
+                match (values.get(a), values.get(b)) {
+                    (Some(&a), Some(&b)) => {
+                        values.insert(gate.output.clone(), gate.operation.apply(a, b));
+                        false
+                    }
+                    _ => true,
+                }
+            });
+            assert!(pending.len() < before, "gates have unresolvable inputs");
+        }
+
+        values
+    }
+
+    /// Finds the gates whose output wires don't fit the structure of a ripple-carry adder.
+    ///
+    /// In such an adder, bit `i` computes `s = x XOR y` and `a = x AND y`, then the output
+    /// `z = s XOR c` and `b = s AND c` from the incoming carry `c`, and the outgoing carry
+    /// `a OR b`. The least significant bit only has a half adder, and the final carry is the
+    /// most significant output bit. Checking the rules this implies for every gate finds the
+    /// swapped outputs without evaluating the circuit.
+    pub fn swapped_wires(&self) -> BTreeSet<&str> {
+        let last_output = self
+            .gates
+            .iter()
+            .map(|gate| gate.output.as_str())
+            .filter(|wire| wire.starts_with('z'))
+            .max();
+        let feeds = |wire: &str, operation: Operation| {
+            self.gates
+                .iter()
+                .any(|gate| gate.operation == operation && gate.reads(wire))
+        };
+
+        let mut swapped = BTreeSet::new();
+        for gate in &self.gates {
+            let output = gate.output.as_str();
+            let is_first_bit = gate.reads("x00") || gate.reads("y00");
+
+            let misplaced = match gate.operation {
+                // The final carry is the only output bit not computed by an XOR.
+                _ if output.starts_with('z') && Some(output) == last_output => {
+                    gate.operation != Operation::Or
+                }
+                Operation::And | Operation::Or if output.starts_with('z') => true,
+                // The sum bits' XORs feed the XORs computing the output bits.
+                Operation::Xor if gate.reads_inputs() => {
+                    !is_first_bit && !feeds(output, Operation::Xor)
+                }
+                // All other XORs compute output bits.
+                Operation::Xor => !output.starts_with('z'),
+                // Both carry halves are combined by an OR, except for the half adder's carry.
+                Operation::And => !is_first_bit && !feeds(output, Operation::Or),
+                Operation::Or => false,
+            };
+
+            if misplaced {
+                swapped.insert(output);
+            }
+        }
+        swapped
+    }
+}
+
+/// Combines the bits of all wires starting with the prefix, with bit `00` being the least significant.
+pub fn number(values: &HashMap<String, bool>, prefix: char) -> u64 {
+    values
+        .iter()
+        .filter(|(wire, &value)| value && wire.starts_with(prefix))
+        .filter_map(|(wire, _)| wire[1..].parse::<u32>().ok())
+        .fold(0, |number, bit| number | (1 << bit))
+}
+
+impl FromStr for Circuit {
+    type Err = ParseCircuitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut lines = s.lines().map(str::trim).skip_while(|line| line.is_empty());
+
+        let mut initial = HashMap::new();
+        for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+            let (wire, value) = line
+                .split_once(": ")
+                .ok_or(ParseCircuitError("invalid initial value"))?;
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => return Err(ParseCircuitError("invalid bit")),
+            };
+            initial.insert(wire.to_string(), value);
+        }
+
+        let gates = lines
+            .filter(|line| !line.is_empty())
+            .map(Gate::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { initial, gates })
+    }
+}
+
+impl FromStr for Gate {
+    type Err = ParseCircuitError;
+
+    /// Parses a gate such as `x00 AND y00 -> z00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let mut next = || parts.next().ok_or(ParseCircuitError("incomplete gate"));
+        let a = next()?;
+        let operation = match next()? {
+            "AND" => Operation::And,
+            "OR" => Operation::Or,
+            "XOR" => Operation::Xor,
+            _ => return Err(ParseCircuitError("invalid operation")),
+        };
+        let b = next()?;
+        if next()? != "->" {
+            return Err(ParseCircuitError("missing arrow"));
+        }
+        let output = next()?;
+
+        Ok(Self {
+            inputs: [a.to_string(), b.to_string()],
+            operation,
+            output: output.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseCircuitError(&'static str);
+
+impl Display for ParseCircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse circuit: {}", self.0)
+    }
+}
+
+impl Error for ParseCircuitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "
+        x00: 1
+        x01: 1
+        x02: 1
+        y00: 0
+        y01: 1
+        y02: 0
+
+        x00 AND y00 -> z00
+        x01 XOR y01 -> z01
+        x02 OR y02 -> z02
+    ";
+
+    const INPUT: &str = "
+        x00: 1
+        x01: 0
+        x02: 1
+        x03: 1
+        x04: 0
+        y00: 1
+        y01: 1
+        y02: 1
+        y03: 1
+        y04: 1
+
+        ntg XOR fgs -> mjb
+        y02 OR x01 -> tnw
+        kwq OR kpj -> z05
+        x00 OR x03 -> fst
+        tgd XOR rvg -> z01
+        vdt OR tnw -> bfw
+        bfw AND frj -> z10
+        ffh OR nrd -> bqk
+        y00 AND y03 -> djm
+        y03 OR y00 -> psh
+        bqk OR frj -> z08
+        tnw OR fst -> frj
+        gnj AND tgd -> z11
+        bfw XOR mjb -> z00
+        x03 OR x00 -> vdt
+        gnj AND wpb -> z02
+        x04 AND y00 -> kjc
+        djm OR pbm -> qhw
+        nrd AND vdt -> hwm
+        kjc AND fst -> rvg
+        y04 OR y02 -> fgs
+        y01 AND x02 -> pbm
+        ntg OR kjc -> kwq
+        psh XOR fgs -> tgd
+        qhw XOR tgd -> z09
+        pbm OR djm -> kpj
+        x03 XOR y03 -> ffh
+        x00 XOR y04 -> ntg
+        bfw OR bqk -> z06
+        nrd XOR fgs -> wpb
+        frj XOR qhw -> z04
+        bqk OR frj -> z07
+        y03 OR x01 -> nrd
+        hwm AND bqk -> z03
+        tgd XOR rvg -> z12
+        tnw OR pbm -> gnj
+    ";
+
+    /// Builds a ripple-carry adder for numbers with the specified number of bits.
+    fn adder(bits: usize, x: u64, y: u64) -> Circuit {
+        let mut initial = HashMap::new();
+        for i in 0..bits {
+            initial.insert(format!("x{i:02}"), x & (1 << i) != 0);
+            initial.insert(format!("y{i:02}"), y & (1 << i) != 0);
+        }
+
+        let gate = |a: &str, operation, b: &str, output: String| Gate {
+            inputs: [a.to_string(), b.to_string()],
+            operation,
+            output,
+        };
+
+        let mut gates = vec![
+            gate("x00", Operation::Xor, "y00", "z00".to_string()),
+            gate("x00", Operation::And, "y00", "c00".to_string()),
+        ];
+        for i in 1..bits {
+            let (x, y) = (format!("x{i:02}"), format!("y{i:02}"));
+            let (sum, carry) = (format!("s{i:02}"), format!("c{:02}", i - 1));
+            let (a, b) = (format!("a{i:02}"), format!("b{i:02}"));
+            let output = if i + 1 == bits {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.extend([
+                gate(&x, Operation::Xor, &y, sum.clone()),
+                gate(&x, Operation::And, &y, a.clone()),
+                gate(&sum, Operation::Xor, &carry, format!("z{i:02}")),
+                gate(&sum, Operation::And, &carry, b.clone()),
+                gate(&a, Operation::Or, &b, output),
+            ]);
+        }
+
+        Circuit { initial, gates }
+    }
+
+    fn swap(circuit: &mut Circuit, a: &str, b: &str) {
+        for gate in &mut circuit.gates {
+            if gate.output == a {
+                gate.output = b.to_string();
+            } else if gate.output == b {
+                gate.output = a.to_string();
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(SMALL), 4);
+        assert_eq!(first_part(INPUT), 2024);
+    }
+
+    #[test]
+    fn test_adder() {
+        let circuit = adder(8, 173, 94);
+        assert_eq!(number(&circuit.evaluate(), 'z'), 173 + 94);
+        assert!(circuit.swapped_wires().is_empty());
+    }
+
+    #[test]
+    fn test_swapped_wires() {
+        let mut circuit = adder(8, 173, 94);
+        swap(&mut circuit, "z01", "b01");
+        swap(&mut circuit, "z03", "a03");
+        swap(&mut circuit, "s05", "a05");
+        swap(&mut circuit, "c06", "z06");
+        assert_eq!(
+            circuit.swapped_wires().into_iter().collect::<Vec<_>>(),
+            ["a03", "a05", "b01", "c06", "s05", "z01", "z03", "z06"]
+        );
+    }
+
+    #[test]
+    fn test_second_part_not_an_adder() {
+        assert_eq!(second_part(INPUT), None);
+    }
+}
@@ -0,0 +1,3 @@
+aoc_utils::aoc_main!(aoc_2024_day_25;
+    "{} unique lock/key pairs fit together" => first_part,
+);
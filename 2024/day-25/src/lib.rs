@@ -0,0 +1,206 @@
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 25,
+    title: "Code Chronicle",
+    example_answers: [Some("3"), None],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let schematics = parse_schematics(input).ok()?;
+    let locks = schematics
+        .iter()
+        .filter(|schematic| schematic.kind == Kind::Lock)
+        .count();
+    Some(format!("{locks} locks, {} keys", schematics.len() - locks))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let schematics = parse_schematics(input).expect("invalid input");
+    let (locks, keys): (Vec<&Schematic>, Vec<_>) = schematics
+        .iter()
+        .partition(|schematic| schematic.kind == Kind::Lock);
+
+    locks
+        .iter()
+        .flat_map(|lock| keys.iter().filter(move |key| lock.fits(key)))
+        .count()
+}
+
+/// The number of pin columns.
+const COLUMNS: usize = 5;
+
+/// The number of rows of a schematic.
+const ROWS: usize = 7;
+
+/// The available space per column between the filled rows of a lock and a key.
+const SPACE: u8 = ROWS as u8 - 2;
+
+/// Whether a schematic describes a lock or a key.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Kind {
+    /// The top row is filled and the pins extend downward.
+    Lock,
+    /// The bottom row is filled and the shape extends upward.
+    Key,
+}
+
+/// A lock or key schematic, converted to column heights.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Schematic {
+    pub kind: Kind,
+    /// The heights of the columns, not counting the filled row.
+    pub heights: [u8; COLUMNS],
+}
+
+impl Schematic {
+    /// Returns `true` if the schematics overlap in no column.
+    pub fn fits(&self, other: &Schematic) -> bool {
+        self.kind != other.kind
+            && self
+                .heights
+                .iter()
+                .zip(other.heights)
+                .all(|(&a, b)| a + b <= SPACE)
+    }
+}
+
+/// Parses the schematics, separated by empty lines.
+pub fn parse_schematics(input: &str) -> Result<Vec<Schematic>, ParseSchematicError> {
+    let _span = trace::parse();
+    let lines: Vec<_> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    lines
+        .chunks(ROWS)
+        .map(|chunk| Schematic::from_str(&chunk.join("\n")))
+        .collect()
+}
+
+impl FromStr for Schematic {
+    type Err = ParseSchematicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<_> = s.lines().map(str::trim).collect();
+        if rows.len() != ROWS {
+            return Err(ParseSchematicError("invalid number of rows"));
+        }
+        if rows.iter().any(|row| row.len() != COLUMNS) {
+            return Err(ParseSchematicError("invalid number of columns"));
+        }
+
+        let kind = match (rows[0], rows[ROWS - 1]) {
+            ("#####", ".....") => Kind::Lock,
+            (".....", "#####") => Kind::Key,
+            _ => return Err(ParseSchematicError("neither a lock nor a key")),
+        };
+
+        let mut heights = [0; COLUMNS];
+        for row in &rows[1..ROWS - 1] {
+            for (height, cell) in heights.iter_mut().zip(row.chars()) {
+                match cell {
+                    '#' => *height += 1,
+                    '.' => {}
+                    _ => return Err(ParseSchematicError("invalid character")),
+                }
+            }
+        }
+
+        Ok(Self { kind, heights })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseSchematicError(&'static str);
+
+impl Display for ParseSchematicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse schematic: {}", self.0)
+    }
+}
+
+impl Error for ParseSchematicError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        #####
+        .####
+        .####
+        .####
+        .#.#.
+        .#...
+        .....
+
+        #####
+        ##.##
+        .#.##
+        ...##
+        ...#.
+        ...#.
+        .....
+
+        .....
+        #....
+        #....
+        #...#
+        #.#.#
+        #.###
+        #####
+
+        .....
+        .....
+        #.#..
+        ###..
+        ###.#
+        ###.#
+        #####
+
+        .....
+        .....
+        .....
+        #....
+        #.#..
+        #.#.#
+        #####
+    ";
+
+    #[test]
+    fn test_parse_schematics() {
+        let schematics = parse_schematics(INPUT).expect("failed to parse schematics");
+        let heights: Vec<_> = schematics
+            .iter()
+            .map(|schematic| (schematic.kind, schematic.heights))
+            .collect();
+        assert_eq!(
+            heights,
+            [
+                (Kind::Lock, [0, 5, 3, 4, 3]),
+                (Kind::Lock, [1, 2, 0, 5, 3]),
+                (Kind::Key, [5, 0, 2, 1, 3]),
+                (Kind::Key, [4, 3, 4, 0, 2]),
+                (Kind::Key, [3, 0, 2, 0, 1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 3);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_5;
+    "The sum of middle pages of correctly-ordered updates is {}" => first_part,
+    "The sum of middle pages of reordered updates is {}" => second_part,
+);
@@ -0,0 +1,207 @@
+use aoc_utils::trace;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 5,
+    title: "Print Queue",
+    example_answers: [Some("143"), Some("123")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let (rules, updates) = parse_input(input).ok()?;
+    let ordered = updates
+        .iter()
+        .filter(|update| is_ordered(update, &rules))
+        .count();
+    Some(format!(
+        "{} updates, {ordered} of them ordered",
+        updates.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> u32 {
+    let (rules, updates) = parse_input(input).expect("invalid input");
+    updates
+        .iter()
+        .filter(|update| is_ordered(update, &rules))
+        .map(|update| middle_page(update))
+        .sum()
+}
+
+pub fn second_part(input: &str) -> u32 {
+    let (rules, updates) = parse_input(input).expect("invalid input");
+    updates
+        .iter()
+        .filter(|update| !is_ordered(update, &rules))
+        .map(|update| middle_page(&reorder(update, &rules)))
+        .sum()
+}
+
+/// The page ordering rules; a rule `a|b` requires page `a` to be printed before page `b`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Rules {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
+    before: HashSet<(u32, u32)>,
+}
+
+/// Serializes the rules as sorted `[a, b]` pairs, since the set has no order of its own.
+#[cfg(feature = "serde")]
+fn serialize_sorted<S>(rules: &HashSet<(u32, u32)>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let mut rules: Vec<_> = rules.iter().collect();
+    rules.sort_unstable();
+    serializer.collect_seq(rules)
+}
+
+impl Rules {
+    /// Adds the rule that `first` must be printed before `second`.
+    pub fn insert(&mut self, first: u32, second: u32) {
+        self.before.insert((first, second));
+    }
+
+    /// Compares two pages according to the rules.
+    ///
+    /// Pages without a rule between them compare as equal.
+    pub fn compare(&self, a: u32, b: u32) -> Ordering {
+        if self.before.contains(&(a, b)) {
+            Ordering::Less
+        } else if self.before.contains(&(b, a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// Determines whether the pages of an update are in the right order.
+///
+/// The puzzle input defines a rule for every pair of pages within an update,
+/// so it suffices to check neighboring pages.
+pub fn is_ordered(update: &[u32], rules: &Rules) -> bool {
+    update
+        .windows(2)
+        .all(|pair| rules.compare(pair[0], pair[1]) != Ordering::Greater)
+}
+
+/// Returns the pages of an update sorted according to the rules.
+pub fn reorder(update: &[u32], rules: &Rules) -> Vec<u32> {
+    let mut pages = update.to_vec();
+    pages.sort_by(|&a, &b| rules.compare(a, b));
+    pages
+}
+
+fn middle_page(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+/// Parses the ordering rules and the updates, separated by an empty line.
+pub fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<u32>>), ParseInputError> {
+    let _span = trace::parse();
+    let mut rules = Rules::default();
+    let mut updates = Vec::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some((first, second)) = line.split_once('|') {
+            rules.insert(parse_page(first)?, parse_page(second)?);
+        } else {
+            let update = line.split(',').map(parse_page).collect::<Result<_, _>>()?;
+            updates.push(update);
+        }
+    }
+
+    Ok((rules, updates))
+}
+
+fn parse_page(value: &str) -> Result<u32, ParseInputError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ParseInputError("invalid page number"))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseInputError(&'static str);
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse input: {}", self.0)
+    }
+}
+
+impl Error for ParseInputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        47|53
+        97|13
+        97|61
+        97|47
+        75|29
+        61|13
+        75|53
+        29|13
+        97|29
+        53|29
+        61|53
+        97|53
+        61|29
+        47|13
+        75|47
+        97|75
+        47|61
+        75|61
+        47|29
+        75|13
+        53|13
+
+        75,47,61,53,29
+        97,61,53,29,13
+        75,29,13
+        75,97,47,61,53
+        61,13,29
+        97,13,75,29,47
+    ";
+
+    #[test]
+    fn test_is_ordered() {
+        let (rules, updates) = parse_input(INPUT).expect("failed to parse input");
+        let ordered: Vec<_> = updates
+            .iter()
+            .map(|update| is_ordered(update, &rules))
+            .collect();
+        assert_eq!(ordered, [true, true, true, false, false, false]);
+    }
+
+    #[test]
+    fn test_reorder() {
+        let (rules, _) = parse_input(INPUT).expect("failed to parse input");
+        assert_eq!(reorder(&[75, 97, 47, 61, 53], &rules), [97, 75, 47, 61, 53]);
+        assert_eq!(reorder(&[61, 13, 29], &rules), [61, 29, 13]);
+        assert_eq!(reorder(&[97, 13, 75, 29, 47], &rules), [97, 75, 47, 29, 13]);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 143);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 123);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_6;
+    "The guard visits {} distinct positions" => first_part,
+    "There are {} positions for an obstruction that cause a loop" => second_part,
+);
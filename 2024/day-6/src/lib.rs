@@ -0,0 +1,216 @@
+use aoc_utils::{trace, Direction, Grid, ParseGridError, Point};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 6,
+    title: "Guard Gallivant",
+    example_answers: [Some("41"), Some("6")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let lab = Lab::parse(input).ok()?;
+    let obstructions = lab
+        .obstructions
+        .iter()
+        .filter(|(_, &blocked)| blocked)
+        .count();
+    Some(format!(
+        "{}×{} lab with {obstructions} obstructions, the guard starting at {}",
+        lab.obstructions.width(),
+        lab.obstructions.height(),
+        lab.start
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let lab = Lab::parse(input).expect("invalid input");
+    lab.patrol().len()
+}
+
+pub fn second_part(input: &str) -> usize {
+    let lab = Lab::parse(input).expect("invalid input");
+    lab.loop_obstructions().len()
+}
+
+/// The lab map with the guard's starting position.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Lab {
+    /// `true` for cells containing an obstruction.
+    pub obstructions: Grid<bool>,
+    /// The guard's starting position; they initially face up.
+    pub start: Point,
+}
+
+/// The outcome of a patrol.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Patrol {
+    /// The guard left the map after visiting the contained positions.
+    Exits(HashSet<Point>),
+    /// The guard ends up walking in a loop.
+    Loops,
+}
+
+impl Lab {
+    /// Parses the map, where `#` is an obstruction and `^` is the guard.
+    pub fn parse(input: &str) -> Result<Self, ParseLabError> {
+        let _span = trace::parse();
+        let cells = Grid::parse(input, |c| match c {
+            '.' | '#' | '^' => Some(c),
+            _ => None,
+        })
+        .map_err(ParseLabError::InvalidGrid)?;
+        let start = cells
+            .find(|&c| c == '^')
+            .ok_or(ParseLabError::MissingGuard)?;
+
+        Ok(Self {
+            obstructions: cells.map(|&c| c == '#'),
+            start,
+        })
+    }
+
+    /// Gets the distinct positions the guard visits before leaving the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guard walks in a loop.
+    pub fn patrol(&self) -> HashSet<Point> {
+        match self.walk(None) {
+            Patrol::Exits(visited) => visited,
+            Patrol::Loops => panic!("the guard walks in a loop"),
+        }
+    }
+
+    /// Simulates the guard's walk, optionally with an additional obstruction.
+    ///
+    /// The guard walks straight ahead and turns right whenever they face an obstruction.
+    /// A loop is detected by the guard hitting the same obstruction from the same direction
+    /// twice, which only requires tracking turns rather than every step.
+    pub fn walk(&self, extra: Option<Point>) -> Patrol {
+        let blocked = |point: Point| {
+            extra == Some(point) || self.obstructions.get(point).copied().unwrap_or(false)
+        };
+
+        let mut position = self.start;
+        let mut direction = Direction::Up;
+        let mut visited = HashSet::from([position]);
+        let mut turns = HashSet::new();
+
+        loop {
+            let next = position + direction.offset();
+            if !self.obstructions.contains(next) {
+                return Patrol::Exits(visited);
+            }
+
+            if blocked(next) {
+                if !turns.insert((position, direction)) {
+                    return Patrol::Loops;
+                }
+                direction = direction.turn_right();
+            } else {
+                position = next;
+                visited.insert(position);
+            }
+        }
+    }
+
+    /// Gets the positions where a single new obstruction makes the guard walk in a loop.
+    ///
+    /// Only positions on the guard's original path can affect it; the starting position
+    /// is excluded since the guard would notice.
+    pub fn loop_obstructions(&self) -> HashSet<Point> {
+        let candidates = self
+            .patrol()
+            .into_iter()
+            .filter(|&point| point != self.start);
+        self.filter_loops(candidates)
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    fn filter_loops(&self, candidates: impl Iterator<Item = Point>) -> HashSet<Point> {
+        candidates
+            .filter(|&point| self.walk(Some(point)) == Patrol::Loops)
+            .collect()
+    }
+
+    #[cfg(feature = "rayon")]
+    fn filter_loops(&self, candidates: impl Iterator<Item = Point>) -> HashSet<Point> {
+        use rayon::prelude::*;
+
+        let candidates: Vec<_> = candidates.collect();
+        candidates
+            .into_par_iter()
+            .filter(|&point| self.walk(Some(point)) == Patrol::Loops)
+            .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseLabError {
+    InvalidGrid(ParseGridError),
+    MissingGuard,
+}
+
+impl Display for ParseLabError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseLabError::InvalidGrid(e) => write!(f, "Failed to parse lab: {e}"),
+            ParseLabError::MissingGuard => write!(f, "Failed to parse lab: missing guard"),
+        }
+    }
+}
+
+impl Error for ParseLabError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ....#.....
+        .........#
+        ..........
+        ..#.......
+        .......#..
+        ..........
+        .#..^.....
+        ........#.
+        #.........
+        ......#...
+    ";
+
+    #[test]
+    fn test_parse() {
+        let lab = Lab::parse(INPUT).expect("failed to parse lab");
+        assert_eq!(lab.start, Point::new(4, 6));
+        assert_eq!(lab.obstructions.iter().filter(|(_, &o)| o).count(), 8);
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 41);
+    }
+
+    #[test]
+    fn test_loop_obstructions() {
+        let lab = Lab::parse(INPUT).expect("failed to parse lab");
+        let obstructions = lab.loop_obstructions();
+        assert_eq!(obstructions.len(), 6);
+        assert!(obstructions.contains(&Point::new(3, 6)));
+        assert!(obstructions.contains(&Point::new(7, 9)));
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 6);
+    }
+}
@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_7;
+    "The total calibration result is {}" => first_part,
+    "The total calibration result with concatenation is {}" => second_part,
+);
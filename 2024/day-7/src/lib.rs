@@ -0,0 +1,213 @@
+use aoc_utils::num::digit_count;
+use aoc_utils::trace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 7,
+    title: "Bridge Repair",
+    example_answers: [Some("3749"), Some("11387")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let equations = parse_equations(input).ok()?;
+    let operands = equations.iter().map(|e| e.operands.len()).max()?;
+    Some(format!(
+        "{} equations with up to {operands} operands",
+        equations.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    total_calibration_result(input, &[Operator::Add, Operator::Multiply])
+}
+
+pub fn second_part(input: &str) -> u64 {
+    total_calibration_result(
+        input,
+        &[Operator::Add, Operator::Multiply, Operator::Concatenate],
+    )
+}
+
+fn total_calibration_result(input: &str, ops: &[Operator]) -> u64 {
+    parse_equations(input)
+        .expect("invalid input")
+        .iter()
+        .filter(|equation| is_solvable(equation.target, &equation.operands, ops))
+        .map(|equation| equation.target)
+        .sum()
+}
+
+/// An operator that can be placed between two operands.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Operator {
+    /// `a + b`
+    Add,
+    /// `a * b`
+    Multiply,
+    /// `a || b`, joining the digits of both numbers.
+    Concatenate,
+}
+
+/// A calibration equation with its operators missing.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Equation {
+    pub target: u64,
+    pub operands: Vec<u64>,
+}
+
+/// Determines whether the operands can be combined into the target using the operators,
+/// evaluated left-to-right.
+///
+/// The search runs backwards from the target: the last operand is undone with every
+/// operator that could have produced the target, which prunes most branches early.
+pub fn is_solvable(target: u64, operands: &[u64], ops: &[Operator]) -> bool {
+    let Some((&last, rest)) = operands.split_last() else {
+        return false;
+    };
+
+    if rest.is_empty() {
+        return target == last;
+    }
+
+    ops.iter()
+        .filter_map(|op| op.undo(target, last))
+        .any(|previous| is_solvable(previous, rest, ops))
+}
+
+impl Operator {
+    /// Applies the operator.
+    pub fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+            Operator::Concatenate => a * digit_factor(b) + b,
+        }
+    }
+
+    /// Determines the left operand `a` such that `a op b == result`, if there is one.
+    pub fn undo(&self, result: u64, b: u64) -> Option<u64> {
+        match self {
+            Operator::Add => result.checked_sub(b),
+            Operator::Multiply => (b != 0 && result.is_multiple_of(b)).then(|| result / b),
+            Operator::Concatenate => {
+                let factor = digit_factor(b);
+                (result % factor == b).then(|| result / factor)
+            }
+        }
+    }
+}
+
+/// Gets the power of ten needed to shift a number left by the digits of `value`.
+fn digit_factor(value: u64) -> u64 {
+    10u64.pow(digit_count(value))
+}
+
+/// Parses one equation per line.
+pub fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseEquationError> {
+    let _span = trace::parse();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(Equation::from_str)
+        .collect()
+}
+
+impl FromStr for Equation {
+    type Err = ParseEquationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, operands) = s
+            .split_once(':')
+            .ok_or(ParseEquationError("missing colon"))?;
+        let target = target
+            .trim()
+            .parse()
+            .map_err(|_| ParseEquationError("invalid test value"))?;
+        let operands = aoc_utils::parse_whitespace_delimited(operands)
+            .map_err(|_| ParseEquationError("invalid operand"))?;
+        Ok(Self { target, operands })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseEquationError(&'static str);
+
+impl Display for ParseEquationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse equation: {}", self.0)
+    }
+}
+
+impl Error for ParseEquationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        190: 10 19
+        3267: 81 40 27
+        83: 17 5
+        156: 15 6
+        7290: 6 8 6 15
+        161011: 16 10 13
+        192: 17 8 14
+        21037: 9 7 18 13
+        292: 11 6 16 20
+    ";
+
+    #[test]
+    fn test_concatenate() {
+        assert_eq!(Operator::Concatenate.apply(12, 345), 12345);
+        assert_eq!(Operator::Concatenate.apply(1, 10), 110);
+        assert_eq!(Operator::Concatenate.undo(12345, 345), Some(12));
+        assert_eq!(Operator::Concatenate.undo(12345, 45), Some(123));
+        assert_eq!(Operator::Concatenate.undo(12345, 44), None);
+    }
+
+    #[test]
+    fn test_is_solvable() {
+        let ops = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+        assert!(is_solvable(7290, &[6, 8, 6, 15], &ops));
+        assert!(!is_solvable(7290, &[6, 8, 6, 15], &ops[..2]));
+        assert!(!is_solvable(83, &[17, 5], &ops));
+    }
+
+    #[test]
+    fn test_is_solvable_matches_brute_force() {
+        use aoc_utils::combinatorics::cartesian_power;
+
+        let ops = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+        for equation in parse_equations(INPUT).expect("invalid input") {
+            let (first, rest) = equation.operands.split_first().expect("no operands");
+            let brute_force = cartesian_power(&ops, rest.len()).any(|chosen| {
+                let result = chosen.iter().zip(rest);
+                result.fold(*first, |acc, (op, &operand)| op.apply(acc, operand)) == equation.target
+            });
+            assert_eq!(
+                is_solvable(equation.target, &equation.operands, &ops),
+                brute_force
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 3749);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 11387);
+    }
+}
@@ -0,0 +1,5 @@
+aoc_utils::aoc_main!(aoc_2024_day_8;
+    "There are {} unique locations containing an antinode" => first_part,
+    "Accounting for resonant harmonics, there are {} unique locations containing an antinode"
+        => second_part,
+);
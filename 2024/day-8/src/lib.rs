@@ -0,0 +1,166 @@
+use aoc_utils::{gcd, trace, ParseGridError, Point, SparseGrid};
+use std::collections::{HashMap, HashSet};
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 8,
+    title: "Resonant Collinearity",
+    example_answers: [Some("14"), Some("34")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let roof = Roof::parse(input).ok()?;
+    Some(format!(
+        "{} antennas of {} frequencies on a {}×{} roof",
+        roof.antennas.len(),
+        roof.frequencies().len(),
+        roof.antennas.width(),
+        roof.antennas.height()
+    ))
+}
+
+pub fn first_part(input: &str) -> usize {
+    let roof = Roof::parse(input).expect("invalid input");
+    roof.antinodes(false).len()
+}
+
+pub fn second_part(input: &str) -> usize {
+    let roof = Roof::parse(input).expect("invalid input");
+    roof.antinodes(true).len()
+}
+
+/// The antennas on the roof, keyed by their position.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Roof {
+    pub antennas: SparseGrid<char>,
+}
+
+impl Roof {
+    /// Parses the map, where every letter or digit is an antenna of that frequency.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        let _span = trace::parse();
+        let antennas = SparseGrid::parse(input, '.', |c| c.is_ascii_alphanumeric().then_some(c))?;
+        Ok(Self { antennas })
+    }
+
+    /// Groups the antenna positions by frequency.
+    pub fn frequencies(&self) -> HashMap<char, Vec<Point>> {
+        let mut frequencies: HashMap<char, Vec<Point>> = HashMap::new();
+        for (point, &frequency) in self.antennas.iter() {
+            frequencies.entry(frequency).or_default().push(point);
+        }
+        frequencies
+    }
+
+    /// Gets the distinct antinode locations within the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `harmonics` - If `false`, each pair of antennas creates one antinode on either side,
+    ///   as far away from the nearer antenna as the antennas are apart. If `true`, every
+    ///   position in line with the pair is an antinode, including the antennas themselves.
+    pub fn antinodes(&self, harmonics: bool) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+        for antennas in self.frequencies().values() {
+            for (i, &a) in antennas.iter().enumerate() {
+                for &b in &antennas[i + 1..] {
+                    if harmonics {
+                        antinodes.extend(self.line_through(a, b));
+                    } else {
+                        let delta = b - a;
+                        antinodes.extend(
+                            [a - delta, b + delta]
+                                .into_iter()
+                                .filter(|&point| self.antennas.contains(point)),
+                        );
+                    }
+                }
+            }
+        }
+        antinodes
+    }
+
+    /// Gets all grid positions on the line through two points.
+    fn line_through(&self, a: Point, b: Point) -> impl Iterator<Item = Point> + '_ {
+        let delta = b - a;
+        let divisor = gcd(
+            delta.x.unsigned_abs() as usize,
+            delta.y.unsigned_abs() as usize,
+        ) as i64;
+        let step = Point::new(delta.x / divisor, delta.y / divisor);
+
+        let forward = (0..).map(move |k| a + step * k);
+        let backward = (1..).map(move |k| a - step * k);
+        forward
+            .take_while(|&point| self.antennas.contains(point))
+            .chain(backward.take_while(|&point| self.antennas.contains(point)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ............
+        ........0...
+        .....0......
+        .......0....
+        ....0.......
+        ......A.....
+        ............
+        ............
+        ........A...
+        .........A..
+        ............
+        ............
+    ";
+
+    #[test]
+    fn test_frequencies() {
+        let roof = Roof::parse(INPUT).expect("failed to parse roof");
+        let frequencies = roof.frequencies();
+        assert_eq!(frequencies.len(), 2);
+        assert_eq!(frequencies[&'0'].len(), 4);
+        assert_eq!(frequencies[&'A'].len(), 3);
+    }
+
+    #[test]
+    fn test_antinodes_of_pair() {
+        let roof = Roof::parse(
+            "
+            ..........
+            ..........
+            ..........
+            ....a.....
+            ..........
+            .....a....
+            ..........
+            ..........
+            ..........
+            ..........
+            ",
+        )
+        .expect("failed to parse roof");
+        assert_eq!(
+            roof.antinodes(false),
+            HashSet::from([Point::new(3, 1), Point::new(6, 7)])
+        );
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 14);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 34);
+    }
+}
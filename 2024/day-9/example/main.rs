@@ -0,0 +1,4 @@
+aoc_utils::aoc_main!(aoc_2024_day_9;
+    "The filesystem checksum after moving blocks is {}" => first_part,
+    "The filesystem checksum after moving files is {}" => second_part,
+);
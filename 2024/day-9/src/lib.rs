@@ -0,0 +1,266 @@
+use aoc_utils::trace;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle solved by this crate.
+pub const PUZZLE: aoc_utils::PuzzleMeta = aoc_utils::PuzzleMeta {
+    year: 2024,
+    day: 9,
+    title: "Disk Fragmenter",
+    example_answers: [Some("1928"), Some("2858")],
+    real_answers: None,
+};
+
+/// Describes what the solution sees in the input, to give context to a wrong answer.
+///
+/// Returns `None` if the input is invalid.
+pub fn debug_info(input: &str) -> Option<String> {
+    let disk: DiskMap = input.parse().ok()?;
+    Some(format!(
+        "{} files and {} free spans over {} blocks",
+        disk.files.len(),
+        disk.free.len(),
+        disk.len()
+    ))
+}
+
+pub fn first_part(input: &str) -> u64 {
+    let disk: DiskMap = input.parse().expect("invalid input");
+    let mut blocks = disk.blocks();
+    compact_blocks(&mut blocks);
+    block_checksum(&blocks)
+}
+
+pub fn second_part(input: &str) -> u64 {
+    let disk: DiskMap = input.parse().expect("invalid input");
+    checksum(&disk.compact_files())
+}
+
+/// The largest span length a single disk map digit can describe.
+const MAX_SPAN: usize = 9;
+
+/// A contiguous run of blocks on the disk.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+}
+
+/// A file occupying a contiguous span of blocks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct File {
+    pub id: usize,
+    pub span: Span,
+}
+
+/// The disk map, with the files and the free spaces between them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiskMap {
+    /// The files, ordered by their ID (and position).
+    pub files: Vec<File>,
+    /// The non-empty free spans, ordered by position.
+    pub free: Vec<Span>,
+}
+
+impl DiskMap {
+    /// Gets the total number of blocks on the disk.
+    pub fn len(&self) -> usize {
+        let end = |span: &Span| span.start + span.len;
+        let files = self.files.last().map(|file| end(&file.span));
+        let free = self.free.last().map(end);
+        files.max(free).unwrap_or_default()
+    }
+
+    /// Determines whether the disk has no blocks at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Expands the disk map into individual blocks, holding the file ID or `None` if free.
+    pub fn blocks(&self) -> Vec<Option<usize>> {
+        let mut blocks = vec![None; self.len()];
+        for file in &self.files {
+            blocks[file.span.start..][..file.span.len].fill(Some(file.id));
+        }
+        blocks
+    }
+
+    /// Moves whole files, in order of decreasing ID, to the leftmost free span that fits them.
+    ///
+    /// Free spans are indexed by length, each in a min-heap keyed by position, so finding the
+    /// leftmost fitting span only needs to look at the top of each heap of sufficient length.
+    pub fn compact_files(&self) -> Vec<File> {
+        let mut free: [BinaryHeap<Reverse<usize>>; MAX_SPAN + 1] = Default::default();
+        for span in &self.free {
+            free[span.len.min(MAX_SPAN)].push(Reverse(span.start));
+        }
+
+        let mut files = self.files.clone();
+        for file in files.iter_mut().rev() {
+            let candidate = (file.span.len..=MAX_SPAN)
+                .filter_map(|len| free[len].peek().map(|&Reverse(start)| (start, len)))
+                .filter(|&(start, _)| start < file.span.start)
+                .min();
+
+            let Some((start, len)) = candidate else {
+                continue;
+            };
+
+            free[len].pop();
+            let remaining = len - file.span.len;
+            if remaining > 0 {
+                free[remaining].push(Reverse(start + file.span.len));
+            }
+
+            // The space freed by the file lies to the right of all files yet to be moved,
+            // so it never needs to be tracked.
+            file.span.start = start;
+        }
+
+        files
+    }
+}
+
+/// Moves individual file blocks from the end of the disk into the leftmost free block
+/// until there are no gaps between file blocks.
+pub fn compact_blocks(blocks: &mut [Option<usize>]) {
+    if blocks.is_empty() {
+        return;
+    }
+
+    let (mut left, mut right) = (0, blocks.len() - 1);
+    while left < right {
+        if blocks[left].is_some() {
+            left += 1;
+        } else if blocks[right].is_none() {
+            right -= 1;
+        } else {
+            blocks.swap(left, right);
+        }
+    }
+}
+
+/// Calculates the filesystem checksum of individual blocks.
+pub fn block_checksum(blocks: &[Option<usize>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(position, id)| id.map(|id| (position * id) as u64))
+        .sum()
+}
+
+/// Calculates the filesystem checksum of whole files.
+pub fn checksum(files: &[File]) -> u64 {
+    files.iter().map(File::checksum).sum()
+}
+
+impl File {
+    /// Gets the sum of the file's block positions multiplied by its ID.
+    pub fn checksum(&self) -> u64 {
+        let Span { start, len } = self.span;
+        // The sum of positions start..start + len.
+        let positions = len * start + len * len.saturating_sub(1) / 2;
+        (positions * self.id) as u64
+    }
+}
+
+impl FromStr for DiskMap {
+    type Err = ParseDiskMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _span = trace::parse();
+        let mut files = Vec::new();
+        let mut free = Vec::new();
+        let mut start = 0;
+
+        for (index, c) in s.trim().chars().enumerate() {
+            let len = c.to_digit(10).ok_or(ParseDiskMapError("invalid digit"))? as usize;
+            let span = Span { start, len };
+            if index % 2 == 0 {
+                files.push(File {
+                    id: files.len(),
+                    span,
+                });
+            } else if len > 0 {
+                free.push(span);
+            }
+            start += len;
+        }
+
+        Ok(Self { files, free })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseDiskMapError(&'static str);
+
+impl Display for ParseDiskMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse disk map: {}", self.0)
+    }
+}
+
+impl Error for ParseDiskMapError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2333133121414131402";
+
+    /// Renders blocks like the puzzle description, e.g. `0..111....22222`.
+    fn render(blocks: &[Option<usize>]) -> String {
+        blocks
+            .iter()
+            .map(|block| match block {
+                Some(id) => char::from_digit(*id as u32, 10).expect("single-digit ID"),
+                None => '.',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_blocks() {
+        let disk: DiskMap = "12345".parse().expect("failed to parse disk map");
+        assert_eq!(render(&disk.blocks()), "0..111....22222");
+
+        let mut blocks = disk.blocks();
+        compact_blocks(&mut blocks);
+        assert_eq!(render(&blocks), "022111222......");
+    }
+
+    #[test]
+    fn test_compact_files() {
+        let disk: DiskMap = INPUT.parse().expect("failed to parse disk map");
+        let mut blocks = vec![None; disk.len()];
+        for file in disk.compact_files() {
+            blocks[file.span.start..][..file.span.len].fill(Some(file.id));
+        }
+        assert_eq!(
+            render(&blocks),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+    }
+
+    #[test]
+    fn test_file_checksum() {
+        let disk: DiskMap = INPUT.parse().expect("failed to parse disk map");
+        assert_eq!(checksum(&disk.files), block_checksum(&disk.blocks()));
+    }
+
+    #[test]
+    fn test_first_part() {
+        assert_eq!(first_part(INPUT), 1928);
+    }
+
+    #[test]
+    fn test_second_part() {
+        assert_eq!(second_part(INPUT), 2858);
+    }
+}
@@ -0,0 +1,37 @@
+//! Python bindings for the Advent of Code solutions.
+//!
+//! ```python
+//! import aoc_py
+//!
+//! aoc_py.solve(2024, 1, 1, open("2024/day-1/input.txt").read())
+//! aoc_py.parse(2024, 13, open("2024/day-13/input.txt").read())
+//! ```
+
+use aoc::{Part, SolveError};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Solves part `1` or `2` of a day's puzzle and returns the answer as a string.
+#[pyfunction]
+fn solve(year: u16, day: u8, part: u8, input: &str) -> PyResult<String> {
+    let part = Part::try_from(part).map_err(to_py_err)?;
+    aoc::solve(year, day, part, input).map_err(to_py_err)
+}
+
+/// Parses a day's puzzle input and returns the parsed structure as a JSON string.
+#[pyfunction]
+fn parse(year: u16, day: u8, input: &str) -> PyResult<String> {
+    aoc::parse_json(year, day, input).map_err(to_py_err)
+}
+
+/// Raises the error as a `ValueError`.
+fn to_py_err(error: SolveError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    Ok(())
+}
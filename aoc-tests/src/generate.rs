@@ -0,0 +1,73 @@
+//! Generated inputs with the size and structure of the real ones, for days whose `input.txt`
+//! only holds the example.
+
+use aoc_utils::property::Rng;
+
+/// Generates 8 stones of up to 7 digits, like the 2024 day 11 input.
+pub fn stones(rng: &mut Rng) -> String {
+    let stones: Vec<_> = (0..8)
+        .map(|_| {
+            let digits = rng.range(1..8) as u32;
+            rng.below(10u64.pow(digits)).to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+/// Generates 2000 initial secret numbers, like the 2024 day 22 input.
+pub fn secrets(rng: &mut Rng) -> String {
+    (0..2000)
+        .map(|_| format!("{}\n", rng.range(1..1 << 24)))
+        .collect()
+}
+
+/// Generates a 131×131 garden, like the 2023 day 21 input.
+///
+/// The start lies in the center, and its row and column as well as the border are free of
+/// rocks, which the extrapolation of part 2 relies on.
+pub fn garden(rng: &mut Rng) -> String {
+    const SIZE: usize = 131;
+    const CENTER: usize = SIZE / 2;
+
+    let mut garden = String::with_capacity(SIZE * (SIZE + 1));
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let clear = x == CENTER || y == CENTER || x % (SIZE - 1) == 0 || y % (SIZE - 1) == 0;
+            garden.push(match (x, y) {
+                (CENTER, CENTER) => 'S',
+                _ if !clear && rng.chance(0.15) => '#',
+                _ => '.',
+            });
+        }
+        garden.push('\n');
+    }
+    garden
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_garden() {
+        let garden = garden(&mut Rng::new(21));
+        let lines: Vec<&str> = garden.lines().collect();
+        assert_eq!(lines.len(), 131);
+        assert!(lines.iter().all(|line| line.len() == 131));
+        assert_eq!(garden.matches('S').count(), 1);
+        assert_eq!(lines[65].as_bytes()[65], b'S');
+        assert!(!lines[65].contains('#') && !lines[0].contains('#'));
+        assert!(garden.contains('#'));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let mut rng = Rng::new(11);
+        assert_eq!(stones(&mut rng).split_whitespace().count(), 8);
+        let secrets = secrets(&mut rng);
+        assert_eq!(secrets.lines().count(), 2000);
+        assert!(secrets
+            .lines()
+            .all(|secret| secret.parse::<u32>().is_ok_and(|secret| secret < 1 << 24)));
+    }
+}
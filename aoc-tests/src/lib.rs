@@ -0,0 +1,140 @@
+//! The example inputs of the puzzle descriptions, for checking the solutions against the
+//! example answers documented in their [`PuzzleMeta`](aoc::PuzzleMeta).
+//!
+//! Each day's main example is stored as `fixtures/<year>/day-<day>.txt`. A part with an example
+//! of its own has it stored next to it, as `day-<day>-part-<part>.txt`.
+//!
+//! The timing budgets are checked against the `input.txt` of each day, see [`median_time`], or
+//! against a [generated](generate) input if that only holds the example.
+
+pub mod generate;
+
+use aoc::Part;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Gets the example input of a part, or `None` if there is no fixture for it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::Part;
+/// use aoc_tests::example;
+///
+/// assert_eq!(example(2024, 11, Part::One).as_deref(), Some("125 17\n"));
+/// assert_eq!(example(2024, 11, Part::Two), example(2024, 11, Part::One));
+/// assert_eq!(example(2024, 26, Part::One), None);
+/// ```
+pub fn example(year: u16, day: u8, part: Part) -> Option<String> {
+    let part = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    let directory = fixtures().join(year.to_string());
+    [
+        format!("day-{day}-part-{part}.txt"),
+        format!("day-{day}.txt"),
+    ]
+    .into_iter()
+    .find_map(|name| std::fs::read_to_string(directory.join(name)).ok())
+}
+
+/// Gets the directory containing the fixtures.
+pub fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+/// Gets the `input.txt` of a day, or `None` if it has none.
+pub fn input(year: u16, day: u8) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../{year}/day-{day}/input.txt"));
+    std::fs::read_to_string(path).ok()
+}
+
+/// Calls the function `warmup` times, then measures `runs` calls and returns their median time.
+///
+/// # Panics
+///
+/// Panics if `runs` is zero.
+pub fn median_time<T>(warmup: usize, runs: usize, mut f: impl FnMut() -> T) -> Duration {
+    assert!(runs > 0, "at least one run is needed");
+    for _ in 0..warmup {
+        std::hint::black_box(f());
+    }
+    let mut timings: Vec<_> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    timings.sort_unstable();
+    timings[runs / 2]
+}
+
+/// Describes how an answer differs from the expected one, on separate lines for both.
+///
+/// The part following the common prefix is colored if `color` is set, and marked by a caret
+/// otherwise. Answers that are both integers also get their difference.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_tests::diff;
+///
+/// assert_eq!(
+///     diff("4361", "4371", false),
+///     "expected: 4361\n  actual: 4371\n            ^\ndifference: +10\n"
+/// );
+/// ```
+pub fn diff(expected: &str, actual: &str, color: bool) -> String {
+    const GREEN: &str = "\x1b[32m";
+    const RED: &str = "\x1b[31m";
+    const RESET: &str = "\x1b[0m";
+
+    let common = expected
+        .char_indices()
+        .zip(actual.chars())
+        .find(|&((_, e), a)| e != a)
+        .map_or(expected.len().min(actual.len()), |((i, _), _)| i);
+    let (prefix, expected_rest, actual_rest) =
+        (&expected[..common], &expected[common..], &actual[common..]);
+
+    let mut diff = if color {
+        format!(
+            "expected: {prefix}{GREEN}{expected_rest}{RESET}\n  actual: {prefix}{RED}{actual_rest}{RESET}\n"
+        )
+    } else {
+        let caret = " ".repeat(10 + prefix.chars().count());
+        format!("expected: {expected}\n  actual: {actual}\n{caret}^\n")
+    };
+
+    if let (Ok(expected), Ok(actual)) = (expected.parse::<i128>(), actual.parse::<i128>()) {
+        if let Some(difference) = actual.checked_sub(expected) {
+            diff.push_str(&format!("difference: {difference:+}\n"));
+        }
+    }
+    diff
+}
+
+/// Tests whether output should be colored, which the `NO_COLOR` environment variable turns off.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("4,6,3", "4,6", false),
+            "expected: 4,6,3\n  actual: 4,6\n             ^\n"
+        );
+        assert_eq!(
+            diff("co,de", "co,dx", true),
+            "expected: co,d\x1b[32me\x1b[0m\n  actual: co,d\x1b[31mx\x1b[0m\n"
+        );
+        assert!(diff("10", "7", false).ends_with("difference: -3\n"));
+    }
+}
@@ -0,0 +1,130 @@
+//! Checks that the optimized solutions stay within their timing budgets on real-sized inputs.
+//!
+//! The budgets only hold for optimized builds, so they are checked by
+//! `cargo test -p aoc-tests --release` and skipped otherwise. They are skipped in release builds
+//! as well by setting [`SKIP_VARIABLE`] or by the `--skip` flag of the test harness, e.g. on
+//! machines too slow or busy for reliable timings:
+//!
+//! ```shell
+//! cargo test -p aoc-tests --release -- --skip budgets
+//! ```
+//!
+//! This test has its own runner, which only understands the `--skip` flag of the harness.
+
+use aoc::{solve, Part};
+use aoc_tests::{generate, input, median_time};
+use aoc_utils::property::Rng;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// The name of the test, which the `--skip` flag is matched against.
+const NAME: &str = "budgets";
+
+/// The environment variable that skips the budgets if set.
+const SKIP_VARIABLE: &str = "AOC_SKIP_BUDGETS";
+
+/// The number of unmeasured calls before the measured ones.
+const WARMUP: usize = 2;
+
+/// The number of measured calls, of which the median is compared to the budget.
+const RUNS: usize = 9;
+
+/// The seed of the generated inputs.
+const SEED: u64 = 2023;
+
+/// The input a budget is measured on.
+enum Source {
+    /// The day's `input.txt`.
+    Input,
+    /// A generated input, for days whose `input.txt` only holds the example.
+    Generated(fn(&mut Rng) -> String),
+}
+
+/// The longest median time a part may take in a release build.
+///
+/// The budgets are generous multiples of the actual timings, yet far below those of brute
+/// force, so that only algorithmic regressions exceed them.
+const BUDGETS: &[(u16, u8, Part, Duration, Source)] = &[
+    (
+        2024,
+        11,
+        Part::Two,
+        Duration::from_millis(50),
+        Source::Generated(generate::stones),
+    ),
+    (
+        2024,
+        22,
+        Part::Two,
+        Duration::from_millis(200),
+        Source::Generated(generate::secrets),
+    ),
+    (2023, 5, Part::Two, Duration::from_millis(50), Source::Input),
+    (2023, 6, Part::Two, Duration::from_millis(1), Source::Input),
+    (2023, 8, Part::Two, Duration::from_millis(50), Source::Input),
+    (
+        2023,
+        10,
+        Part::Two,
+        Duration::from_millis(50),
+        Source::Input,
+    ),
+    (
+        2023,
+        11,
+        Part::Two,
+        Duration::from_millis(20),
+        Source::Input,
+    ),
+    (
+        2023,
+        21,
+        Part::Two,
+        Duration::from_millis(300),
+        Source::Generated(generate::garden),
+    ),
+];
+
+fn main() -> ExitCode {
+    if cfg!(debug_assertions) || is_skipped() || std::env::var_os(SKIP_VARIABLE).is_some() {
+        eprintln!(
+            "skipping the timing budgets; run them with --release, without --skip and without {SKIP_VARIABLE}"
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    let mut exceeded = Vec::new();
+    for (year, day, part, budget, source) in BUDGETS {
+        let (year, day, part, budget) = (*year, *day, *part, *budget);
+        let input = match source {
+            Source::Input => {
+                input(year, day).unwrap_or_else(|| panic!("{year} day {day} has no input"))
+            }
+            Source::Generated(generate) => generate(&mut Rng::new(SEED)),
+        };
+        if let Err(e) = solve(year, day, part, &input) {
+            panic!("{year} day {day}, part {part:?} failed: {e}");
+        }
+
+        let median = median_time(WARMUP, RUNS, || solve(year, day, part, &input));
+        println!("{year} day {day}, part {part:?}: {median:.2?} of {budget:.2?}");
+        if median > budget {
+            exceeded.push(format!(
+                "{year} day {day}, part {part:?}: {median:.2?} exceeds {budget:.2?}"
+            ));
+        }
+    }
+
+    if exceeded.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    eprintln!("{}", exceeded.join("\n"));
+    ExitCode::FAILURE
+}
+
+/// Tests whether a `--skip <filter>` argument matches the test, like it does in the harness.
+fn is_skipped() -> bool {
+    let args: Vec<String> = std::env::args().collect();
+    args.windows(2)
+        .any(|pair| pair[0] == "--skip" && NAME.contains(pair[1].as_str()))
+}
@@ -0,0 +1,97 @@
+//! Solves every day's example input with the example's parameters and compares the answers to
+//! the documented ones.
+
+use aoc::{debug_info, parse_json, solve_example, Part, PUZZLES};
+use aoc_tests::{diff, example, fixtures, use_color};
+use std::path::Path;
+
+#[test]
+fn test_example_answers() {
+    let mut failures = Vec::new();
+    for puzzle in PUZZLES {
+        for (part, answer) in [Part::One, Part::Two]
+            .into_iter()
+            .zip(puzzle.example_answers)
+        {
+            let Some(answer) = answer else { continue };
+            let Some(input) = example(puzzle.year, puzzle.day, part) else {
+                failures.push(format!("{puzzle}, part {part:?}: missing fixture"));
+                continue;
+            };
+            match solve_example(puzzle.year, puzzle.day, part, &input) {
+                Ok(actual) if actual == answer => {}
+                Ok(actual) => {
+                    let mut failure = format!("{puzzle}, part {part:?}:\n");
+                    failure.push_str(&diff(answer, &actual, use_color()));
+                    if let Some(info) = debug_info(puzzle.year, puzzle.day, &input) {
+                        failure.push_str(&info);
+                        failure.push('\n');
+                    }
+                    failures.push(failure);
+                }
+                Err(e) => failures.push(format!("{puzzle}, part {part:?}: {e}")),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_debug_info() {
+    for puzzle in PUZZLES {
+        for part in [Part::One, Part::Two] {
+            let Some(input) = example(puzzle.year, puzzle.day, part) else {
+                continue;
+            };
+            assert!(
+                debug_info(puzzle.year, puzzle.day, &input).is_some(),
+                "{puzzle}, part {part:?} has no debug info"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_json() {
+    for puzzle in PUZZLES {
+        for part in [Part::One, Part::Two] {
+            let Some(input) = example(puzzle.year, puzzle.day, part) else {
+                continue;
+            };
+            if let Err(e) = parse_json(puzzle.year, puzzle.day, &input) {
+                panic!("{puzzle}, part {part:?} can't be rendered as JSON: {e}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fixtures_belong_to_puzzles() {
+    let entries = |path: &Path| {
+        std::fs::read_dir(path)
+            .expect("failed to read fixtures")
+            .map(|entry| entry.expect("failed to read fixtures").path())
+            .collect::<Vec<_>>()
+    };
+    for directory in entries(&fixtures()) {
+        for path in entries(&directory) {
+            // Fixtures are named `<year>/day-<day>.txt` or `<year>/day-<day>-part-<part>.txt`.
+            let date = directory
+                .file_name()
+                .and_then(|year| year.to_str()?.parse::<u16>().ok())
+                .zip(path.file_stem().and_then(|name| {
+                    name.to_str()?
+                        .strip_prefix("day-")?
+                        .split('-')
+                        .next()?
+                        .parse::<u8>()
+                        .ok()
+                }));
+            let used = PUZZLES.iter().any(|puzzle| {
+                date == Some((puzzle.year, puzzle.day))
+                    && puzzle.example_answers.iter().any(Option::is_some)
+            });
+            assert!(used, "fixture {} has no example answer", path.display());
+        }
+    }
+}
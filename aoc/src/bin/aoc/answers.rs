@@ -0,0 +1,105 @@
+//! Answers of previous runs, kept in `answers.tsv` next to the cached inputs.
+
+use crate::runner::Run;
+use aoc::Part;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{fs, io};
+
+/// The most recent run of every part, persisted across sessions.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: Option<PathBuf>,
+    runs: HashMap<(u16, u8, Part), Run>,
+}
+
+impl Answers {
+    /// Loads the answers from the cache directory, skipping lines that fail to parse.
+    pub fn load() -> Self {
+        let path = aoc_utils::input::cache_dir()
+            .ok()
+            .map(|dir| dir.join("answers.tsv"));
+        let runs = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|cache| cache.lines().filter_map(parse_line).collect())
+            .unwrap_or_default();
+        Self { path, runs }
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Run> {
+        self.runs.get(&(year, day, part))
+    }
+
+    /// Records a run, replacing the previous one of the part.
+    pub fn insert(&mut self, year: u16, day: u8, part: Part, run: Run) {
+        self.runs.insert((year, day, part), run);
+    }
+
+    /// Writes all answers back to the cache directory.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut keys: Vec<_> = self.runs.keys().collect();
+        keys.sort_by_key(|&&(year, day, part)| (year, day, part == Part::Two));
+
+        let mut cache = String::new();
+        for key in keys {
+            cache.push_str(&format_line(*key, &self.runs[key]));
+            cache.push('\n');
+        }
+        fs::write(path, cache)
+    }
+}
+
+/// Formats a run as `year  day  part  nanoseconds  ok|error  answer`, separated by tabs.
+fn format_line((year, day, part): (u16, u8, Part), run: &Run) -> String {
+    let part = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    let (status, text) = match &run.answer {
+        Ok(answer) => ("ok", answer),
+        Err(error) => ("error", error),
+    };
+    let text = text.replace(['\t', '\n'], " ");
+    let nanos = run.elapsed.as_nanos();
+    format!("{year}\t{day}\t{part}\t{nanos}\t{status}\t{text}")
+}
+
+fn parse_line(line: &str) -> Option<((u16, u8, Part), Run)> {
+    let mut fields = line.splitn(6, '\t');
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::try_from(fields.next()?.parse::<u8>().ok()?).ok()?;
+    let elapsed = Duration::from_nanos(fields.next()?.parse().ok()?);
+    let answer = match (fields.next()?, fields.next()?.to_string()) {
+        ("ok", answer) => Ok(answer),
+        ("error", error) => Err(error),
+        _ => return None,
+    };
+    Some(((year, day, part), Run { answer, elapsed }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_roundtrip() {
+        let run = Run {
+            answer: Ok("co,de,ka,ta".into()),
+            elapsed: Duration::from_micros(1234),
+        };
+        let line = format_line((2024, 23, Part::Two), &run);
+        assert_eq!(line, "2024\t23\t2\t1234000\tok\tco,de,ka,ta");
+        assert_eq!(parse_line(&line), Some(((2024, 23, Part::Two), run)));
+        assert_eq!(parse_line("2024\t23\t3\t0\tok\t1"), None);
+    }
+}
@@ -0,0 +1,271 @@
+//! Timing the solutions across runs and flagging regressions.
+//!
+//! Every run solves each part several times and records the median timing in
+//! `target/aoc-bench/<year>-day-<day>.json`. A part is flagged as regressed if it got slower
+//! than in the previous run by more than the threshold.
+
+use crate::runner::{input, solve, WORKSPACE};
+use aoc::{Part, PuzzleMeta, PUZZLES};
+use serde_json::{json, Value};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The usage of the `bench` command.
+pub const USAGE: &str = "aoc bench [--year <year>] [--runs <n>] [--threshold <percent>]";
+
+/// The options of the `bench` command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    /// The year to benchmark, or `None` for all enabled days.
+    year: Option<u16>,
+    /// How often each part is solved per run.
+    runs: usize,
+    /// The slowdown in percent above which a part is flagged.
+    threshold: f64,
+}
+
+/// The median timings of a day's parts in one run.
+#[derive(Debug, Clone, PartialEq)]
+struct Timing {
+    /// The seconds since the Unix epoch at which the run happened.
+    timestamp: u64,
+    /// The median time per part, or `None` if the part has no answer.
+    parts: [Option<Duration>; 2],
+}
+
+/// A part that got slower than the threshold allows.
+#[derive(Debug, Clone, PartialEq)]
+struct Regression {
+    part: Part,
+    previous: Duration,
+    current: Duration,
+}
+
+impl Options {
+    /// Parses the options following the `bench` command.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Self {
+            year: None,
+            runs: 10,
+            threshold: 10.0,
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+            match arg.as_str() {
+                "--year" => {
+                    let year = value("--year")?;
+                    options.year = Some(year.parse().map_err(|_| format!("invalid year {year}"))?);
+                }
+                "--runs" => {
+                    let runs = value("--runs")?;
+                    options.runs = runs
+                        .parse()
+                        .ok()
+                        .filter(|&runs| runs > 0)
+                        .ok_or(format!("invalid number of runs {runs}"))?;
+                }
+                "--threshold" => {
+                    let threshold = value("--threshold")?;
+                    options.threshold = threshold
+                        .parse()
+                        .ok()
+                        .filter(|&threshold: &f64| threshold >= 0.0)
+                        .ok_or(format!("invalid threshold {threshold}"))?;
+                }
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Benchmarks the enabled days, records the timings and reports the regressions.
+pub fn run(options: &Options) -> io::Result<()> {
+    let dir = history_dir();
+    fs::create_dir_all(&dir)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+
+    let mut regressions = 0;
+    for puzzle in PUZZLES
+        .iter()
+        .filter(|puzzle| options.year.is_none_or(|year| puzzle.year == year))
+    {
+        let Ok(text) = input(puzzle.year, puzzle.day).read() else {
+            eprintln!("{puzzle}: no input");
+            continue;
+        };
+        let parts = [Part::One, Part::Two].map(|part| median(puzzle, part, &text, options.runs));
+        let timing = Timing { timestamp, parts };
+
+        let path = dir.join(format!("{}-day-{}.json", puzzle.year, puzzle.day));
+        let mut history = fs::read_to_string(&path)
+            .map(|json| parse_history(&json))
+            .unwrap_or_default();
+
+        println!("{puzzle}: {}", describe(&timing));
+        for regression in compare(history.last(), &timing, options.threshold) {
+            regressions += 1;
+            println!(
+                "  part {} regressed: {:.2?} -> {:.2?}",
+                regression.part, regression.previous, regression.current
+            );
+        }
+
+        history.push(timing);
+        fs::write(&path, render_history(&history))?;
+    }
+
+    if regressions > 0 {
+        return Err(io::Error::other(format!(
+            "{regressions} parts regressed by more than {}%",
+            options.threshold
+        )));
+    }
+    Ok(())
+}
+
+/// Gets the directory of the recorded timings, `target/aoc-bench` in the workspace.
+fn history_dir() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(WORKSPACE).join("target"))
+        .join("aoc-bench")
+}
+
+/// Solves the part repeatedly, returning the median time, or `None` if it has no answer.
+fn median(puzzle: &PuzzleMeta, part: Part, input: &str, runs: usize) -> Option<Duration> {
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let run = solve(puzzle.year, puzzle.day, part, input.as_bytes());
+        run.answer.ok()?;
+        timings.push(run.elapsed);
+    }
+    timings.sort_unstable();
+    Some(timings[timings.len() / 2])
+}
+
+/// Finds the parts that got slower than the previous timing by more than the threshold.
+fn compare(previous: Option<&Timing>, current: &Timing, threshold: f64) -> Vec<Regression> {
+    let Some(previous) = previous else {
+        return Vec::new();
+    };
+
+    [Part::One, Part::Two]
+        .into_iter()
+        .zip(previous.parts.iter().zip(&current.parts))
+        .filter_map(|(part, (&previous, &current))| {
+            let (previous, current) = (previous?, current?);
+            let limit = previous.as_secs_f64() * (1.0 + threshold / 100.0);
+            (current.as_secs_f64() > limit).then_some(Regression {
+                part,
+                previous,
+                current,
+            })
+        })
+        .collect()
+}
+
+fn describe(timing: &Timing) -> String {
+    let part = |duration: Option<Duration>| match duration {
+        Some(duration) => format!("{duration:.2?}"),
+        None => String::from("-"),
+    };
+    format!("{} / {}", part(timing.parts[0]), part(timing.parts[1]))
+}
+
+/// Renders the timings as a JSON array, with the durations in nanoseconds.
+fn render_history(history: &[Timing]) -> String {
+    let nanos = |duration: Option<Duration>| duration.map(|duration| duration.as_nanos() as u64);
+    let history: Vec<_> = history
+        .iter()
+        .map(|timing| {
+            json!({
+                "timestamp": timing.timestamp,
+                "part_one_ns": nanos(timing.parts[0]),
+                "part_two_ns": nanos(timing.parts[1]),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&history).expect("timings are valid JSON")
+}
+
+/// Parses the timings rendered by [`render_history`], skipping malformed entries.
+fn parse_history(json: &str) -> Vec<Timing> {
+    let Ok(Value::Array(history)) = serde_json::from_str(json) else {
+        return Vec::new();
+    };
+    let part = |timing: &Value, key: &str| timing.get(key)?.as_u64().map(Duration::from_nanos);
+    history
+        .iter()
+        .filter_map(|timing| {
+            Some(Timing {
+                timestamp: timing.get("timestamp")?.as_u64()?,
+                parts: [part(timing, "part_one_ns"), part(timing, "part_two_ns")],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(one: u64, two: Option<u64>) -> Timing {
+        Timing {
+            timestamp: 1_700_000_000,
+            parts: [
+                Some(Duration::from_micros(one)),
+                two.map(Duration::from_micros),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let previous = timing(100, Some(100));
+        assert!(compare(None, &previous, 10.0).is_empty());
+        assert!(compare(Some(&previous), &timing(110, Some(50)), 10.0).is_empty());
+        assert_eq!(
+            compare(Some(&previous), &timing(111, None), 10.0),
+            [Regression {
+                part: Part::One,
+                previous: Duration::from_micros(100),
+                current: Duration::from_micros(111),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = [timing(1500, None), timing(42, Some(7))];
+        assert_eq!(parse_history(&render_history(&history)), history);
+        assert!(parse_history("{}").is_empty());
+        assert_eq!(parse_history(r#"[{"timestamp": 1}, {"nope": 2}]"#).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let args = |args: &str| {
+            args.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            Options::parse(args("--year 2024 --runs 3 --threshold 25")),
+            Ok(Options {
+                year: Some(2024),
+                runs: 3,
+                threshold: 25.0
+            })
+        );
+        assert!(Options::parse(args("--runs 0")).is_err());
+        assert!(Options::parse(args("--threshold -5")).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! The `aoc` command-line runner.
+//!
+//! ```shell
+//! cargo run -p aoc --features tui -- tui
+//! cargo run -p aoc --features cli --release -- report --year 2023 --out report.md --svg
+//! AOC_SESSION=... cargo run -p aoc --features cli -- stats --year 2023
+//! cargo run -p aoc --features cli --release -- bench --year 2024
+//! cargo run -p aoc --features cli,parallel --release -- --threads 4 --deterministic bench
+//! cargo run -p aoc --features cli -- sample --year 2023 --day 5 --seed 1 > sample.txt
+//! cargo run -p aoc --features trace --release -- --trace trace.json bench --year 2024
+//! ```
+
+use std::io;
+use std::process::ExitCode;
+
+#[cfg(feature = "tui")]
+mod answers;
+mod bench;
+mod report;
+mod runner;
+mod sample;
+mod stats;
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "tui")]
+mod tui;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    let options = match runner::Options::parse(&mut args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {error}\nusage: aoc {} <command>", runner::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = options.apply() {
+        eprintln!("error: {error}");
+        return ExitCode::FAILURE;
+    }
+    #[cfg(feature = "trace")]
+    let _trace = match options.trace().map(trace::record).transpose() {
+        Ok(guard) => guard,
+        Err(error) => {
+            eprintln!("error: failed to create the trace: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match args.next().as_deref() {
+        #[cfg(feature = "tui")]
+        Some("tui") => tui::run(),
+        #[cfg(not(feature = "tui"))]
+        Some("tui") => {
+            eprintln!("error: the dashboard needs the tui feature");
+            return ExitCode::FAILURE;
+        }
+        Some("report") => match report::Options::parse(args) {
+            Ok(options) => report::run(&options),
+            Err(error) => {
+                eprintln!("error: {error}\nusage: {}", report::USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        Some("stats") => match stats::Options::parse(args) {
+            Ok(options) => stats::run(&options),
+            Err(error) => {
+                eprintln!("error: {error}\nusage: {}", stats::USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        Some("bench") => match bench::Options::parse(args) {
+            Ok(options) => bench::run(&options),
+            Err(error) => {
+                eprintln!("error: {error}\nusage: {}", bench::USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        Some("sample") => match sample::Options::parse(args) {
+            Ok(options) => sample::run(&options),
+            Err(error) => {
+                eprintln!("error: {error}\nusage: {}", sample::USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!(
+                "usage: aoc tui\n       {}\n       {}\n       {}\n       {}\n\n\
+                 The commands solving days can be preceded by {}.",
+                report::USAGE,
+                stats::USAGE,
+                bench::USAGE,
+                sample::USAGE,
+                runner::USAGE
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    exit_code(result)
+}
+
+fn exit_code(result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
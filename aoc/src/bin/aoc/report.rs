@@ -0,0 +1,234 @@
+//! A Markdown report of a year's answers and timings.
+
+use crate::runner::{input, solve, Run};
+use aoc::{Part, PuzzleMeta, PUZZLES};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The usage of the `report` command.
+pub const USAGE: &str = "aoc report --year <year> [--out <report.md>] [--svg]";
+
+/// The options of the `report` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Options {
+    year: u16,
+    /// The file to write the report to, or `None` to print it.
+    out: Option<PathBuf>,
+    /// Whether to write the visualizations next to the report and embed them.
+    svg: bool,
+}
+
+/// The outcome of solving a day for the report.
+struct DayReport {
+    puzzle: &'static PuzzleMeta,
+    runs: [Run; 2],
+    /// The file name of the day's visualization, relative to the report.
+    svg: Option<String>,
+}
+
+impl Options {
+    /// Parses the options following the `report` command.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut year = None;
+        let mut out = None;
+        let mut svg = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    let value = args.next().ok_or("--year needs a value")?;
+                    year = Some(value.parse().map_err(|_| format!("invalid year {value}"))?);
+                }
+                "--out" => out = Some(PathBuf::from(args.next().ok_or("--out needs a value")?)),
+                "--svg" => svg = true,
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        let year = year.ok_or("--year is required")?;
+        if svg && out.is_none() {
+            return Err(String::from(
+                "--svg needs --out to place the images next to",
+            ));
+        }
+        Ok(Self { year, out, svg })
+    }
+}
+
+/// Solves all enabled days of the year and writes the report.
+pub fn run(options: &Options) -> io::Result<()> {
+    let mut puzzles: Vec<_> = PUZZLES
+        .iter()
+        .filter(|puzzle| puzzle.year == options.year)
+        .collect();
+    puzzles.sort_by_key(|puzzle| puzzle.day);
+
+    let mut days = Vec::with_capacity(puzzles.len());
+    for puzzle in puzzles {
+        eprintln!("Solving {puzzle}");
+        let runs = [Part::One, Part::Two].map(|part| solve_part(puzzle, part));
+        let svg = match &options.out {
+            Some(out) if options.svg => write_svg(puzzle, out)?,
+            _ => None,
+        };
+        days.push(DayReport { puzzle, runs, svg });
+    }
+
+    let report = render(options.year, &days);
+    match &options.out {
+        Some(out) => fs::write(out, report),
+        None => {
+            print!("{report}");
+            Ok(())
+        }
+    }
+}
+
+fn solve_part(puzzle: &PuzzleMeta, part: Part) -> Run {
+    match input(puzzle.year, puzzle.day).open() {
+        Ok(reader) => solve(puzzle.year, puzzle.day, part, reader),
+        Err(error) => Run {
+            answer: Err(format!("No input: {error}")),
+            elapsed: Duration::ZERO,
+        },
+    }
+}
+
+/// Renders the day's visualization next to the report, returning its file name.
+fn write_svg(puzzle: &PuzzleMeta, out: &Path) -> io::Result<Option<String>> {
+    let Ok(text) = input(puzzle.year, puzzle.day).read() else {
+        return Ok(None);
+    };
+
+    // Like the answers, a failing visualization shouldn't abort the whole report.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let svg = panic::catch_unwind(AssertUnwindSafe(|| {
+        aoc::render_svg(puzzle.year, puzzle.day, &text)
+    }));
+    panic::set_hook(hook);
+    let Ok(Some(svg)) = svg else {
+        return Ok(None);
+    };
+
+    let stem = out
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("report");
+    let name = format!("{stem}-{}-day-{}.svg", puzzle.year, puzzle.day);
+    fs::write(out.with_file_name(&name), svg)?;
+    Ok(Some(name))
+}
+
+/// Renders the report with a section per day.
+fn render(year: u16, days: &[DayReport]) -> String {
+    let solved = days
+        .iter()
+        .flat_map(|day| &day.runs)
+        .filter(|run| run.answer.is_ok())
+        .count();
+    let total: Duration = days
+        .iter()
+        .flat_map(|day| &day.runs)
+        .map(|run| run.elapsed)
+        .sum();
+
+    let mut report = format!("# Advent of Code {year}\n\n");
+    let _ = writeln!(report, "{solved} answers in {total:.2?}.");
+
+    for day in days {
+        let puzzle = day.puzzle;
+        let _ = writeln!(report, "\n## Day {}: {}\n", puzzle.day, puzzle.title);
+        report.push_str("| Part | Answer | Time |\n| --- | --- | --: |\n");
+        for (index, (part, run)) in [Part::One, Part::Two].iter().zip(&day.runs).enumerate() {
+            let expected = puzzle.real_answers.and_then(|answers| answers[index]);
+            let answer = describe(run, expected);
+            let _ = writeln!(report, "| {part} | {answer} | {:.2?} |", run.elapsed);
+        }
+
+        if let Some(svg) = &day.svg {
+            let _ = writeln!(report, "\n![Visualization of {puzzle}]({svg})");
+        }
+    }
+    report
+}
+
+/// Describes an answer for a table cell, flagging answers that differ from the known one.
+fn describe(run: &Run, expected: Option<&str>) -> String {
+    let text = match &run.answer {
+        Ok(answer) => match expected {
+            Some(expected) if expected != answer => format!("`{answer}` (expected `{expected}`)"),
+            _ => format!("`{answer}`"),
+        },
+        Err(error) => format!("*{error}*"),
+    };
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> impl Iterator<Item = String> + '_ {
+        args.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(
+            Options::parse(args("--year 2023 --out report.md --svg")),
+            Ok(Options {
+                year: 2023,
+                out: Some(PathBuf::from("report.md")),
+                svg: true
+            })
+        );
+        assert!(Options::parse(args("--out report.md")).is_err());
+        assert!(Options::parse(args("--year 2023 --svg")).is_err());
+        assert!(Options::parse(args("--year twenty")).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        const PUZZLE: PuzzleMeta = PuzzleMeta {
+            year: 2023,
+            day: 10,
+            title: "Pipe Maze",
+            example_answers: [None, None],
+            real_answers: Some([Some("6725"), Some("383")]),
+        };
+        let run = |answer: Result<&str, &str>, micros| Run {
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::from_micros(micros),
+        };
+        let days = [DayReport {
+            puzzle: &PUZZLE,
+            runs: [run(Ok("6725"), 1500), run(Ok("12"), 500)],
+            svg: Some(String::from("report-2023-day-10.svg")),
+        }];
+
+        assert_eq!(
+            render(2023, &days),
+            "# Advent of Code 2023\n\
+             \n\
+             2 answers in 2.00ms.\n\
+             \n\
+             ## Day 10: Pipe Maze\n\
+             \n\
+             | Part | Answer | Time |\n\
+             | --- | --- | --: |\n\
+             | one | `6725` | 1.50ms |\n\
+             | two | `12` (expected `383`) | 500.00µs |\n\
+             \n\
+             ![Visualization of 2023 Day 10: Pipe Maze](report-2023-day-10.svg)\n"
+        );
+
+        let failed = run(Err("a | b"), 0);
+        assert_eq!(describe(&failed, None), "*a \\| b*");
+    }
+}
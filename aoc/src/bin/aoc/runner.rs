@@ -0,0 +1,148 @@
+//! Solving a day on its input, as shared by the dashboard and the report.
+//!
+//! With the `parallel` feature, some days spread their work across a thread pool. The options
+//! in front of the command size the pool and make its reductions deterministic, so the answers
+//! and timings can be compared across machines. With the `trace` feature, they can also record
+//! the spans of the solved days as a chrome trace.
+
+use aoc::Part;
+use aoc_utils::input::Input;
+use aoc_utils::par;
+use std::io::BufRead;
+use std::iter::Peekable;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// The root of the workspace, holding the `<year>/day-<day>/input.txt` files.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The usage of the options in front of the command.
+pub const USAGE: &str = "[--threads <n>] [--deterministic] [--trace <file.json>]";
+
+/// The options in front of the command.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Options {
+    /// The number of threads, or `None` for one per core.
+    threads: Option<usize>,
+    /// Whether reductions combine their partial results in a fixed order.
+    deterministic: bool,
+    /// The file to write the chrome trace to, if any.
+    trace: Option<PathBuf>,
+}
+
+impl Options {
+    /// Parses the options in front of the command, leaving the command and its options.
+    pub fn parse<I: Iterator<Item = String>>(args: &mut Peekable<I>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next_if(|arg| arg.starts_with("--")) {
+            match arg.as_str() {
+                "--threads" => {
+                    let value = args.next().ok_or("--threads needs a value")?;
+                    options.threads = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|&threads| threads > 0)
+                            .ok_or(format!("invalid number of threads {value}"))?,
+                    );
+                }
+                "--deterministic" => options.deterministic = true,
+                "--trace" if cfg!(feature = "trace") => {
+                    options.trace = Some(args.next().ok_or("--trace needs a file")?.into());
+                }
+                "--trace" => return Err(String::from("the trace needs the trace feature")),
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Sets up the thread pool before anything is solved.
+    pub fn apply(&self) -> Result<(), par::ConfigurePoolError> {
+        if let Some(threads) = self.threads {
+            par::configure_pool(threads)?;
+        }
+        par::set_deterministic(self.deterministic);
+        Ok(())
+    }
+
+    /// The file to write the chrome trace to, if any.
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    pub fn trace(&self) -> Option<&Path> {
+        self.trace.as_deref()
+    }
+}
+
+/// The outcome of solving a part, and how long it took.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Run {
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Selects the input of the day from the workspace, falling back to the input cache.
+pub fn input(year: u16, day: u8) -> Input {
+    let path = Path::new(WORKSPACE).join(format!("{year}/day-{day}/input.txt"));
+    if path.is_file() {
+        return Input::File(path);
+    }
+    Input::Cache { year, day }
+}
+
+/// Solves a part, timing it and turning a panic into an error.
+///
+/// The input is streamed by the days that support it, so reading it is part of the timing.
+pub fn solve(year: u16, day: u8, part: Part, input: impl BufRead) -> Run {
+    // The terminal's panic hook would restore the screen, so silence it while solving.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        aoc::solve_reader(year, day, part, input)
+    }));
+    let elapsed = start.elapsed();
+    panic::set_hook(hook);
+
+    let answer = match result {
+        Ok(answer) => answer.map_err(|error| error.to_string()),
+        Err(_) => Err(String::from("the solution panicked")),
+    };
+    Run { answer, elapsed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pool_options() {
+        let mut args = "--threads 4 --deterministic bench --year 2024"
+            .split_whitespace()
+            .map(String::from)
+            .peekable();
+        assert_eq!(
+            Options::parse(&mut args),
+            Ok(Options {
+                threads: Some(4),
+                deterministic: true,
+                trace: None
+            })
+        );
+        assert_eq!(args.next().as_deref(), Some("bench"));
+
+        let mut args = ["--threads", "0"].map(String::from).into_iter().peekable();
+        assert!(Options::parse(&mut args).is_err());
+
+        let mut args = ["--trace", "trace.json", "bench"]
+            .map(String::from)
+            .into_iter()
+            .peekable();
+        let trace = Options::parse(&mut args).map(|options| options.trace);
+        if cfg!(feature = "trace") {
+            assert_eq!(trace, Ok(Some(PathBuf::from("trace.json"))));
+        } else {
+            assert!(trace.is_err());
+        }
+    }
+}
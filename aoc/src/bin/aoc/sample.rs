@@ -0,0 +1,174 @@
+//! Generating synthetic inputs shaped like a real one, to reproduce wrong answers in public.
+//!
+//! Puzzle inputs may not be shared, so the sample keeps the dimensions and statistics of the
+//! real input but none of its values. Which properties are kept depends on the day, see
+//! [`generate`]. The sample is printed to the standard output.
+
+use crate::runner::input;
+use aoc_utils::input::Input;
+use aoc_utils::property::{self, Rng};
+use std::io;
+
+/// The usage of the `sample` command.
+pub const USAGE: &str =
+    "aoc sample --year <year> --day <day> [--input <path|-|cache:<year>/<day>>] [--seed <n>]";
+
+/// The options of the `sample` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Options {
+    year: u16,
+    day: u8,
+    /// The real input, or `None` for the day's input in the workspace or the cache.
+    input: Option<Input>,
+    /// The seed of the generator; the same seed and real input give the same sample.
+    seed: u64,
+}
+
+impl Options {
+    /// Parses the options following the `sample` command.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let (mut year, mut day, mut input, mut seed) = (None, None, None, 0);
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+            match arg.as_str() {
+                "--year" => {
+                    let value = value("--year")?;
+                    year = Some(value.parse().map_err(|_| format!("invalid year {value}"))?);
+                }
+                "--day" => {
+                    let value = value("--day")?;
+                    day = Some(value.parse().map_err(|_| format!("invalid day {value}"))?);
+                }
+                "--input" => {
+                    let value = value("--input")?;
+                    input = Some(value.parse().map_err(|error| format!("{error}"))?);
+                }
+                "--seed" => {
+                    let value = value("--seed")?;
+                    seed = value.parse().map_err(|_| format!("invalid seed {value}"))?;
+                }
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        let year = year.ok_or("--year is required")?;
+        let day = day.ok_or("--day is required")?;
+        Ok(Self {
+            year,
+            day,
+            input,
+            seed,
+        })
+    }
+}
+
+/// Prints a sample shaped like the real input of the day.
+pub fn run(options: &Options) -> io::Result<()> {
+    let (year, day) = (options.year, options.day);
+    let real = options
+        .input
+        .clone()
+        .unwrap_or_else(|| input(year, day))
+        .read()
+        .map_err(io::Error::other)?;
+
+    let sample = generate(year, day, &real, &mut Rng::new(options.seed)).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("{year} day {day} has no sample generator"),
+        )
+    })?;
+    print!("{sample}");
+    Ok(())
+}
+
+/// Generates a sample shaped like the real input of the day, or `None` if the day has no
+/// generator or the real input lacks the expected shape.
+///
+/// - Days whose numbers are independent of each other get new numbers of the same lengths.
+/// - Days on a grid of independent tiles get the tiles shuffled.
+/// - 2023 day 5 gets an almanac with as many seed ranges and values of the same magnitude.
+/// - 2023 day 10 gets a maze of about the same size, around a new loop.
+fn generate(year: u16, day: u8, real: &str, rng: &mut Rng) -> Option<String> {
+    let mut sample = match (year, day) {
+        (2015, 2)
+        | (2023, 2)
+        | (2023, 6)
+        | (2024, 1)
+        | (2024, 7)
+        | (2024, 11)
+        | (2024, 13)
+        | (2024, 22) => property::numbers_like(rng, real),
+        (2023, 3) | (2023, 11) | (2024, 8) | (2024, 10) | (2024, 12) => {
+            property::grid_like(rng, real)
+        }
+        (2023, 5) => {
+            let seeds = real.lines().next()?.split_whitespace().skip(1).count();
+            let largest = real
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|number| number.parse::<u64>().ok())
+                .max()?;
+            property::almanac(rng, seeds / 2, largest.checked_add(1)?)
+        }
+        (2023, 10) => {
+            let lines = real.lines().filter(|line| !line.is_empty());
+            let height = lines.clone().count();
+            let width = lines.map(str::len).max()?;
+            let blocks = |tiles: usize| (tiles.saturating_sub(1) / 2).max(1);
+            property::pipe_maze(rng, blocks(width), blocks(height)).input
+        }
+        _ => return None,
+    };
+
+    if !sample.ends_with('\n') {
+        sample.push('\n');
+    }
+    Some(sample)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Part, SolveError, PUZZLES};
+
+    #[test]
+    fn test_parse_options() {
+        let args = |args: &str| {
+            args.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            Options::parse(args("--day 5 --year 2023 --input - --seed 7")),
+            Ok(Options {
+                year: 2023,
+                day: 5,
+                input: Some(Input::Stdin),
+                seed: 7
+            })
+        );
+        assert!(Options::parse(args("--year 2023")).is_err());
+        assert!(Options::parse(args("--year 2023 --day 5 --seed")).is_err());
+    }
+
+    #[test]
+    fn test_samples_are_solvable() {
+        for puzzle in PUZZLES {
+            let Ok(real) = input(puzzle.year, puzzle.day).read() else {
+                continue;
+            };
+            let Some(sample) = generate(puzzle.year, puzzle.day, &real, &mut Rng::new(1)) else {
+                continue;
+            };
+            assert_ne!(sample, real, "{puzzle}");
+            for part in [Part::One, Part::Two] {
+                match aoc::solve(puzzle.year, puzzle.day, part, &sample) {
+                    Ok(_) | Err(SolveError::MissingPart(_)) => {}
+                    Err(error) => panic!("{puzzle}, part {part:?}: {error}\n{sample}"),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Comparing the stars earned on adventofcode.com with the days solved in this workspace.
+//!
+//! The year's calendar page is fetched with `curl`, authenticated by the session cookie in
+//! `$AOC_SESSION`, and cached as `<year>/calendar.html` in the input cache directory. A cached
+//! page is reused for [`MAX_AGE`] to keep the requests to the site to a minimum; a page listing
+//! no days, as served to an invalid session, is never cached.
+
+use aoc::PUZZLES;
+use std::fmt::Write as _;
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{env, fs};
+
+/// The usage of the `stats` command.
+pub const USAGE: &str = "aoc stats --year <year> [--refresh]";
+
+/// How long a cached calendar is used before fetching it again.
+const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// Identifies the tool in the requests, as asked for by the site.
+const USER_AGENT: &str = "github.com/sunsided/aoc-rs";
+
+/// The options of the `stats` command.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Options {
+    year: u16,
+    /// Whether to fetch the calendar even if the cached one is recent.
+    refresh: bool,
+}
+
+impl Options {
+    /// Parses the options following the `stats` command.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut year = None;
+        let mut refresh = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    let value = args.next().ok_or("--year needs a value")?;
+                    year = Some(value.parse().map_err(|_| format!("invalid year {value}"))?);
+                }
+                "--refresh" => refresh = true,
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        let year = year.ok_or("--year is required")?;
+        Ok(Self { year, refresh })
+    }
+}
+
+/// Prints the stars per day next to whether the day is solved locally.
+pub fn run(options: &Options) -> io::Result<()> {
+    let stars = stars(options.year, options.refresh)?;
+    print!("{}", summarize(options.year, &stars));
+    Ok(())
+}
+
+/// Gets the stars per day of the year, from the cached calendar if it is recent enough.
+fn stars(year: u16, refresh: bool) -> io::Result<Vec<(u8, u8)>> {
+    let dir = aoc_utils::input::cache_dir()
+        .map_err(|error| io::Error::new(io::ErrorKind::NotFound, error.to_string()))?;
+    let path = dir.join(format!("{year}/calendar.html"));
+    if !refresh && is_recent(&path) {
+        let stars = parse_calendar(&fs::read_to_string(&path)?);
+        if !stars.is_empty() {
+            return Ok(stars);
+        }
+    }
+
+    let session = env::var("AOC_SESSION").map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "set AOC_SESSION to the session cookie of adventofcode.com",
+        )
+    })?;
+    // The cookie is passed as a config on the standard input, as the command line can be read
+    // by other users through the process list.
+    let mut curl = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--user-agent",
+            USER_AGENT,
+            "--config",
+            "-",
+        ])
+        .arg(format!("https://adventofcode.com/{year}"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = curl.stdin.take() {
+        stdin.write_all(curl_config(&session).as_bytes())?;
+    }
+    let output = curl.wait_with_output()?;
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!(
+            "failed to fetch the calendar: {error}"
+        )));
+    }
+
+    let page = String::from_utf8(output.stdout)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    let stars = parse_calendar(&page);
+    if stars.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the calendar lists no days; is AOC_SESSION valid?",
+        ));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, &page)?;
+    Ok(stars)
+}
+
+/// Gets the curl config sending the session cookie.
+fn curl_config(session: &str) -> String {
+    let session = session.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("cookie = \"session={session}\"\n")
+}
+
+fn is_recent(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < MAX_AGE)
+}
+
+/// Extracts the stars per day from a calendar page.
+///
+/// Each unlocked day is a link labeled like `Day 7, two stars`, `Day 7, one star` or `Day 7`.
+fn parse_calendar(html: &str) -> Vec<(u8, u8)> {
+    const LABEL: &str = "aria-label=\"Day ";
+
+    let mut days: Vec<_> = html
+        .match_indices(LABEL)
+        .filter_map(|(start, _)| {
+            let label = &html[start + LABEL.len()..];
+            let label = &label[..label.find('"')?];
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+            let stars = match stars.trim() {
+                "two stars" => 2,
+                "one star" => 1,
+                _ => 0,
+            };
+            Some((day.trim().parse().ok()?, stars))
+        })
+        .collect();
+    days.sort_unstable();
+    days.dedup_by_key(|&mut (day, _)| day);
+    days
+}
+
+/// Lists the days with their stars, marking the ones with stars but no local solution.
+fn summarize(year: u16, stars: &[(u8, u8)]) -> String {
+    let solved = |day: u8| {
+        PUZZLES
+            .iter()
+            .any(|puzzle| puzzle.year == year && puzzle.day == day)
+    };
+
+    let mut summary = String::new();
+    let mut missing = Vec::new();
+    for &(day, count) in stars {
+        let local = if solved(day) {
+            "solved here"
+        } else if count > 0 {
+            missing.push(day.to_string());
+            "missing here"
+        } else {
+            ""
+        };
+        let stars = ["  ", "* ", "**"][usize::from(count.min(2))];
+        let line = format!("{year} day {day:>2} {stars} {local}");
+        summary.push_str(line.trim_end());
+        summary.push('\n');
+    }
+
+    let earned: u32 = stars.iter().map(|&(_, count)| u32::from(count)).sum();
+    let _ = writeln!(summary, "\n{earned} stars earned");
+    if missing.is_empty() {
+        summary.push_str("Every day with stars is solved here\n");
+    } else {
+        let _ = writeln!(summary, "Not solved here: days {}", missing.join(", "));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_calendar() {
+        const PAGE: &str = r#"<pre class="calendar">
+<a aria-label="Day 25" href="/2023/day/25" class="calendar-day25">
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 1, two stars" href="/2023/day/1">
+</pre>"#;
+        assert_eq!(parse_calendar(PAGE), [(1, 2), (2, 1), (25, 0)]);
+        assert!(parse_calendar("<html></html>").is_empty());
+    }
+
+    #[test]
+    fn test_curl_config() {
+        assert_eq!(curl_config("53616c"), "cookie = \"session=53616c\"\n");
+        assert_eq!(curl_config("a\"b\\"), "cookie = \"session=a\\\"b\\\\\"\n");
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let args = |args: &str| {
+            args.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            Options::parse(args("--refresh --year 2015")),
+            Ok(Options {
+                year: 2015,
+                refresh: true
+            })
+        );
+        assert!(Options::parse(args("--refresh")).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "y2015-day-1")]
+    fn test_summarize() {
+        let summary = summarize(2015, &[(1, 2), (6, 1), (7, 0)]);
+        assert!(summary.contains("2015 day  1 ** solved here\n"));
+        assert!(summary.contains("2015 day  6 *  missing here\n2015 day  7\n"));
+        assert!(summary.ends_with("3 stars earned\nNot solved here: days 6\n"));
+    }
+}
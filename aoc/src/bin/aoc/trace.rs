@@ -0,0 +1,23 @@
+//! Recording the spans of the solved days as a chrome trace, to be opened in `chrome://tracing`
+//! or Perfetto.
+//!
+//! Each solved part is a `part1` or `part2` span with the date as its arguments, and the days
+//! nest a `parse` span for reading their input into it.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::prelude::*;
+
+/// Records the spans into the file, which is completed when the guard is dropped.
+pub fn record(path: &Path) -> io::Result<FlushGuard> {
+    let file = File::create(path)?;
+    let (layer, guard) = ChromeLayerBuilder::new()
+        .writer(file)
+        .include_args(true)
+        .include_locations(false)
+        .build();
+    tracing_subscriber::registry().with(layer).init();
+    Ok(guard)
+}
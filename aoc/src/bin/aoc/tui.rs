@@ -0,0 +1,192 @@
+//! A dashboard listing the enabled days with their latest answers and timings.
+
+use crate::answers::Answers;
+use crate::runner::{input, solve, Run, WORKSPACE};
+use aoc::{Part, PuzzleMeta, PUZZLES};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use std::process::Command;
+
+/// The days whose example binary can be animated with `--animate`.
+const VISUALIZATIONS: &[(u16, u8)] = &[(2023, 10), (2023, 11)];
+
+/// Runs the dashboard until it is quit.
+pub fn run() -> io::Result<()> {
+    let mut app = App {
+        answers: Answers::load(),
+        list: ListState::default().with_selected((!PUZZLES.is_empty()).then_some(0)),
+        status: String::from("Press r to run the selected day"),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = app.event_loop(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct App {
+    answers: Answers,
+    list: ListState,
+    status: String,
+}
+
+impl App {
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+                KeyCode::Char('r') | KeyCode::Enter => {
+                    if let Some(&PuzzleMeta { year, day, .. }) = self.selected() {
+                        self.status = format!("Running {year} day {day} …");
+                        terminal.draw(|frame| self.draw(frame))?;
+                        self.run_day(year, day);
+                    }
+                }
+                KeyCode::Char('v') => {
+                    if let Some(&PuzzleMeta { year, day, .. }) = self.selected() {
+                        ratatui::restore();
+                        self.visualize(year, day);
+                        *terminal = ratatui::init();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn selected(&self) -> Option<&'static PuzzleMeta> {
+        self.list.selected().and_then(|index| PUZZLES.get(index))
+    }
+
+    /// Solves both parts of the day and records their answers.
+    fn run_day(&mut self, year: u16, day: u8) {
+        let input = input(year, day);
+        for part in [Part::One, Part::Two] {
+            let reader = match input.open() {
+                Ok(reader) => reader,
+                Err(error) => {
+                    self.status = format!("No input for {year} day {day}: {error}");
+                    return;
+                }
+            };
+            let run = solve(year, day, part, reader);
+            self.answers.insert(year, day, part, run);
+        }
+
+        self.status = match self.answers.save() {
+            Ok(()) => format!("Solved {year} day {day}"),
+            Err(error) => {
+                format!("Solved {year} day {day}, but failed to save the answers: {error}")
+            }
+        };
+    }
+
+    /// Runs the animated example binary of the day in the terminal.
+    fn visualize(&mut self, year: u16, day: u8) {
+        if !VISUALIZATIONS.contains(&(year, day)) {
+            self.status = format!("{year} day {day} has no visualization");
+            return;
+        }
+
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let status = Command::new(cargo)
+            .current_dir(WORKSPACE)
+            .args(["run", "--release", "--example"])
+            .arg(format!("{year}-day-{day}"))
+            .args(["--", "--animate"])
+            .status();
+
+        self.status = match status {
+            Ok(status) if status.success() => format!("Visualized {year} day {day}"),
+            Ok(status) => format!("The visualization of {year} day {day} failed: {status}"),
+            Err(error) => format!("Failed to start the visualization: {error}"),
+        };
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [days, details] =
+            Layout::horizontal([Constraint::Length(18), Constraint::Min(0)]).areas(main);
+
+        let items = PUZZLES.iter().map(|&PuzzleMeta { year, day, .. }| {
+            let solved = [Part::One, Part::Two]
+                .iter()
+                .filter(|&&part| {
+                    self.answers
+                        .get(year, day, part)
+                        .is_some_and(|run| run.answer.is_ok())
+                })
+                .count();
+            let stars = ["  ", "* ", "**"][solved];
+            format!("{year} day {day:>2} {stars}")
+        });
+        let list = List::new(items)
+            .block(Block::bordered().title(" Days "))
+            .highlight_style(Style::new().reversed());
+        frame.render_stateful_widget(list, days, &mut self.list);
+
+        if let Some(puzzle) = self.selected() {
+            let (year, day) = (puzzle.year, puzzle.day);
+            let mut lines = Vec::new();
+            for (index, part) in [Part::One, Part::Two].into_iter().enumerate() {
+                let expected = puzzle.real_answers.and_then(|answers| answers[index]);
+                lines.push(Line::from(format!("Part {part}").bold()));
+                lines.push(match self.answers.get(year, day, part) {
+                    None => Line::from("  not run yet".dim()),
+                    Some(run) => describe(run, expected),
+                });
+                lines.push(Line::default());
+            }
+            if VISUALIZATIONS.contains(&(year, day)) {
+                lines.push(Line::from("Press v to watch the visualization".italic()));
+            }
+
+            let title = format!(" {puzzle} ");
+            let paragraph = Paragraph::new(lines).block(Block::bordered().title(title));
+            frame.render_widget(paragraph, details);
+        }
+
+        let help = "↑/↓ select · r run · v visualize · q quit";
+        let [message, keys] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(help.chars().count() as u16),
+        ])
+        .areas(status);
+        frame.render_widget(Line::from(self.status.as_str()), message);
+        frame.render_widget(Line::from(help.dim()), keys);
+    }
+}
+
+/// Describes the outcome of a run, flagging answers that differ from the known one.
+fn describe(run: &Run, expected: Option<&str>) -> Line<'static> {
+    let elapsed = format!("  ({:.2?})", run.elapsed).dim();
+    match &run.answer {
+        Ok(answer) => match expected {
+            Some(expected) if expected != answer => Line::from(vec![
+                "  ".into(),
+                answer.clone().red(),
+                format!(" (expected {expected})").red(),
+                elapsed,
+            ]),
+            _ => Line::from(vec!["  ".into(), answer.clone().green(), elapsed]),
+        },
+        Err(error) => Line::from(vec!["  ".into(), error.clone().red(), elapsed]),
+    }
+}
@@ -0,0 +1,108 @@
+//! Describing the intermediate results of days, to give context to a wrong answer.
+
+/// Describes the intermediate results of a day's solution on the input, such as the sizes of
+/// what it parsed, using the `debug_info` of the day's crate.
+///
+/// Returns `None` if the day's feature isn't enabled, or the input is invalid.
+///
+/// ```
+/// # #[cfg(feature = "y2024-day-12")]
+/// assert_eq!(aoc::debug_info(2024, 12, "AAB\nABB").as_deref(), Some("2 regions, the largest with 3 plots"));
+/// assert_eq!(aoc::debug_info(2023, 12, ""), None);
+/// ```
+#[allow(unused_variables)] // with no day enabled, only the date is looked at
+pub fn debug_info(year: u16, day: u8, input: &str) -> Option<String> {
+    match (year, day) {
+        #[cfg(feature = "y2024-day-1")]
+        (2024, 1) => crate::y2024::day_1::debug_info(input),
+        #[cfg(feature = "y2024-day-5")]
+        (2024, 5) => crate::y2024::day_5::debug_info(input),
+        #[cfg(feature = "y2024-day-6")]
+        (2024, 6) => crate::y2024::day_6::debug_info(input),
+        #[cfg(feature = "y2024-day-7")]
+        (2024, 7) => crate::y2024::day_7::debug_info(input),
+        #[cfg(feature = "y2024-day-8")]
+        (2024, 8) => crate::y2024::day_8::debug_info(input),
+        #[cfg(feature = "y2024-day-9")]
+        (2024, 9) => crate::y2024::day_9::debug_info(input),
+        #[cfg(feature = "y2024-day-10")]
+        (2024, 10) => crate::y2024::day_10::debug_info(input),
+        #[cfg(feature = "y2024-day-11")]
+        (2024, 11) => crate::y2024::day_11::debug_info(input),
+        #[cfg(feature = "y2024-day-12")]
+        (2024, 12) => crate::y2024::day_12::debug_info(input),
+        #[cfg(feature = "y2024-day-13")]
+        (2024, 13) => crate::y2024::day_13::debug_info(input),
+        #[cfg(feature = "y2024-day-14")]
+        (2024, 14) => crate::y2024::day_14::debug_info(input),
+        #[cfg(feature = "y2024-day-15")]
+        (2024, 15) => crate::y2024::day_15::debug_info(input),
+        #[cfg(feature = "y2024-day-16")]
+        (2024, 16) => crate::y2024::day_16::debug_info(input),
+        #[cfg(feature = "y2024-day-17")]
+        (2024, 17) => crate::y2024::day_17::debug_info(input),
+        #[cfg(feature = "y2024-day-18")]
+        (2024, 18) => crate::y2024::day_18::debug_info(input),
+        #[cfg(feature = "y2024-day-19")]
+        (2024, 19) => crate::y2024::day_19::debug_info(input),
+        #[cfg(feature = "y2024-day-20")]
+        (2024, 20) => crate::y2024::day_20::debug_info(input),
+        #[cfg(feature = "y2024-day-21")]
+        (2024, 21) => crate::y2024::day_21::debug_info(input),
+        #[cfg(feature = "y2024-day-22")]
+        (2024, 22) => crate::y2024::day_22::debug_info(input),
+        #[cfg(feature = "y2024-day-23")]
+        (2024, 23) => crate::y2024::day_23::debug_info(input),
+        #[cfg(feature = "y2024-day-24")]
+        (2024, 24) => crate::y2024::day_24::debug_info(input),
+        #[cfg(feature = "y2024-day-25")]
+        (2024, 25) => crate::y2024::day_25::debug_info(input),
+        #[cfg(feature = "y2023-day-1")]
+        (2023, 1) => crate::y2023::day_1::debug_info(input),
+        #[cfg(feature = "y2023-day-2")]
+        (2023, 2) => crate::y2023::day_2::debug_info(input),
+        #[cfg(feature = "y2023-day-3")]
+        (2023, 3) => crate::y2023::day_3::debug_info(input),
+        #[cfg(feature = "y2023-day-4")]
+        (2023, 4) => crate::y2023::day_4::debug_info(input),
+        #[cfg(feature = "y2023-day-5")]
+        (2023, 5) => crate::y2023::day_5::debug_info(input),
+        #[cfg(feature = "y2023-day-6")]
+        (2023, 6) => crate::y2023::day_6::debug_info(input),
+        #[cfg(feature = "y2023-day-7")]
+        (2023, 7) => crate::y2023::day_7::debug_info(input),
+        #[cfg(feature = "y2023-day-8")]
+        (2023, 8) => crate::y2023::day_8::debug_info(input),
+        #[cfg(feature = "y2023-day-9")]
+        (2023, 9) => crate::y2023::day_9::debug_info(input),
+        #[cfg(feature = "y2023-day-10")]
+        (2023, 10) => crate::y2023::day_10::debug_info(input),
+        #[cfg(feature = "y2023-day-11")]
+        (2023, 11) => crate::y2023::day_11::debug_info(input),
+        #[cfg(feature = "y2023-day-19")]
+        (2023, 19) => crate::y2023::day_19::debug_info(input),
+        #[cfg(feature = "y2023-day-20")]
+        (2023, 20) => crate::y2023::day_20::debug_info(input),
+        #[cfg(feature = "y2023-day-21")]
+        (2023, 21) => crate::y2023::day_21::debug_info(input),
+        #[cfg(feature = "y2023-day-22")]
+        (2023, 22) => crate::y2023::day_22::debug_info(input),
+        #[cfg(feature = "y2023-day-23")]
+        (2023, 23) => crate::y2023::day_23::debug_info(input),
+        #[cfg(feature = "y2023-day-24")]
+        (2023, 24) => crate::y2023::day_24::debug_info(input),
+        #[cfg(feature = "y2023-day-25")]
+        (2023, 25) => crate::y2023::day_25::debug_info(input),
+        #[cfg(feature = "y2015-day-1")]
+        (2015, 1) => crate::y2015::day_1::debug_info(input),
+        #[cfg(feature = "y2015-day-2")]
+        (2015, 2) => crate::y2015::day_2::debug_info(input),
+        #[cfg(feature = "y2015-day-3")]
+        (2015, 3) => crate::y2015::day_3::debug_info(input),
+        #[cfg(feature = "y2015-day-4")]
+        (2015, 4) => crate::y2015::day_4::debug_info(input),
+        #[cfg(feature = "y2015-day-5")]
+        (2015, 5) => crate::y2015::day_5::debug_info(input),
+        _ => None,
+    }
+}
@@ -0,0 +1,134 @@
+//! All Advent of Code solutions in one crate.
+//!
+//! Every day is an optional dependency enabled by a feature named after it, e.g. `y2023-day-10`;
+//! the `y2023` feature enables all days of a year. All years are enabled by default, so building
+//! a single day requires disabling the default features:
+//!
+//! ```shell
+//! cargo build -p aoc --no-default-features --features y2023-day-10
+//! ```
+//!
+//! Any enabled day can also be solved by its date using [`solve`], or with the parameters of its
+//! example using [`solve_example`], and the days with a visualization can be drawn using
+//! [`render_svg`]. Each day describes its intermediate results using [`debug_info`]. With the
+//! `serde` feature, every day can render its parsed input as JSON using `parse_json`. With the
+//! `tracing` feature, solving a part enters a `part1` or `part2` span, and the days enter a
+//! `parse` span while parsing their input.
+
+mod debug;
+#[cfg(feature = "serde")]
+mod parsed;
+mod render;
+mod solve;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "serde")]
+pub use parsed::parse_json;
+
+pub use aoc_utils::PuzzleMeta;
+pub use debug::debug_info;
+pub use render::render_svg;
+pub use solve::{solve, solve_example, solve_reader, Part, SolveError, PUZZLES};
+
+/// The solutions of 2024.
+pub mod y2024 {
+    #[cfg(feature = "y2024-day-1")]
+    pub use aoc_2024_day_1 as day_1;
+    #[cfg(feature = "y2024-day-10")]
+    pub use aoc_2024_day_10 as day_10;
+    #[cfg(feature = "y2024-day-11")]
+    pub use aoc_2024_day_11 as day_11;
+    #[cfg(feature = "y2024-day-12")]
+    pub use aoc_2024_day_12 as day_12;
+    #[cfg(feature = "y2024-day-13")]
+    pub use aoc_2024_day_13 as day_13;
+    #[cfg(feature = "y2024-day-14")]
+    pub use aoc_2024_day_14 as day_14;
+    #[cfg(feature = "y2024-day-15")]
+    pub use aoc_2024_day_15 as day_15;
+    #[cfg(feature = "y2024-day-16")]
+    pub use aoc_2024_day_16 as day_16;
+    #[cfg(feature = "y2024-day-17")]
+    pub use aoc_2024_day_17 as day_17;
+    #[cfg(feature = "y2024-day-18")]
+    pub use aoc_2024_day_18 as day_18;
+    #[cfg(feature = "y2024-day-19")]
+    pub use aoc_2024_day_19 as day_19;
+    #[cfg(feature = "y2024-day-20")]
+    pub use aoc_2024_day_20 as day_20;
+    #[cfg(feature = "y2024-day-21")]
+    pub use aoc_2024_day_21 as day_21;
+    #[cfg(feature = "y2024-day-22")]
+    pub use aoc_2024_day_22 as day_22;
+    #[cfg(feature = "y2024-day-23")]
+    pub use aoc_2024_day_23 as day_23;
+    #[cfg(feature = "y2024-day-24")]
+    pub use aoc_2024_day_24 as day_24;
+    #[cfg(feature = "y2024-day-25")]
+    pub use aoc_2024_day_25 as day_25;
+    #[cfg(feature = "y2024-day-5")]
+    pub use aoc_2024_day_5 as day_5;
+    #[cfg(feature = "y2024-day-6")]
+    pub use aoc_2024_day_6 as day_6;
+    #[cfg(feature = "y2024-day-7")]
+    pub use aoc_2024_day_7 as day_7;
+    #[cfg(feature = "y2024-day-8")]
+    pub use aoc_2024_day_8 as day_8;
+    #[cfg(feature = "y2024-day-9")]
+    pub use aoc_2024_day_9 as day_9;
+}
+
+/// The solutions of 2023.
+pub mod y2023 {
+    #[cfg(feature = "y2023-day-1")]
+    pub use aoc_2023_day_1 as day_1;
+    #[cfg(feature = "y2023-day-10")]
+    pub use aoc_2023_day_10 as day_10;
+    #[cfg(feature = "y2023-day-11")]
+    pub use aoc_2023_day_11 as day_11;
+    #[cfg(feature = "y2023-day-19")]
+    pub use aoc_2023_day_19 as day_19;
+    #[cfg(feature = "y2023-day-2")]
+    pub use aoc_2023_day_2 as day_2;
+    #[cfg(feature = "y2023-day-20")]
+    pub use aoc_2023_day_20 as day_20;
+    #[cfg(feature = "y2023-day-21")]
+    pub use aoc_2023_day_21 as day_21;
+    #[cfg(feature = "y2023-day-22")]
+    pub use aoc_2023_day_22 as day_22;
+    #[cfg(feature = "y2023-day-23")]
+    pub use aoc_2023_day_23 as day_23;
+    #[cfg(feature = "y2023-day-24")]
+    pub use aoc_2023_day_24 as day_24;
+    #[cfg(feature = "y2023-day-25")]
+    pub use aoc_2023_day_25 as day_25;
+    #[cfg(feature = "y2023-day-3")]
+    pub use aoc_2023_day_3 as day_3;
+    #[cfg(feature = "y2023-day-4")]
+    pub use aoc_2023_day_4 as day_4;
+    #[cfg(feature = "y2023-day-5")]
+    pub use aoc_2023_day_5 as day_5;
+    #[cfg(feature = "y2023-day-6")]
+    pub use aoc_2023_day_6 as day_6;
+    #[cfg(feature = "y2023-day-7")]
+    pub use aoc_2023_day_7 as day_7;
+    #[cfg(feature = "y2023-day-8")]
+    pub use aoc_2023_day_8 as day_8;
+    #[cfg(feature = "y2023-day-9")]
+    pub use aoc_2023_day_9 as day_9;
+}
+
+/// The solutions of 2015.
+pub mod y2015 {
+    #[cfg(feature = "y2015-day-1")]
+    pub use aoc_2015_day_1 as day_1;
+    #[cfg(feature = "y2015-day-2")]
+    pub use aoc_2015_day_2 as day_2;
+    #[cfg(feature = "y2015-day-3")]
+    pub use aoc_2015_day_3 as day_3;
+    #[cfg(feature = "y2015-day-4")]
+    pub use aoc_2015_day_4 as day_4;
+    #[cfg(feature = "y2015-day-5")]
+    pub use aoc_2015_day_5 as day_5;
+}
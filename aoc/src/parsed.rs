@@ -0,0 +1,131 @@
+//! The parsed puzzle inputs as JSON, enabled by the `serde` feature.
+
+use crate::SolveError;
+use serde::Serialize;
+use std::convert::Infallible;
+use std::fmt::Display;
+
+/// Parses a day's puzzle input and renders the parsed structure as JSON.
+///
+/// Every enabled day supports this. Days without a parsed structure of their own render the
+/// values their solution reads off the input, such as the codes of 2024 day 21 or the floors
+/// of 2015 day 1.
+///
+/// ```
+/// # #[cfg(feature = "y2024-day-22")]
+/// assert_eq!(aoc::parse_json(2024, 22, "1\n10\n"), Ok("[1,10]".into()));
+/// ```
+#[allow(unused_variables)] // with no day enabled, only the date is looked at
+pub fn parse_json(year: u16, day: u8, input: &str) -> Result<String, SolveError> {
+    match (year, day) {
+        #[cfg(feature = "y2024-day-1")]
+        (2024, 1) => json(crate::y2024::day_1::parse_lists(input)),
+        #[cfg(feature = "y2024-day-5")]
+        (2024, 5) => json(crate::y2024::day_5::parse_input(input)),
+        #[cfg(feature = "y2024-day-6")]
+        (2024, 6) => json(crate::y2024::day_6::Lab::parse(input)),
+        #[cfg(feature = "y2024-day-7")]
+        (2024, 7) => json(crate::y2024::day_7::parse_equations(input)),
+        #[cfg(feature = "y2024-day-8")]
+        (2024, 8) => json(crate::y2024::day_8::Roof::parse(input)),
+        #[cfg(feature = "y2024-day-9")]
+        (2024, 9) => json(input.parse::<crate::y2024::day_9::DiskMap>()),
+        #[cfg(feature = "y2024-day-10")]
+        (2024, 10) => json(crate::y2024::day_10::TopographicMap::parse(input)),
+        #[cfg(feature = "y2024-day-11")]
+        (2024, 11) => json(crate::y2024::day_11::parse_stones(input)),
+        #[cfg(feature = "y2024-day-12")]
+        (2024, 12) => json(crate::y2024::day_12::Garden::parse(input)),
+        #[cfg(feature = "y2024-day-13")]
+        (2024, 13) => json(crate::y2024::day_13::parse_machines(input)),
+        #[cfg(feature = "y2024-day-14")]
+        (2024, 14) => json(crate::y2024::day_14::parse_robots(input)),
+        #[cfg(feature = "y2024-day-15")]
+        (2024, 15) => json(crate::y2024::day_15::parse_input(input)),
+        #[cfg(feature = "y2024-day-16")]
+        (2024, 16) => json(crate::y2024::day_16::Maze::parse(input)),
+        #[cfg(feature = "y2024-day-17")]
+        (2024, 17) => json(input.parse::<crate::y2024::day_17::Computer>()),
+        #[cfg(feature = "y2024-day-18")]
+        (2024, 18) => json(crate::y2024::day_18::parse_bytes(input)),
+        #[cfg(feature = "y2024-day-19")]
+        (2024, 19) => json(crate::y2024::day_19::parse_input(input)),
+        #[cfg(feature = "y2024-day-20")]
+        (2024, 20) => json(crate::y2024::day_20::Racetrack::parse(input)),
+        #[cfg(feature = "y2024-day-21")]
+        (2024, 21) => json(infallible(crate::y2024::day_21::codes(input))),
+        #[cfg(feature = "y2024-day-22")]
+        (2024, 22) => json(crate::y2024::day_22::parse_secrets(input)),
+        #[cfg(feature = "y2024-day-23")]
+        (2024, 23) => json(crate::y2024::day_23::Network::parse(input)),
+        #[cfg(feature = "y2024-day-24")]
+        (2024, 24) => json(input.parse::<crate::y2024::day_24::Circuit>()),
+        #[cfg(feature = "y2024-day-25")]
+        (2024, 25) => json(crate::y2024::day_25::parse_schematics(input)),
+        #[cfg(feature = "y2023-day-1")]
+        (2023, 1) => json(crate::y2023::day_1::parse_calibration_digits(input)),
+        #[cfg(feature = "y2023-day-2")]
+        (2023, 2) => json(crate::y2023::day_2::parse_games(input)),
+        #[cfg(feature = "y2023-day-3")]
+        (2023, 3) => json(crate::y2023::day_3::Schematic::parse_borrowed(input)),
+        #[cfg(feature = "y2023-day-4")]
+        (2023, 4) => json(crate::y2023::day_4::Card::parse_all(input)),
+        #[cfg(feature = "y2023-day-5")]
+        (2023, 5) => json(input.parse::<crate::y2023::day_5::Almanac>()),
+        #[cfg(feature = "y2023-day-6")]
+        (2023, 6) => json(input.parse::<crate::y2023::day_6::Races>()),
+        #[cfg(feature = "y2023-day-7")]
+        (2023, 7) => json(crate::y2023::day_7::parse_games(
+            input,
+            crate::y2023::day_7::Jokers::Disallowed,
+        )),
+        #[cfg(feature = "y2023-day-8")]
+        (2023, 8) => json(input.parse::<crate::y2023::day_8::Network>()),
+        #[cfg(feature = "y2023-day-9")]
+        (2023, 9) => json(crate::y2023::day_9::parse_histories(input)),
+        #[cfg(feature = "y2023-day-10")]
+        (2023, 10) => json(input.parse::<crate::y2023::day_10::Map>()),
+        #[cfg(feature = "y2023-day-11")]
+        (2023, 11) => json(infallible(crate::y2023::day_11::expanded_galaxies(
+            input, 1,
+        ))),
+        #[cfg(feature = "y2023-day-19")]
+        (2023, 19) => json(crate::y2023::day_19::parse_input(input)),
+        #[cfg(feature = "y2023-day-20")]
+        (2023, 20) => json(input.parse::<crate::y2023::day_20::Network>()),
+        #[cfg(feature = "y2023-day-21")]
+        (2023, 21) => json(input.parse::<crate::y2023::day_21::Garden>()),
+        #[cfg(feature = "y2023-day-22")]
+        (2023, 22) => json(input.parse::<crate::y2023::day_22::Stack>()),
+        #[cfg(feature = "y2023-day-23")]
+        (2023, 23) => json(input.parse::<crate::y2023::day_23::TrailMap>()),
+        #[cfg(feature = "y2023-day-24")]
+        (2023, 24) => json(crate::y2023::day_24::parse_hailstones(input)),
+        #[cfg(feature = "y2023-day-25")]
+        (2023, 25) => json(crate::y2023::day_25::Components::parse(input)),
+        #[cfg(feature = "y2015-day-1")]
+        (2015, 1) => json(infallible(crate::y2015::day_1::floors(input))),
+        #[cfg(feature = "y2015-day-2")]
+        (2015, 2) => json(crate::y2015::day_2::parse_presents(input)),
+        #[cfg(feature = "y2015-day-3")]
+        (2015, 3) => json(infallible(crate::y2015::day_3::parse_moves(input))),
+        #[cfg(feature = "y2015-day-4")]
+        (2015, 4) => json(infallible(crate::y2015::day_4::secret_key(input))),
+        #[cfg(feature = "y2015-day-5")]
+        (2015, 5) => json(infallible(crate::y2015::day_5::strings(input))),
+        _ => Err(SolveError::UnknownDay { year, day }),
+    }
+}
+
+/// Renders a parsed input as JSON.
+#[allow(dead_code)] // unused with no day enabled
+fn json<T: Serialize, E: Display>(parsed: Result<T, E>) -> Result<String, SolveError> {
+    let parsed = parsed.map_err(|e| SolveError::InvalidInput(e.to_string()))?;
+    Ok(serde_json::to_string(&parsed).expect("parsed inputs are valid JSON"))
+}
+
+/// Wraps the result of a parser that can't fail.
+#[allow(dead_code)] // unused with no day enabled
+fn infallible<T>(parsed: T) -> Result<T, Infallible> {
+    Ok(parsed)
+}
@@ -0,0 +1,21 @@
+//! Rendering the solutions of days with a visualization as SVG documents.
+
+/// Renders a day's solution of the input as an SVG document.
+///
+/// Returns `None` if the day has no rendering, or its feature isn't enabled.
+///
+/// ```
+/// # #[cfg(feature = "y2023-day-11")]
+/// assert!(aoc::render_svg(2023, 11, "#.\n.#").is_some_and(|svg| svg.starts_with("<svg")));
+/// assert_eq!(aoc::render_svg(2023, 12, ""), None);
+/// ```
+#[allow(unused_variables)] // with no day enabled, only the date is looked at
+pub fn render_svg(year: u16, day: u8, input: &str) -> Option<String> {
+    match (year, day) {
+        #[cfg(feature = "y2023-day-10")]
+        (2023, 10) => Some(crate::y2023::day_10::trace_loop(input).to_svg()),
+        #[cfg(feature = "y2023-day-11")]
+        (2023, 11) => Some(crate::y2023::day_11::GalaxyMap::expanded(input, 2).to_svg()),
+        _ => None,
+    }
+}
@@ -0,0 +1,747 @@
+//! Solving any enabled day by its date, with the answer rendered as text.
+//!
+//! ```
+//! use aoc::{solve, Part};
+//!
+//! # #[cfg(feature = "y2024-day-1")]
+//! assert_eq!(solve(2024, 1, Part::One, "3 4\n4 3\n2 5\n1 3\n3 9\n3 3"), Ok("11".into()));
+//! ```
+
+use aoc_utils::{trace, PuzzleMeta};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+/// A part of a day's puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// The puzzles of all enabled days, newest year first.
+pub const PUZZLES: &[PuzzleMeta] = &[
+    #[cfg(feature = "y2024-day-1")]
+    crate::y2024::day_1::PUZZLE,
+    #[cfg(feature = "y2024-day-5")]
+    crate::y2024::day_5::PUZZLE,
+    #[cfg(feature = "y2024-day-6")]
+    crate::y2024::day_6::PUZZLE,
+    #[cfg(feature = "y2024-day-7")]
+    crate::y2024::day_7::PUZZLE,
+    #[cfg(feature = "y2024-day-8")]
+    crate::y2024::day_8::PUZZLE,
+    #[cfg(feature = "y2024-day-9")]
+    crate::y2024::day_9::PUZZLE,
+    #[cfg(feature = "y2024-day-10")]
+    crate::y2024::day_10::PUZZLE,
+    #[cfg(feature = "y2024-day-11")]
+    crate::y2024::day_11::PUZZLE,
+    #[cfg(feature = "y2024-day-12")]
+    crate::y2024::day_12::PUZZLE,
+    #[cfg(feature = "y2024-day-13")]
+    crate::y2024::day_13::PUZZLE,
+    #[cfg(feature = "y2024-day-14")]
+    crate::y2024::day_14::PUZZLE,
+    #[cfg(feature = "y2024-day-15")]
+    crate::y2024::day_15::PUZZLE,
+    #[cfg(feature = "y2024-day-16")]
+    crate::y2024::day_16::PUZZLE,
+    #[cfg(feature = "y2024-day-17")]
+    crate::y2024::day_17::PUZZLE,
+    #[cfg(feature = "y2024-day-18")]
+    crate::y2024::day_18::PUZZLE,
+    #[cfg(feature = "y2024-day-19")]
+    crate::y2024::day_19::PUZZLE,
+    #[cfg(feature = "y2024-day-20")]
+    crate::y2024::day_20::PUZZLE,
+    #[cfg(feature = "y2024-day-21")]
+    crate::y2024::day_21::PUZZLE,
+    #[cfg(feature = "y2024-day-22")]
+    crate::y2024::day_22::PUZZLE,
+    #[cfg(feature = "y2024-day-23")]
+    crate::y2024::day_23::PUZZLE,
+    #[cfg(feature = "y2024-day-24")]
+    crate::y2024::day_24::PUZZLE,
+    #[cfg(feature = "y2024-day-25")]
+    crate::y2024::day_25::PUZZLE,
+    #[cfg(feature = "y2023-day-1")]
+    crate::y2023::day_1::PUZZLE,
+    #[cfg(feature = "y2023-day-2")]
+    crate::y2023::day_2::PUZZLE,
+    #[cfg(feature = "y2023-day-3")]
+    crate::y2023::day_3::PUZZLE,
+    #[cfg(feature = "y2023-day-4")]
+    crate::y2023::day_4::PUZZLE,
+    #[cfg(feature = "y2023-day-5")]
+    crate::y2023::day_5::PUZZLE,
+    #[cfg(feature = "y2023-day-6")]
+    crate::y2023::day_6::PUZZLE,
+    #[cfg(feature = "y2023-day-7")]
+    crate::y2023::day_7::PUZZLE,
+    #[cfg(feature = "y2023-day-8")]
+    crate::y2023::day_8::PUZZLE,
+    #[cfg(feature = "y2023-day-9")]
+    crate::y2023::day_9::PUZZLE,
+    #[cfg(feature = "y2023-day-10")]
+    crate::y2023::day_10::PUZZLE,
+    #[cfg(feature = "y2023-day-11")]
+    crate::y2023::day_11::PUZZLE,
+    #[cfg(feature = "y2023-day-19")]
+    crate::y2023::day_19::PUZZLE,
+    #[cfg(feature = "y2023-day-20")]
+    crate::y2023::day_20::PUZZLE,
+    #[cfg(feature = "y2023-day-21")]
+    crate::y2023::day_21::PUZZLE,
+    #[cfg(feature = "y2023-day-22")]
+    crate::y2023::day_22::PUZZLE,
+    #[cfg(feature = "y2023-day-23")]
+    crate::y2023::day_23::PUZZLE,
+    #[cfg(feature = "y2023-day-24")]
+    crate::y2023::day_24::PUZZLE,
+    #[cfg(feature = "y2023-day-25")]
+    crate::y2023::day_25::PUZZLE,
+    #[cfg(feature = "y2015-day-1")]
+    crate::y2015::day_1::PUZZLE,
+    #[cfg(feature = "y2015-day-2")]
+    crate::y2015::day_2::PUZZLE,
+    #[cfg(feature = "y2015-day-3")]
+    crate::y2015::day_3::PUZZLE,
+    #[cfg(feature = "y2015-day-4")]
+    crate::y2015::day_4::PUZZLE,
+    #[cfg(feature = "y2015-day-5")]
+    crate::y2015::day_5::PUZZLE,
+];
+
+/// Solves a part of a day's puzzle.
+///
+/// The days are solved with the parameters of the actual puzzle, e.g. the 71×71 memory space
+/// of 2024 day 18 rather than the smaller one of its example. Like the example binaries, some
+/// days panic on malformed input instead of reporting it.
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Result<String, SolveError> {
+    let _span = enter(year, day, part);
+    dispatch(year, day, part, input)
+}
+
+/// Solves a part of a day's puzzle within the span entered by the caller.
+#[allow(unused_variables)] // with no day enabled, only the date is looked at
+fn dispatch(year: u16, day: u8, part: Part, input: &str) -> Result<String, SolveError> {
+    match (year, day) {
+        #[cfg(feature = "y2024-day-1")]
+        (2024, 1) => {
+            use crate::y2024::day_1::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-5")]
+        (2024, 5) => {
+            use crate::y2024::day_5::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-6")]
+        (2024, 6) => {
+            use crate::y2024::day_6::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-7")]
+        (2024, 7) => {
+            use crate::y2024::day_7::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-8")]
+        (2024, 8) => {
+            use crate::y2024::day_8::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-9")]
+        (2024, 9) => {
+            use crate::y2024::day_9::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-10")]
+        (2024, 10) => {
+            use crate::y2024::day_10::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-11")]
+        (2024, 11) => {
+            use crate::y2024::day_11::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-12")]
+        (2024, 12) => {
+            use crate::y2024::day_12::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-13")]
+        (2024, 13) => {
+            use crate::y2024::day_13::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-14")]
+        (2024, 14) => {
+            use crate::y2024::day_14::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, WIDTH, HEIGHT),
+                |input| second_part(input, WIDTH, HEIGHT),
+            )
+        }
+        #[cfg(feature = "y2024-day-15")]
+        (2024, 15) => {
+            use crate::y2024::day_15::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-16")]
+        (2024, 16) => {
+            use crate::y2024::day_16::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-17")]
+        (2024, 17) => {
+            use crate::y2024::day_17::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-18")]
+        (2024, 18) => {
+            use crate::y2024::day_18::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, SIZE, BYTES),
+                |input| second_part(input, SIZE),
+            )
+        }
+        #[cfg(feature = "y2024-day-19")]
+        (2024, 19) => {
+            use crate::y2024::day_19::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-20")]
+        (2024, 20) => {
+            use crate::y2024::day_20::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, MIN_SAVING),
+                |input| second_part(input, MIN_SAVING),
+            )
+        }
+        #[cfg(feature = "y2024-day-21")]
+        (2024, 21) => {
+            use crate::y2024::day_21::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-22")]
+        (2024, 22) => {
+            use crate::y2024::day_22::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-23")]
+        (2024, 23) => {
+            use crate::y2024::day_23::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-24")]
+        (2024, 24) => {
+            use crate::y2024::day_24::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2024-day-25")]
+        (2024, 25) => {
+            use crate::y2024::day_25::*;
+            only(Part::One, part, input, first_part)
+        }
+        #[cfg(feature = "y2023-day-1")]
+        (2023, 1) => {
+            use crate::y2023::day_1::*;
+            both(
+                part,
+                input,
+                sum_numeral_calibration_values,
+                sum_calibration_values,
+            )
+        }
+        #[cfg(feature = "y2023-day-2")]
+        (2023, 2) => {
+            use crate::y2023::day_2::*;
+            const GIVEN: SetOfCubes = SetOfCubes::rgb(12, 13, 14);
+            both(
+                part,
+                parse_games(input),
+                |games| {
+                    games.map(|games| {
+                        Game::filter_playable_games(games.iter(), &GIVEN)
+                            .map(Game::game_number)
+                            .sum::<u32>()
+                    })
+                },
+                |games| {
+                    games.map(|games| {
+                        games
+                            .iter()
+                            .map(|game| game.smallest_set_needed().power())
+                            .sum::<u32>()
+                    })
+                },
+            )
+        }
+        #[cfg(feature = "y2023-day-3")]
+        (2023, 3) => {
+            use crate::y2023::day_3::*;
+            both(
+                part,
+                Schematic::parse_borrowed(input),
+                |schematic| schematic.map(|schematic| schematic.sum_valid_parts()),
+                |schematic| schematic.map(|schematic| schematic.sum_gear_ratios()),
+            )
+        }
+        #[cfg(feature = "y2023-day-4")]
+        (2023, 4) => {
+            use crate::y2023::day_4::*;
+            both(
+                part,
+                Card::parse_all(input),
+                |cards| cards.map(|cards| Card::sum_all_scores(&cards)),
+                |cards| cards.map(Card::count_copied_cards),
+            )
+        }
+        #[cfg(feature = "y2023-day-5")]
+        (2023, 5) => {
+            use crate::y2023::day_5::*;
+            let location = |(_, location)| u64::from(location);
+            both(
+                part,
+                input.parse::<Almanac>(),
+                |almanac| almanac.map(|almanac| almanac.map_smallest_from_seeds().map(location)),
+                |almanac| {
+                    almanac.map(|almanac| almanac.map_smallest_from_seed_ranges().map(location))
+                },
+            )
+        }
+        #[cfg(feature = "y2023-day-6")]
+        (2023, 6) => {
+            use crate::y2023::day_6::*;
+            both(
+                part,
+                input,
+                product_of_winning_conditions_with_spaces,
+                product_of_winning_conditions_without_spaces,
+            )
+        }
+        #[cfg(feature = "y2023-day-7")]
+        (2023, 7) => {
+            use crate::y2023::day_7::*;
+            both(
+                part,
+                input,
+                |input| total_winnings(input, Jokers::Disallowed),
+                |input| total_winnings(input, Jokers::Allowed),
+            )
+        }
+        #[cfg(feature = "y2023-day-8")]
+        (2023, 8) => {
+            use crate::y2023::day_8::*;
+            both(
+                part,
+                input,
+                count_steps_to_destination,
+                count_ghost_steps_to_destination,
+            )
+        }
+        #[cfg(feature = "y2023-day-9")]
+        (2023, 9) => {
+            use crate::y2023::day_9::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-10")]
+        (2023, 10) => {
+            use crate::y2023::day_10::*;
+            both(part, input, part1, |input| part2(input, false))
+        }
+        #[cfg(feature = "y2023-day-11")]
+        (2023, 11) => {
+            use crate::y2023::day_11::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-19")]
+        (2023, 19) => {
+            use crate::y2023::day_19::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-20")]
+        (2023, 20) => {
+            use crate::y2023::day_20::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-21")]
+        (2023, 21) => {
+            use crate::y2023::day_21::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-22")]
+        (2023, 22) => {
+            use crate::y2023::day_22::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-23")]
+        (2023, 23) => {
+            use crate::y2023::day_23::*;
+            both(part, input, part1, part2)
+        }
+        #[cfg(feature = "y2023-day-24")]
+        (2023, 24) => {
+            use crate::y2023::day_24::*;
+            both(
+                part,
+                input,
+                |input| part1(input, TEST_AREA_MIN, TEST_AREA_MAX),
+                part2,
+            )
+        }
+        #[cfg(feature = "y2023-day-25")]
+        (2023, 25) => {
+            use crate::y2023::day_25::*;
+            only(Part::One, part, input, part1)
+        }
+        #[cfg(feature = "y2015-day-1")]
+        (2015, 1) => {
+            use crate::y2015::day_1::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2015-day-2")]
+        (2015, 2) => {
+            use crate::y2015::day_2::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2015-day-3")]
+        (2015, 3) => {
+            use crate::y2015::day_3::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2015-day-4")]
+        (2015, 4) => {
+            use crate::y2015::day_4::*;
+            both(part, input, first_part, second_part)
+        }
+        #[cfg(feature = "y2015-day-5")]
+        (2015, 5) => {
+            use crate::y2015::day_5::*;
+            both(part, input, first_part, second_part)
+        }
+        _ => Err(SolveError::UnknownDay { year, day }),
+    }
+}
+
+/// Solves a part of a day's puzzle with the parameters of its example.
+///
+/// Some days are parameterized by more than their input, such as the 11×7 room of the
+/// 2024 day 14 example instead of the real 101×103 one. These are solved with the example's
+/// parameters, so that the answers match the puzzle description; all other days are solved
+/// with [`solve`].
+#[allow(unused_variables)]
+pub fn solve_example(year: u16, day: u8, part: Part, input: &str) -> Result<String, SolveError> {
+    let _span = enter(year, day, part);
+    match (year, day) {
+        #[cfg(feature = "y2024-day-14")]
+        (2024, 14) => {
+            use crate::y2024::day_14::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, 11, 7),
+                |input| second_part(input, 11, 7),
+            )
+        }
+        #[cfg(feature = "y2024-day-18")]
+        (2024, 18) => {
+            use crate::y2024::day_18::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, 7, 12),
+                |input| second_part(input, 7),
+            )
+        }
+        #[cfg(feature = "y2024-day-20")]
+        (2024, 20) => {
+            // Part 1 counts every cheat, part 2 those saving at least 50 picoseconds.
+            use crate::y2024::day_20::*;
+            both(
+                part,
+                input,
+                |input| first_part(input, 1),
+                |input| second_part(input, 50),
+            )
+        }
+        #[cfg(feature = "y2023-day-21")]
+        (2023, 21) => {
+            use crate::y2023::day_21::*;
+            both(part, input, |input| reachable_after(input, 6), part2)
+        }
+        #[cfg(feature = "y2023-day-24")]
+        (2023, 24) => {
+            use crate::y2023::day_24::*;
+            both(part, input, |input| part1(input, 7, 27), part2)
+        }
+        _ => dispatch(year, day, part, input),
+    }
+}
+
+/// Solves a part of a day's puzzle, reading its input from the reader.
+///
+/// Days whose solutions only look at one line at a time stream the input, so it never needs to
+/// be resident at once; all other days read it into memory and are solved with [`solve`].
+pub fn solve_reader<R: BufRead>(
+    year: u16,
+    day: u8,
+    part: Part,
+    mut reader: R,
+) -> Result<String, SolveError> {
+    let _span = enter(year, day, part);
+    match (year, day) {
+        #[cfg(feature = "y2024-day-1")]
+        (2024, 1) => {
+            use crate::y2024::day_1::*;
+            both(
+                part,
+                reader,
+                first_part_from_reader,
+                second_part_from_reader,
+            )
+        }
+        #[cfg(feature = "y2023-day-1")]
+        (2023, 1) if part == Part::Two => {
+            use crate::y2023::day_1::*;
+            sum_calibration_values_from_reader(reader).into_answer()
+        }
+        #[cfg(feature = "y2023-day-4")]
+        (2023, 4) => {
+            use crate::y2023::day_4::*;
+            both(
+                part,
+                reader,
+                Card::sum_scores_from_reader,
+                Card::count_copied_cards_from_reader,
+            )
+        }
+        #[cfg(feature = "y2023-day-9")]
+        (2023, 9) => {
+            use crate::y2023::day_9::*;
+            both(part, reader, part1_from_reader, part2_from_reader)
+        }
+        _ => {
+            let mut input = String::new();
+            reader
+                .read_to_string(&mut input)
+                .map_err(|e| SolveError::InvalidInput(e.to_string()))?;
+            dispatch(year, day, part, &input)
+        }
+    }
+}
+
+/// Enters the span of solving a part, named `part1` or `part2`, with the `tracing` feature.
+fn enter(year: u16, day: u8, part: Part) -> trace::Entered {
+    let number = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    trace::part(year, day, number)
+}
+
+/// Solves the part of a day that has solutions for both parts.
+#[allow(dead_code)] // unused with no day enabled
+fn both<I, A, B>(
+    part: Part,
+    input: I,
+    one: impl FnOnce(I) -> A,
+    two: impl FnOnce(I) -> B,
+) -> Result<String, SolveError>
+where
+    A: Answer,
+    B: Answer,
+{
+    match part {
+        Part::One => one(input).into_answer(),
+        Part::Two => two(input).into_answer(),
+    }
+}
+
+/// Solves the part of a day that only has a solution for one part.
+#[allow(dead_code)]
+fn only<I, A: Answer>(
+    solved: Part,
+    part: Part,
+    input: I,
+    solve: impl FnOnce(I) -> A,
+) -> Result<String, SolveError> {
+    if part != solved {
+        return Err(SolveError::MissingPart(part));
+    }
+    solve(input).into_answer()
+}
+
+/// A value produced by a solution that can be turned into an answer.
+#[allow(dead_code)]
+trait Answer {
+    fn into_answer(self) -> Result<String, SolveError>;
+}
+
+macro_rules! impl_answer {
+    ($($type:ty),*) => {
+        $(
+            impl Answer for $type {
+                fn into_answer(self) -> Result<String, SolveError> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(u32, u64, u128, usize, i64, i128, String, aoc_utils::Point);
+
+impl<T: Answer> Answer for Option<T> {
+    fn into_answer(self) -> Result<String, SolveError> {
+        self.ok_or(SolveError::NoAnswer)?.into_answer()
+    }
+}
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<String, SolveError> {
+        self.map_err(|e| SolveError::InvalidInput(e.to_string()))?
+            .into_answer()
+    }
+}
+
+/// An error returned by [`solve`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolveError {
+    /// The day isn't solved, or its feature isn't enabled.
+    UnknownDay { year: u16, day: u8 },
+    /// The day has no solution for the part.
+    MissingPart(Part),
+    /// The number doesn't denote a part.
+    InvalidPart(u8),
+    /// The input couldn't be parsed.
+    InvalidInput(String),
+    /// The puzzle has no answer for the input.
+    NoAnswer,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownDay { year, day } => write!(f, "No solution for {year} day {day}"),
+            SolveError::MissingPart(part) => write!(f, "No solution for part {part}"),
+            SolveError::InvalidPart(part) => write!(f, "There is no part {part}"),
+            SolveError::InvalidInput(error) => write!(f, "Invalid input: {error}"),
+            SolveError::NoAnswer => write!(f, "The puzzle has no answer for the input"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = SolveError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(SolveError::InvalidPart(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "y2023-day-9")]
+    fn test_solve() {
+        assert!(PUZZLES
+            .iter()
+            .any(|puzzle| (puzzle.year, puzzle.day) == (2023, 9)));
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        assert_eq!(solve(2023, 9, Part::One, input), Ok("114".into()));
+        assert_eq!(solve(2023, 9, Part::Two, input), Ok("2".into()));
+        assert_eq!(
+            solve_reader(2023, 9, Part::One, input.as_bytes()),
+            Ok("114".into())
+        );
+        assert_eq!(solve_example(2023, 9, Part::Two, input), Ok("2".into()));
+    }
+
+    #[test]
+    #[cfg(feature = "y2024-day-18")]
+    fn test_solve_example() {
+        let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2";
+        assert_eq!(solve_example(2024, 18, Part::One, input), Ok("22".into()));
+        assert_ne!(solve(2024, 18, Part::One, input), Ok("22".into()));
+    }
+
+    #[test]
+    fn test_real_answers() {
+        for puzzle in PUZZLES {
+            let Some(answers) = puzzle.real_answers else {
+                continue;
+            };
+            let path = format!(
+                "{}/../{}/day-{}/input.txt",
+                env!("CARGO_MANIFEST_DIR"),
+                puzzle.year,
+                puzzle.day
+            );
+            let input = std::fs::read_to_string(path).expect("failed to read the input");
+            for (part, answer) in [Part::One, Part::Two].into_iter().zip(answers) {
+                let Some(answer) = answer else {
+                    continue;
+                };
+                let solved = solve(puzzle.year, puzzle.day, part, &input);
+                assert_eq!(solved.as_deref(), Ok(answer), "{puzzle}, part {part}");
+                let streamed = solve_reader(puzzle.year, puzzle.day, part, input.as_bytes());
+                assert_eq!(streamed.as_deref(), Ok(answer), "{puzzle}, part {part}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert_eq!(
+            solve(2023, 12, Part::One, ""),
+            Err(SolveError::UnknownDay {
+                year: 2023,
+                day: 12
+            })
+        );
+        #[cfg(feature = "y2023-day-25")]
+        assert_eq!(
+            solve(2023, 25, Part::Two, ""),
+            Err(SolveError::MissingPart(Part::Two))
+        );
+        #[cfg(feature = "y2023-day-4")]
+        assert!(matches!(
+            solve(2023, 4, Part::One, "Card 1: x | 2"),
+            Err(SolveError::InvalidInput(_))
+        ));
+        assert_eq!(Part::try_from(3), Err(SolveError::InvalidPart(3)));
+        assert_eq!(
+            solve_reader(2023, 12, Part::One, &[0xff][..]),
+            Err(SolveError::InvalidInput(
+                "stream did not contain valid UTF-8".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Bindings for running the solutions in a web page, enabled by the `wasm` feature.
+//!
+//! ```shell
+//! wasm-pack build aoc --target web --features wasm
+//! ```
+//!
+//! The generated module exports `part1` and `part2`, taking the year, the day and the puzzle
+//! input and returning the answer as a string; failures are thrown as JavaScript errors:
+//!
+//! ```js
+//! import init, { part1 } from "./pkg/aoc.js";
+//!
+//! await init();
+//! console.log(part1(2024, 1, input));
+//! ```
+
+use crate::{solve, Part};
+use wasm_bindgen::prelude::*;
+
+/// Solves part one of a day's puzzle.
+#[wasm_bindgen]
+pub fn part1(year: u16, day: u8, input: &str) -> Result<String, JsError> {
+    Ok(solve(year, day, Part::One, input)?)
+}
+
+/// Solves part two of a day's puzzle.
+#[wasm_bindgen]
+pub fn part2(year: u16, day: u8, input: &str) -> Result<String, JsError> {
+    Ok(solve(year, day, Part::Two, input)?)
+}
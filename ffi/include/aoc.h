@@ -0,0 +1,30 @@
+/* C declarations for the aoc-ffi library. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define AOC_OK 0
+#define AOC_UNKNOWN_DAY 1
+#define AOC_UNKNOWN_PART 2
+#define AOC_INVALID_INPUT 3
+#define AOC_NO_ANSWER 4
+#define AOC_BUFFER_TOO_SMALL 5
+#define AOC_NULL_POINTER 6
+#define AOC_PANIC 7
+
+/* A caller-owned buffer receiving the answer, which is not null-terminated. */
+typedef struct AocBuffer {
+    uint8_t *data;
+    size_t capacity;
+    size_t len;
+} AocBuffer;
+
+/* Solves part 1 or 2 of a day's puzzle and writes the answer to out_buf.
+ * On AOC_BUFFER_TOO_SMALL, out_buf->len holds the required capacity. */
+int32_t aoc_solve(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input, size_t len,
+                  AocBuffer *out_buf);
+
+#endif
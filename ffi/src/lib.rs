@@ -0,0 +1,125 @@
+//! C-compatible entry points for the Advent of Code solutions, e.g. for benchmarking
+//! harnesses written in other languages. The matching declarations are in `include/aoc.h`.
+
+use aoc::{Part, SolveError};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The answer was written to the output buffer.
+pub const AOC_OK: i32 = 0;
+/// The day isn't solved, or wasn't enabled when building the library.
+pub const AOC_UNKNOWN_DAY: i32 = 1;
+/// The part isn't `1` or `2`, or the day has no solution for it.
+pub const AOC_UNKNOWN_PART: i32 = 2;
+/// The input isn't valid UTF-8 or couldn't be parsed.
+pub const AOC_INVALID_INPUT: i32 = 3;
+/// The puzzle has no answer for the input.
+pub const AOC_NO_ANSWER: i32 = 4;
+/// The output buffer is too small; its `len` is set to the required capacity.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 5;
+/// A required pointer was null.
+pub const AOC_NULL_POINTER: i32 = 6;
+/// The solution panicked, typically on malformed input.
+pub const AOC_PANIC: i32 = 7;
+
+/// A caller-owned buffer receiving the answer.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocBuffer {
+    /// The start of the buffer.
+    pub data: *mut u8,
+    /// The capacity of the buffer in bytes.
+    pub capacity: usize,
+    /// The length of the answer in bytes, set by [`aoc_solve`]. The answer is not
+    /// null-terminated.
+    pub len: usize,
+}
+
+/// Solves part `1` or `2` of a day's puzzle and writes the answer to `out_buf`.
+///
+/// Returns [`AOC_OK`] on success, or one of the other `AOC_*` status codes.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes and `out_buf` to a valid [`AocBuffer`]
+/// whose `data` points to `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut AocBuffer,
+) -> i32 {
+    if input.is_null() || out_buf.is_null() {
+        return AOC_NULL_POINTER;
+    }
+
+    let input = std::slice::from_raw_parts(input, len);
+    let out_buf = &mut *out_buf;
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AOC_INVALID_INPUT;
+    };
+    let Ok(part) = Part::try_from(part) else {
+        return AOC_UNKNOWN_PART;
+    };
+
+    // Unwinding into the caller is undefined behavior, so panics become a status code.
+    let answer = match catch_unwind(AssertUnwindSafe(|| aoc::solve(year, day, part, input))) {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(error)) => return status(&error),
+        Err(_) => return AOC_PANIC,
+    };
+
+    out_buf.len = answer.len();
+    if answer.len() > out_buf.capacity {
+        return AOC_BUFFER_TOO_SMALL;
+    }
+    if out_buf.data.is_null() {
+        return AOC_NULL_POINTER;
+    }
+
+    std::ptr::copy_nonoverlapping(answer.as_ptr(), out_buf.data, answer.len());
+    AOC_OK
+}
+
+/// Maps a solver error to its status code.
+fn status(error: &SolveError) -> i32 {
+    match error {
+        SolveError::UnknownDay { .. } => AOC_UNKNOWN_DAY,
+        SolveError::MissingPart(_) | SolveError::InvalidPart(_) => AOC_UNKNOWN_PART,
+        SolveError::InvalidInput(_) => AOC_INVALID_INPUT,
+        SolveError::NoAnswer => AOC_NO_ANSWER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(year: u16, day: u8, part: u8, input: &str, capacity: usize) -> (i32, String) {
+        let mut data = vec![0; capacity];
+        let mut out = AocBuffer {
+            data: data.as_mut_ptr(),
+            capacity,
+            len: 0,
+        };
+        let status = unsafe { aoc_solve(year, day, part, input.as_ptr(), input.len(), &mut out) };
+        let written = out.len.min(capacity);
+        (
+            status,
+            String::from_utf8_lossy(&data[..written]).into_owned(),
+        )
+    }
+
+    #[test]
+    fn test_aoc_solve() {
+        const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        assert_eq!(solve(2023, 9, 1, INPUT, 16), (AOC_OK, "114".into()));
+        assert_eq!(solve(2023, 9, 2, INPUT, 16), (AOC_OK, "2".into()));
+        assert_eq!(solve(2023, 9, 1, INPUT, 2).0, AOC_BUFFER_TOO_SMALL);
+        assert_eq!(solve(2023, 12, 1, INPUT, 16).0, AOC_UNKNOWN_DAY);
+        assert_eq!(solve(2023, 9, 3, INPUT, 16).0, AOC_UNKNOWN_PART);
+        assert_eq!(solve(2023, 9, 1, "0 3 x", 16).0, AOC_PANIC);
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    let _ = aoc_2023_day_10::Map::from_str(input);
+});
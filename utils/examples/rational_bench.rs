@@ -0,0 +1,45 @@
+//! Times `Rational` against `num_rational::Ratio<i128>` on the same random operations.
+//!
+//! ```shell
+//! cargo run -p aoc-utils --release --features num-rational --example rational-bench
+//! ```
+
+use aoc_utils::example::timed;
+use aoc_utils::property::Rng;
+use aoc_utils::Rational;
+use num_rational::Ratio;
+use std::hint::black_box;
+use std::ops::{Add, Mul, Sub};
+
+const PAIRS: usize = 1_000_000;
+
+type Fraction = (i128, i128);
+
+fn main() {
+    let mut rng = Rng::new(2023);
+    let mut fraction = || (rng.signed(-1000..1000) as i128, rng.signed(1..1000) as i128);
+    let pairs: Vec<(Fraction, Fraction)> = (0..PAIRS).map(|_| (fraction(), fraction())).collect();
+
+    let (ours, ours_elapsed) = timed("", |_| count(&pairs, Rational::new));
+    let (theirs, theirs_elapsed) = timed("", |_| count(&pairs, Ratio::new));
+
+    assert_eq!(ours, theirs, "the results differ");
+    println!("{PAIRS} pairs, {ours} matching");
+    println!("Rational:    {ours_elapsed:.2?}");
+    println!("Ratio<i128>: {theirs_elapsed:.2?}");
+}
+
+/// Creates, combines and compares the fractions of each pair, counting the pairs `(a, b)` for
+/// which `a + b > a · b - b`.
+fn count<T>(pairs: &[(Fraction, Fraction)], new: fn(i128, i128) -> T) -> usize
+where
+    T: Copy + Ord + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    pairs
+        .iter()
+        .filter(|&&(a, b)| {
+            let (a, b) = black_box((new(a.0, a.1), new(b.0, b.1)));
+            a + b > a * b - b
+        })
+        .count()
+}
@@ -0,0 +1,299 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+
+/// The number of cells stored per word.
+const BITS: usize = u64::BITS as usize;
+
+/// A fixed-size grid of boolean cells, storing one bit per cell.
+///
+/// Each row is stored as a separate run of words, so whole rows can be combined,
+/// shifted and counted a word at a time. The bits beyond the width of a row are always
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::BitGrid;
+///
+/// let mut grid = BitGrid::new(100, 2);
+/// grid.set(3, 0, true);
+/// grid.set(70, 0, true);
+/// grid.shift_row_right(0, 1);
+/// assert!(grid.get(4, 0) && grid.get(71, 0));
+///
+/// grid.or_row(1, &grid.clone(), 0);
+/// assert_eq!(grid.count_ones(), 4);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Creates a grid with all cells cleared.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the value of a cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell lies outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let (index, mask) = self.locate(x, y);
+        self.words[index] & mask != 0
+    }
+
+    /// Sets the value of a cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell lies outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        let (index, mask) = self.locate(x, y);
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+    }
+
+    /// Sets a cell and returns `true` if it was previously cleared.
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        let (index, mask) = self.locate(x, y);
+        let inserted = self.words[index] & mask == 0;
+        self.words[index] |= mask;
+        inserted
+    }
+
+    /// Clears all cells.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Counts the set cells of the grid.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Counts the set cells of a row.
+    pub fn count_ones_in_row(&self, y: usize) -> usize {
+        self.row(y)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Sets every cell of the row `y` that is set in the row `source_y` of the other grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids have different widths.
+    pub fn or_row(&mut self, y: usize, other: &BitGrid, source_y: usize) {
+        assert_eq!(self.width, other.width, "grids have different widths");
+        for (word, source) in self.row_mut(y).iter_mut().zip(other.row(source_y)) {
+            *word |= source;
+        }
+    }
+
+    /// Clears every cell of the row `y` that is cleared in the row `source_y` of the other grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids have different widths.
+    pub fn and_row(&mut self, y: usize, other: &BitGrid, source_y: usize) {
+        assert_eq!(self.width, other.width, "grids have different widths");
+        for (word, source) in self.row_mut(y).iter_mut().zip(other.row(source_y)) {
+            *word &= source;
+        }
+    }
+
+    /// Sets every cell that is set in the other grid of the same size.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids have different sizes"
+        );
+        for (word, source) in self.words.iter_mut().zip(&other.words) {
+            *word |= source;
+        }
+    }
+
+    /// Clears every cell that is cleared in the other grid of the same size.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids have different sizes"
+        );
+        for (word, source) in self.words.iter_mut().zip(&other.words) {
+            *word &= source;
+        }
+    }
+
+    /// Moves the cells of a row towards higher `x` coordinates; cells moved past the width are dropped.
+    pub fn shift_row_right(&mut self, y: usize, amount: usize) {
+        let (words, bits) = (amount / BITS, amount % BITS);
+        let row = self.row_mut(y);
+        for i in (0..row.len()).rev() {
+            let low = i.checked_sub(words).map_or(0, |j| row[j]);
+            let carry = match i.checked_sub(words + 1) {
+                Some(j) if bits > 0 => row[j] >> (BITS - bits),
+                _ => 0,
+            };
+            row[i] = (low << bits) | carry;
+        }
+        self.mask_row(y);
+    }
+
+    /// Moves the cells of a row towards lower `x` coordinates; cells moved past zero are dropped.
+    pub fn shift_row_left(&mut self, y: usize, amount: usize) {
+        let (words, bits) = (amount / BITS, amount % BITS);
+        let row = self.row_mut(y);
+        let len = row.len();
+        for i in 0..len {
+            let high = row.get(i + words).copied().unwrap_or(0);
+            let carry = match row.get(i + words + 1) {
+                Some(&word) if bits > 0 => word << (BITS - bits),
+                _ => 0,
+            };
+            row[i] = (high >> bits) | carry;
+        }
+    }
+
+    /// Iterates the coordinates of all set cells, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| {
+            self.row(y).iter().enumerate().flat_map(move |(i, &word)| {
+                let mut word = word;
+                core::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some((i * BITS + bit, y))
+                })
+            })
+        })
+    }
+
+    fn locate(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "cell ({x}, {y}) lies outside the grid"
+        );
+        (y * self.words_per_row + x / BITS, 1 << (x % BITS))
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// Clears the padding bits beyond the width of a row.
+    fn mask_row(&mut self, y: usize) {
+        let used = self.width % BITS;
+        if used > 0 {
+            if let Some(last) = self.row_mut(y).last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+}
+
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "BitGrid {}x{}", self.width, self.height)?;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = BitGrid::new(130, 3);
+        assert!(grid.insert(129, 2));
+        assert!(!grid.insert(129, 2));
+        grid.set(64, 1, true);
+        grid.set(0, 0, true);
+        grid.set(0, 0, false);
+        assert_eq!(grid.iter_ones().collect::<Vec<_>>(), [(64, 1), (129, 2)]);
+        assert_eq!(grid.count_ones_in_row(1), 1);
+        assert_eq!(grid.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut grid = BitGrid::new(130, 1);
+        for x in [0, 63, 127, 129] {
+            grid.set(x, 0, true);
+        }
+
+        grid.shift_row_right(0, 65);
+        assert_eq!(grid.iter_ones().collect::<Vec<_>>(), [(65, 0), (128, 0)]);
+
+        grid.shift_row_left(0, 65);
+        assert_eq!(grid.iter_ones().collect::<Vec<_>>(), [(0, 0), (63, 0)]);
+
+        grid.shift_row_left(0, 1);
+        assert_eq!(grid.iter_ones().collect::<Vec<_>>(), [(62, 0)]);
+    }
+
+    #[test]
+    fn test_row_operations() {
+        let mut a = BitGrid::new(10, 2);
+        let mut b = BitGrid::new(10, 2);
+        a.set(1, 0, true);
+        a.set(2, 0, true);
+        b.set(2, 1, true);
+        b.set(3, 1, true);
+
+        let mut and = a.clone();
+        and.and_row(0, &b, 1);
+        assert_eq!(and.iter_ones().collect::<Vec<_>>(), [(2, 0)]);
+
+        a.or_row(0, &b, 1);
+        assert_eq!(a.count_ones_in_row(0), 3);
+
+        a.union_with(&b);
+        assert_eq!(a.count_ones(), 5);
+        a.intersect_with(&b);
+        assert_eq!(a, b);
+    }
+}
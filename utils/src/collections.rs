@@ -0,0 +1,25 @@
+//! Hash map and set aliases using the fast [`FxHasher`](crate::hash::FxHasher).
+//!
+//! Being type aliases, they need to be created with `default()` or `collect()` instead of `new()`.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc_utils::collections::{FastMap, FastSet};
+//!
+//! let mut map: FastMap<u32, &str> = FastMap::default();
+//! map.insert(1, "one");
+//! assert_eq!(map[&1], "one");
+//!
+//! let set: FastSet<u32> = (0..10).collect();
+//! assert!(set.contains(&5));
+//! ```
+
+use crate::hash::FxBuildHasher;
+use std::collections::{HashMap, HashSet};
+
+/// A [`HashMap`] using the [`FxHasher`](crate::hash::FxHasher).
+pub type FastMap<K, V> = HashMap<K, V, FxBuildHasher>;
+
+/// A [`HashSet`] using the [`FxHasher`](crate::hash::FxHasher).
+pub type FastSet<T> = HashSet<T, FxBuildHasher>;
@@ -0,0 +1,251 @@
+//! Counting and enumerating arrangements of items.
+//!
+//! The iterators yield each arrangement as a new `Vec` of cloned items, in lexicographic
+//! order of the items' positions in the input slice.
+
+use crate::OverflowError;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Calculates `n!`, failing if it doesn't fit into a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::factorial;
+///
+/// assert_eq!(factorial(0), Ok(1));
+/// assert_eq!(factorial(10), Ok(3_628_800));
+/// assert!(factorial(35).is_err());
+/// ```
+pub fn factorial(n: u32) -> Result<u128, OverflowError> {
+    (2..=u128::from(n)).try_fold(1u128, |product, factor| {
+        product.checked_mul(factor).ok_or(OverflowError)
+    })
+}
+
+/// Calculates the number of ways to choose `k` out of `n` items, failing if it doesn't fit
+/// into a `u128`.
+///
+/// The intermediate results never exceed the final one, so this only fails if the result
+/// itself is too large.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::binomial;
+///
+/// assert_eq!(binomial(5, 2), Ok(10));
+/// assert_eq!(binomial(2, 5), Ok(0));
+/// assert_eq!(binomial(100, 50), Ok(100_891_344_545_564_193_334_812_497_256));
+/// ```
+pub fn binomial(n: u64, k: u64) -> Result<u128, OverflowError> {
+    if k > n {
+        return Ok(0);
+    }
+
+    let (n, k) = (u128::from(n), u128::from(k.min(n - k)));
+    (0..k).try_fold(1u128, |result, i| {
+        // result · (n - i) is divisible by i + 1; cancel before multiplying to avoid overflow.
+        let divisor = gcd(result, i + 1);
+        let factor = (n - i) / ((i + 1) / divisor);
+        (result / divisor).checked_mul(factor).ok_or(OverflowError)
+    })
+}
+
+/// Iterates all orderings of the items.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::permutations;
+///
+/// let orders: Vec<_> = permutations(&['a', 'b', 'c']).collect();
+/// assert_eq!(orders.len(), 6);
+/// assert_eq!(orders[0], ['a', 'b', 'c']);
+/// assert_eq!(orders[1], ['a', 'c', 'b']);
+/// assert_eq!(orders[5], ['c', 'b', 'a']);
+/// ```
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<'_, T> {
+    Permutations {
+        items,
+        indices: Some((0..items.len()).collect()),
+    }
+}
+
+/// Iterates all ways to pick `k` of the items, keeping their order.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::combinations;
+///
+/// let pairs: Vec<_> = combinations(&[1, 2, 3], 2).collect();
+/// assert_eq!(pairs, [[1, 2], [1, 3], [2, 3]]);
+/// assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+/// ```
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (k <= items.len()).then(|| (0..k).collect()),
+    }
+}
+
+/// Iterates all sequences of `n` items, each of which may be any of the items.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::cartesian_power;
+///
+/// let operators: Vec<_> = cartesian_power(&['+', '*'], 2).collect();
+/// assert_eq!(operators, [['+', '+'], ['+', '*'], ['*', '+'], ['*', '*']]);
+/// ```
+pub fn cartesian_power<T: Clone>(items: &[T], n: usize) -> CartesianPower<'_, T> {
+    CartesianPower {
+        items,
+        indices: (n == 0 || !items.is_empty()).then(|| vec![0; n]),
+    }
+}
+
+/// An iterator over the orderings of items; see [`permutations`].
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    /// The positions of the next ordering, or `None` when done.
+    indices: Option<Vec<usize>>,
+}
+
+/// An iterator over the ways to pick a number of items; see [`combinations`].
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    /// The ascending positions of the next pick, or `None` when done.
+    indices: Option<Vec<usize>>,
+}
+
+/// An iterator over the sequences of items; see [`cartesian_power`].
+#[derive(Debug, Clone)]
+pub struct CartesianPower<'a, T> {
+    items: &'a [T],
+    /// The positions of the next sequence, or `None` when done.
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Iterator for Permutations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let current = pick(self.items, indices);
+
+        // Advance to the next ordering: find the last ascent, swap its start with the smallest
+        // larger position after it, and reverse the (descending) rest.
+        match indices.windows(2).rposition(|pair| pair[0] < pair[1]) {
+            Some(pivot) => {
+                let successor = indices
+                    .iter()
+                    .rposition(|&index| index > indices[pivot])
+                    .expect("the ascent has a larger position");
+                indices.swap(pivot, successor);
+                indices[pivot + 1..].reverse();
+            }
+            None => self.indices = None,
+        }
+        Some(current)
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let current = pick(self.items, indices);
+
+        // Advance the last position that hasn't reached its maximum, and reset the ones after it.
+        let (n, k) = (self.items.len(), indices.len());
+        match (0..k).rposition(|i| indices[i] != i + n - k) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(current)
+    }
+}
+
+impl<T: Clone> Iterator for CartesianPower<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let current = pick(self.items, indices);
+
+        // Count up like an odometer, with the last position turning fastest.
+        let wrapped = indices.iter_mut().rev().all(|index| {
+            *index += 1;
+            if *index < self.items.len() {
+                return false;
+            }
+            *index = 0;
+            true
+        });
+        if wrapped {
+            self.indices = None;
+        }
+        Some(current)
+    }
+}
+
+fn pick<T: Clone>(items: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&index| items[index].clone()).collect()
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_match() {
+        let items: Vec<u32> = (0..6).collect();
+        assert_eq!(permutations(&items).count() as u128, factorial(6).unwrap());
+        for k in 0..=7 {
+            let count = combinations(&items, k).count() as u128;
+            assert_eq!(count, binomial(6, k as u64).unwrap());
+        }
+        assert_eq!(cartesian_power(&items, 3).count(), 216);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let none: [u8; 0] = [];
+        assert_eq!(permutations(&none).collect::<Vec<_>>(), [Vec::<u8>::new()]);
+        assert_eq!(combinations(&none, 0).count(), 1);
+        assert_eq!(cartesian_power(&none, 0).count(), 1);
+        assert_eq!(cartesian_power(&none, 2).count(), 0);
+    }
+
+    #[test]
+    fn test_permutations_are_distinct_and_sorted() {
+        let orders: Vec<_> = permutations(&[1, 2, 3, 4]).collect();
+        assert!(orders.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_binomial_overflow() {
+        assert_eq!(binomial(200, 100), Err(OverflowError));
+        assert_eq!(binomial(200, 199), Ok(200));
+        assert_eq!(binomial(u64::MAX, 1), Ok(u128::from(u64::MAX)));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Point;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::vec::Vec;
+
+/// Maps values to dense indices by their rank among the distinct values.
+///
+/// Returns the distinct values in ascending order, and the index of each value in that list.
+/// This lets algorithms that need dense indices, such as grid searches, work on few but huge
+/// coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::compress;
+///
+/// let (values, index) = compress([1_000_000, -7, 42, 1_000_000]);
+/// assert_eq!(values, [-7, 42, 1_000_000]);
+/// assert_eq!(index[&42], 1);
+/// assert_eq!(values[index[&1_000_000]], 1_000_000);
+/// ```
+pub fn compress<T, I>(values: I) -> (Vec<T>, HashMap<T, usize>)
+where
+    T: Ord + Hash + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let mut values: Vec<T> = values.into_iter().collect();
+    values.sort_unstable();
+    values.dedup();
+    let index = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| (value.clone(), index))
+        .collect();
+    (values, index)
+}
+
+/// Compresses the `x` and `y` coordinates of the points separately; see [`compress`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{compress_2d, Point};
+///
+/// let compressed = compress_2d([Point::new(0, 0), Point::new(500, -3), Point::new(9000, 0)]);
+/// assert_eq!((compressed.width(), compressed.height()), (3, 2));
+/// assert_eq!(compressed.compress(Point::new(500, -3)), Some(Point::new(1, 0)));
+/// assert_eq!(compressed.compress(Point::new(500, 1)), None);
+/// assert_eq!(compressed.decompress(Point::new(2, 1)), Some(Point::new(9000, 0)));
+/// ```
+pub fn compress_2d<I>(points: I) -> Compressed2D
+where
+    I: IntoIterator<Item = Point>,
+{
+    let (xs, ys): (Vec<_>, Vec<_>) = points.into_iter().map(|point| (point.x, point.y)).unzip();
+    let (xs, x_index) = compress(xs);
+    let (ys, y_index) = compress(ys);
+    Compressed2D {
+        xs,
+        ys,
+        x_index,
+        y_index,
+    }
+}
+
+/// The dense grid spanned by the distinct coordinates of some points; see [`compress_2d`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Compressed2D {
+    /// The distinct `x` coordinates in ascending order.
+    pub xs: Vec<i64>,
+    /// The distinct `y` coordinates in ascending order.
+    pub ys: Vec<i64>,
+    x_index: HashMap<i64, usize>,
+    y_index: HashMap<i64, usize>,
+}
+
+impl Compressed2D {
+    /// Gets the number of distinct `x` coordinates.
+    pub fn width(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Gets the number of distinct `y` coordinates.
+    pub fn height(&self) -> usize {
+        self.ys.len()
+    }
+
+    /// Maps a point to its dense position, or `None` if either coordinate wasn't compressed.
+    pub fn compress(&self, point: Point) -> Option<Point> {
+        let x = *self.x_index.get(&point.x)?;
+        let y = *self.y_index.get(&point.y)?;
+        Some(Point::new(x as i64, y as i64))
+    }
+
+    /// Maps a dense position back to the original point, or `None` if it's out of range.
+    pub fn decompress(&self, point: Point) -> Option<Point> {
+        let x = *self.xs.get(usize::try_from(point.x).ok()?)?;
+        let y = *self.ys.get(usize::try_from(point.y).ok()?)?;
+        Some(Point::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress() {
+        let (values, index) = compress(Vec::<u64>::new());
+        assert!(values.is_empty() && index.is_empty());
+
+        let (values, index) = compress(["b", "a", "c", "a"]);
+        assert_eq!(values, ["a", "b", "c"]);
+        assert_eq!(index.len(), 3);
+        assert!(values.iter().all(|value| values[index[value]] == *value));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let points = [
+            Point::new(-5, 10),
+            Point::new(1 << 40, 10),
+            Point::new(3, -(1 << 50)),
+        ];
+        let compressed = compress_2d(points);
+        for point in points {
+            let dense = compressed.compress(point).expect("point was compressed");
+            assert!((0..3).contains(&dense.x) && (0..2).contains(&dense.y));
+            assert_eq!(compressed.decompress(dense), Some(point));
+        }
+        assert_eq!(compressed.decompress(Point::new(-1, 0)), None);
+        assert_eq!(compressed.decompress(Point::new(0, 2)), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Comparisons of strings and other sequences, such as finding the one differing position.
+//!
+//! Every function takes anything that can be viewed as a slice, so strings compare byte by
+//! byte and rows of a grid cell by cell.
+
+#[cfg(feature = "levenshtein")]
+use alloc::vec::Vec;
+
+/// Counts the positions at which the sequences differ, or returns `None` if their lengths
+/// differ.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::diff::hamming;
+///
+/// assert_eq!(hamming("karolin", "kathrin"), Some(3));
+/// assert_eq!(hamming([true, false], [true, true]), Some(1));
+/// assert_eq!(hamming("ab", "abc"), None);
+/// ```
+pub fn hamming<T: PartialEq>(a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> Option<usize> {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(a, b)| a != b).count())
+}
+
+/// Gets the number of leading items both sequences share.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::diff::common_prefix_len;
+///
+/// assert_eq!(common_prefix_len("fghij", "fguij"), 2);
+/// assert_eq!(common_prefix_len("abc", "ab"), 2);
+/// ```
+pub fn common_prefix_len<T: PartialEq>(a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> usize {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+/// Gets the number of trailing items both sequences share.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::diff::common_suffix_len;
+///
+/// assert_eq!(common_suffix_len("fghij", "fguij"), 2);
+/// assert_eq!(common_suffix_len("abc", "c"), 1);
+/// ```
+pub fn common_suffix_len<T: PartialEq>(a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> usize {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// Finds the position of the only difference between two sequences of the same length.
+///
+/// Returns `None` if the sequences are equal, differ in more than one position, or differ
+/// in length. Only the items before the first and after the last difference are compared.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::diff::single_char_difference;
+///
+/// assert_eq!(single_char_difference("fghij", "fguij"), Some(2));
+/// assert_eq!(single_char_difference("abcde", "axcye"), None);
+/// assert_eq!(single_char_difference("abc", "abc"), None);
+/// ```
+pub fn single_char_difference<T: PartialEq>(
+    a: impl AsRef<[T]>,
+    b: impl AsRef<[T]>,
+) -> Option<usize> {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if a.len() != b.len() {
+        return None;
+    }
+
+    let prefix = common_prefix_len(a, b);
+    let suffix = common_suffix_len(&a[prefix..], &b[prefix..]);
+    (prefix + suffix + 1 == a.len()).then_some(prefix)
+}
+
+/// Calculates the least number of insertions, deletions and substitutions that turn one
+/// sequence into the other.
+///
+/// Takes `O(n · m)` time and `O(m)` space for sequences of lengths `n` and `m`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::diff::levenshtein;
+///
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// ```
+#[cfg(feature = "levenshtein")]
+pub fn levenshtein<T: PartialEq>(a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> usize {
+    let (a, b) = (a.as_ref(), b.as_ref());
+
+    // The distances from the prefix of `a` processed so far to each prefix of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_ids() {
+        // Only the second and fifth IDs differ by exactly one letter.
+        let ids = [
+            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+        ];
+        let mut pairs = alloc::vec::Vec::new();
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                if let Some(index) = single_char_difference(a, b) {
+                    pairs.push((*a, *b, index));
+                }
+            }
+        }
+        assert_eq!(pairs, [("fghij", "fguij", 2)]);
+    }
+
+    #[test]
+    fn test_mirror_smudge() {
+        assert_eq!(hamming("#.##..##.", "#.##..##."), Some(0));
+        assert_eq!(hamming("#...##..#", "#....#..#"), Some(1));
+        assert_eq!(single_char_difference("#...##..#", "#....#..#"), Some(4));
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let empty: [u8; 0] = [];
+        assert_eq!(hamming(empty, empty), Some(0));
+        assert_eq!(common_prefix_len(empty, [1]), 0);
+        assert_eq!(single_char_difference("a", "b"), Some(0));
+        assert_eq!(single_char_difference("", ""), None);
+        assert_eq!(single_char_difference("ab", "a"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "levenshtein")]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("abc", "abc"), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein([1, 2, 3, 4], [2, 3, 4, 5]), 2);
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A union-find structure tracking which elements belong to the same set;
+/// elements are identified by their index.
+///
+/// Uses path compression and union by size, making both operations effectively constant time.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(2, 3));
+/// assert!(!sets.union(1, 0));
+///
+/// assert!(sets.connected(0, 1));
+/// assert!(!sets.connected(1, 2));
+/// assert_eq!(sets.set_size(3), 2);
+/// assert_eq!(sets.set_count(), 2);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// Creates the specified number of elements, each in its own set.
+    pub fn new(count: usize) -> Self {
+        Self {
+            parents: (0..count).collect(),
+            sizes: vec![1; count],
+            sets: count,
+        }
+    }
+
+    /// Gets the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Gets the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// Gets the representative element of the set containing the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element does not exist.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Point every element along the path directly at the root.
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing both elements.
+    ///
+    /// Returns `false` if they already were in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] >= self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns `true` if both elements are in the same set.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Gets the number of elements in the set containing the element.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain() {
+        let mut sets = DisjointSet::new(100);
+        for i in 1..100 {
+            assert!(sets.union(i - 1, i));
+        }
+
+        assert_eq!(sets.set_count(), 1);
+        assert_eq!(sets.set_size(42), 100);
+        assert!(sets.connected(0, 99));
+        assert_eq!(sets.len(), 100);
+    }
+}
@@ -0,0 +1,71 @@
+//! The shared `main` of the example binaries.
+//!
+//! Each day's example loads its input (see [`Input`](crate::input::Input)), prints the
+//! puzzle title and then solves both parts, printing each answer along with its timing.
+//! The [`aoc_main!`](crate::aoc_main) macro generates that `main` from one line per part:
+//!
+//! ```ignore
+//! aoc_utils::aoc_main!(aoc_2024_day_18;
+//!     "The exit can be reached in {} steps" or "The exit cannot be reached"
+//!         => |input| first_part(input, SIZE, BYTES),
+//!     "The first byte cutting off the exit is {}" or "No byte cuts off the exit"
+//!         => |input| second_part(input, SIZE),
+//! );
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// Generates the `main` function of an example binary.
+///
+/// The first argument is the day's crate, whose items are glob-imported into `main` and which
+/// must export a `PUZZLE`. It is followed by one `"format" => part` entry per part, where
+/// `format` is the message with a `{}` placeholder for the answer and `part` is called with
+/// the input. If the part returns an `Option`, `"format" or "message" => part` prints the
+/// message in case there is no answer.
+///
+/// An optional `before = hook;` ahead of the parts is called with the input before the puzzle
+/// is solved; if it returns `true`, `main` returns without solving, e.g. after an animation.
+///
+/// The input is read from the `input.txt` next to the day's `Cargo.toml`, unless another one
+/// is selected with `--input`.
+#[macro_export]
+macro_rules! aoc_main {
+    ($krate:ident; $(before = $before:expr;)? $($format:literal $(or $none:literal)? => $part:expr),+ $(,)?) => {
+        fn main() {
+            #[allow(unused_imports)]
+            use $krate::*;
+
+            const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+            let input = $crate::input::Input::load(INPUT).expect("failed to load the input");
+            $( if $before(&input) { return; } )?
+            println!("{}", PUZZLE);
+            $( $crate::aoc_main!(@part &input, $format $(or $none)? => $part); )+
+        }
+    };
+    (@part $input:expr, $format:literal => $part:expr) => {{
+        let (answer, elapsed) = $crate::example::timed($input, $part);
+        println!(concat!($format, " ({:.2?})"), answer, elapsed);
+    }};
+    (@part $input:expr, $format:literal or $none:literal => $part:expr) => {{
+        match $crate::example::timed($input, $part) {
+            (Some(answer), elapsed) => println!(concat!($format, " ({:.2?})"), answer, elapsed),
+            (None, elapsed) => println!(concat!($none, " ({:.2?})"), elapsed),
+        }
+    }};
+}
+
+/// Solves a part, measuring the time it takes.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::example::timed;
+///
+/// let (answer, _elapsed) = timed("1 2 3", |input| input.split(' ').count());
+/// assert_eq!(answer, 3);
+/// ```
+pub fn timed<T>(input: &str, part: impl FnOnce(&str) -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let answer = part(input);
+    (answer, start.elapsed())
+}
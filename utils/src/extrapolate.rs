@@ -0,0 +1,133 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Obtains the differences of consecutive values.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::differentiate;
+///
+/// assert_eq!(differentiate(&[1, 3, 6, 10]), [2, 3, 4]);
+/// ```
+pub fn differentiate(values: &[i64]) -> Vec<i64> {
+    values.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+/// Extrapolates the value following a sequence.
+///
+/// The sequence is differentiated until all differences are zero; the next value is
+/// the sum of the last values of all difference sequences.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::extrapolate_next;
+///
+/// assert_eq!(extrapolate_next(&[0, 3, 6, 9, 12, 15]), 18);
+/// assert_eq!(extrapolate_next(&[10, 13, 16, 21, 30, 45]), 68);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `values` is empty.
+pub fn extrapolate_next(values: &[i64]) -> i64 {
+    let mut values = values.to_vec();
+    let mut next = *values.last().expect("sequence has zero length");
+
+    while !values.iter().all(|&value| value == 0) {
+        values = differentiate(&values);
+        next += values.last().copied().unwrap_or(0);
+    }
+
+    next
+}
+
+/// Extrapolates the value preceding a sequence.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::extrapolate_previous;
+///
+/// assert_eq!(extrapolate_previous(&[10, 13, 16, 21, 30, 45]), 5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `values` is empty.
+pub fn extrapolate_previous(values: &[i64]) -> i64 {
+    let mut values = values.to_vec();
+    let mut first_values = vec![*values.first().expect("sequence has zero length")];
+
+    while !values.iter().all(|&value| value == 0) {
+        values = differentiate(&values);
+        first_values.push(values.first().copied().unwrap_or(0));
+    }
+
+    first_values
+        .into_iter()
+        .rev()
+        .fold(0, |sum, current| current - sum)
+}
+
+/// Evaluates the polynomial running through equally spaced samples at an arbitrary index.
+///
+/// The samples `values[0], values[1], ...` are interpreted as the polynomial's values at
+/// `0, 1, ...`; the result is the value at `index` (which may lie outside the samples).
+/// This is Newton's forward difference formula and equivalent to calling
+/// [`extrapolate_next`] repeatedly, but runs in constant time per difference.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::extrapolate_at;
+///
+/// // Samples of n² + 1.
+/// let samples = [1, 2, 5];
+/// assert_eq!(extrapolate_at(&samples, 3), 10);
+/// assert_eq!(extrapolate_at(&samples, 1000), 1_000_001);
+/// assert_eq!(extrapolate_at(&samples, -2), 5);
+/// ```
+pub fn extrapolate_at(values: &[i64], index: i64) -> i64 {
+    let mut values = values.to_vec();
+    let mut result = 0;
+
+    // The generalized binomial coefficient C(index, k), starting at k = 0.
+    let mut binomial = 1;
+    let mut k = 0;
+    while let Some(&first) = values.first() {
+        result += first * binomial;
+
+        k += 1;
+        binomial = binomial * (index - k + 1) / k;
+        values = differentiate(&values);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate_next() {
+        assert_eq!(extrapolate_next(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(extrapolate_next(&[7]), 7);
+    }
+
+    #[test]
+    fn test_extrapolate_previous() {
+        assert_eq!(extrapolate_previous(&[0, 3, 6, 9, 12, 15]), -3);
+        assert_eq!(extrapolate_previous(&[1, 3, 6, 10, 15, 21]), 0);
+    }
+
+    #[test]
+    fn test_extrapolate_at_matches_next_and_previous() {
+        let values = [10, 13, 16, 21, 30, 45];
+        assert_eq!(extrapolate_at(&values, 6), extrapolate_next(&values));
+        assert_eq!(extrapolate_at(&values, -1), extrapolate_previous(&values));
+        assert_eq!(extrapolate_at(&values, 3), 21);
+    }
+}
@@ -0,0 +1,856 @@
+use crate::intern::Interner;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+use std::vec;
+use std::vec::Vec;
+
+/// An undirected graph stored as adjacency lists; nodes are identified by their index.
+///
+/// Each node carries a value of type `N`, such as its label, and each edge a weight of type
+/// `E`. Both default to `()` for plain graphs.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::Graph;
+///
+/// let mut graph = Graph::new();
+/// let a = graph.add_node();
+/// let b = graph.add_node();
+/// let c = graph.add_node();
+/// graph.add_edge(a, b);
+/// graph.add_edge(b, c);
+///
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 2);
+/// assert_eq!(graph.neighbors(b), &[a, c]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Graph<N = (), E = ()> {
+    nodes: Vec<N>,
+    adjacency: Vec<Vec<usize>>,
+    /// The weights of the edges, in the same order as the `adjacency`.
+    weights: Vec<Vec<E>>,
+    edges: usize,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            adjacency: Vec::new(),
+            weights: Vec::new(),
+            edges: 0,
+        }
+    }
+}
+
+impl Graph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a graph with the specified number of unconnected nodes.
+    pub fn with_nodes(count: usize) -> Self {
+        Self {
+            nodes: vec![(); count],
+            adjacency: vec![Vec::new(); count],
+            weights: vec![Vec::new(); count],
+            edges: 0,
+        }
+    }
+}
+
+impl<E> Graph<(), E> {
+    /// Adds a node and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        self.insert_node(())
+    }
+}
+
+impl<N> Graph<N, ()> {
+    /// Adds an undirected edge between two nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node does not exist.
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        self.add_weighted_edge(a, b, ());
+    }
+}
+
+impl<N: Hash + Eq + Clone, E: Clone> Graph<N, E> {
+    /// Builds a graph from a list of weighted edges between labeled nodes.
+    ///
+    /// Every distinct label becomes a node, numbered in the order the labels are first seen,
+    /// and carrying the label as its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// let graph = Graph::from_edges([("a", "b", 3), ("b", "c", 4), ("c", "a", 5)]);
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert_eq!(graph[2], "c");
+    /// let weights: Vec<_> = graph.weighted_neighbors(0).collect();
+    /// assert_eq!(weights, [(1, &3), (2, &5)]);
+    /// ```
+    pub fn from_edges<I>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (N, N, E)>,
+    {
+        let mut labels = Interner::new();
+        let mut graph = Self::default();
+        for (a, b, weight) in edges {
+            let mut node = |label: N| {
+                let id = labels.intern(label.clone()) as usize;
+                if id == graph.node_count() {
+                    graph.insert_node(label);
+                }
+                id
+            };
+            let (a, b) = (node(a), node(b));
+            graph.add_weighted_edge(a, b, weight);
+        }
+        graph
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    /// Adds a node carrying the value and returns its index.
+    pub fn insert_node(&mut self, value: N) -> usize {
+        self.nodes.push(value);
+        self.adjacency.push(Vec::new());
+        self.weights.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// Adds an undirected edge with the weight between two nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node does not exist.
+    pub fn add_weighted_edge(&mut self, a: usize, b: usize, weight: E)
+    where
+        E: Clone,
+    {
+        assert!(
+            a < self.node_count() && b < self.node_count(),
+            "no such node"
+        );
+        self.adjacency[a].push(b);
+        self.weights[a].push(weight.clone());
+        self.adjacency[b].push(a);
+        self.weights[b].push(weight);
+        self.edges += 1;
+    }
+
+    /// Gets the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Gets the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    /// Gets the nodes directly connected to the specified node.
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// Iterates the nodes directly connected to the specified node with the edges' weights.
+    pub fn weighted_neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &E)> + '_ {
+        self.adjacency[node]
+            .iter()
+            .copied()
+            .zip(&self.weights[node])
+    }
+
+    /// Iterates all node indices.
+    pub fn nodes(&self) -> std::ops::Range<usize> {
+        0..self.adjacency.len()
+    }
+
+    /// Returns `true` if the two nodes are directly connected.
+    pub fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].contains(&b)
+    }
+
+    /// Groups the nodes by the parts of the graph they're connected in.
+    ///
+    /// Each component lists its nodes in ascending order, and the components are ordered by
+    /// their first node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// let graph = Graph::from_edges([(0, 1, ()), (2, 3, ()), (3, 4, ())]);
+    /// assert_eq!(graph.connected_components(), [vec![0, 1], vec![2, 3, 4]]);
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.node_count()];
+        let mut components = Vec::new();
+        for start in self.nodes() {
+            if seen[start] {
+                continue;
+            }
+
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut next = 0;
+            while let Some(&node) = component.get(next) {
+                next += 1;
+                for &neighbor in self.neighbors(node) {
+                    if !seen[neighbor] {
+                        seen[neighbor] = true;
+                        component.push(neighbor);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Finds the edges whose removal splits their component in two, as `(a, b)` with `a < b`
+    /// in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// // Two triangles joined by the edge between 2 and 3.
+    /// let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+    /// let graph = Graph::from_edges(edges.map(|(a, b)| (a, b, ())));
+    /// assert_eq!(graph.bridges(), [(2, 3)]);
+    /// assert_eq!(graph.articulation_points(), [2, 3]);
+    /// ```
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        let mut bridges = self.low_links().bridges;
+        bridges.sort_unstable();
+        bridges
+    }
+
+    /// Finds the nodes whose removal splits their component, in ascending order.
+    pub fn articulation_points(&self) -> Vec<usize> {
+        let cuts = self.low_links().cuts;
+        self.nodes().filter(|&node| cuts[node]).collect()
+    }
+
+    /// Runs Tarjan's depth-first search, comparing each node's discovery time with the
+    /// earliest one reachable from its subtree through a single back edge.
+    fn low_links(&self) -> LowLinks {
+        /// A node on the depth-first search's stack.
+        struct Frame {
+            node: usize,
+            parent: Option<usize>,
+            /// The position of the next neighbor to visit.
+            next: usize,
+            /// Whether the edge to the parent was skipped; parallel edges to it are back edges.
+            skipped_parent: bool,
+        }
+
+        let mut discovered = vec![usize::MAX; self.node_count()];
+        let mut low = vec![0; self.node_count()];
+        let mut links = LowLinks {
+            bridges: Vec::new(),
+            cuts: vec![false; self.node_count()],
+        };
+        let mut time = 0;
+
+        for root in self.nodes() {
+            if discovered[root] != usize::MAX {
+                continue;
+            }
+
+            discovered[root] = time;
+            low[root] = time;
+            time += 1;
+            let mut root_children = 0;
+            let mut stack = vec![Frame {
+                node: root,
+                parent: None,
+                next: 0,
+                skipped_parent: false,
+            }];
+
+            while let Some(frame) = stack.last_mut() {
+                let node = frame.node;
+                if let Some(&neighbor) = self.adjacency[node].get(frame.next) {
+                    frame.next += 1;
+                    if Some(neighbor) == frame.parent && !frame.skipped_parent {
+                        frame.skipped_parent = true;
+                    } else if discovered[neighbor] == usize::MAX {
+                        discovered[neighbor] = time;
+                        low[neighbor] = time;
+                        time += 1;
+                        root_children += usize::from(node == root);
+                        stack.push(Frame {
+                            node: neighbor,
+                            parent: Some(node),
+                            next: 0,
+                            skipped_parent: false,
+                        });
+                    } else {
+                        low[node] = low[node].min(discovered[neighbor]);
+                    }
+                    continue;
+                }
+
+                let parent = frame.parent;
+                stack.pop();
+                if let Some(parent) = parent {
+                    low[parent] = low[parent].min(low[node]);
+                    if low[node] > discovered[parent] {
+                        links.bridges.push((parent.min(node), parent.max(node)));
+                    }
+                    if parent != root && low[node] >= discovered[parent] {
+                        links.cuts[parent] = true;
+                    }
+                }
+            }
+
+            links.cuts[root] = root_children > 1;
+        }
+        links
+    }
+
+    /// Finds a largest set of nodes that are all connected to each other.
+    ///
+    /// Uses the Bron–Kerbosch algorithm with pivoting: each step only branches on candidates
+    /// not adjacent to a pivot, since any maximal clique must contain the pivot or one of
+    /// its non-neighbors. Returns the nodes in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// let mut graph = Graph::with_nodes(5);
+    /// for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 3), (3, 4)] {
+    ///     graph.add_edge(a, b);
+    /// }
+    ///
+    /// assert_eq!(graph.max_clique(), [0, 1, 2]);
+    /// ```
+    pub fn max_clique(&self) -> Vec<usize> {
+        let neighbors = self.neighbor_sets();
+        let mut best = Vec::new();
+        bron_kerbosch(
+            &neighbors,
+            &mut Vec::new(),
+            self.nodes().collect(),
+            HashSet::new(),
+            &mut best,
+        );
+        best.sort_unstable();
+        best
+    }
+
+    /// Iterates all cliques that can't be extended by another node, each in ascending order.
+    ///
+    /// Uses the Bron–Kerbosch algorithm with pivoting, starting from each node in
+    /// [degeneracy order](Self::degeneracy_order) with only its later neighbors as candidates.
+    /// This keeps the search small on sparse graphs, where each node has few later neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// let mut graph = Graph::with_nodes(5);
+    /// for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 3), (3, 4)] {
+    ///     graph.add_edge(a, b);
+    /// }
+    ///
+    /// let mut cliques: Vec<_> = graph.maximal_cliques().collect();
+    /// cliques.sort();
+    /// assert_eq!(cliques, [vec![0, 1, 2], vec![2, 3], vec![3, 4]]);
+    /// ```
+    pub fn maximal_cliques(&self) -> MaximalCliques {
+        let order = self.degeneracy_order();
+        let mut position = vec![0; self.node_count()];
+        for (index, &node) in order.iter().enumerate() {
+            position[node] = index;
+        }
+
+        MaximalCliques {
+            neighbors: self.neighbor_sets(),
+            order,
+            position,
+            next_start: 0,
+            clique: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Orders the nodes by repeatedly taking one with the fewest neighbors among the nodes not
+    /// taken yet.
+    ///
+    /// Parallel edges and loops are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// // A triangle with a tail.
+    /// let graph = Graph::from_edges([(0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ())]);
+    /// assert_eq!(graph.degeneracy_order()[0], 3);
+    /// ```
+    pub fn degeneracy_order(&self) -> Vec<usize> {
+        let neighbors = self.neighbor_sets();
+        let mut degrees: Vec<usize> = neighbors.iter().map(HashSet::len).collect();
+        let max_degree = degrees.iter().copied().max().unwrap_or_default();
+
+        // Nodes bucketed by their degree; entries with an outdated degree are skipped.
+        let mut buckets = vec![Vec::new(); max_degree + 1];
+        for node in self.nodes() {
+            buckets[degrees[node]].push(node);
+        }
+
+        let mut taken = vec![false; self.node_count()];
+        let mut order = Vec::with_capacity(self.node_count());
+        let mut degree = 0;
+        while order.len() < self.node_count() {
+            let Some(node) = buckets[degree].pop() else {
+                degree += 1;
+                continue;
+            };
+            if taken[node] || degrees[node] != degree {
+                continue;
+            }
+
+            taken[node] = true;
+            order.push(node);
+            for &neighbor in &neighbors[node] {
+                if !taken[neighbor] {
+                    degrees[neighbor] -= 1;
+                    buckets[degrees[neighbor]].push(neighbor);
+                }
+            }
+            // Taking the node lowered its neighbors' degrees by at most one.
+            degree = degree.saturating_sub(1);
+        }
+        order
+    }
+
+    /// Finds a set of nodes without edges between them, to which no other node can be added.
+    ///
+    /// Greedily takes the nodes in [degeneracy order](Self::degeneracy_order), so nodes with few
+    /// neighbors are preferred. The set is maximal but not necessarily the largest possible.
+    /// Returns the nodes in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Graph;
+    ///
+    /// // A star whose center is connected to each of the leaves.
+    /// let graph = Graph::from_edges((1..5).map(|leaf| (0, leaf, ())));
+    /// assert_eq!(graph.maximal_independent_set(), [1, 2, 3, 4]);
+    /// ```
+    pub fn maximal_independent_set(&self) -> Vec<usize> {
+        let mut blocked = vec![false; self.node_count()];
+        let mut set = Vec::new();
+        for node in self.degeneracy_order() {
+            if blocked[node] {
+                continue;
+            }
+
+            set.push(node);
+            for &neighbor in self.neighbors(node) {
+                blocked[neighbor] = true;
+            }
+        }
+        set.sort_unstable();
+        set
+    }
+
+    /// Collects the distinct neighbors of each node, leaving out loops.
+    fn neighbor_sets(&self) -> Vec<HashSet<usize>> {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .map(|(node, neighbors)| {
+                neighbors
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| neighbor != node)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Iterates all edges once, as `(a, b)` with `a < b`.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(a, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |&&b| a < b)
+                    .map(move |&b| (a, b))
+            })
+    }
+}
+
+impl<N, E> Index<usize> for Graph<N, E> {
+    type Output = N;
+
+    /// Gets the value of a node.
+    fn index(&self, node: usize) -> &N {
+        &self.nodes[node]
+    }
+}
+
+impl<N, E> IndexMut<usize> for Graph<N, E> {
+    fn index_mut(&mut self, node: usize) -> &mut N {
+        &mut self.nodes[node]
+    }
+}
+
+/// An iterator over the maximal cliques of a graph; see [`Graph::maximal_cliques`].
+#[derive(Debug, Clone)]
+pub struct MaximalCliques {
+    neighbors: Vec<HashSet<usize>>,
+    order: Vec<usize>,
+    /// The position of each node in the `order`.
+    position: Vec<usize>,
+    /// The position of the next node in the `order` to start a search from.
+    next_start: usize,
+    /// The clique being extended; each frame on the `stack` extends it by one node.
+    clique: Vec<usize>,
+    stack: Vec<CliqueFrame>,
+}
+
+/// A step of the Bron–Kerbosch search, extending a clique by the `candidates`.
+#[derive(Debug, Clone)]
+struct CliqueFrame {
+    candidates: HashSet<usize>,
+    /// The nodes that could extend the clique but whose cliques were already found.
+    visited: HashSet<usize>,
+    /// The candidates not adjacent to the pivot, which are the only ones branched on.
+    branches: Vec<usize>,
+    next: usize,
+}
+
+impl CliqueFrame {
+    fn new(
+        neighbors: &[HashSet<usize>],
+        candidates: HashSet<usize>,
+        visited: HashSet<usize>,
+    ) -> Self {
+        let pivot = candidates
+            .union(&visited)
+            .max_by_key(|&&node| neighbors[node].intersection(&candidates).count())
+            .copied()
+            .expect("candidates are not empty");
+        let branches = candidates.difference(&neighbors[pivot]).copied().collect();
+        Self {
+            candidates,
+            visited,
+            branches,
+            next: 0,
+        }
+    }
+}
+
+impl Iterator for MaximalCliques {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                // Start from the next node, with its earlier neighbors already covered.
+                let &start = self.order.get(self.next_start)?;
+                self.next_start += 1;
+                let (visited, candidates) = self.neighbors[start]
+                    .iter()
+                    .partition(|&&node| self.position[node] < self.position[start]);
+                self.clique = vec![start];
+                if let Some(clique) = self.extend(candidates, visited) {
+                    return Some(clique);
+                }
+                continue;
+            };
+
+            let Some(&node) = frame.branches.get(frame.next) else {
+                self.stack.pop();
+                self.clique.pop();
+                continue;
+            };
+            frame.next += 1;
+
+            let neighbors = &self.neighbors[node];
+            let candidates = frame.candidates.intersection(neighbors).copied().collect();
+            let visited = frame.visited.intersection(neighbors).copied().collect();
+            frame.candidates.remove(&node);
+            frame.visited.insert(node);
+
+            self.clique.push(node);
+            if let Some(clique) = self.extend(candidates, visited) {
+                return Some(clique);
+            }
+        }
+    }
+}
+
+impl MaximalCliques {
+    /// Pushes a frame extending the current clique, or returns the clique if it can't be
+    /// extended.
+    fn extend(
+        &mut self,
+        candidates: HashSet<usize>,
+        visited: HashSet<usize>,
+    ) -> Option<Vec<usize>> {
+        if !candidates.is_empty() {
+            self.stack
+                .push(CliqueFrame::new(&self.neighbors, candidates, visited));
+            return None;
+        }
+
+        // Without candidates, the clique is maximal unless a visited node could extend it.
+        let clique = visited.is_empty().then(|| {
+            let mut clique = self.clique.clone();
+            clique.sort_unstable();
+            clique
+        });
+        self.clique.pop();
+        clique
+    }
+}
+
+/// The result of [`Graph::low_links`].
+struct LowLinks {
+    bridges: Vec<(usize, usize)>,
+    /// Whether each node is an articulation point.
+    cuts: Vec<bool>,
+}
+
+/// Extends the `clique` by the `candidates`, excluding the already `visited` nodes, and
+/// records the largest clique found in `best`.
+fn bron_kerbosch(
+    neighbors: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut visited: HashSet<usize>,
+    best: &mut Vec<usize>,
+) {
+    if candidates.is_empty() {
+        if visited.is_empty() && clique.len() > best.len() {
+            best.clone_from(clique);
+        }
+        return;
+    }
+
+    // A clique that cannot grow beyond the best one is not worth extending.
+    if clique.len() + candidates.len() <= best.len() {
+        return;
+    }
+
+    let pivot = candidates
+        .union(&visited)
+        .max_by_key(|&&node| neighbors[node].len())
+        .copied()
+        .expect("candidates are not empty");
+    let branches: Vec<_> = candidates.difference(&neighbors[pivot]).copied().collect();
+
+    for node in branches {
+        clique.push(node);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(&neighbors[node]).copied().collect(),
+            visited.intersection(&neighbors[node]).copied().collect(),
+            best,
+        );
+        clique.pop();
+
+        candidates.remove(&node);
+        visited.insert(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::check;
+
+    #[test]
+    fn test_edges() {
+        let mut graph = Graph::with_nodes(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 1);
+        graph.add_edge(3, 0);
+
+        let mut edges: Vec<_> = graph.edges().collect();
+        edges.sort();
+        assert_eq!(edges, [(0, 1), (0, 3), (1, 2)]);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.nodes().count(), 4);
+    }
+
+    #[test]
+    fn test_max_clique() {
+        // Two overlapping 4-cliques sharing the nodes 2 and 3, plus a triangle.
+        let mut graph = Graph::with_nodes(9);
+        for (a, b) in [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (2, 5),
+            (3, 5),
+            (4, 5),
+            (6, 7),
+            (7, 8),
+            (6, 8),
+        ] {
+            graph.add_edge(a, b);
+        }
+
+        let clique = graph.max_clique();
+        assert_eq!(clique.len(), 4);
+        for (i, &a) in clique.iter().enumerate() {
+            for &b in &clique[i + 1..] {
+                assert!(graph.contains_edge(a, b));
+            }
+        }
+        assert!(Graph::new().max_clique().is_empty());
+    }
+
+    #[test]
+    fn test_maximal_cliques_match_brute_force() {
+        check(
+            200,
+            |rng| {
+                let nodes = rng.index(1..9);
+                let edges: Vec<_> = (0..rng.index(0..24))
+                    .map(|_| (rng.index(0..nodes), rng.index(0..nodes)))
+                    .collect();
+                (nodes, edges)
+            },
+            |(nodes, edges)| {
+                let mut graph = Graph::with_nodes(*nodes);
+                for &(a, b) in edges {
+                    graph.add_edge(a, b);
+                }
+                let adjacent = |a: usize, b: usize| a == b || graph.contains_edge(a, b);
+                let is_clique =
+                    |set: &[usize]| set.iter().all(|&a| set.iter().all(|&b| adjacent(a, b)));
+
+                // Every subset that is a clique and can't take any other node.
+                let mut expected: Vec<Vec<usize>> = (0..1u32 << nodes)
+                    .map(|mask| {
+                        graph
+                            .nodes()
+                            .filter(|&node| mask & 1 << node != 0)
+                            .collect()
+                    })
+                    .filter(|set: &Vec<usize>| {
+                        is_clique(set)
+                            && graph.nodes().all(|node| {
+                                set.contains(&node)
+                                    || !set.iter().all(|&other| adjacent(node, other))
+                            })
+                    })
+                    .collect();
+                expected.sort();
+
+                let mut cliques: Vec<_> = graph.maximal_cliques().collect();
+                cliques.sort();
+
+                let independent = graph.maximal_independent_set();
+                let is_independent = independent.iter().all(|&a| {
+                    independent
+                        .iter()
+                        .all(|&b| a == b || !graph.contains_edge(a, b))
+                });
+                let is_maximal = graph.nodes().all(|node| {
+                    independent.contains(&node)
+                        || independent
+                            .iter()
+                            .any(|&other| graph.contains_edge(node, other))
+                });
+
+                let largest = expected.iter().map(Vec::len).max().unwrap_or_default();
+                cliques == expected
+                    && graph.max_clique().len() == largest
+                    && is_independent
+                    && is_maximal
+            },
+        );
+    }
+
+    #[test]
+    fn test_degeneracy_order() {
+        // A 4-clique with a path 3-4-5 attached.
+        let mut graph = Graph::with_nodes(6);
+        for (a, b) in [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+        ] {
+            graph.add_edge(a, b);
+        }
+        let order = graph.degeneracy_order();
+        assert_eq!(order[..2], [5, 4]);
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_from_edges() {
+        let graph = Graph::from_edges([("kh", "tc", 1), ("qp", "kh", 2), ("kh", "tc", 3)]);
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph[1], "tc");
+        let edges: Vec<_> = graph.weighted_neighbors(0).collect();
+        assert_eq!(edges, [(1, &1), (2, &2), (1, &3)]);
+    }
+
+    #[test]
+    fn test_bridges_and_articulation_points() {
+        // A path 0-1-2 with a cycle 2-3-4 hanging off it, a doubled edge 4=5, and an
+        // isolated node 6.
+        let mut graph = Graph::with_nodes(7);
+        for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 2), (4, 5), (5, 4)] {
+            graph.add_edge(a, b);
+        }
+        assert_eq!(graph.bridges(), [(0, 1), (1, 2)]);
+        assert_eq!(graph.articulation_points(), [1, 2, 4]);
+        assert_eq!(
+            graph.connected_components(),
+            [vec![0, 1, 2, 3, 4, 5], vec![6]]
+        );
+
+        let mut star = Graph::with_nodes(4);
+        for leaf in 1..4 {
+            star.add_edge(0, leaf);
+        }
+        assert_eq!(star.articulation_points(), [0]);
+        assert_eq!(star.bridges().len(), 3);
+    }
+}
@@ -0,0 +1,745 @@
+use crate::{OverflowError, Ring};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A position on a two-dimensional grid; `y` grows downwards.
+///
+/// Coordinates are signed so that stepping off the edge of a grid can be represented.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// One of the four cardinal directions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// A dense, rectangular grid of cells stored in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{Direction, Grid, Point};
+///
+/// let grid = Grid::parse("..#\n.^.", |c| Some(c)).unwrap();
+/// assert_eq!(grid.width(), 3);
+/// assert_eq!(grid.height(), 2);
+///
+/// let start = grid.find(|&c| c == '^').unwrap();
+/// assert_eq!(start, Point::new(1, 1));
+/// assert_eq!(grid.get(start + Direction::Up.offset()), Some(&'.'));
+/// assert_eq!(grid.get(start + Direction::Down.offset()), None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A rectangular grid that only stores its occupied cells.
+///
+/// This suits maps that are mostly empty, such as a few antennas on a large roof.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{Point, SparseGrid};
+///
+/// let grid = SparseGrid::parse("..a\n.A.", '.', Some).unwrap();
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid.get(Point::new(1, 1)), Some(&'A'));
+/// assert_eq!(grid.get(Point::new(0, 0)), None);
+/// assert!(grid.contains(Point::new(0, 0)));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound = "T: serde::Serialize")
+)]
+pub struct SparseGrid<T> {
+    width: usize,
+    height: usize,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_cells"))]
+    cells: HashMap<Point, T>,
+}
+
+/// Serializes the occupied cells as `(point, cell)` pairs in row-major order, since
+/// points aren't valid keys of a map in formats such as JSON.
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serialize_cells<T, S>(cells: &HashMap<Point, T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: serde::Serialize,
+    S: serde::Serializer,
+{
+    let mut cells: Vec<_> = cells.iter().collect();
+    cells.sort_unstable_by_key(|(point, _)| (point.y, point.x));
+    serializer.collect_seq(cells)
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Calculates the taxicab/Manhattan distance to another point.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Gets the four orthogonally adjacent points.
+    pub fn neighbors(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self + direction.offset())
+    }
+}
+
+impl Direction {
+    /// All directions, clockwise starting at [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Gets the offset of a single step in this direction.
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Gets the direction after a clockwise quarter turn.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Gets the direction after a counterclockwise quarter turn.
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    /// Gets the opposite direction.
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to the same value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with one row per line, converting each character to a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The lines of the grid; surrounding whitespace and empty lines are ignored.
+    /// * `cell` - Converts a character into a cell, or returns `None` if it is invalid.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self, ParseGridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let before = cells.len();
+            for c in line.chars() {
+                cells.push(cell(c).ok_or(ParseGridError("invalid cell"))?);
+            }
+
+            let length = cells.len() - before;
+            if *width.get_or_insert(length) != length {
+                return Err(ParseGridError("rows must have the same length"));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    /// Gets the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Determines whether the point lies inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    /// Gets the cell at the point, or `None` if it lies outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    /// Gets the cell at the point mutably, or `None` if it lies outside the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Iterates all points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Iterates all cells together with their points in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Finds the first point (in row-major order) whose cell matches the predicate.
+    pub fn find<F>(&self, mut predicate: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find_map(|(point, cell)| predicate(cell).then_some(point))
+    }
+
+    /// Creates a grid of the same size by converting every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Counts for each cell how many of its eight neighbors match the predicate.
+    ///
+    /// The counts are summed along each row first and then across three rows, so every cell
+    /// is only read a few times and always in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Grid, Point};
+    ///
+    /// let grid = Grid::parse(".#.\n.#.\n.#.", Some).unwrap();
+    /// let counts = grid.count_neighbors(|&c| c == '#');
+    /// assert_eq!(counts[Point::new(0, 1)], 3);
+    /// assert_eq!(counts[Point::new(1, 1)], 2);
+    /// assert_eq!(counts[Point::new(2, 0)], 2);
+    /// ```
+    pub fn count_neighbors<F>(&self, mut predicate: F) -> Grid<u8>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let width = self.width;
+        if width == 0 {
+            return Grid::new(0, self.height, 0);
+        }
+        let matches: Vec<u8> = self
+            .cells
+            .iter()
+            .map(|cell| u8::from(predicate(cell)))
+            .collect();
+
+        // The matches among each cell and its left and right neighbor.
+        let mut row_sums = vec![0u8; matches.len()];
+        for (sums, row) in row_sums.chunks_mut(width).zip(matches.chunks(width)) {
+            for (x, sum) in sums.iter_mut().enumerate() {
+                *sum = row[x.saturating_sub(1)..(x + 2).min(width)].iter().sum();
+            }
+        }
+
+        // The row sums of the rows above, at and below each cell, without the cell itself.
+        let mut cells = vec![0u8; matches.len()];
+        for y in 0..self.height {
+            let rows = y.saturating_sub(1)..(y + 2).min(self.height);
+            let counts = &mut cells[y * width..][..width];
+            for row in rows {
+                for (count, &sum) in counts.iter_mut().zip(&row_sums[row * width..][..width]) {
+                    *count += sum;
+                }
+            }
+            for (count, &own) in counts.iter_mut().zip(&matches[y * width..][..width]) {
+                *count -= own;
+            }
+        }
+
+        Grid {
+            width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// Up-samples the grid so that every cell becomes a `factor` × `factor` block.
+    ///
+    /// The block cells are computed from the point of the original cell and the offset within
+    /// the block, so the gaps between cells can depend on their neighbors, e.g. to widen a
+    /// maze of pipes until the spaces between them become cells of their own. Fails if the
+    /// scaled grid would be too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Grid, Point};
+    ///
+    /// let grid = Grid::parse("#.\n.#", Some).unwrap();
+    /// let scaled = grid.scale(2, |point, _| grid[point]).unwrap();
+    /// assert_eq!((scaled.width(), scaled.height()), (4, 4));
+    /// assert_eq!(scaled[Point::new(3, 2)], '#');
+    ///
+    /// // Keep only the original cells and leave gaps between them.
+    /// let spread = grid.scale(2, |point, offset| if offset == Point::default() { grid[point] } else { ' ' });
+    /// assert_eq!(spread.unwrap()[Point::new(2, 2)], '#');
+    /// ```
+    pub fn scale<U, F>(&self, factor: usize, mut fill: F) -> Result<Grid<U>, OverflowError>
+    where
+        F: FnMut(Point, Point) -> U,
+    {
+        let width = self.width.checked_mul(factor).ok_or(OverflowError)?;
+        let height = self.height.checked_mul(factor).ok_or(OverflowError)?;
+        let mut cells = Vec::with_capacity(width.checked_mul(height).ok_or(OverflowError)?);
+        for y in 0..height {
+            for x in 0..width {
+                let source = Point::new((x / factor) as i64, (y / factor) as i64);
+                let offset = Point::new((x % factor) as i64, (y % factor) as i64);
+                cells.push(fill(source, offset));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Surrounds the grid with a frame of `border` cells set to the value, moving every cell
+    /// by `border` to the right and down.
+    ///
+    /// A frame of sentinels lets neighbor lookups skip bounds checks, as long as they start
+    /// inside the original grid. Fails if the padded grid would be too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Grid, Point};
+    ///
+    /// let grid = Grid::parse("ab\ncd", Some).unwrap();
+    /// let padded = grid.pad(1, '.').unwrap();
+    /// assert_eq!((padded.width(), padded.height()), (4, 4));
+    /// assert_eq!(padded[Point::new(0, 0)], '.');
+    /// assert_eq!(padded[Point::new(2, 2)], 'd');
+    /// ```
+    pub fn pad(&self, border: usize, value: T) -> Result<Grid<T>, OverflowError>
+    where
+        T: Clone,
+    {
+        let frame = border.checked_mul(2).ok_or(OverflowError)?;
+        let width = self.width.checked_add(frame).ok_or(OverflowError)?;
+        let height = self.height.checked_add(frame).ok_or(OverflowError)?;
+        width.checked_mul(height).ok_or(OverflowError)?;
+
+        let mut padded = Grid::new(width, height, value);
+        for y in 0..self.height {
+            let row = &self.cells[y * self.width..][..self.width];
+            padded.cells[(y + border) * width + border..][..self.width].clone_from_slice(row);
+        }
+        Ok(padded)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+}
+
+impl<T: Ring> Grid<T> {
+    /// Replaces each cell with the sum of its 3×3 neighborhood weighted by the kernel.
+    ///
+    /// `kernel[1][1]` weights the cell itself and `kernel[0][2]` its upper right neighbor;
+    /// the kernel isn't flipped. Cells outside the grid count as zero. Each kernel row is
+    /// applied to whole rows of the grid at once, which keeps the memory accesses sequential.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Grid, Point};
+    ///
+    /// let mut grid = Grid::new(3, 3, 0i32);
+    /// grid[Point::new(1, 1)] = 1;
+    /// let shifted = grid.convolve3x3([[0, 0, 0], [0, 0, 0], [0, 0, 2]]);
+    /// assert_eq!(shifted[Point::new(0, 0)], 2);
+    /// assert_eq!(shifted.iter().filter(|(_, &v)| v != 0).count(), 1);
+    /// ```
+    pub fn convolve3x3(&self, kernel: [[T; 3]; 3]) -> Grid<T> {
+        let mut result = Grid::new(self.width, self.height, T::ZERO);
+        if self.width == 0 {
+            return result;
+        }
+
+        for (dy, weights) in kernel.iter().enumerate() {
+            for y in 0..self.height {
+                // The source row lies `dy - 1` rows below the output row.
+                let Some(source) = (y + dy).checked_sub(1).filter(|&s| s < self.height) else {
+                    continue;
+                };
+                let source = &self.cells[source * self.width..][..self.width];
+                let target = &mut result.cells[y * self.width..][..self.width];
+                accumulate_row(target, source, weights);
+            }
+        }
+        result
+    }
+}
+
+/// Adds the weighted sum of each cell's left, own and right value in the source row.
+fn accumulate_row<T: Ring>(target: &mut [T], source: &[T], &[left, center, right]: &[T; 3]) {
+    for (x, target) in target.iter_mut().enumerate() {
+        let mut sum = center * source[x];
+        if x > 0 {
+            sum = sum + left * source[x - 1];
+        }
+        if let Some(&next) = source.get(x + 1) {
+            sum = sum + right * next;
+        }
+        *target = *target + sum;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid of the specified size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a grid with one row per line, storing every non-empty character as a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The lines of the grid; surrounding whitespace and empty lines are ignored.
+    /// * `empty` - The character marking an empty cell.
+    /// * `cell` - Converts any other character into a cell, or returns `None` if it is invalid.
+    pub fn parse<F>(input: &str, empty: char, mut cell: F) -> Result<Self, ParseGridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let grid = Grid::parse(input, |c| match c {
+            c if c == empty => Some(None),
+            c => cell(c).map(Some),
+        })?;
+        Ok(Self::from(grid))
+    }
+
+    /// Gets the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Determines whether no cell is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Determines whether the point lies inside the grid, regardless of whether it is occupied.
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    /// Gets the cell at the point, or `None` if it is empty.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    /// Occupies the cell at the point and returns its previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point lies outside the grid.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        assert!(self.contains(point), "point lies outside the grid");
+        self.cells.insert(point, value)
+    }
+
+    /// Empties the cell at the point and returns its previous value.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Iterates all occupied cells together with their points, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<Grid<Option<T>>> for SparseGrid<T> {
+    fn from(grid: Grid<Option<T>>) -> Self {
+        let width = grid.width;
+        let height = grid.height;
+        let cells = grid
+            .points()
+            .zip(grid.cells)
+            .filter_map(|(point, cell)| Some((point, cell?)))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if the point lies outside the grid.
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).expect("point lies outside the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    /// # Panics
+    ///
+    /// Panics if the point lies outside the grid.
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point).expect("point lies outside the grid")
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseGridError(&'static str);
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Failed to parse grid: {}", self.0)
+    }
+}
+
+impl Error for ParseGridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = Grid::parse(
+            "
+            #..
+            ..#
+            ",
+            |c| (c == '#' || c == '.').then_some(c == '#'),
+        )
+        .expect("failed to parse grid");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert!(grid[Point::new(2, 1)]);
+        assert_eq!(grid.iter().filter(|(_, &wall)| wall).count(), 2);
+
+        assert_eq!(
+            Grid::parse("..\n...", Some),
+            Err(ParseGridError("rows must have the same length"))
+        );
+        assert_eq!(
+            Grid::parse("x", |c| (c == '.').then_some(c)),
+            Err(ParseGridError("invalid cell"))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_kernels_match_brute_force() {
+        use crate::property::check;
+
+        check(
+            100,
+            |rng| {
+                let (width, height) = (rng.index(0..8), rng.index(0..8));
+                let mut grid = Grid::new(width, height, 0i64);
+                for point in grid.points().collect::<Vec<_>>() {
+                    grid[point] = rng.signed(-3..4);
+                }
+                let kernel = [(); 3].map(|_| [(); 3].map(|_| rng.signed(-2..3)));
+                (grid, kernel)
+            },
+            |(grid, kernel)| {
+                let convolved = grid.convolve3x3(*kernel);
+                let counts = grid.count_neighbors(|&value| value > 0);
+                grid.points().all(|point| {
+                    let mut sum = 0;
+                    let mut count = 0;
+                    for dy in -1..=1 {
+                        for dx in -1..=1 {
+                            let Some(&value) = grid.get(point + Point::new(dx, dy)) else {
+                                continue;
+                            };
+                            sum += kernel[(dy + 1) as usize][(dx + 1) as usize] * value;
+                            count += u8::from((dx, dy) != (0, 0) && value > 0);
+                        }
+                    }
+                    convolved[point] == sum && counts[point] == count
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn test_scale_and_pad() {
+        let grid = Grid::parse("ab\ncd\nef", Some).unwrap();
+        let scaled = grid
+            .scale(3, |point, offset| (grid[point], offset))
+            .unwrap();
+        assert_eq!((scaled.width(), scaled.height()), (6, 9));
+        assert_eq!(scaled[Point::new(4, 7)], ('f', Point::new(1, 1)));
+        assert_eq!(grid.scale(0, |_, _| ()).unwrap().width(), 0);
+
+        let padded = grid.pad(2, '.').unwrap();
+        assert_eq!((padded.width(), padded.height()), (6, 7));
+        for (point, &cell) in grid.iter() {
+            assert_eq!(padded[point + Point::new(2, 2)], cell);
+        }
+        assert_eq!(padded.iter().filter(|(_, &cell)| cell == '.').count(), 36);
+        assert_eq!(Grid::new(0, 1, 0).pad(1, 9).unwrap().cells, [9; 6]);
+
+        assert_eq!(grid.scale(usize::MAX, |_, _| ()), Err(OverflowError));
+        assert_eq!(grid.pad(usize::MAX / 2, '.'), Err(OverflowError));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::parse(
+            "
+            0..
+            ..A
+            ",
+            '.',
+            |c| c.is_ascii_alphanumeric().then_some(c),
+        )
+        .expect("failed to parse grid");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.insert(Point::new(1, 0), 'b'), None);
+        assert_eq!(grid.remove(Point::new(2, 1)), Some('A'));
+        assert_eq!(grid.len(), 2);
+        assert!(!grid.contains(Point::new(3, 0)));
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Point::default()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+}
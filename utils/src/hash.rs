@@ -0,0 +1,204 @@
+//! Hashing utilities: MD5 for puzzles built around it, and a fast non-cryptographic hasher
+//! for hash maps on hot paths. Neither requires an external dependency.
+
+use core::hash::{BuildHasherDefault, Hasher};
+
+/// The per-round shift amounts of MD5.
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, //
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, //
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, //
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// The per-round constants of MD5, the integer parts of `abs(sin(i + 1)) * 2^32`.
+const CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Calculates the MD5 digest of the data.
+///
+/// MD5 is broken for cryptographic purposes; this exists for puzzles that are built around it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::md5;
+///
+/// let digest = md5(b"abcdef609043");
+/// assert_eq!(&digest[..3], [0x00, 0x00, 0x01]);
+/// ```
+pub fn md5(data: &[u8]) -> [u8; 16] {
+    let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    // Pad with a single one bit, zeros up to 56 bytes modulo 64, and the length in bits.
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_le_bytes());
+
+    for chunk in message.chunks_exact(64) {
+        let mut words = [0u32; 16];
+        for (word, bytes) in words.iter_mut().zip(chunk.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().expect("chunk of four bytes"));
+        }
+
+        let [mut a, mut b, mut c, mut d] = state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+
+            let f = f
+                .wrapping_add(a)
+                .wrapping_add(CONSTANTS[i])
+                .wrapping_add(words[g]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(SHIFTS[i]));
+        }
+
+        for (value, added) in state.iter_mut().zip([a, b, c, d]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    let mut digest = [0; 16];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_le_bytes());
+    }
+    digest
+}
+
+/// The multiplier of the Fx hash, as used by the Rust compiler.
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// A fast, non-cryptographic hasher in the style of the Rust compiler's `FxHasher`.
+///
+/// It is not resistant against collision attacks (HashDoS), which doesn't matter for puzzle
+/// inputs, but is considerably faster than the standard library's SipHash for small keys
+/// such as integers, points or short strings.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::hash::FxBuildHasher;
+/// use std::collections::HashMap;
+///
+/// let mut map: HashMap<&str, u32, FxBuildHasher> = HashMap::default();
+/// map.insert("AAA", 1);
+/// assert_eq!(map.get("AAA"), Some(&1));
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+/// Builds [`FxHasher`] instances, e.g. for `HashMap<K, V, FxBuildHasher>`.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(FX_SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            self.add(u64::from_le_bytes(
+                chunk.try_into().expect("chunk of eight bytes"),
+            ));
+        }
+
+        let mut rest = chunks.remainder();
+        if rest.len() >= 4 {
+            let (word, tail) = rest.split_at(4);
+            self.add(u64::from(u32::from_le_bytes(
+                word.try_into().expect("four bytes"),
+            )));
+            rest = tail;
+        }
+        for &byte in rest {
+            self.add(u64::from(byte));
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.add(u64::from(value));
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.add(u64::from(value));
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.add(u64::from(value));
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.add(value);
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.add(value as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+
+    fn hex(digest: [u8; 16]) -> String {
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(hex(md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            hex(md5(b"The quick brown fox jumps over the lazy dog")),
+            "9e107d9d372bb6826bd81d3542a419d6"
+        );
+        assert_eq!(hex(md5(&[b'a'; 100])), "36a92cc94a9e0fa21f625f8bfb007adf");
+    }
+
+    #[test]
+    fn test_fx_hasher() {
+        use core::hash::{BuildHasher, Hash};
+
+        let hash = |value: &dyn Fn(&mut FxHasher)| {
+            let mut hasher = FxBuildHasher::default().build_hasher();
+            value(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(hash(&|h| 42u64.hash(h)), hash(&|h| 42u64.hash(h)));
+        assert_ne!(hash(&|h| 1u64.hash(h)), hash(&|h| 2u64.hash(h)));
+        assert_ne!(hash(&|h| "ab".hash(h)), hash(&|h| "ba".hash(h)));
+        assert_ne!(
+            hash(&|h| "0123456789abc".hash(h)),
+            hash(&|h| "0123456789abd".hash(h))
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub};
+use core::str::FromStr;
+
+/// A cell of a hexagonal grid in axial coordinates.
+///
+/// The third cube coordinate, [`s`](Self::s), follows from `q + r + s = 0`. Axial
+/// coordinates work the same for pointy-topped and flat-topped grids; only the names of the
+/// directions differ, see [`HexDirection`] and [`FlatHexDirection`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{FlatHexDirection, Hex};
+///
+/// let path: Vec<FlatHexDirection> = "ne,ne,s,s".split(',').map(|s| s.parse().unwrap()).collect();
+/// let end = path.iter().fold(Hex::ORIGIN, |hex, &direction| hex + direction.offset());
+/// assert_eq!(end.distance(Hex::ORIGIN), 2);
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// The six directions of a hexagonal grid whose cells have a corner at the top, so that
+/// neighbors lie east and west.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+/// The six directions of a hexagonal grid whose cells have a flat top, so that neighbors
+/// lie north and south.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum FlatHexDirection {
+    SouthEast,
+    NorthEast,
+    North,
+    NorthWest,
+    SouthWest,
+    South,
+}
+
+/// The offsets of the six neighbors, counterclockwise, in the order of both direction enums.
+const OFFSETS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+impl Hex {
+    /// The cell at the center of the grid.
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Gets the third cube coordinate.
+    pub const fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Calculates the number of steps to another cell.
+    pub fn distance(self, other: Self) -> u64 {
+        let delta = self - other;
+        (delta.q.unsigned_abs() + delta.r.unsigned_abs() + delta.s().unsigned_abs()) / 2
+    }
+
+    /// Gets the six adjacent cells, counterclockwise starting at the east or southeast.
+    pub fn neighbors(self) -> [Hex; 6] {
+        OFFSETS.map(|offset| self + offset)
+    }
+
+    /// Iterates the cells at exactly the given distance, counterclockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Hex;
+    ///
+    /// assert_eq!(Hex::ORIGIN.ring(0).collect::<Vec<_>>(), [Hex::ORIGIN]);
+    /// assert_eq!(Hex::ORIGIN.ring(1).count(), 6);
+    /// assert!(Hex::new(2, 3).ring(4).all(|hex| hex.distance(Hex::new(2, 3)) == 4));
+    /// ```
+    pub fn ring(self, radius: u64) -> impl Iterator<Item = Hex> {
+        let center = (radius == 0).then_some(self);
+        let radius = radius as i64;
+        let sides = (0..6).flat_map(move |side| {
+            // Each side starts at the corner reached by walking straight out, and turns
+            // counterclockwise from there.
+            let corner = self + OFFSETS[(side + 4) % 6] * radius;
+            (0..radius).map(move |step| corner + OFFSETS[side] * step)
+        });
+        center.into_iter().chain(sides)
+    }
+
+    /// Iterates the cells up to the given distance, ring by ring from the center outwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Hex;
+    ///
+    /// assert_eq!(Hex::ORIGIN.spiral(2).count(), 1 + 6 + 12);
+    /// ```
+    pub fn spiral(self, radius: u64) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+}
+
+impl HexDirection {
+    /// All directions, counterclockwise starting at [`HexDirection::East`].
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// Gets the offset of a single step in this direction.
+    pub const fn offset(self) -> Hex {
+        OFFSETS[self as usize]
+    }
+
+    /// Gets the direction after turning a sixth clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 5) % 6]
+    }
+
+    /// Gets the direction after turning a sixth counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 1) % 6]
+    }
+
+    /// Gets the opposite direction.
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+}
+
+impl FlatHexDirection {
+    /// All directions, counterclockwise starting at [`FlatHexDirection::SouthEast`].
+    pub const ALL: [FlatHexDirection; 6] = [
+        FlatHexDirection::SouthEast,
+        FlatHexDirection::NorthEast,
+        FlatHexDirection::North,
+        FlatHexDirection::NorthWest,
+        FlatHexDirection::SouthWest,
+        FlatHexDirection::South,
+    ];
+
+    /// Gets the offset of a single step in this direction.
+    pub const fn offset(self) -> Hex {
+        OFFSETS[self as usize]
+    }
+
+    /// Gets the direction after turning a sixth clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 5) % 6]
+    }
+
+    /// Gets the direction after turning a sixth counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 1) % 6]
+    }
+
+    /// Gets the opposite direction.
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Hex::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Self::Output {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = ParseHexDirectionError;
+
+    /// Parses the abbreviations `e`, `ne`, `nw`, `w`, `sw` and `se`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(HexDirection::East),
+            "ne" => Ok(HexDirection::NorthEast),
+            "nw" => Ok(HexDirection::NorthWest),
+            "w" => Ok(HexDirection::West),
+            "sw" => Ok(HexDirection::SouthWest),
+            "se" => Ok(HexDirection::SouthEast),
+            _ => Err(ParseHexDirectionError("unknown direction")),
+        }
+    }
+}
+
+impl FromStr for FlatHexDirection {
+    type Err = ParseHexDirectionError;
+
+    /// Parses the abbreviations `se`, `ne`, `n`, `nw`, `sw` and `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "se" => Ok(FlatHexDirection::SouthEast),
+            "ne" => Ok(FlatHexDirection::NorthEast),
+            "n" => Ok(FlatHexDirection::North),
+            "nw" => Ok(FlatHexDirection::NorthWest),
+            "sw" => Ok(FlatHexDirection::SouthWest),
+            "s" => Ok(FlatHexDirection::South),
+            _ => Err(ParseHexDirectionError("unknown direction")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseHexDirectionError(&'static str);
+
+impl Display for ParseHexDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Failed to parse hex direction: {}", self.0)
+    }
+}
+
+impl Error for ParseHexDirectionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn walk(path: &str) -> Hex {
+        path.split(',')
+            .map(|step| step.parse::<FlatHexDirection>().unwrap().offset())
+            .fold(Hex::ORIGIN, Add::add)
+    }
+
+    #[test]
+    fn test_flat_paths() {
+        assert_eq!(walk("ne,ne,ne").distance(Hex::ORIGIN), 3);
+        assert_eq!(walk("ne,ne,sw,sw"), Hex::ORIGIN);
+        assert_eq!(walk("ne,ne,s,s").distance(Hex::ORIGIN), 2);
+        assert_eq!(walk("se,sw,se,sw,sw").distance(Hex::ORIGIN), 3);
+        assert!("x".parse::<FlatHexDirection>().is_err());
+    }
+
+    #[test]
+    fn test_pointy_directions() {
+        // Walking around a hexagon returns to the start.
+        let end = [
+            "nw", "w", "sw", "e", "e", "ne", "se", "se", "w", "nw", "e", "w",
+        ]
+        .iter()
+        .map(|step| step.parse::<HexDirection>().unwrap().offset())
+        .fold(Hex::ORIGIN, Add::add);
+        assert_eq!(end, Hex::ORIGIN);
+
+        for direction in HexDirection::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+            assert_eq!(
+                direction.turn_right().turn_right().turn_right(),
+                direction.opposite()
+            );
+        }
+        assert_eq!(HexDirection::East.turn_right(), HexDirection::SouthEast);
+        assert_eq!(
+            FlatHexDirection::North.turn_right(),
+            FlatHexDirection::NorthEast
+        );
+    }
+
+    #[test]
+    fn test_rings_and_spirals() {
+        let center = Hex::new(-3, 5);
+        for radius in 0..6u64 {
+            let ring: Vec<_> = center.ring(radius).collect();
+            assert_eq!(ring.len() as u64, if radius == 0 { 1 } else { 6 * radius });
+            assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+            // Consecutive cells are adjacent, including the wrap-around.
+            if radius > 0 {
+                let next = ring.iter().cycle().skip(1);
+                assert!(ring.iter().zip(next).all(|(a, b)| a.distance(*b) == 1));
+            }
+        }
+
+        let mut spiral: Vec<_> = center.spiral(4).collect();
+        let count = spiral.len();
+        spiral.sort();
+        spiral.dedup();
+        assert_eq!((spiral.len(), count), (61, 61));
+    }
+
+    #[test]
+    fn test_distance() {
+        let (a, b) = (Hex::new(1, -3), Hex::new(-2, 4));
+        assert_eq!(a.distance(b), b.distance(a));
+        assert_eq!(a.distance(b), 7);
+        assert_eq!(a.s() + a.q + a.r, 0);
+        assert!(a.neighbors().iter().all(|&n| n.distance(a) == 1));
+    }
+}
@@ -0,0 +1,277 @@
+//! Selecting the puzzle input at runtime.
+//!
+//! Example binaries embed their input with `include_str!`, but can be pointed at another
+//! input without editing the source:
+//!
+//! ```shell
+//! cargo run --example 2024-day-1 -- --input path/to/input.txt
+//! cargo run --example 2024-day-1 -- --input - < path/to/input.txt
+//! cargo run --example 2024-day-1 -- --input cache:2024/1
+//! ```
+//!
+//! Cached inputs are read from `$AOC_CACHE_DIR/<year>/day-<day>.txt`, defaulting to
+//! `~/.cache/aoc` if the variable isn't set.
+
+use std::borrow::Cow;
+use std::boxed::Box;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::format;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::string::{String, ToString};
+
+/// The command-line option selecting the input.
+const OPTION: &str = "--input";
+
+/// The source of a puzzle input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Input {
+    /// An input compiled into the binary, e.g. via `include_str!`.
+    Embedded(&'static str),
+    /// A file on disk.
+    File(PathBuf),
+    /// The standard input.
+    Stdin,
+    /// A previously downloaded input in the cache directory.
+    Cache { year: u16, day: u8 },
+}
+
+impl Input {
+    /// Selects the input from the `--input` command-line option, falling back to the
+    /// embedded input if the option isn't given.
+    pub fn from_args(embedded: &'static str) -> Result<Self, InputError> {
+        Self::parse_args(std::env::args().skip(1), embedded)
+    }
+
+    /// Selects the input from the command-line options and reads it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::input::Input;
+    ///
+    /// let input = Input::load("1 2 3").expect("failed to load the input");
+    /// assert_eq!(input, "1 2 3");
+    /// ```
+    pub fn load(embedded: &'static str) -> Result<Cow<'static, str>, InputError> {
+        Self::from_args(embedded)?.read()
+    }
+
+    /// Selects the input from the specified arguments, not including the program name.
+    pub fn parse_args<I, S>(args: I, embedded: &'static str) -> Result<Self, InputError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            let value = if arg == OPTION {
+                match args.next() {
+                    Some(value) => value.as_ref().to_string(),
+                    None => return Err(InputError::MissingValue),
+                }
+            } else if let Some(value) = arg.strip_prefix("--input=") {
+                value.to_string()
+            } else {
+                continue;
+            };
+
+            return value.parse();
+        }
+
+        Ok(Self::Embedded(embedded))
+    }
+
+    /// Reads the input. Embedded inputs are returned without copying.
+    pub fn read(&self) -> Result<Cow<'static, str>, InputError> {
+        match self {
+            Input::Embedded(input) => Ok(Cow::Borrowed(input)),
+            Input::File(path) => read_file(path.clone()),
+            Input::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| InputError::Io(None, error.kind()))?;
+                Ok(Cow::Owned(input))
+            }
+            Input::Cache { year, day } => {
+                read_file(cache_dir()?.join(format!("{year}/day-{day}.txt")))
+            }
+        }
+    }
+
+    /// Opens the input for reading it line by line, without loading it into memory first.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Input::Embedded(input) => Ok(Box::new(input.as_bytes())),
+            Input::File(path) => open_file(path.clone()),
+            Input::Stdin => Ok(Box::new(std::io::stdin().lock())),
+            Input::Cache { year, day } => {
+                open_file(cache_dir()?.join(format!("{year}/day-{day}.txt")))
+            }
+        }
+    }
+}
+
+/// Folds the lines of a reader, without their `\n` or `\r\n` terminators.
+///
+/// A single buffer is reused for all lines, so the input never needs to be resident at once.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::input::fold_lines;
+///
+/// let sum = fold_lines("1\n2\r\n3".as_bytes(), 0, |sum, line| Ok(sum + line.len()));
+/// assert_eq!(sum.unwrap(), 3);
+/// ```
+pub fn fold_lines<R, T, F>(mut reader: R, init: T, mut f: F) -> std::io::Result<T>
+where
+    R: BufRead,
+    F: FnMut(T, &str) -> std::io::Result<T>,
+{
+    let mut line = String::new();
+    let mut acc = init;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(acc);
+        }
+        let content = line.strip_suffix('\n').unwrap_or(&line);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        acc = f(acc, content)?;
+    }
+}
+
+impl std::str::FromStr for Input {
+    type Err = InputError;
+
+    /// Parses `-` as the standard input, `cache:<year>/<day>` as a cached input
+    /// and everything else as a path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            return Ok(Input::Stdin);
+        }
+
+        if let Some(cached) = s.strip_prefix("cache:") {
+            let (year, day) = cached.split_once('/').ok_or(InputError::InvalidCacheKey)?;
+            let year = year.parse().map_err(|_| InputError::InvalidCacheKey)?;
+            let day = day.parse().map_err(|_| InputError::InvalidCacheKey)?;
+            return Ok(Input::Cache { year, day });
+        }
+
+        Ok(Input::File(PathBuf::from(s)))
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<Cow<'static, str>, InputError> {
+    std::fs::read_to_string(&path)
+        .map(Cow::Owned)
+        .map_err(|error| InputError::Io(Some(path), error.kind()))
+}
+
+fn open_file(path: PathBuf) -> Result<Box<dyn BufRead>, InputError> {
+    match File::open(&path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(error) => Err(InputError::Io(Some(path), error.kind())),
+    }
+}
+
+/// Gets the directory of the cached inputs, `$AOC_CACHE_DIR` or `~/.cache/aoc`.
+pub fn cache_dir() -> Result<PathBuf, InputError> {
+    if let Some(dir) = std::env::var_os("AOC_CACHE_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".cache").join("aoc"))
+        .ok_or(InputError::NoCacheDir)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputError {
+    /// The `--input` option was given without a value.
+    MissingValue,
+    /// The cache key is not of the form `<year>/<day>`.
+    InvalidCacheKey,
+    /// Neither `AOC_CACHE_DIR` nor `HOME` is set.
+    NoCacheDir,
+    /// The input could not be read from the file, or the standard input if there is no path.
+    Io(Option<PathBuf>, std::io::ErrorKind),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingValue => write!(f, "Missing value for the {OPTION} option"),
+            InputError::InvalidCacheKey => {
+                write!(f, "Invalid cache key, expected cache:<year>/<day>")
+            }
+            InputError::NoCacheDir => {
+                write!(f, "Unable to locate the cache directory, set AOC_CACHE_DIR")
+            }
+            InputError::Io(Some(path), kind) => {
+                write!(f, "Failed to read {}: {kind}", path.display())
+            }
+            InputError::Io(None, kind) => write!(f, "Failed to read the standard input: {kind}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| Input::parse_args(args, "embedded");
+        assert_eq!(parse(&[]), Ok(Input::Embedded("embedded")));
+        assert_eq!(parse(&["--release"]), Ok(Input::Embedded("embedded")));
+        assert_eq!(parse(&["--input", "-"]), Ok(Input::Stdin));
+        assert_eq!(
+            parse(&["--input=day-1.txt"]),
+            Ok(Input::File(PathBuf::from("day-1.txt")))
+        );
+        assert_eq!(
+            parse(&["--input", "cache:2024/1"]),
+            Ok(Input::Cache { year: 2024, day: 1 })
+        );
+        assert_eq!(parse(&["--input"]), Err(InputError::MissingValue));
+        assert_eq!(
+            parse(&["--input", "cache:2024"]),
+            Err(InputError::InvalidCacheKey)
+        );
+    }
+
+    #[test]
+    fn test_fold_lines() {
+        let lines = fold_lines("a\r\n\nbc\n".as_bytes(), Vec::new(), |mut lines, line| {
+            lines.push(line.to_string());
+            Ok(lines)
+        });
+        assert_eq!(lines.unwrap(), ["a", "", "bc"]);
+    }
+
+    #[test]
+    fn test_read() {
+        let input = Input::Embedded("1 2 3").read().expect("failed to read");
+        assert!(matches!(input, Cow::Borrowed("1 2 3")));
+
+        let error = Input::File(PathBuf::from("does/not/exist.txt"))
+            .read()
+            .expect_err("file should not exist");
+        assert_eq!(
+            error,
+            InputError::Io(
+                Some(PathBuf::from("does/not/exist.txt")),
+                std::io::ErrorKind::NotFound
+            )
+        );
+    }
+}
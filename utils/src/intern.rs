@@ -0,0 +1,132 @@
+//! Interning keys, such as node labels, into dense ids usable as array indices.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc_utils::intern::Interner;
+//!
+//! let mut labels = Interner::new();
+//! let aaa = labels.intern("AAA");
+//! let zzz = labels.intern("ZZZ");
+//! assert_eq!((aaa, zzz), (0, 1));
+//! assert_eq!(labels.intern("AAA"), aaa);
+//! assert_eq!(labels.get("ZZZ"), Some(zzz));
+//! assert_eq!(labels[zzz], "ZZZ");
+//! ```
+
+use crate::collections::FastMap;
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::ops::Index;
+use std::vec::Vec;
+
+/// Assigns consecutive `u32` ids to keys, in the order they are first seen.
+///
+/// Serializes as the list of keys, whose positions are their ids.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Interner<K> {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ids: FastMap<K, u32>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Self {
+            ids: FastMap::default(),
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Interner<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the id of the key, assigning the next free one if it wasn't seen before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` keys are interned.
+    pub fn intern(&mut self, key: K) -> u32 {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+
+        let id = u32::try_from(self.keys.len()).expect("too many interned keys");
+        self.keys.push(key.clone());
+        self.ids.insert(key, id);
+        id
+    }
+
+    /// Gets the id of a previously interned key.
+    pub fn get<Q>(&self, key: &Q) -> Option<u32>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+}
+
+impl<K> Interner<K> {
+    /// Gets the key of an id, if it was assigned.
+    pub fn resolve(&self, id: u32) -> Option<&K> {
+        self.keys.get(id as usize)
+    }
+
+    /// Gets the number of interned keys, which is also the next id to be assigned.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterates the ids and their keys in the order of the ids.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &K)> {
+        (0..).zip(&self.keys)
+    }
+}
+
+impl<K> Index<u32> for Interner<K> {
+    type Output = K;
+
+    /// Gets the key of an id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id wasn't assigned.
+    fn index(&self, id: u32) -> &K {
+        &self.keys[id as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn test_interner() {
+        let mut interner: Interner<String> = Interner::new();
+        assert!(interner.is_empty());
+        assert_eq!(interner.intern("b".to_string()), 0);
+        assert_eq!(interner.intern("a".to_string()), 1);
+        assert_eq!(interner.intern("b".to_string()), 0);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("a"), Some(1));
+        assert_eq!(interner.get("c"), None);
+        assert_eq!(interner.resolve(1).map(String::as_str), Some("a"));
+        assert_eq!(interner.resolve(2), None);
+
+        let keys: Vec<_> = interner
+            .iter()
+            .map(|(id, key)| (id, key.as_str()))
+            .collect();
+        assert_eq!(keys, [(0, "b"), (1, "a")]);
+    }
+}
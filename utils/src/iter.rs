@@ -0,0 +1,200 @@
+//! Iterator helpers for sorted and grouped data.
+
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+/// Joins two slices sorted by the same key, yielding the groups of elements whose key
+/// occurs in both slices.
+///
+/// Each item holds the run of elements sharing a key in the first slice and the run sharing
+/// it in the second. Keys found in only one of the slices are skipped by binary search.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::iter::merge_join;
+///
+/// let lhs = [1, 3, 3, 3, 4];
+/// let rhs = [3, 3, 4, 5, 9];
+/// let groups: Vec<_> = merge_join(&lhs, &rhs, |&value| value).collect();
+/// assert_eq!(groups, [(&[3, 3, 3][..], &[3, 3][..]), (&[4][..], &[4][..])]);
+/// ```
+pub fn merge_join<'a, T, K, F>(sorted_a: &'a [T], sorted_b: &'a [T], key: F) -> MergeJoin<'a, T, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    MergeJoin {
+        a: sorted_a,
+        b: sorted_b,
+        key,
+    }
+}
+
+/// An iterator over the groups of matching keys in two sorted slices; see [`merge_join`].
+#[derive(Debug, Clone)]
+pub struct MergeJoin<'a, T, F> {
+    a: &'a [T],
+    b: &'a [T],
+    key: F,
+}
+
+impl<'a, T, K, F> Iterator for MergeJoin<'a, T, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = &mut self.key;
+        loop {
+            let key_a = key(self.a.first()?);
+            let key_b = key(self.b.first()?);
+            match key_a.cmp(&key_b) {
+                Ordering::Less => {
+                    let skip = self.a.partition_point(|item| key(item) < key_b);
+                    self.a = &self.a[skip..];
+                }
+                Ordering::Greater => {
+                    let skip = self.b.partition_point(|item| key(item) < key_a);
+                    self.b = &self.b[skip..];
+                }
+                Ordering::Equal => {
+                    let (group_a, rest_a) = self
+                        .a
+                        .split_at(self.a.partition_point(|item| key(item) <= key_a));
+                    let (group_b, rest_b) = self
+                        .b
+                        .split_at(self.b.partition_point(|item| key(item) <= key_b));
+                    self.a = rest_a;
+                    self.b = rest_b;
+                    return Some((group_a, group_b));
+                }
+            }
+        }
+    }
+}
+
+/// Collapses runs of equal consecutive items into `(item, count)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::iter::run_lengths;
+///
+/// // One step of the look-and-say sequence.
+/// let runs: Vec<_> = run_lengths("1211".chars()).collect();
+/// assert_eq!(runs, [('1', 1), ('2', 1), ('1', 2)]);
+/// ```
+pub fn run_lengths<I>(iter: I) -> RunLengths<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: PartialEq,
+{
+    RunLengths {
+        iter: iter.into_iter().peekable(),
+    }
+}
+
+/// Collapses runs of consecutive items sharing a key into `(first item, count)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::iter::group_consecutive_by;
+///
+/// let groups: Vec<_> = group_consecutive_by([1, 3, 4, 6, 8, 9], |n| n % 2).collect();
+/// assert_eq!(groups, [(1, 2), (4, 3), (9, 1)]);
+/// ```
+pub fn group_consecutive_by<I, K, F>(iter: I, key: F) -> GroupConsecutiveBy<I::IntoIter, F>
+where
+    I: IntoIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    GroupConsecutiveBy {
+        iter: iter.into_iter().peekable(),
+        key,
+    }
+}
+
+/// An iterator over the runs of equal items; see [`run_lengths`].
+pub struct RunLengths<I: Iterator> {
+    iter: Peekable<I>,
+}
+
+impl<I> Iterator for RunLengths<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut count = 1;
+        while self.iter.next_if_eq(&first).is_some() {
+            count += 1;
+        }
+        Some((first, count))
+    }
+}
+
+/// An iterator over the runs of items sharing a key; see [`group_consecutive_by`].
+pub struct GroupConsecutiveBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for GroupConsecutiveBy<I, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let group = (self.key)(&first);
+        let mut count = 1;
+        while self
+            .iter
+            .next_if(|item| (self.key)(item) == group)
+            .is_some()
+        {
+            count += 1;
+        }
+        Some((first, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_merge_join_without_matches() {
+        assert_eq!(merge_join(&[1, 2], &[], |&v: &i32| v).count(), 0);
+        assert_eq!(merge_join(&[1, 3, 5], &[2, 4, 6], |&v| v).count(), 0);
+    }
+
+    #[test]
+    fn test_run_lengths() {
+        assert_eq!(run_lengths(core::iter::empty::<u8>()).count(), 0);
+        let runs: Vec<_> = run_lengths([7, 7, 7]).collect();
+        assert_eq!(runs, [(7, 3)]);
+        let runs: Vec<_> = run_lengths("aabccc".bytes()).collect();
+        assert_eq!(runs, [(b'a', 2), (b'b', 1), (b'c', 3)]);
+    }
+
+    #[test]
+    fn test_merge_join_by_key() {
+        let lhs = [(1, 'a'), (2, 'b'), (2, 'c'), (7, 'd')];
+        let rhs = [(0, 'e'), (2, 'f'), (7, 'g'), (7, 'h')];
+        let groups: Vec<_> = merge_join(&lhs, &rhs, |&(key, _)| key).collect();
+        assert_eq!(groups, [(&lhs[1..3], &rhs[1..2]), (&lhs[3..4], &rhs[2..4])]);
+    }
+}
@@ -1,4 +1,99 @@
-use std::str::FromStr;
+//! Helpers shared by the Advent of Code solutions.
+//!
+//! The crate is `no_std` and only requires `alloc`; everything touching the file system,
+//! the environment or std's hash maps is gated behind the default `std` feature.
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+mod bit_grid;
+#[cfg(feature = "std")]
+pub mod collections;
+pub mod combinatorics;
+#[cfg(feature = "std")]
+mod compress;
+pub mod diff;
+mod disjoint_set;
+#[cfg(feature = "std")]
+pub mod example;
+mod extrapolate;
+#[cfg(feature = "std")]
+mod flow;
+#[cfg(feature = "std")]
+mod graph;
+mod grid;
+pub mod hash;
+mod hex;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "std")]
+pub mod intern;
+pub mod iter;
+mod linear;
+mod math;
+mod matrix;
+mod modular;
+pub mod num;
+#[cfg(feature = "std")]
+pub mod par;
+mod prefix_sum;
+#[cfg(feature = "std")]
+pub mod property;
+mod puzzle;
+pub mod radix;
+mod range_set;
+mod rational;
+mod regions;
+pub mod render;
+pub mod scan;
+#[cfg(feature = "std")]
+mod search;
+mod segment;
+mod simulation;
+pub mod trace;
+mod trie;
+mod vec3;
+#[cfg(feature = "std")]
+pub mod vm;
+
+pub use bit_grid::BitGrid;
+#[cfg(feature = "std")]
+pub use compress::{compress, compress_2d, Compressed2D};
+pub use disjoint_set::DisjointSet;
+pub use extrapolate::{differentiate, extrapolate_at, extrapolate_next, extrapolate_previous};
+#[cfg(feature = "std")]
+pub use flow::FlowNetwork;
+#[cfg(feature = "std")]
+pub use graph::{Graph, MaximalCliques};
+#[cfg(feature = "std")]
+pub use grid::SparseGrid;
+pub use grid::{Direction, Grid, ParseGridError, Point};
+pub use hash::md5;
+pub use hex::{FlatHexDirection, Hex, HexDirection, ParseHexDirectionError};
+pub use linear::{solve_integer_2x2, solve_linear_system};
+pub use math::{checked_lcm, checked_lcm_slice, gcd, lcm, lcm_slice, OverflowError};
+pub use matrix::{Field, Matrix, Ring};
+pub use modular::ModInt;
+pub use prefix_sum::PrefixSum2D;
+pub use puzzle::PuzzleMeta;
+pub use range_set::RangeSet;
+pub use rational::{gcd_i128, Rational};
+pub use regions::{label_regions, Region, Regions};
+#[cfg(feature = "std")]
+pub use search::{bfs_distances, bfs_distances_within, count_paths, dijkstra_distances};
+pub use segment::{
+    on_segment, orientation, ray_crossing_count, segment_intersection, Orientation,
+    SegmentIntersection,
+};
+pub use simulation::{Cycle, Simulate, Simulation, Snapshot};
+pub use trie::{Keys, Prefixes, Trie};
+pub use vec3::{BoundingBox3, ParseVec3Error, Rotation3, Vec3};
 
 /// Parses whitespace-delimited values from an input string.
 ///
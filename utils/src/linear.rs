@@ -0,0 +1,129 @@
+use crate::{Matrix, Rational};
+use alloc::vec::Vec;
+
+/// Solves the linear system `a · x = b` exactly using Gaussian elimination; see [`Matrix::solve`].
+///
+/// # Arguments
+///
+/// * `a` - The square coefficient matrix, given as a list of rows.
+/// * `b` - The right-hand side; must have one entry per row of `a`.
+///
+/// # Returns
+///
+/// The unique solution `x`, or `None` if the matrix is singular.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{solve_linear_system, Rational};
+///
+/// // x + y = 3, x - y = 1
+/// let a = vec![
+///     vec![Rational::from(1), Rational::from(1)],
+///     vec![Rational::from(1), Rational::from(-1)],
+/// ];
+/// let b = vec![Rational::from(3), Rational::from(1)];
+/// assert_eq!(
+///     solve_linear_system(a, b),
+///     Some(vec![Rational::from(2), Rational::from(1)])
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if the dimensions don't match.
+pub fn solve_linear_system(a: Vec<Vec<Rational>>, b: Vec<Rational>) -> Option<Vec<Rational>> {
+    Matrix::from_rows(a).solve(&b)
+}
+
+/// Solves the 2×2 linear system `a · x = b` exactly and returns the solution only if
+/// it is integral.
+///
+/// Uses Cramer's rule on `i128` values, so no intermediate result can overflow.
+///
+/// # Arguments
+///
+/// * `a` - The coefficient matrix, given as a list of rows.
+/// * `b` - The right-hand side.
+///
+/// # Returns
+///
+/// The unique integer solution `x`, or `None` if the matrix is singular or the
+/// solution is fractional.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::solve_integer_2x2;
+///
+/// // 94a + 22b = 8400, 34a + 67b = 5400
+/// assert_eq!(solve_integer_2x2([[94, 22], [34, 67]], [8400, 5400]), Some([80, 40]));
+///
+/// // 2a = 1 has no integer solution.
+/// assert_eq!(solve_integer_2x2([[2, 0], [0, 1]], [1, 1]), None);
+/// ```
+pub fn solve_integer_2x2(a: [[i64; 2]; 2], b: [i64; 2]) -> Option<[i64; 2]> {
+    let [[a11, a12], [a21, a22]] = a.map(|row| row.map(i128::from));
+    let [b1, b2] = b.map(i128::from);
+
+    let determinant = a11 * a22 - a12 * a21;
+    if determinant == 0 {
+        return None;
+    }
+
+    let x1 = b1 * a22 - a12 * b2;
+    let x2 = a11 * b2 - b1 * a21;
+    if x1 % determinant != 0 || x2 % determinant != 0 {
+        return None;
+    }
+
+    Some([
+        i64::try_from(x1 / determinant).ok()?,
+        i64::try_from(x2 / determinant).ok()?,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn matrix(rows: &[&[i128]]) -> Vec<Vec<Rational>> {
+        rows.iter()
+            .map(|row| row.iter().map(|&v| Rational::from(v)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_solve_requires_pivoting() {
+        let a = matrix(&[&[0, 2, 1], &[1, 1, 1], &[2, 1, 0]]);
+        let b = vec![Rational::from(5), Rational::from(4), Rational::from(4)];
+        let x = solve_linear_system(a, b).expect("system is solvable");
+        assert_eq!(x, [Rational::from(1), Rational::from(2), Rational::from(1)]);
+    }
+
+    #[test]
+    fn test_solve_fractional() {
+        let a = matrix(&[&[2, 0], &[0, 3]]);
+        let b = vec![Rational::from(1), Rational::from(1)];
+        let x = solve_linear_system(a, b).expect("system is solvable");
+        assert_eq!(x, [Rational::new(1, 2), Rational::new(1, 3)]);
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a = matrix(&[&[1, 2], &[2, 4]]);
+        let b = vec![Rational::from(1), Rational::from(2)];
+        assert_eq!(solve_linear_system(a, b), None);
+    }
+
+    #[test]
+    fn test_solve_integer_2x2() {
+        assert_eq!(
+            solve_integer_2x2([[26, 67], [66, 21]], [12748, 12176]),
+            None
+        );
+        assert_eq!(solve_integer_2x2([[1, 2], [2, 4]], [3, 6]), None);
+        assert_eq!(solve_integer_2x2([[-1, 1], [1, 1]], [1, 5]), Some([2, 3]));
+    }
+}
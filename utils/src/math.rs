@@ -0,0 +1,146 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// Calculate the greatest common divisor (GCD) of two numbers.
+///
+/// The GCD is the largest positive integer that divides both `a` and `b` without remainder.
+/// This function uses the Euclidean algorithm to calculate the GCD.
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The GCD of `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::gcd;
+///
+/// let result = gcd(10, 15);
+/// assert_eq!(result, 5);
+///
+/// let result = gcd(24, 36);
+/// assert_eq!(result, 12);
+/// ```
+///
+pub fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Calculates the least common multiple (LCM) of two numbers.
+///
+/// # Arguments
+///
+/// * `a` - A positive integer number.
+/// * `b` - Another positive integer number.
+///
+/// # Returns
+///
+/// The LCM of `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::lcm;
+///
+/// let result = lcm(12, 18);
+/// assert_eq!(result, 36);
+/// ```
+pub fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+/// Calculates the least common multiple (LCM) of a vector of numbers.
+///
+/// The function takes a vector of `usize` numbers and returns their
+/// least common multiple (LCM).
+///
+/// # Arguments
+///
+/// * `numbers` - A vector of `usize` numbers.
+///
+/// # Returns
+///
+/// The LCM of the given numbers.
+///
+/// # Panics
+///
+/// The function will panic if called with an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use core::iter::FromIterator;
+/// use aoc_utils::lcm_slice;
+///
+/// let numbers = Vec::from_iter([2, 3, 4, 5]);
+/// let lcm = lcm_slice(&numbers);
+/// assert_eq!(lcm, 60);
+/// ```
+pub fn lcm_slice(numbers: &[usize]) -> usize {
+    let mut iter = numbers.iter();
+    let &first = iter.next().unwrap();
+    iter.fold(first, |a, &b| lcm(a, b))
+}
+
+/// The error returned when a result doesn't fit into its integer type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OverflowError;
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl Error for OverflowError {}
+
+/// Calculates the least common multiple (LCM) of two numbers, failing instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{checked_lcm, OverflowError};
+///
+/// assert_eq!(checked_lcm(12, 18), Ok(36));
+/// assert_eq!(checked_lcm(u128::MAX, 2), Err(OverflowError));
+/// ```
+pub fn checked_lcm(a: u128, b: u128) -> Result<u128, OverflowError> {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    if x == 0 {
+        return Ok(0);
+    }
+    (a / x).checked_mul(b).ok_or(OverflowError)
+}
+
+/// Calculates the least common multiple (LCM) of a slice of numbers, failing instead of
+/// overflowing.
+///
+/// # Panics
+///
+/// The function will panic if called with an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::checked_lcm_slice;
+///
+/// assert_eq!(checked_lcm_slice(&[2, 3, 4, 5]), Ok(60));
+/// ```
+pub fn checked_lcm_slice(numbers: &[u128]) -> Result<u128, OverflowError> {
+    let mut iter = numbers.iter();
+    let &first = iter.next().unwrap();
+    iter.try_fold(first, |a, &b| checked_lcm(a, b))
+}
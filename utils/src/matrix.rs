@@ -0,0 +1,354 @@
+use crate::{ModInt, Rational};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+/// A number type that can be added and multiplied, such as the matrix entries.
+pub trait Ring:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// The neutral element of addition.
+    const ZERO: Self;
+    /// The neutral element of multiplication.
+    const ONE: Self;
+}
+
+/// A number type that can also be divided by anything but zero, which makes exact elimination
+/// possible.
+pub trait Field: Ring + Div<Output = Self> {}
+
+macro_rules! impl_ring {
+    ($($t:ty),*) => {
+        $(impl Ring for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+impl_ring!(i32, i64, i128, u32, u64, u128, usize);
+
+impl Ring for Rational {
+    const ZERO: Self = Rational::ZERO;
+    const ONE: Self = Rational::ONE;
+}
+
+impl Field for Rational {}
+
+impl<const M: u64> Ring for ModInt<M> {
+    const ZERO: Self = ModInt::ZERO;
+    const ONE: Self = ModInt::ONE;
+}
+
+impl<const M: u64> Field for ModInt<M> {}
+
+/// A dense matrix, stored row by row.
+///
+/// # Examples
+///
+/// Fast-forwarding a linear recurrence, such as the Fibonacci numbers:
+///
+/// ```
+/// use aoc_utils::Matrix;
+///
+/// let step = Matrix::from_rows(vec![vec![1u64, 1], vec![1, 0]]);
+/// assert_eq!(step.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Ring> Matrix<T> {
+    /// Creates a matrix with every entry set to zero.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![T::ZERO; rows * cols],
+        }
+    }
+
+    /// Creates the square matrix with ones on the diagonal and zeros elsewhere.
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
+        for i in 0..size {
+            matrix[(i, i)] = T::ONE;
+        }
+        matrix
+    }
+
+    /// Creates a matrix from its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows must have the same length"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Gets the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Gets the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Gets the entries of a row.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..][..self.cols]
+    }
+
+    /// Gets the matrix with rows and columns swapped.
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed[(col, row)] = self[(row, col)];
+            }
+        }
+        transposed
+    }
+
+    /// Multiplies the matrix with a column vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector doesn't have one entry per column.
+    pub fn mul_vector(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(
+            vector.len(),
+            self.cols,
+            "vector must have one entry per column"
+        );
+        (0..self.rows)
+            .map(|row| dot(self.row(row), vector))
+            .collect()
+    }
+
+    /// Raises the square matrix to the given power by repeated squaring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix isn't square.
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+        let mut base = self.clone();
+        let mut result = Self::identity(self.rows);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Solves `self · x = b` exactly using Gaussian elimination.
+    ///
+    /// Returns the unique solution `x`, or `None` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Matrix, ModInt};
+    ///
+    /// type Mod5 = ModInt<5>;
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Mod5::new(2), Mod5::new(1)],
+    ///     vec![Mod5::new(1), Mod5::new(4)],
+    /// ]);
+    /// let x = a.solve(&[Mod5::new(1), Mod5::new(0)]).unwrap();
+    /// assert_eq!(a.mul_vector(&x), [Mod5::new(1), Mod5::new(0)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix isn't square or `b` doesn't have one entry per row.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(
+            b.len(),
+            self.rows,
+            "right-hand side must have one entry per row"
+        );
+        assert_eq!(self.rows, self.cols, "coefficient matrix must be square");
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut b = b.to_vec();
+
+        for column in 0..n {
+            // Any non-zero pivot works since the arithmetic is exact.
+            let pivot = a.pivot(column)?;
+            a.swap_rows(column, pivot);
+            b.swap(column, pivot);
+
+            for row in column + 1..n {
+                if a[(row, column)] == T::ZERO {
+                    continue;
+                }
+
+                let factor = a[(row, column)] / a[(column, column)];
+                for k in column..n {
+                    a[(row, k)] = a[(row, k)] - factor * a[(column, k)];
+                }
+                b[row] = b[row] - factor * b[column];
+            }
+        }
+
+        // Back substitution.
+        let mut x = vec![T::ZERO; n];
+        for row in (0..n).rev() {
+            let sum = (row + 1..n).fold(b[row], |sum, k| sum - a[(row, k)] * x[k]);
+            x[row] = sum / a[(row, row)];
+        }
+        Some(x)
+    }
+
+    /// Calculates the determinant of the square matrix using Gaussian elimination.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix isn't square.
+    pub fn determinant(&self) -> T {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut determinant = T::ONE;
+
+        for column in 0..n {
+            let Some(pivot) = a.pivot(column) else {
+                return T::ZERO;
+            };
+            if pivot != column {
+                a.swap_rows(column, pivot);
+                determinant = T::ZERO - determinant;
+            }
+            determinant = determinant * a[(column, column)];
+
+            for row in column + 1..n {
+                let factor = a[(row, column)] / a[(column, column)];
+                for k in column..n {
+                    a[(row, k)] = a[(row, k)] - factor * a[(column, k)];
+                }
+            }
+        }
+        determinant
+    }
+
+    /// Finds the first row from the diagonal down with a non-zero entry in the column.
+    fn pivot(&self, column: usize) -> Option<usize> {
+        (column..self.rows).find(|&row| self[(row, column)] != T::ZERO)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.cells.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Gets the entry at `(row, column)`.
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl<T: Ring> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the columns of the left matrix don't match the rows of the right one.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows, "matrix dimensions must match");
+        let rhs = rhs.transpose();
+        let mut product = Matrix::zeros(self.rows, rhs.rows);
+        for row in 0..self.rows {
+            for col in 0..rhs.rows {
+                product[(row, col)] = dot(self.row(row), rhs.row(col));
+            }
+        }
+        product
+    }
+}
+
+impl<T: Ring> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+fn dot<T: Ring>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::ZERO, |sum, (&a, &b)| sum + a * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul() {
+        let a = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from_rows(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(
+            &a * &b,
+            Matrix::from_rows(vec![vec![58, 64], vec![139, 154]])
+        );
+        assert_eq!(a.mul_vector(&[1, 0, -1]), [-2, -2]);
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn test_pow_mod_p() {
+        type Mod = ModInt<1_000_000_007>;
+        let step = Matrix::from_rows(vec![vec![Mod::ONE, Mod::ONE], vec![Mod::ONE, Mod::ZERO]]);
+        assert_eq!(step.pow(0), Matrix::identity(2));
+        // F(1000) mod 1e9+7.
+        assert_eq!(step.pow(1000)[(0, 1)], Mod::new(517_691_607));
+    }
+
+    #[test]
+    fn test_determinant() {
+        let matrix = |rows: [[i64; 3]; 3]| {
+            Matrix::from_rows(rows.map(|row| row.map(Rational::from).to_vec()).to_vec())
+        };
+        let a = matrix([[0, 2, 1], [1, 1, 1], [2, 1, 0]]);
+        assert_eq!(a.determinant(), Rational::from(3));
+        assert_eq!(
+            matrix([[1, 2, 3], [2, 4, 6], [0, 1, 1]]).determinant(),
+            Rational::ZERO
+        );
+    }
+}
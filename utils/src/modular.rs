@@ -0,0 +1,146 @@
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An integer modulo `M`, always stored as its remainder in `0..M`.
+///
+/// Division uses Fermat's little theorem and therefore requires `M` to be prime.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::ModInt;
+///
+/// type Mod7 = ModInt<7>;
+/// let a = Mod7::new(5);
+/// assert_eq!(a + Mod7::new(4), Mod7::new(2));
+/// assert_eq!(a * Mod7::new(3), Mod7::new(1));
+/// assert_eq!(Mod7::new(1) / a, Mod7::new(3));
+/// assert_eq!(-a, Mod7::new(2));
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// The value `0`.
+    pub const ZERO: ModInt<M> = ModInt { value: 0 };
+
+    /// The value `1`.
+    pub const ONE: ModInt<M> = ModInt { value: 1 % M };
+
+    /// Creates the remainder of the value modulo `M`.
+    pub const fn new(value: u64) -> Self {
+        Self { value: value % M }
+    }
+
+    /// Gets the remainder in `0..M`.
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Raises the value to the given power by repeated squaring.
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let (mut base, mut result) = (self, Self::ONE);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Gets the multiplicative inverse, or `None` for zero. Requires `M` to be prime.
+    pub fn inverse(self) -> Option<Self> {
+        (self.value != 0).then(|| self.pow(M - 2))
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        Self::new((value as i128).rem_euclid(M as i128) as u64)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(((self.value as u128 + rhs.value as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new((self.value as u128 * rhs.value as u128 % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = ModInt<M>;
+
+    /// # Panics
+    ///
+    /// Panics when dividing by zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(M - self.value)
+    }
+}
+
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIME: u64 = 1_000_000_007;
+
+    #[test]
+    fn test_inverse() {
+        for value in [1, 2, 12345, PRIME - 1] {
+            let value = ModInt::<PRIME>::new(value);
+            assert_eq!(value * value.inverse().unwrap(), ModInt::ONE);
+        }
+        assert_eq!(ModInt::<PRIME>::ZERO.inverse(), None);
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        const LARGE: u64 = u64::MAX - 58; // The largest 64-bit prime.
+        let a = ModInt::<LARGE>::new(LARGE - 1);
+        assert_eq!(a + a, ModInt::new(LARGE - 2));
+        assert_eq!(a * a, ModInt::ONE);
+        assert_eq!(ModInt::<LARGE>::from(-1i64), a);
+    }
+}
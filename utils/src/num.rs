@@ -0,0 +1,204 @@
+//! Numeric helpers that work across all integer widths.
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::ops::{Add, Sub};
+
+/// A primitive integer type.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    /// The unsigned type of the same width.
+    type Unsigned;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute difference, which always fits the unsigned type.
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    /// The difference `self - other`, clamped to the bounds of the type.
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($type:ty => $unsigned:ty),*) => {
+        $(
+            impl Integer for $type {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    <$type>::abs_diff(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$type>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// Gets the absolute difference of two integers, without overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::abs_diff;
+///
+/// assert_eq!(abs_diff(3u8, 7), 4);
+/// assert_eq!(abs_diff(i64::MIN, i64::MAX), u64::MAX);
+/// ```
+pub fn abs_diff<T: Integer>(a: T, b: T) -> T::Unsigned {
+    a.abs_diff(b)
+}
+
+/// Gets the change from `from` to `to`, clamped to the bounds of the type.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::saturating_delta;
+///
+/// assert_eq!(saturating_delta(3u32, 7), 4);
+/// assert_eq!(saturating_delta(7u32, 3), 0);
+/// assert_eq!(saturating_delta(1i8, -128), -128);
+/// ```
+pub fn saturating_delta<T: Integer>(from: T, to: T) -> T {
+    to.saturating_sub(from)
+}
+
+/// Gets the unit step from `from` towards `to`: `1`, `-1`, or `0` if they are equal.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::step;
+///
+/// assert_eq!(step(2u8, 9), 1);
+/// assert_eq!(step(9u8, 2), -1);
+/// assert_eq!(step(4u8, 4), 0);
+/// ```
+pub fn step<T: Ord>(from: T, to: T) -> i8 {
+    match from.cmp(&to) {
+        Ordering::Less => 1,
+        Ordering::Equal => 0,
+        Ordering::Greater => -1,
+    }
+}
+
+/// Moves `from` one unit towards `to`, stopping once it is reached.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::step_towards;
+///
+/// assert_eq!(step_towards(0u32, 5), 1);
+/// assert_eq!(step_towards(0i32, -5), -1);
+/// assert_eq!(step_towards(5u32, 5), 5);
+/// ```
+pub fn step_towards<T: Integer>(from: T, to: T) -> T {
+    match from.cmp(&to) {
+        Ordering::Less => from + T::ONE,
+        Ordering::Equal => from,
+        Ordering::Greater => from - T::ONE,
+    }
+}
+
+/// Gets the number of decimal digits of a number, which is `1` for zero.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::digit_count;
+///
+/// assert_eq!(digit_count(0), 1);
+/// assert_eq!(digit_count(2024), 4);
+/// ```
+pub fn digit_count(value: u64) -> u32 {
+    value.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// Iterates the decimal digits of a number, starting with the most significant one.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::num::digits;
+///
+/// assert!(digits(2024).eq([2, 0, 2, 4]));
+/// assert!(digits(0).eq([0]));
+/// ```
+pub fn digits(value: u64) -> Digits {
+    Digits {
+        value,
+        divisor: Some(10u64.pow(digit_count(value) - 1)),
+    }
+}
+
+/// An iterator over the decimal digits of a number; see [`digits`].
+#[derive(Debug, Clone)]
+pub struct Digits {
+    value: u64,
+    /// The power of ten of the next digit, or `None` when all digits were produced.
+    divisor: Option<u64>,
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let divisor = self.divisor?;
+        self.divisor = (divisor > 1).then_some(divisor / 10);
+        Some((self.value / divisor % 10) as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Digits {
+    fn len(&self) -> usize {
+        self.divisor
+            .map_or(0, |divisor| digit_count(divisor) as usize)
+    }
+}
+
+impl FusedIterator for Digits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(u64::MAX).len(), 20);
+        assert_eq!(digits(u64::MAX).collect::<Vec<_>>().len(), 20);
+        assert!(digits(1_000_000).eq([1, 0, 0, 0, 0, 0, 0]));
+
+        let mut iter = digits(57);
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_integer_widths() {
+        assert_eq!(abs_diff(u128::MAX, 0), u128::MAX);
+        assert_eq!(abs_diff(-3isize, 3), 6usize);
+        assert_eq!(saturating_delta(i32::MIN, i32::MAX), i32::MAX);
+        assert_eq!(step_towards(u8::MAX, 0), u8::MAX - 1);
+    }
+}
@@ -0,0 +1,259 @@
+//! Helpers for solving inputs whose lines can be processed independently.
+//!
+//! With the `parallel` feature enabled the work is spread across the rayon thread pool;
+//! without it, the same functions run sequentially so callers don't need to care.
+//!
+//! The runner sizes the pool with [`configure_pool`] and can ask for [deterministic] reductions,
+//! which combine the partial results in a fixed order instead of the order the threads finish in.
+//!
+//! [deterministic]: set_deterministic
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::vec::Vec;
+
+/// How many items each partial result of a deterministic [`fold_reduce`] covers.
+#[cfg(feature = "parallel")]
+const CHUNK_LEN: usize = 64;
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether reductions combine their partial results in a fixed order.
+///
+/// The order then only depends on the input, not on the number of threads or their timing,
+/// so reductions that aren't strictly associative (like float sums) give the same result on
+/// every machine. This comes at the cost of keeping the partial results until the end.
+pub fn set_deterministic(deterministic: bool) {
+    DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+}
+
+/// Gets whether reductions combine their partial results in a fixed order.
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+/// Sets up the global thread pool with the given number of threads.
+///
+/// This needs to happen before anything runs on the pool. Without the `parallel` feature
+/// there is no pool and this does nothing.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::par::configure_pool;
+///
+/// configure_pool(2).expect("the pool is set up before it's used");
+/// assert!(configure_pool(0).is_err());
+/// ```
+pub fn configure_pool(threads: usize) -> Result<(), ConfigurePoolError> {
+    if threads == 0 {
+        return Err(ConfigurePoolError("at least one thread is required"));
+    }
+
+    #[cfg(feature = "parallel")]
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .map_err(|_| ConfigurePoolError("the pool is already running"))?;
+    Ok(())
+}
+
+/// Applies `f` to every non-empty line of the input and collects the results in input order.
+///
+/// Lines are trimmed before being handed to `f`, and lines that are empty after trimming
+/// are skipped, so indented or blank-line-padded inputs behave the same as clean ones.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::par::map_lines;
+///
+/// let lengths = map_lines("  abc\n\n de\n", str::len);
+/// assert_eq!(lengths, [3, 2]);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn map_lines<'a, T, F>(input: &'a str, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&'a str) -> T + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    input
+        .par_lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(f)
+        .collect()
+}
+
+/// Applies `f` to every non-empty line of the input and collects the results in input order.
+///
+/// Lines are trimmed before being handed to `f`, and lines that are empty after trimming
+/// are skipped, so indented or blank-line-padded inputs behave the same as clean ones.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::par::map_lines;
+///
+/// let lengths = map_lines("  abc\n\n de\n", str::len);
+/// assert_eq!(lengths, [3, 2]);
+/// ```
+#[cfg(not(feature = "parallel"))]
+pub fn map_lines<'a, T, F>(input: &'a str, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&'a str) -> T + Send + Sync,
+{
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(f)
+        .collect()
+}
+
+/// Folds the items into partial results and reduces them into one.
+///
+/// `fold` is called with each item and its index, starting from an `identity` per partial
+/// result. The partial results are combined with `reduce`, in the order of the items if
+/// [`is_deterministic`] is set, and in any order otherwise. Without the `parallel` feature all
+/// items are folded into a single result in order.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::par::fold_reduce;
+///
+/// let words = ["a", "b", "c"];
+/// let joined = fold_reduce(
+///     &words,
+///     String::new,
+///     |acc, (_, word)| acc + word,
+///     |lhs, rhs| lhs + &rhs,
+/// );
+/// assert_eq!(joined.len(), 3);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn fold_reduce<T, A, I, F, R>(items: &[T], identity: I, fold: F, reduce: R) -> A
+where
+    T: Sync,
+    A: Send,
+    I: Fn() -> A + Send + Sync,
+    F: Fn(A, (usize, &T)) -> A + Send + Sync,
+    R: Fn(A, A) -> A + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    if !is_deterministic() {
+        return items
+            .par_iter()
+            .enumerate()
+            .fold(&identity, &fold)
+            .reduce(&identity, reduce);
+    }
+
+    let partials: Vec<A> = items
+        .par_chunks(CHUNK_LEN)
+        .enumerate()
+        .map(|(chunk, items)| {
+            let offset = chunk * CHUNK_LEN;
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (offset + index, item))
+                .fold(identity(), &fold)
+        })
+        .collect();
+    partials.into_iter().fold(identity(), reduce)
+}
+
+/// Folds the items into partial results and reduces them into one.
+///
+/// `fold` is called with each item and its index, starting from an `identity` per partial
+/// result. The partial results are combined with `reduce`, in the order of the items if
+/// [`is_deterministic`] is set, and in any order otherwise. Without the `parallel` feature all
+/// items are folded into a single result in order.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::par::fold_reduce;
+///
+/// let words = ["a", "b", "c"];
+/// let joined = fold_reduce(
+///     &words,
+///     String::new,
+///     |acc, (_, word)| acc + word,
+///     |lhs, rhs| lhs + &rhs,
+/// );
+/// assert_eq!(joined.len(), 3);
+/// ```
+#[cfg(not(feature = "parallel"))]
+pub fn fold_reduce<T, A, I, F, R>(items: &[T], identity: I, fold: F, _reduce: R) -> A
+where
+    T: Sync,
+    A: Send,
+    I: Fn() -> A + Send + Sync,
+    F: Fn(A, (usize, &T)) -> A + Send + Sync,
+    R: Fn(A, A) -> A + Send + Sync,
+{
+    items.iter().enumerate().fold(identity(), fold)
+}
+
+/// The error when the thread pool can't be set up.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ConfigurePoolError(&'static str);
+
+impl Display for ConfigurePoolError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to set up the thread pool: {}", self.0)
+    }
+}
+
+impl Error for ConfigurePoolError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_map_lines_preserves_order() {
+        let input = (0..1000)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let values = map_lines(&input, |line| line.parse::<u32>().unwrap());
+        assert_eq!(values, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_fold_reduce_in_order() {
+        let items: Vec<u32> = (0..1000).collect();
+        let fold = |mut acc: Vec<usize>, (index, &item): (usize, &u32)| {
+            assert_eq!(index, item as usize);
+            acc.push(index);
+            acc
+        };
+        let reduce = |mut lhs: Vec<usize>, rhs: Vec<usize>| {
+            lhs.extend(rhs);
+            lhs
+        };
+
+        set_deterministic(true);
+        let indices = fold_reduce(&items, Vec::new, fold, reduce);
+        set_deterministic(false);
+        assert_eq!(indices, (0..1000).collect::<Vec<_>>());
+
+        let sum = fold_reduce(&items, || 0, |acc, (_, &item)| acc + item, |l, r| l + r);
+        assert_eq!(sum, 499_500);
+    }
+
+    #[test]
+    fn test_configure_pool_needs_threads() {
+        assert!(configure_pool(0).is_err());
+    }
+}
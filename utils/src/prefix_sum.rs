@@ -0,0 +1,158 @@
+use crate::{Grid, Point, Ring};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A summed-area table of a grid, answering the sum of any rectangle in constant time.
+///
+/// Each entry holds the sum of all cells above and to the left of it, so the sum of a
+/// rectangle follows from the entries at its four corners. The sums are built in the grid's
+/// own number type; convert the cells with [`Grid::map`] first if they might overflow it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{Grid, Point, PrefixSum2D};
+///
+/// let grid = Grid::parse("123\n456\n789", |c| c.to_digit(10)).unwrap();
+/// let sums = PrefixSum2D::new(&grid);
+///
+/// assert_eq!(sums.total(), 45);
+/// assert_eq!(sums.sum(1..3, 0..2), 2 + 3 + 5 + 6);
+///
+/// let (corner, sum) = sums.windows(2, 2).max_by_key(|&(_, sum)| sum).unwrap();
+/// assert_eq!((corner, sum), (Point::new(1, 1), 5 + 6 + 8 + 9));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PrefixSum2D<T> {
+    width: usize,
+    height: usize,
+    /// The sums of the cells in `0..x` and `0..y` at `y · (width + 1) + x`.
+    sums: Vec<T>,
+}
+
+impl<T: Ring> PrefixSum2D<T> {
+    /// Builds the table of the grid.
+    pub fn new(grid: &Grid<T>) -> Self {
+        let (width, height) = (grid.width(), grid.height());
+        let stride = width + 1;
+        let mut sums = vec![T::ZERO; stride * (height + 1)];
+        for (point, &cell) in grid.iter() {
+            let (x, y) = (point.x as usize + 1, point.y as usize + 1);
+            // Adding both neighboring sums counts the one diagonally above twice.
+            sums[y * stride + x] = cell + sums[(y - 1) * stride + x] + sums[y * stride + x - 1]
+                - sums[(y - 1) * stride + x - 1];
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    /// Gets the number of columns of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of rows of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the sum of all cells.
+    pub fn total(&self) -> T {
+        self.corner(self.width, self.height)
+    }
+
+    /// Gets the sum of the cells in the columns `x` and rows `y`.
+    ///
+    /// The corners are combined as `(bottom right + top left) - (top right + bottom left)`,
+    /// so unsigned sums never go below zero in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges exceed the grid.
+    pub fn sum(&self, x: Range<usize>, y: Range<usize>) -> T {
+        assert!(
+            x.end <= self.width && y.end <= self.height,
+            "range exceeds the grid"
+        );
+        if x.is_empty() || y.is_empty() {
+            return T::ZERO;
+        }
+        (self.corner(x.end, y.end) + self.corner(x.start, y.start))
+            - (self.corner(x.end, y.start) + self.corner(x.start, y.end))
+    }
+
+    /// Iterates the sums of all rectangles of the given size, with their top left corners in
+    /// row-major order.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = (Point, T)> + '_ {
+        let columns = (self.width + 1).saturating_sub(width);
+        let rows = (self.height + 1).saturating_sub(height);
+        (0..rows).flat_map(move |y| {
+            (0..columns).map(move |x| {
+                let corner = Point::new(x as i64, y as i64);
+                (corner, self.sum(x..x + width, y..y + height))
+            })
+        })
+    }
+
+    /// Gets the sum of the cells in `0..x` and `0..y`.
+    fn corner(&self, x: usize, y: usize) -> T {
+        self.sums[y * (self.width + 1) + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_matches_brute_force() {
+        use crate::property::check;
+
+        check(
+            100,
+            |rng| {
+                let (width, height) = (rng.index(0..7), rng.index(0..7));
+                let mut grid = Grid::new(width, height, 0i64);
+                for point in grid.points().collect::<Vec<_>>() {
+                    grid[point] = rng.signed(-50..50);
+                }
+                let x = [rng.index(0..width + 1), rng.index(0..width + 1)];
+                let y = [rng.index(0..height + 1), rng.index(0..height + 1)];
+                (
+                    grid,
+                    x[0].min(x[1])..x[0].max(x[1]),
+                    y[0].min(y[1])..y[0].max(y[1]),
+                )
+            },
+            |(grid, x, y)| {
+                let expected: i64 = grid
+                    .iter()
+                    .filter(|(point, _)| {
+                        x.contains(&(point.x as usize)) && y.contains(&(point.y as usize))
+                    })
+                    .map(|(_, &cell)| cell)
+                    .sum();
+                let sums = PrefixSum2D::new(grid);
+                sums.sum(x.clone(), y.clone()) == expected
+                    && sums.total() == grid.iter().map(|(_, &cell)| cell).sum::<i64>()
+            },
+        );
+    }
+
+    #[test]
+    fn test_windows() {
+        let grid = Grid::new(4, 3, 1u32);
+        let sums = PrefixSum2D::new(&grid);
+        let windows: Vec<_> = sums.windows(3, 2).collect();
+        assert_eq!(windows.len(), 4);
+        assert!(windows.iter().all(|&(_, sum)| sum == 6));
+        assert_eq!(windows[3].0, Point::new(1, 1));
+        assert_eq!(sums.windows(5, 1).count(), 0);
+        assert_eq!(sums.windows(0, 0).count(), 20);
+    }
+}
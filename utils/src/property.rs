@@ -0,0 +1,489 @@
+//! Randomized property tests and generators for puzzle-shaped inputs.
+//!
+//! Properties are checked against a fixed sequence of seeds, so failures are reproducible.
+//! A failing case reports its seed; setting the `AOC_PROPERTY_SEED` environment variable to it
+//! makes that case the first one to be checked.
+//!
+//! ```
+//! use aoc_utils::property::{check, integers};
+//!
+//! check(
+//!     100,
+//!     |rng| integers(rng, 0..10, -5..5),
+//!     |values| values.iter().all(|v| (-5..5).contains(v)),
+//! );
+//! ```
+
+use std::fmt::Debug;
+use std::ops::Range;
+use std::string::String;
+use std::vec::Vec;
+use std::{format, vec};
+
+/// The environment variable overriding the seed of the first checked case.
+pub const SEED_VARIABLE: &str = "AOC_PROPERTY_SEED";
+
+/// The seed of the first checked case, unless overridden.
+const DEFAULT_SEED: u64 = 0x2023_1225;
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+///
+/// Not suitable for anything but generating test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a value in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bound is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "the bound must not be zero");
+        // The modulo bias is negligible for the small bounds used in tests.
+        self.next_u64() % bound
+    }
+
+    /// Gets a value in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "the range must not be empty");
+        range.start + self.below(range.end - range.start)
+    }
+
+    /// Gets an index in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn index(&mut self, range: Range<usize>) -> usize {
+        self.range(range.start as u64..range.end as u64) as usize
+    }
+
+    /// Gets a signed value in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn signed(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "the range must not be empty");
+        let span = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(span) as i64)
+    }
+
+    /// Returns `true` with the specified probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    /// Picks an element of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+        &values[self.index(0..values.len())]
+    }
+}
+
+/// Checks a property for the specified number of generated values.
+///
+/// # Panics
+///
+/// Panics with the seed and the value of the first case violating the property.
+pub fn check<T, G, P>(cases: usize, mut generate: G, mut property: P)
+where
+    T: Debug,
+    G: FnMut(&mut Rng) -> T,
+    P: FnMut(&T) -> bool,
+{
+    for seed in seeds(cases) {
+        let value = generate(&mut Rng::new(seed));
+        if !property(&value) {
+            panic!("property failed for {SEED_VARIABLE}={seed}: {value:#?}");
+        }
+    }
+}
+
+/// Checks that a brute-force and an optimized solution agree on the specified number of
+/// generated inputs.
+///
+/// ```
+/// use aoc_utils::property::{assert_equivalent, integers};
+///
+/// assert_equivalent(
+///     100,
+///     |values: &Vec<i64>| values.iter().copied().fold(i64::MIN, i64::max),
+///     |values: &Vec<i64>| values.iter().copied().max().unwrap_or(i64::MIN),
+///     |rng| integers(rng, 0..10, -5..5),
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics with the seed, the input and both results of the first case the solutions disagree on.
+pub fn assert_equivalent<T, R, B, F, G>(cases: usize, mut brute: B, mut fast: F, mut generate: G)
+where
+    T: Debug,
+    R: PartialEq + Debug,
+    B: FnMut(&T) -> R,
+    F: FnMut(&T) -> R,
+    G: FnMut(&mut Rng) -> T,
+{
+    for seed in seeds(cases) {
+        let input = generate(&mut Rng::new(seed));
+        let (expected, actual) = (brute(&input), fast(&input));
+        if expected != actual {
+            panic!(
+                "solutions differ for {SEED_VARIABLE}={seed}: {input:#?}\n\
+                 brute force: {expected:?}\n\
+                 optimized:   {actual:?}"
+            );
+        }
+    }
+}
+
+/// Gets the seeds of the cases to check.
+fn seeds(cases: usize) -> impl Iterator<Item = u64> {
+    let first_seed = std::env::var(SEED_VARIABLE)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    (0..cases as u64).map(move |case| first_seed.wrapping_add(case))
+}
+
+/// Generates a sequence of integers whose length and values lie in the specified ranges.
+pub fn integers(rng: &mut Rng, len: Range<usize>, values: Range<i64>) -> Vec<i64> {
+    let len = rng.index(len);
+    (0..len).map(|_| rng.signed(values.clone())).collect()
+}
+
+/// Generates a grid of the specified size, with each tile picked from the symbols.
+///
+/// Repeating a symbol makes it more likely to be picked.
+pub fn grid(rng: &mut Rng, width: usize, height: usize, symbols: &[char]) -> String {
+    let mut grid = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        if y > 0 {
+            grid.push('\n');
+        }
+        grid.extend((0..width).map(|_| *rng.choose(symbols)));
+    }
+    grid
+}
+
+/// Generates up to `count` non-overlapping ranges within `0..limit`, given as
+/// `(destination, source, length)` triples like in an almanac map.
+///
+/// The source ranges are shuffled and may leave gaps; destinations may overlap.
+pub fn range_map(rng: &mut Rng, count: usize, limit: u64) -> Vec<(u64, u64, u64)> {
+    let mut bounds: Vec<u64> = (0..2 * count).map(|_| rng.below(limit + 1)).collect();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut ranges: Vec<_> = bounds
+        .chunks_exact(2)
+        .map(|pair| {
+            let length = pair[1] - pair[0];
+            (rng.below(limit), pair[0], length)
+        })
+        .collect();
+
+    for i in (1..ranges.len()).rev() {
+        ranges.swap(i, rng.index(0..i + 1));
+    }
+    ranges
+}
+
+/// Generates an almanac with the specified number of seed ranges, with all values below `limit`.
+pub fn almanac(rng: &mut Rng, seed_ranges: usize, limit: u64) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let mut almanac = String::from("seeds:");
+    for _ in 0..seed_ranges {
+        let start = rng.below(limit);
+        let length = rng.range(1..limit - start + 1);
+        almanac.push_str(&format!(" {start} {length}"));
+    }
+
+    for map in MAPS {
+        almanac.push_str(&format!("\n\n{map} map:"));
+        let count = rng.index(1..5);
+        for (destination, source, length) in range_map(rng, count, limit) {
+            if length > 0 {
+                almanac.push_str(&format!("\n{destination} {source} {length}"));
+            }
+        }
+    }
+    almanac
+}
+
+/// Generates an input shaped like the sample, with each number replaced by a random one with
+/// as many digits.
+///
+/// Everything but the digits is kept, so this suits inputs whose numbers are independent of
+/// each other. Multi-digit numbers don't get a leading zero.
+pub fn numbers_like(rng: &mut Rng, sample: &str) -> String {
+    let mut generated = String::with_capacity(sample.len());
+    let mut chars = sample.chars().peekable();
+    let mut in_number = false;
+    while let Some(c) = chars.next() {
+        if !c.is_ascii_digit() {
+            generated.push(c);
+            in_number = false;
+            continue;
+        }
+
+        let leading = !in_number && chars.peek().is_some_and(char::is_ascii_digit);
+        let digit = rng.range(u64::from(leading)..10) as u8;
+        generated.push(char::from(b'0' + digit));
+        in_number = true;
+    }
+    generated
+}
+
+/// Generates a grid shaped like the sample, by shuffling its tiles.
+///
+/// The generated grid has the sample's lines and line lengths, and each tile occurs as often
+/// as in the sample, so e.g. a single start tile remains single.
+pub fn grid_like(rng: &mut Rng, sample: &str) -> String {
+    let lines: Vec<&str> = sample.lines().filter(|line| !line.is_empty()).collect();
+    let mut tiles: Vec<char> = lines.iter().flat_map(|line| line.chars()).collect();
+    for i in (1..tiles.len()).rev() {
+        tiles.swap(i, rng.index(0..i + 1));
+    }
+
+    let mut tiles = tiles.into_iter();
+    let mut grid = String::with_capacity(sample.len());
+    for line in lines {
+        grid.extend(tiles.by_ref().take(line.chars().count()));
+        grid.push('\n');
+    }
+    grid
+}
+
+/// A generated pipe maze along with the properties of its loop.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PipeMaze {
+    /// The maze, in the `|-LJ7F.S` notation.
+    pub input: String,
+    /// The number of tiles on the loop.
+    pub length: usize,
+    /// The number of tiles enclosed by the loop.
+    pub enclosed: usize,
+}
+
+/// Generates a pipe maze whose loop runs around a random shape of up to `width` by `height`
+/// blocks. The maze is `2 * width + 1` tiles wide and `2 * height + 1` tiles high.
+///
+/// Tiles not on the loop are either ground or unconnected pipes; none of them connects to the start.
+///
+/// # Panics
+///
+/// Panics if the width or height is zero.
+pub fn pipe_maze(rng: &mut Rng, width: usize, height: usize) -> PipeMaze {
+    assert!(width > 0 && height > 0, "the maze must not be empty");
+
+    // A column-convex shape whose neighboring columns overlap has a single, simple boundary.
+    let mut blocks = vec![false; width * height];
+    let (mut top, mut bottom) = (0, 0);
+    for x in 0..width {
+        let new_top = if x == 0 {
+            rng.index(0..height)
+        } else {
+            rng.index(0..bottom)
+        };
+        let new_bottom = rng.index(new_top.max(top) + 1..height + 1);
+        (top, bottom) = (new_top, new_bottom);
+        for y in top..bottom {
+            blocks[x + y * width] = true;
+        }
+    }
+
+    let block = |x: isize, y: isize| {
+        (0..width as isize).contains(&x)
+            && (0..height as isize).contains(&y)
+            && blocks[x as usize + y as usize * width]
+    };
+
+    // Edges between block corners; `(x, y)` is the corner where the edge starts.
+    let horizontal = |x: isize, y: isize| block(x, y - 1) != block(x, y);
+    let vertical = |x: isize, y: isize| block(x - 1, y) != block(x, y);
+
+    let (tiles_x, tiles_y) = (2 * width + 1, 2 * height + 1);
+    let mut tiles = vec!['.'; tiles_x * tiles_y];
+    let mut perimeter = 0;
+    for y in 0..=height as isize {
+        for x in 0..=width as isize {
+            let tile = 2 * x as usize + 2 * y as usize * tiles_x;
+            tiles[tile] = match (
+                y > 0 && vertical(x, y - 1),
+                vertical(x, y),
+                x > 0 && horizontal(x - 1, y),
+                horizontal(x, y),
+            ) {
+                (true, true, false, false) => '|',
+                (false, false, true, true) => '-',
+                (true, false, false, true) => 'L',
+                (true, false, true, false) => 'J',
+                (false, true, true, false) => '7',
+                (false, true, false, true) => 'F',
+                _ => '.',
+            };
+            if horizontal(x, y) {
+                tiles[tile + 1] = '-';
+                perimeter += 1;
+            }
+            if vertical(x, y) {
+                tiles[tile + tiles_x] = '|';
+                perimeter += 1;
+            }
+        }
+    }
+
+    let on_loop: Vec<usize> = (0..tiles.len()).filter(|&i| tiles[i] != '.').collect();
+    let start = *rng.choose(&on_loop);
+    tiles[start] = 'S';
+
+    let (start_x, start_y) = (start % tiles_x, start / tiles_x);
+    for (i, tile) in tiles.iter_mut().enumerate() {
+        let (x, y) = (i % tiles_x, i / tiles_x);
+        let next_to_start = x.abs_diff(start_x) + y.abs_diff(start_y) == 1;
+        if *tile == '.' && !next_to_start && rng.chance(0.2) {
+            *tile = *rng.choose(&['|', '-', 'L', 'J', '7', 'F']);
+        }
+    }
+
+    // By Pick's theorem, with each block covering four tiles and each edge spanning two tiles.
+    let area = 4 * blocks.iter().filter(|&&b| b).count();
+    let input = tiles
+        .chunks(tiles_x)
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    PipeMaze {
+        input,
+        length: 2 * perimeter,
+        enclosed: area - perimeter + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(1);
+        let values: Vec<_> = (0..1000).map(|_| rng.range(3..7)).collect();
+        assert!(values.iter().all(|v| (3..7).contains(v)));
+        assert!((3..7).all(|v| values.contains(&v)));
+        assert!((0..100).all(|_| (-3..-1).contains(&rng.signed(-3..-1))));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+    }
+
+    #[test]
+    fn test_numbers_like() {
+        let sample = "Button A: X+94, Y+34\nPrize: X=8400, Y=0\n";
+        check(
+            100,
+            |rng| numbers_like(rng, sample),
+            |generated| {
+                let shape = |s: &str| s.replace(|c: char| c.is_ascii_digit(), "0");
+                let no_leading_zeros = generated
+                    .split(|c: char| !c.is_ascii_digit())
+                    .all(|number| number.len() < 2 || !number.starts_with('0'));
+                shape(generated) == shape(sample) && no_leading_zeros
+            },
+        );
+    }
+
+    #[test]
+    fn test_grid_like() {
+        let sample = "S..#\n.##.\n...E\n";
+        check(
+            100,
+            |rng| grid_like(rng, sample),
+            |generated| {
+                let sorted = |s: &str| {
+                    let mut tiles: Vec<char> = s.chars().filter(|&c| c != '\n').collect();
+                    tiles.sort_unstable();
+                    tiles
+                };
+                generated.lines().all(|line| line.len() == 4)
+                    && generated.lines().count() == 3
+                    && sorted(generated) == sorted(sample)
+            },
+        );
+    }
+
+    #[test]
+    fn test_range_map() {
+        check(
+            200,
+            |rng| range_map(rng, 4, 20),
+            |ranges| {
+                let mut sources: Vec<_> = ranges.iter().map(|&(_, s, l)| s..s + l).collect();
+                sources.sort_by_key(|r| r.start);
+                sources.windows(2).all(|w| w[0].end <= w[1].start)
+                    && sources.iter().all(|r| r.end <= 20)
+            },
+        );
+    }
+
+    #[test]
+    fn test_pipe_maze() {
+        let maze = pipe_maze(&mut Rng::new(3), 1, 1);
+        assert_eq!(maze.length, 8);
+        assert_eq!(maze.enclosed, 1);
+        assert_eq!(maze.input.lines().count(), 3);
+
+        check(
+            100,
+            |rng| pipe_maze(rng, 5, 4),
+            |maze| {
+                maze.input.matches('S').count() == 1
+                    && maze.input.lines().all(|line| line.len() == 11)
+                    && maze.input.lines().count() == 9
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "brute force: 3")]
+    fn test_assert_equivalent_reports_results() {
+        assert_equivalent(10, |_| 3, |&value| value, |rng| rng.below(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed for AOC_PROPERTY_SEED=")]
+    fn test_check_reports_seed() {
+        check(10, |rng| rng.below(10), |&value| value < 5);
+    }
+}
@@ -0,0 +1,40 @@
+//! Metadata describing a day's puzzle.
+
+use core::fmt::{Display, Formatter};
+
+/// The date, title and known answers of a day's puzzle.
+///
+/// Answers are given per part as they are rendered by `aoc::solve`, and are `None` where a
+/// part has no (known) answer.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::PuzzleMeta;
+///
+/// const PUZZLE: PuzzleMeta = PuzzleMeta {
+///     year: 2024,
+///     day: 1,
+///     title: "Historian Hysteria",
+///     example_answers: [Some("11"), Some("31")],
+///     real_answers: None,
+/// };
+///
+/// assert_eq!(PUZZLE.to_string(), "2024 Day 1: Historian Hysteria");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PuzzleMeta {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The answers to the main example of the puzzle description.
+    pub example_answers: [Option<&'static str>; 2],
+    /// The answers to the `input.txt` of the day, if it is an actual puzzle input.
+    pub real_answers: Option<[Option<&'static str>; 2]>,
+}
+
+impl Display for PuzzleMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} Day {}: {}", self.year, self.day, self.title)
+    }
+}
@@ -0,0 +1,248 @@
+//! Positional numerals with custom digits, including signed-digit systems such as balanced
+//! base five.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// A positional numeral system, given by its digits in ascending order of their values.
+///
+/// The base is the number of digits. In a signed-digit system, the digit at the `zero` index
+/// stands for zero and the digits before it for negative values, so negative numbers need no
+/// sign.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Radix<'a> {
+    digits: &'a [u8],
+    /// The index of the digit whose value is zero.
+    zero: usize,
+}
+
+impl<'a> Radix<'a> {
+    /// The digits `0` and `1`.
+    pub const BINARY: Radix<'static> = Radix::new(b"01");
+
+    /// The digits `0` to `7`.
+    pub const OCTAL: Radix<'static> = Radix::new(b"01234567");
+
+    /// The digits `0` to `9`.
+    pub const DECIMAL: Radix<'static> = Radix::new(b"0123456789");
+
+    /// The digits `0` to `9` and `a` to `f`.
+    pub const HEXADECIMAL: Radix<'static> = Radix::new(b"0123456789abcdef");
+
+    /// Balanced base five with the digits `=` (minus two), `-` (minus one), `0`, `1` and `2`,
+    /// as used by SNAFU numbers.
+    pub const SNAFU: Radix<'static> = Radix::signed(b"=-012", 2);
+
+    /// Creates a system whose digits have the values `0` to `base - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two digits.
+    pub const fn new(digits: &'a [u8]) -> Self {
+        Self::signed(digits, 0)
+    }
+
+    /// Creates a signed-digit system in which the digit at the `zero` index has the value
+    /// zero, and each other digit its offset from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two digits or `zero` is out of range.
+    pub const fn signed(digits: &'a [u8], zero: usize) -> Self {
+        assert!(digits.len() >= 2, "a radix needs at least two digits");
+        assert!(
+            zero < digits.len(),
+            "the zero digit must be one of the digits"
+        );
+        Self { digits, zero }
+    }
+
+    /// Gets the number of digits.
+    pub const fn base(&self) -> usize {
+        self.digits.len()
+    }
+
+    /// Gets the value of a digit, or `None` if it isn't one of the digits.
+    pub fn value(&self, digit: u8) -> Option<i128> {
+        let index = self.digits.iter().position(|&d| d == digit)?;
+        Some(index as i128 - self.zero as i128)
+    }
+}
+
+/// Writes the value in the numeral system.
+///
+/// Systems without negative digits write negative values with a leading `-`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::radix::{to_radix, Radix};
+///
+/// assert_eq!(to_radix(2022, Radix::SNAFU), "1=11-2");
+/// assert_eq!(to_radix(-3, Radix::SNAFU), "-2");
+/// assert_eq!(to_radix(0b1011, Radix::BINARY), "1011");
+/// assert_eq!(to_radix(-255, Radix::HEXADECIMAL), "-ff");
+/// ```
+pub fn to_radix(value: i128, radix: Radix<'_>) -> String {
+    let base = radix.base() as i128;
+    let highest = (radix.base() - 1 - radix.zero) as i128;
+
+    let mut digits = Vec::new();
+    if radix.zero == 0 {
+        // Plain digits can't represent negative values; write the magnitude instead.
+        let mut magnitude = value.unsigned_abs();
+        while magnitude != 0 || digits.is_empty() {
+            digits.push(radix.digits[(magnitude % base as u128) as usize]);
+            magnitude /= base as u128;
+        }
+        if value < 0 {
+            digits.push(b'-');
+        }
+    } else {
+        let mut value = value;
+        while value != 0 || digits.is_empty() {
+            // Choose the digit congruent to the remainder that lies within the digits' range.
+            let mut quotient = value.div_euclid(base);
+            let mut digit = value.rem_euclid(base);
+            if digit > highest {
+                digit -= base;
+                quotient += 1;
+            }
+            digits.push(radix.digits[(digit + radix.zero as i128) as usize]);
+            value = quotient;
+        }
+    }
+
+    digits.reverse();
+    digits.into_iter().map(char::from).collect()
+}
+
+/// Reads a value written in the numeral system.
+///
+/// Systems without negative digits accept a leading `-`. In signed-digit systems, values
+/// within a few digits' worth of the limits of `i128` may fail to parse, because the
+/// intermediate sums overflow.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::radix::{from_radix, Radix};
+///
+/// assert_eq!(from_radix("1=11-2", Radix::SNAFU), Ok(2022));
+/// assert_eq!(from_radix("-ff", Radix::HEXADECIMAL), Ok(-255));
+/// assert!(from_radix("12", Radix::BINARY).is_err());
+/// ```
+pub fn from_radix(numeral: &str, radix: Radix<'_>) -> Result<i128, ParseRadixError> {
+    let (negative, digits) = match numeral.strip_prefix('-') {
+        Some(digits) if radix.zero == 0 => (true, digits),
+        _ => (false, numeral),
+    };
+    if digits.is_empty() {
+        return Err(ParseRadixError("empty numeral"));
+    }
+
+    // Negative values accumulate negatively, so that `i128::MIN` doesn't overflow.
+    let (base, sign) = (radix.base() as i128, if negative { -1 } else { 1 });
+    digits.bytes().try_fold(0i128, |value, digit| {
+        let digit = radix.value(digit).ok_or(ParseRadixError("invalid digit"))?;
+        value
+            .checked_mul(base)
+            .and_then(|value| value.checked_add(sign * digit))
+            .ok_or(ParseRadixError("value out of range"))
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseRadixError(&'static str);
+
+impl Display for ParseRadixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Failed to parse numeral: {}", self.0)
+    }
+}
+
+impl Error for ParseRadixError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snafu() {
+        let numbers = [
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (8, "2="),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ];
+        for (value, numeral) in numbers {
+            assert_eq!(to_radix(value, Radix::SNAFU), numeral);
+            assert_eq!(from_radix(numeral, Radix::SNAFU), Ok(value));
+        }
+        // Signed-digit systems have no sign, so a leading `-` is a digit.
+        assert_eq!(from_radix("-1", Radix::SNAFU), Ok(-4));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let balanced_ternary = Radix::signed(b"T01", 1);
+        let custom = Radix::new(b".#");
+        for radix in [
+            Radix::BINARY,
+            Radix::DECIMAL,
+            Radix::SNAFU,
+            balanced_ternary,
+            custom,
+        ] {
+            for value in [
+                0,
+                1,
+                -1,
+                7,
+                -100,
+                123_456_789,
+                i64::MIN as i128,
+                i64::MAX as i128,
+            ] {
+                let numeral = to_radix(value, radix);
+                assert_eq!(from_radix(&numeral, radix), Ok(value), "{numeral}");
+            }
+        }
+        for value in [i128::MIN, i128::MAX] {
+            let numeral = to_radix(value, Radix::HEXADECIMAL);
+            assert_eq!(from_radix(&numeral, Radix::HEXADECIMAL), Ok(value));
+        }
+        assert_eq!(to_radix(5, custom), "#.#");
+        assert_eq!(to_radix(-4, balanced_ternary), "TT");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            from_radix("", Radix::DECIMAL),
+            Err(ParseRadixError("empty numeral"))
+        );
+        assert_eq!(
+            from_radix("-", Radix::DECIMAL),
+            Err(ParseRadixError("empty numeral"))
+        );
+        assert_eq!(
+            from_radix("1x", Radix::DECIMAL),
+            Err(ParseRadixError("invalid digit"))
+        );
+        assert_eq!(
+            from_radix("1".repeat(40).as_str(), Radix::DECIMAL),
+            Err(ParseRadixError("value out of range"))
+        );
+    }
+}
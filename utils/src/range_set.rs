@@ -0,0 +1,238 @@
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Range, Sub};
+
+/// A set of values, stored as a sorted list of disjoint, half-open ranges.
+///
+/// Adjacent and overlapping ranges are merged on insertion, so every value
+/// is covered by at most one range.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::RangeSet;
+///
+/// let mut set = RangeSet::from(1..4001_u64);
+/// assert_eq!(set.len(), 4000);
+///
+/// let (below, above) = set.split_at(1351);
+/// assert_eq!(below.len(), 1350);
+/// assert_eq!(above.len(), 2650);
+///
+/// set.insert(5000..5010);
+/// assert_eq!(set.ranges(), &[1..4001, 5000..5010]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Determines whether the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the sorted, disjoint ranges making up this set.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Iterates the sorted, disjoint ranges making up this set.
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> + '_ {
+        self.ranges.iter()
+    }
+
+    /// Adds a range to the set, merging it with any range it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // The first range that could be merged with the new one.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        // The first range that is strictly after the new one.
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Determines whether the set contains the specified value.
+    pub fn contains(&self, value: &T) -> bool {
+        let pos = self.ranges.partition_point(|r| r.end <= *value);
+        self.ranges
+            .get(pos)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// Splits the set into the values strictly less than `value`
+    /// and the values greater than or equal to it.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for range in &self.ranges {
+            if range.end <= value {
+                below.push(range.clone());
+            } else if range.start >= value {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..value);
+                above.push(value..range.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Returns the values contained in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut lhs, mut rhs) = (self.ranges.iter(), other.ranges.iter());
+        let (mut a, mut b) = (lhs.next(), rhs.next());
+        while let (Some(left), Some(right)) = (a, b) {
+            let start = left.start.max(right.start);
+            let end = left.end.min(right.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            if left.end < right.end {
+                a = lhs.next();
+            } else {
+                b = rhs.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Returns the values contained in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.insert(range.clone());
+        }
+        set
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Ord + Sub<Output = T> + Sum<T>,
+{
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T> Default for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = RangeSet::new();
+        set.insert(10..20);
+        set.insert(30..40);
+        assert_eq!(set.ranges(), &[10..20, 30..40]);
+
+        // Touching ranges are merged.
+        set.insert(20..25);
+        assert_eq!(set.ranges(), &[10..25, 30..40]);
+
+        // Bridging ranges are merged.
+        set.insert(22..35);
+        assert_eq!(set.ranges(), &[10..40]);
+
+        // Empty ranges are ignored.
+        set.insert(50..50);
+        assert_eq!(set.ranges(), &[10..40]);
+
+        set.insert(0..5);
+        assert_eq!(set.ranges(), &[0..5, 10..40]);
+        assert_eq!(set.len(), 35);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: RangeSet<i32> = [0..5, 10..20].into_iter().collect();
+        assert!(set.contains(&0));
+        assert!(set.contains(&4));
+        assert!(!set.contains(&5));
+        assert!(set.contains(&10));
+        assert!(!set.contains(&20));
+        assert!(!set.contains(&-1));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let set: RangeSet<u64> = [0..5, 10..20].into_iter().collect();
+        let (below, above) = set.split_at(15);
+        assert_eq!(below.ranges(), &[0..5, 10..15]);
+        assert_eq!(above.ranges(), &[15..20]);
+
+        let (below, above) = set.split_at(5);
+        assert_eq!(below.ranges(), &[0..5]);
+        assert_eq!(above.ranges(), &[10..20]);
+    }
+
+    #[test]
+    fn test_intersection_and_union() {
+        let lhs: RangeSet<u64> = [0..10, 20..30].into_iter().collect();
+        let rhs: RangeSet<u64> = [5..25].into_iter().collect();
+        assert_eq!(lhs.intersection(&rhs).ranges(), &[5..10, 20..25]);
+        assert_eq!(lhs.union(&rhs).ranges(), &[0..30]);
+    }
+}
@@ -0,0 +1,474 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction of two `i128` values, always stored in lowest terms
+/// with a positive denominator.
+///
+/// The operators panic if a result doesn't fit; the `checked_` methods return `None` instead.
+/// Since the denominator is positive, fractions such as `1/i128::MIN` can't be represented,
+/// and neither can the negation of `i128::MIN`.
+/// Comparisons never overflow. With the `num-rational` feature, values convert to and from
+/// `num_rational::Ratio<i128>`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::Rational;
+///
+/// let a = Rational::new(1, 3);
+/// let b = Rational::new(1, 6);
+/// assert_eq!(a + b, Rational::new(1, 2));
+/// assert!(a > b);
+/// assert_eq!((a * Rational::from(3)).to_integer(), Some(1));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// The value `0`.
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// The value `1`.
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a new fraction and reduces it to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero, or if the reduced fraction doesn't fit,
+    /// such as `1/i128::MIN`.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert_ne!(denominator, 0, "denominator must not be zero");
+        Self::checked_new(numerator, denominator).expect("rational overflow")
+    }
+
+    /// Creates a new fraction and reduces it to lowest terms, or returns `None` if the
+    /// denominator is zero or the reduced fraction doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::Rational;
+    ///
+    /// assert_eq!(Rational::checked_new(2, -4), Some(Rational::new(-1, 2)));
+    /// assert_eq!(Rational::checked_new(i128::MIN, i128::MIN), Some(Rational::ONE));
+    /// assert_eq!(Rational::checked_new(1, i128::MIN), None);
+    /// assert_eq!(Rational::checked_new(1, 0), None);
+    /// ```
+    pub fn checked_new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        // Reduce the magnitudes, which can't overflow, and only then apply the sign.
+        let divisor = gcd_u128(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let magnitude = numerator.unsigned_abs() / divisor;
+        let numerator = if (numerator < 0) != (denominator < 0) {
+            0i128.checked_sub_unsigned(magnitude)?
+        } else {
+            i128::try_from(magnitude).ok()?
+        };
+        Some(Self {
+            numerator,
+            denominator: i128::try_from(denominator.unsigned_abs() / divisor).ok()?,
+        })
+    }
+
+    /// Gets the numerator.
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// Gets the (always positive) denominator.
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Determines whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Determines whether the value is a whole number.
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Gets the value as an integer, if it is a whole number.
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// Gets the absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numerator is `i128::MIN`, whose absolute value doesn't fit.
+    pub fn abs(&self) -> Self {
+        self.checked_abs().expect("rational overflow")
+    }
+
+    /// Gets the absolute value, or `None` if the numerator is `i128::MIN`.
+    pub fn checked_abs(&self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_abs()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Negates the value, or returns `None` if the numerator is `i128::MIN`.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Gets the multiplicative inverse, or `None` for zero or if the inverse doesn't fit,
+    /// as for `1/i128::MIN`.
+    pub fn recip(&self) -> Option<Self> {
+        Self::checked_new(self.denominator, self.numerator)
+    }
+
+    /// Adds the values, or returns `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, i128::checked_add)
+    }
+
+    /// Subtracts the values, or returns `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        // Subtracting the numerators directly, as negating `rhs` would overflow on `i128::MIN`.
+        self.combine(rhs, i128::checked_sub)
+    }
+
+    /// Brings the values to a common denominator and combines their numerators.
+    fn combine(self, rhs: Self, op: fn(i128, i128) -> Option<i128>) -> Option<Self> {
+        let divisor = gcd_i128(self.denominator, rhs.denominator);
+        let lhs_factor = rhs.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+        let numerator = op(
+            self.numerator.checked_mul(lhs_factor)?,
+            rhs.numerator.checked_mul(rhs_factor)?,
+        )?;
+        Self::checked_new(numerator, self.denominator.checked_mul(lhs_factor)?)
+    }
+
+    /// Multiplies the values, or returns `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce first to keep the intermediate values small.
+        let a = gcd_i128(self.numerator, rhs.denominator).max(1);
+        let b = gcd_i128(rhs.numerator, self.denominator).max(1);
+        Self::checked_new(
+            (self.numerator / a).checked_mul(rhs.numerator / b)?,
+            (self.denominator / b).checked_mul(rhs.denominator / a)?,
+        )
+    }
+
+    /// Divides the values, or returns `None` on overflow or when dividing by zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+}
+
+/// Calculates the (non-negative) greatest common divisor of two signed numbers.
+///
+/// # Panics
+///
+/// Panics if the divisor is 2¹²⁷, which only happens if one number is `i128::MIN` and the
+/// other is either zero or `i128::MIN` as well.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::gcd_i128;
+///
+/// assert_eq!(gcd_i128(-12, 18), 6);
+/// assert_eq!(gcd_i128(0, -5), 5);
+/// assert_eq!(gcd_i128(i128::MIN, 6), 2);
+/// ```
+pub fn gcd_i128(a: i128, b: i128) -> i128 {
+    i128::try_from(gcd_u128(a.unsigned_abs(), b.unsigned_abs())).expect("gcd overflow")
+}
+
+/// Calculates the greatest common divisor of two unsigned numbers.
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Panics when dividing by zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "division by zero");
+        self.checked_div(rhs).expect("rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Panics if the numerator is `i128::MIN`, whose negation doesn't fit.
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("rational overflow")
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl From<num_rational::Ratio<i128>> for Rational {
+    fn from(value: num_rational::Ratio<i128>) -> Self {
+        Self::new(*value.numer(), *value.denom())
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl From<Rational> for num_rational::Ratio<i128> {
+    fn from(value: Rational) -> Self {
+        // Already in lowest terms with a positive denominator.
+        num_rational::Ratio::new_raw(value.numerator, value.denominator)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying keeps the order.
+        let lhs = self.numerator.checked_mul(other.denominator);
+        let rhs = other.numerator.checked_mul(self.denominator);
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            _ => cmp_continued(
+                (self.numerator, self.denominator),
+                (other.numerator, other.denominator),
+            ),
+        }
+    }
+}
+
+/// Compares two fractions with positive denominators by their continued fraction expansions,
+/// which only needs divisions and therefore can't overflow.
+fn cmp_continued(mut a: (i128, i128), mut b: (i128, i128)) -> Ordering {
+    let mut reversed = false;
+    loop {
+        let (whole_a, rest_a) = (a.0.div_euclid(a.1), a.0.rem_euclid(a.1));
+        let (whole_b, rest_b) = (b.0.div_euclid(b.1), b.0.rem_euclid(b.1));
+        let ordering = match (whole_a.cmp(&whole_b), rest_a, rest_b) {
+            (Ordering::Equal, 0, 0) => Ordering::Equal,
+            (Ordering::Equal, 0, _) => Ordering::Less,
+            (Ordering::Equal, _, 0) => Ordering::Greater,
+            (Ordering::Equal, _, _) => {
+                // The remainders compare like their reciprocals, reversed.
+                (a, b) = ((a.1, rest_a), (b.1, rest_b));
+                reversed = !reversed;
+                continue;
+            }
+            (ordering, _, _) => ordering,
+        };
+        return if reversed {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_normalization() {
+        let value = Rational::new(4, -6);
+        assert_eq!(value.numerator(), -2);
+        assert_eq!(value.denominator(), 3);
+        assert_eq!(value.to_string(), "-2/3");
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Rational::new(3, 4);
+        let b = Rational::new(-5, 6);
+        assert_eq!(a + b, Rational::new(-1, 12));
+        assert_eq!(a - b, Rational::new(19, 12));
+        assert_eq!(a * b, Rational::new(-5, 8));
+        assert_eq!(a / b, Rational::new(-9, 10));
+        assert_eq!(b.abs(), Rational::new(5, 6));
+        assert_eq!(Rational::ZERO.recip(), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert!(Rational::new(7, 3) > Rational::from(2));
+
+        // Cross-multiplying these would overflow.
+        let big = i128::MAX / 3;
+        assert!(Rational::new(big, big - 1) > Rational::new(big - 1, big - 2).recip().unwrap());
+        assert!(Rational::new(big - 1, big) < Rational::new(big, big + 1));
+        assert!(Rational::new(-big, big - 1) > Rational::new(-(big - 1), big - 2));
+        assert_eq!(
+            Rational::new(big, big - 1).cmp(&Rational::new(big, big - 1)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_checked() {
+        let big = Rational::from(i128::MAX);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_mul(Rational::from(2)), None);
+        assert_eq!(big.checked_sub(big), Some(Rational::ZERO));
+        assert_eq!(Rational::ONE.checked_div(Rational::ZERO), None);
+        assert_eq!(
+            Rational::new(1, 3).checked_add(Rational::new(1, 6)),
+            Some(Rational::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_min() {
+        let min = Rational::from(i128::MIN);
+        assert_eq!(
+            Rational::from(i128::MIN + 1).checked_sub(Rational::ONE),
+            Some(min)
+        );
+        assert_eq!(min.checked_sub(Rational::ONE), None);
+        assert_eq!(min.checked_mul(Rational::ONE), Some(min));
+        assert_eq!(min.checked_mul(Rational::from(-1)), None);
+        assert_eq!(
+            min.checked_div(Rational::from(2)),
+            Some(Rational::from(i128::MIN / 2))
+        );
+        assert_eq!(Rational::ZERO.checked_sub(min), None);
+        assert_eq!(
+            Rational::from(-1).checked_sub(min),
+            Some(Rational::from(i128::MAX))
+        );
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.checked_abs(), None);
+        assert_eq!(min.recip(), None);
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Rational::ONE);
+        assert_eq!(Rational::new(i128::MIN, 2).numerator(), i128::MIN / 2);
+        assert_eq!(Rational::new(i128::MIN, -2).numerator(), -(i128::MIN / 2));
+        assert_eq!(Rational::checked_new(3, i128::MIN), None);
+        assert_eq!(Rational::new(2, i128::MIN), Rational::new(-1, 1 << 126));
+        assert_eq!(gcd_i128(i128::MIN, 12), 4);
+        assert!(min < Rational::from(i128::MIN + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "rational overflow")]
+    fn test_neg_min() {
+        let _ = -Rational::from(i128::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "rational overflow")]
+    fn test_abs_min() {
+        let _ = Rational::from(i128::MIN).abs();
+    }
+
+    #[test]
+    #[cfg(feature = "num-rational")]
+    fn test_matches_num_rational() {
+        use crate::property::{assert_equivalent, Rng};
+        use num_rational::Ratio;
+
+        let fraction = |rng: &mut Rng| {
+            let denominator = rng.signed(1..1000) * if rng.chance(0.5) { 1 } else { -1 };
+            (rng.signed(-1000..1000) as i128, denominator as i128)
+        };
+        assert_equivalent(
+            1000,
+            |&(a, b): &((i128, i128), (i128, i128))| {
+                let (a, b) = (Ratio::new(a.0, a.1), Ratio::new(b.0, b.1));
+                let quotient = (*b.numer() != 0).then(|| Rational::from(a / b));
+                let results = [a + b, a - b, a * b].map(Rational::from);
+                (results, quotient, a.cmp(&b))
+            },
+            |&(a, b)| {
+                let (a, b) = (Rational::new(a.0, a.1), Rational::new(b.0, b.1));
+                ([a + b, a - b, a * b], a.checked_div(b), a.cmp(&b))
+            },
+            |rng| (fraction(rng), fraction(rng)),
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{Direction, Grid, Point};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Index;
+
+/// The connected regions of a grid, as found by [`label_regions`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+/// A maximal group of orthogonally connected cells that compare equal.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Region {
+    cells: Vec<Point>,
+    perimeter: usize,
+    corners: usize,
+    touches_border: bool,
+}
+
+/// Splits the grid into regions of orthogonally adjacent cells for which `eq` holds.
+///
+/// Regions are labeled in the row-major order of their first cell. Each region's cells are
+/// listed in the order the flood fill reached them, starting with that first cell.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{label_regions, Grid, Point};
+///
+/// let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC", Some).unwrap();
+/// let regions = label_regions(&grid, |a, b| a == b);
+/// assert_eq!(regions.len(), 5);
+///
+/// let c = &regions[regions.label(Point::new(2, 1)).unwrap()];
+/// assert_eq!((c.area(), c.perimeter(), c.corners()), (4, 10, 8));
+/// ```
+pub fn label_regions<T, F>(grid: &Grid<T>, mut eq: F) -> Regions
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut labels = Grid::new(grid.width(), grid.height(), usize::MAX);
+    let mut regions = Vec::new();
+
+    for start in grid.points() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+
+        let label = regions.len();
+        labels[start] = label;
+        let mut cells = vec![start];
+        let mut next = 0;
+        while let Some(&cell) = cells.get(next) {
+            next += 1;
+            for neighbor in cell.neighbors() {
+                if labels.get(neighbor) == Some(&usize::MAX) && eq(&grid[cell], &grid[neighbor]) {
+                    labels[neighbor] = label;
+                    cells.push(neighbor);
+                }
+            }
+        }
+
+        regions.push(Region {
+            cells,
+            perimeter: 0,
+            corners: 0,
+            touches_border: false,
+        });
+    }
+
+    for (label, region) in regions.iter_mut().enumerate() {
+        let inside = |point: Point| labels.get(point) == Some(&label);
+        for &cell in &region.cells {
+            for direction in Direction::ALL {
+                let a = cell + direction.offset();
+                let b = cell + direction.turn_right().offset();
+                let diagonal = a + direction.turn_right().offset();
+
+                region.perimeter += usize::from(!inside(a));
+                region.touches_border |= !labels.contains(a);
+
+                // Convex corners have both sides open, concave ones only the diagonal.
+                let outer = !inside(a) && !inside(b);
+                let inner = inside(a) && inside(b) && !inside(diagonal);
+                region.corners += usize::from(outer || inner);
+            }
+        }
+    }
+
+    Regions { labels, regions }
+}
+
+impl Regions {
+    /// Gets the number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Tests whether there are no regions, which only happens for an empty grid.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Gets the label of the region containing the cell, or `None` if it is off the grid.
+    pub fn label(&self, point: Point) -> Option<usize> {
+        self.labels.get(point).copied()
+    }
+
+    /// Gets the grid of region labels.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// Iterates the regions in the order of their labels.
+    pub fn iter(&self) -> core::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+}
+
+impl Region {
+    /// Gets the cells of the region.
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    /// Gets the number of cells.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Gets the number of cell edges that don't touch another cell of the region, including
+    /// the edges around holes.
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Gets the number of corners of the outline, which equals its number of straight sides.
+    pub fn corners(&self) -> usize {
+        self.corners
+    }
+
+    /// Tests whether any cell lies on the edge of the grid, i.e. whether the region is open
+    /// to the outside rather than enclosed.
+    pub fn touches_border(&self) -> bool {
+        self.touches_border
+    }
+}
+
+impl Index<usize> for Regions {
+    type Output = Region;
+
+    fn index(&self, label: usize) -> &Self::Output {
+        &self.regions[label]
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = core::slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holes() {
+        let grid = Grid::parse("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA", Some).unwrap();
+        let regions = label_regions(&grid, |a, b| a == b);
+        assert_eq!(regions.len(), 3);
+
+        let outer = &regions[0];
+        assert_eq!(
+            (outer.area(), outer.perimeter(), outer.corners()),
+            (28, 40, 12)
+        );
+        assert!(outer.touches_border());
+        for hole in regions.iter().skip(1) {
+            assert_eq!((hole.area(), hole.perimeter(), hole.corners()), (4, 8, 4));
+            assert!(!hole.touches_border());
+        }
+        // The two holes touch only diagonally.
+        assert_ne!(
+            regions.label(Point::new(3, 1)),
+            regions.label(Point::new(2, 3))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_labels_match_neighbors() {
+        use crate::property::check;
+
+        check(
+            100,
+            |rng| {
+                let (width, height) = (rng.index(0..8), rng.index(0..8));
+                let mut grid = Grid::new(width, height, 0);
+                for point in grid.points().collect::<Vec<_>>() {
+                    grid[point] = rng.below(3);
+                }
+                grid
+            },
+            |grid| {
+                let regions = label_regions(grid, |a, b| a == b);
+                let area: usize = regions.iter().map(Region::area).sum();
+                let labeled = regions.iter().enumerate().all(|(label, region)| {
+                    let first = grid[region.cells()[0]];
+                    region
+                        .cells()
+                        .iter()
+                        .all(|&cell| regions.label(cell) == Some(label) && grid[cell] == first)
+                });
+                // Adjacent cells share their region exactly if they are equal.
+                let connected = grid.points().all(|point| {
+                    point
+                        .neighbors()
+                        .into_iter()
+                        .all(|neighbor| match grid.get(neighbor) {
+                            Some(value) => {
+                                (value == &grid[point])
+                                    == (regions.label(neighbor) == regions.label(point))
+                            }
+                            None => true,
+                        })
+                });
+                area == grid.width() * grid.height() && labeled && connected
+            },
+        );
+    }
+}
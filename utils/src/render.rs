@@ -0,0 +1,3 @@
+//! Rendering puzzle states for sharing, e.g. as static vector graphics.
+
+pub mod svg;
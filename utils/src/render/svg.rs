@@ -0,0 +1,219 @@
+//! Building SVG documents for grid- and path-based puzzles.
+//!
+//! Coordinates are given in grid cells; the document scales them by the cell size.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc_utils::render::svg::Svg;
+//!
+//! let mut svg = Svg::new(3, 2, 10);
+//! svg.cell(0, 0, "black");
+//! svg.path([(0, 1), (2, 1)], "red", false);
+//! svg.point(2, 0, "blue", Some("end"));
+//!
+//! let document = svg.to_string();
+//! assert!(document.starts_with("<svg"));
+//! assert!(document.contains(r#"<rect x="0" y="0" width="10" height="10" fill="black"/>"#));
+//! assert!(document.contains(r#"points="5,15 25,15""#));
+//! ```
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Write};
+
+/// An SVG document drawn on a grid of square cells.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Svg {
+    columns: usize,
+    rows: usize,
+    cell_size: u32,
+    body: String,
+}
+
+impl Svg {
+    /// Creates an empty document for a grid of the specified size.
+    pub fn new(columns: usize, rows: usize, cell_size: u32) -> Self {
+        Self {
+            columns,
+            rows,
+            cell_size,
+            body: String::new(),
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Fills a single cell.
+    pub fn cell(&mut self, x: usize, y: usize, fill: &str) {
+        self.span(x, y, 1, fill);
+    }
+
+    /// Fills a horizontal run of cells, starting at `(x, y)`.
+    pub fn span(&mut self, x: usize, y: usize, length: usize, fill: &str) {
+        let size = self.cell_size as usize;
+        let _ = writeln!(
+            self.body,
+            r#"<rect x="{}" y="{}" width="{}" height="{size}" fill="{}"/>"#,
+            x * size,
+            y * size,
+            length * size,
+            escape(fill)
+        );
+    }
+
+    /// Fills every cell for which the function returns a color.
+    pub fn grid<'a, F>(&mut self, mut fill: F)
+    where
+        F: FnMut(usize, usize) -> Option<&'a str>,
+    {
+        for y in 0..self.rows {
+            for x in 0..self.columns {
+                if let Some(color) = fill(x, y) {
+                    self.cell(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Draws the lines between all cells.
+    pub fn grid_lines(&mut self, stroke: &str) {
+        let size = self.cell_size as usize;
+        let (width, height) = (self.columns * size, self.rows * size);
+        let mut path = String::new();
+        for x in 0..=self.columns {
+            let _ = write!(path, "M{},0V{height}", x * size);
+        }
+        for y in 0..=self.rows {
+            let _ = write!(path, "M0,{}H{width}", y * size);
+        }
+        let _ = writeln!(
+            self.body,
+            r#"<path d="{path}" stroke="{}" stroke-width="1" fill="none"/>"#,
+            escape(stroke)
+        );
+    }
+
+    /// Draws a line through the centers of the cells, optionally closing it into a polygon.
+    pub fn path<I>(&mut self, cells: I, stroke: &str, closed: bool)
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let mut points = String::new();
+        for (x, y) in cells {
+            let (cx, cy) = self.center(x, y);
+            if !points.is_empty() {
+                points.push(' ');
+            }
+            let _ = write!(points, "{cx},{cy}");
+        }
+
+        let element = if closed { "polygon" } else { "polyline" };
+        let _ = writeln!(
+            self.body,
+            r#"<{element} points="{points}" stroke="{}" stroke-width="{}" fill="none"/>"#,
+            escape(stroke),
+            self.stroke_width()
+        );
+    }
+
+    /// Draws a dot in the center of the cell, optionally labeled to its right.
+    pub fn point(&mut self, x: usize, y: usize, fill: &str, label: Option<&str>) {
+        let (cx, cy) = self.center(x, y);
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{cx}" cy="{cy}" r="{}" fill="{}"/>"#,
+            self.cell_size as f64 / 3.0,
+            escape(fill)
+        );
+
+        if let Some(label) = label {
+            let size = self.cell_size as f64;
+            self.write_text(cx + size / 2.0, cy, label, fill, "start");
+        }
+    }
+
+    /// Writes text centered on the cell.
+    pub fn text(&mut self, x: usize, y: usize, text: &str, fill: &str) {
+        let (cx, cy) = self.center(x, y);
+        self.write_text(cx, cy, text, fill, "middle");
+    }
+
+    fn write_text(&mut self, x: f64, y: f64, text: &str, fill: &str, anchor: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"<text x="{x}" y="{y}" font-size="{}" font-family="monospace" fill="{}" text-anchor="{anchor}" dominant-baseline="central">{}</text>"#,
+            self.cell_size as f64 * 0.8,
+            escape(fill),
+            escape(text)
+        );
+    }
+
+    fn center(&self, x: usize, y: usize) -> (f64, f64) {
+        let size = self.cell_size as f64;
+        ((x as f64 + 0.5) * size, (y as f64 + 0.5) * size)
+    }
+
+    fn stroke_width(&self) -> f64 {
+        (self.cell_size as f64 / 4.0).max(1.0)
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let size = self.cell_size as usize;
+        let (width, height) = (self.columns * size, self.rows * size);
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )?;
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Escapes the characters with a special meaning in XML text and attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_document() {
+        let mut svg = Svg::new(2, 2, 8);
+        svg.grid(|x, y| (x == y).then_some("gray"));
+        svg.path([(0, 0), (1, 0), (1, 1)], "red", true);
+        svg.text(1, 1, "<5>", "white");
+
+        let document = svg.to_string();
+        assert!(document.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 16 16">"#
+        ));
+        assert_eq!(document.matches("<rect").count(), 2);
+        assert!(
+            document.contains(r#"<polygon points="4,4 12,4 12,12" stroke="red" stroke-width="2""#)
+        );
+        assert!(document.contains(">&lt;5&gt;</text>"));
+        assert!(document.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,279 @@
+//! Bytewise scanners for line-oriented ASCII inputs.
+//!
+//! Lines and fields are split with [`memchr`], digits are located eight bytes at a time
+//! by classifying a whole `u64` word in a handful of arithmetic operations.
+
+use core::ops::Range;
+
+/// The number of bytes classified at once.
+const WORD: usize = core::mem::size_of::<u64>();
+
+/// The lowest bit of every byte in a word.
+const LOW_BITS: u64 = u64::from_ne_bytes([0x01; WORD]);
+
+/// The highest bit of every byte in a word.
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; WORD]);
+
+/// Iterates the lines of the input, without their `\n` or `\r\n` terminators.
+///
+/// Like [`str::lines`], a trailing line terminator does not produce an empty last line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan::lines;
+///
+/// let lines: Vec<_> = lines(b"abc\r\ndef\n\nghi\n").collect();
+/// assert_eq!(lines, [&b"abc"[..], b"def", b"", b"ghi"]);
+/// ```
+pub fn lines(input: &[u8]) -> Lines<'_> {
+    Lines {
+        rest: Some(input).filter(|input| !input.is_empty()),
+    }
+}
+
+/// Iterates the fields of a line that are separated by the specified byte.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan::fields;
+///
+/// let fields: Vec<_> = fields(b"12,,34", b',').collect();
+/// assert_eq!(fields, [&b"12"[..], b"", b"34"]);
+/// ```
+pub fn fields(line: &[u8], separator: u8) -> Fields<'_> {
+    Fields {
+        rest: Some(line),
+        separator,
+    }
+}
+
+/// Iterates the index ranges of all runs of consecutive ASCII digits.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan::digit_runs;
+///
+/// let runs: Vec<_> = digit_runs(b"467..114..").collect();
+/// assert_eq!(runs, [0..3, 5..8]);
+/// ```
+pub fn digit_runs(bytes: &[u8]) -> DigitRuns<'_> {
+    DigitRuns { bytes, offset: 0 }
+}
+
+/// Finds the index of the first ASCII digit.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan::first_digit;
+///
+/// assert_eq!(first_digit(b"pqr3stu8vwx"), Some(3));
+/// assert_eq!(first_digit(b"trebuchet"), None);
+/// ```
+pub fn first_digit(bytes: &[u8]) -> Option<usize> {
+    position(bytes, digit_mask)
+}
+
+/// Finds the index of the last ASCII digit.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan::last_digit;
+///
+/// assert_eq!(last_digit(b"pqr3stu8vwx"), Some(7));
+/// assert_eq!(last_digit(b"trebuchet"), None);
+/// ```
+pub fn last_digit(bytes: &[u8]) -> Option<usize> {
+    let mut end = bytes.len();
+    while end >= WORD {
+        let mask = digit_mask(load(&bytes[end - WORD..end]));
+        if mask != 0 {
+            return Some(end - WORD + last_byte(mask));
+        }
+        end -= WORD;
+    }
+    bytes[..end].iter().rposition(u8::is_ascii_digit)
+}
+
+/// Finds the index of the first byte that isn't an ASCII digit.
+fn first_non_digit(bytes: &[u8]) -> Option<usize> {
+    position(bytes, |word| !digit_mask(word) & HIGH_BITS)
+}
+
+/// Finds the first byte whose high bit is set in the mask computed for its word.
+fn position(bytes: &[u8], mask: impl Fn(u64) -> u64) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(WORD);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = mask(load(chunk));
+        if mask != 0 {
+            return Some(index * WORD + first_byte(mask));
+        }
+    }
+
+    // Pad the remainder to a full word; matches in the padding are cut off by the bounds check.
+    let remainder = chunks.remainder();
+    let offset = bytes.len() - remainder.len();
+    let mut word = [b'.'; WORD];
+    word[..remainder.len()].copy_from_slice(remainder);
+    let found = first_byte_checked(mask(u64::from_le_bytes(word)))?;
+    (found < remainder.len()).then_some(offset + found)
+}
+
+/// Sets the high bit of every byte in the word that is an ASCII digit.
+fn digit_mask(word: u64) -> u64 {
+    // Clearing the high bits first ensures the additions never carry into the next byte.
+    let low = word & !HIGH_BITS;
+    let at_least_zero = low + (0x80 - b'0' as u64) * LOW_BITS;
+    let above_nine = low + (0x80 - b'9' as u64 - 1) * LOW_BITS;
+    at_least_zero & !above_nine & !word & HIGH_BITS
+}
+
+/// Reads eight bytes into a word such that the first byte is the least significant one.
+fn load(chunk: &[u8]) -> u64 {
+    u64::from_le_bytes(chunk.try_into().expect("chunk must be one word long"))
+}
+
+/// Gets the index of the first byte flagged in a non-zero mask.
+fn first_byte(mask: u64) -> usize {
+    mask.trailing_zeros() as usize / WORD
+}
+
+/// Gets the index of the first byte flagged in the mask, if any.
+fn first_byte_checked(mask: u64) -> Option<usize> {
+    (mask != 0).then(|| first_byte(mask))
+}
+
+/// Gets the index of the last byte flagged in a non-zero mask.
+fn last_byte(mask: u64) -> usize {
+    (u64::BITS - 1 - mask.leading_zeros()) as usize / WORD
+}
+
+/// An iterator over the lines of an input; see [`lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: Option<&'a [u8]>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        let line = match memchr::memchr(b'\n', rest) {
+            Some(end) => {
+                self.rest = Some(&rest[end + 1..]).filter(|rest| !rest.is_empty());
+                &rest[..end]
+            }
+            None => {
+                self.rest = None;
+                rest
+            }
+        };
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+/// An iterator over the fields of a line; see [`fields`].
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    rest: Option<&'a [u8]>,
+    separator: u8,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        match memchr::memchr(self.separator, rest) {
+            Some(end) => {
+                self.rest = Some(&rest[end + 1..]);
+                Some(&rest[..end])
+            }
+            None => {
+                self.rest = None;
+                Some(rest)
+            }
+        }
+    }
+}
+
+/// An iterator over the runs of ASCII digits; see [`digit_runs`].
+#[derive(Debug, Clone)]
+pub struct DigitRuns<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Iterator for DigitRuns<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.bytes[self.offset..];
+        let start = first_digit(rest)?;
+        let len = first_non_digit(&rest[start..]).unwrap_or(rest.len() - start);
+        let run = self.offset + start..self.offset + start + len;
+        self.offset = run.end;
+        Some(run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_digit_mask_matches_is_ascii_digit() {
+        for byte in 0..=u8::MAX {
+            let word = u64::from_le_bytes([b'.', byte, b'.', b'.', b'.', b'.', b'.', byte]);
+            let expected = if byte.is_ascii_digit() {
+                0x80 << 8 | 0x80 << 56
+            } else {
+                0
+            };
+            assert_eq!(digit_mask(word), expected, "byte {byte:#04x}");
+        }
+    }
+
+    #[test]
+    fn test_scanners_match_naive_search() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"7",
+            b"abc",
+            b"1234567890123456789",
+            b"..35..633.......617*......\xff9",
+            b"two1nine\x80eightwothree47",
+        ];
+
+        for bytes in inputs {
+            assert_eq!(
+                first_digit(bytes),
+                bytes.iter().position(u8::is_ascii_digit)
+            );
+            assert_eq!(
+                last_digit(bytes),
+                bytes.iter().rposition(u8::is_ascii_digit)
+            );
+
+            let naive: Vec<_> = bytes
+                .split(|byte| !byte.is_ascii_digit())
+                .filter(|run| !run.is_empty())
+                .collect();
+            let runs: Vec<_> = digit_runs(bytes).map(|run| &bytes[run]).collect();
+            assert_eq!(runs, naive);
+        }
+    }
+
+    #[test]
+    fn test_lines_without_trailing_newline() {
+        assert_eq!(lines(b"").count(), 0);
+        assert_eq!(lines(b"a\n").collect::<Vec<_>>(), [b"a"]);
+        assert_eq!(lines(b"a\nb").collect::<Vec<_>>(), [b"a", b"b"]);
+    }
+}
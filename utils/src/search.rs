@@ -0,0 +1,243 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Runs a breadth-first search and returns the distance of every reachable node.
+///
+/// # Arguments
+///
+/// * `start` - The node to start the search at; it has distance `0`.
+/// * `neighbors` - A function returning the direct neighbors of a node.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::bfs_distances;
+///
+/// // A line of nodes 0 to 9, each connected to the next.
+/// let distances = bfs_distances(0, |&n: &u32| (n < 9).then_some(n + 1));
+/// assert_eq!(distances.len(), 10);
+/// assert_eq!(distances[&9], 9);
+/// ```
+pub fn bfs_distances<N, F, I>(start: N, neighbors: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    bfs_distances_within(start, usize::MAX, neighbors)
+}
+
+/// Runs a breadth-first search up to a maximum distance and returns the distance
+/// of every node reached.
+///
+/// Nodes further away than `max_distance` are not visited, which allows searching
+/// infinite graphs such as tiled grids.
+///
+/// # Arguments
+///
+/// * `start` - The node to start the search at; it has distance `0`.
+/// * `max_distance` - The maximum distance (inclusive) to search.
+/// * `neighbors` - A function returning the direct neighbors of a node.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::bfs_distances_within;
+///
+/// // An infinite line of nodes, each connected to both of its neighbors.
+/// let distances = bfs_distances_within(0, 3, |&n: &i32| [n - 1, n + 1]);
+/// assert_eq!(distances.len(), 7);
+/// assert_eq!(distances[&-3], 3);
+/// ```
+pub fn bfs_distances_within<N, F, I>(
+    start: N,
+    max_distance: usize,
+    mut neighbors: F,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if distance == max_distance {
+            continue;
+        }
+
+        for neighbor in neighbors(&node) {
+            if distances.contains_key(&neighbor) {
+                continue;
+            }
+
+            distances.insert(neighbor.clone(), distance + 1);
+            queue.push_back((neighbor, distance + 1));
+        }
+    }
+
+    distances
+}
+
+/// Runs Dijkstra's algorithm and returns the shortest distance of every reachable node.
+///
+/// # Arguments
+///
+/// * `starts` - The nodes to start the search at; they all have distance `0`.
+/// * `neighbors` - A function returning the direct neighbors of a node together with
+///   the (non-negative) cost of moving there.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::dijkstra_distances;
+///
+/// // Stepping to n + 1 costs 1, jumping to n * 2 costs 2.
+/// let distances = dijkstra_distances([1], |&n: &u32| {
+///     [(n + 1, 1), (n * 2, 2)].into_iter().filter(|&(m, _)| m <= 16)
+/// });
+/// assert_eq!(distances[&16], 7);
+/// assert_eq!(distances[&5], 4);
+/// ```
+pub fn dijkstra_distances<N, S, F, I>(starts: S, mut neighbors: F) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash + Ord,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut distances = HashMap::new();
+    let mut queue: BinaryHeap<_> = starts
+        .into_iter()
+        .map(|start| Reverse((0, start)))
+        .collect();
+
+    while let Some(Reverse((distance, node))) = queue.pop() {
+        if distances.contains_key(&node) {
+            continue;
+        }
+
+        for (neighbor, cost) in neighbors(&node) {
+            if !distances.contains_key(&neighbor) {
+                queue.push(Reverse((distance + cost, neighbor)));
+            }
+        }
+        distances.insert(node, distance);
+    }
+
+    distances
+}
+
+/// Counts the distinct paths from a node to any goal node in a directed acyclic graph.
+///
+/// The search is a depth-first traversal that memoizes the number of paths from each node,
+/// so every node is only expanded once. Paths end at the first goal node they reach.
+///
+/// # Arguments
+///
+/// * `start` - The node to start the paths at.
+/// * `neighbors` - A function returning the successors of a node; the graph must not have cycles.
+/// * `is_goal` - A function determining whether a node ends a path.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::count_paths;
+///
+/// // Each number can step to the next one or skip it; there are 8 ways from 0 to 5.
+/// let paths = count_paths(0, |&n: &u32| [n + 1, n + 2].into_iter().filter(|&m| m <= 5), |&n| n == 5);
+/// assert_eq!(paths, 8);
+/// ```
+pub fn count_paths<N, F, I, G>(start: N, mut neighbors: F, mut is_goal: G) -> usize
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    fn visit<N, F, I, G>(
+        node: N,
+        neighbors: &mut F,
+        is_goal: &mut G,
+        memo: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        G: FnMut(&N) -> bool,
+    {
+        if is_goal(&node) {
+            return 1;
+        }
+
+        if let Some(&count) = memo.get(&node) {
+            return count;
+        }
+
+        let count = neighbors(&node)
+            .into_iter()
+            .map(|next| visit(next, neighbors, is_goal, memo))
+            .sum();
+        memo.insert(node, count);
+        count
+    }
+
+    visit(start, &mut neighbors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn test_bfs_distances_grid() {
+        // A 3x3 grid with a wall in the middle column, except for the bottom row.
+        const GRID: [&str; 3] = ["..#", ".#.", "..."];
+        let open = |x: i32, y: i32| {
+            (0..3).contains(&x)
+                && (0..3).contains(&y)
+                && GRID[y as usize].as_bytes()[x as usize] == b'.'
+        };
+
+        let distances = bfs_distances((0, 0), |&(x, y)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| open(x, y))
+        });
+
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances[&(2, 1)], 5);
+        assert!(!distances.contains_key(&(2, 0)));
+    }
+
+    #[test]
+    fn test_count_paths_diamond() {
+        // 0 -> {1, 2} -> 3, plus a dead end 1 -> 4.
+        let edges = |&n: &u8| match n {
+            0 => vec![1, 2],
+            1 => vec![3, 4],
+            2 => vec![3],
+            _ => vec![],
+        };
+        assert_eq!(count_paths(0, edges, |&n| n == 3), 2);
+        assert_eq!(count_paths(4, edges, |&n| n == 3), 0);
+    }
+
+    #[test]
+    fn test_dijkstra_multiple_starts() {
+        // A line of nodes 0 to 10 with unit costs, starting from both ends.
+        let distances = dijkstra_distances([0, 10], |&n: &i32| {
+            [n - 1, n + 1]
+                .into_iter()
+                .filter(|m| (0..=10).contains(m))
+                .map(|m| (m, 1))
+        });
+        assert_eq!(distances.len(), 11);
+        assert_eq!(distances[&5], 5);
+        assert_eq!(distances[&8], 2);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{Point, Rational};
+use core::cmp::Ordering;
+
+/// The turn taken when going from one point over a second to a third.
+///
+/// The sense is that of the grid, where `y` grows downwards: going right and then down is a
+/// clockwise turn, just like [`Direction::turn_right`](crate::Direction::turn_right).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+    /// The three points lie on a line.
+    Collinear,
+}
+
+/// The points two line segments have in common.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SegmentIntersection {
+    /// The segments don't touch.
+    None,
+    /// The segments cross or touch at a single point, given as exact `(x, y)` fractions.
+    Point(Rational, Rational),
+    /// The segments are collinear and share the part between the two points, in ascending order.
+    Overlap(Point, Point),
+}
+
+/// Determines the turn taken at `b` when going from `a` over `b` to `c`.
+///
+/// The cross product is calculated on `i128` values, so the result is exact for any
+/// coordinates of up to 62 bits.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{orientation, Orientation, Point};
+///
+/// let (a, b) = (Point::new(0, 0), Point::new(2, 0));
+/// assert_eq!(orientation(a, b, Point::new(2, 1)), Orientation::Clockwise);
+/// assert_eq!(orientation(a, b, Point::new(2, -1)), Orientation::Counterclockwise);
+/// assert_eq!(orientation(a, b, Point::new(5, 0)), Orientation::Collinear);
+/// ```
+pub fn orientation(a: Point, b: Point, c: Point) -> Orientation {
+    match cross(b - a, c - a).signum() {
+        1 => Orientation::Clockwise,
+        -1 => Orientation::Counterclockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+/// Determines whether the point lies on the segment between `a` and `b`, both inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{on_segment, Point};
+///
+/// let (a, b) = (Point::new(0, 0), Point::new(4, 2));
+/// assert!(on_segment(Point::new(2, 1), a, b));
+/// assert!(!on_segment(Point::new(6, 3), a, b));
+/// ```
+pub fn on_segment(point: Point, a: Point, b: Point) -> bool {
+    orientation(a, b, point) == Orientation::Collinear
+        && point.x >= a.x.min(b.x)
+        && point.x <= a.x.max(b.x)
+        && point.y >= a.y.min(b.y)
+        && point.y <= a.y.max(b.y)
+}
+
+/// Finds the points the segments `a` and `b` have in common, all endpoints inclusive.
+///
+/// The intersection is calculated with `i128` cross products and exact fractions, so it
+/// doesn't suffer from rounding errors for coordinates of up to 40 bits.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{segment_intersection, Point, Rational, SegmentIntersection};
+///
+/// let a = [Point::new(0, 0), Point::new(3, 3)];
+/// let b = [Point::new(0, 2), Point::new(2, 0)];
+/// let one = Rational::from(1);
+/// assert_eq!(segment_intersection(a, b), SegmentIntersection::Point(one, one));
+///
+/// let c = [Point::new(2, 2), Point::new(5, 5)];
+/// assert_eq!(
+///     segment_intersection(a, c),
+///     SegmentIntersection::Overlap(Point::new(2, 2), Point::new(3, 3))
+/// );
+/// ```
+pub fn segment_intersection(a: [Point; 2], b: [Point; 2]) -> SegmentIntersection {
+    let (r, s) = (a[1] - a[0], b[1] - b[0]);
+    let offset = b[0] - a[0];
+    let denominator = cross(r, s);
+
+    if denominator == 0 {
+        if cross(offset, r) != 0 || cross(offset, s) != 0 {
+            return SegmentIntersection::None;
+        }
+
+        // Collinear points are ordered along the line when ordered by their coordinates.
+        let start = a[0].min(a[1]).max(b[0].min(b[1]));
+        let end = a[0].max(a[1]).min(b[0].max(b[1]));
+        return match start.cmp(&end) {
+            Ordering::Less => SegmentIntersection::Overlap(start, end),
+            Ordering::Equal => SegmentIntersection::Point(start.x.into(), start.y.into()),
+            Ordering::Greater => SegmentIntersection::None,
+        };
+    }
+
+    // The intersection is at a[0] + t·r = b[0] + u·s, with t and u in [0, 1].
+    let t = cross(offset, s);
+    let u = cross(offset, r);
+    let within = |numerator: i128| {
+        let (numerator, denominator) = if denominator < 0 {
+            (-numerator, -denominator)
+        } else {
+            (numerator, denominator)
+        };
+        (0..=denominator).contains(&numerator)
+    };
+    if !within(t) || !within(u) {
+        return SegmentIntersection::None;
+    }
+
+    let at = |start: i64, delta: i64| {
+        Rational::new(start as i128 * denominator + delta as i128 * t, denominator)
+    };
+    SegmentIntersection::Point(at(a[0].x, r.x), at(a[0].y, r.y))
+}
+
+/// Counts how often a ray cast from the point towards increasing `x` crosses the edges of
+/// the closed polygon, which is odd exactly if the point lies inside.
+///
+/// Vertices on the ray count as crossed if the edge continues to increasing `y`, so a ray
+/// grazing a vertex or running along an edge isn't counted twice. Points on the boundary
+/// itself may count either way; check them with [`on_segment`] if that matters.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{ray_crossing_count, Point};
+///
+/// let square = [
+///     Point::new(0, 0),
+///     Point::new(4, 0),
+///     Point::new(4, 4),
+///     Point::new(0, 4),
+/// ];
+/// assert_eq!(ray_crossing_count(Point::new(2, 2), &square), 1);
+/// assert_eq!(ray_crossing_count(Point::new(-1, 2), &square), 2);
+/// assert_eq!(ray_crossing_count(Point::new(5, 2), &square), 0);
+/// ```
+pub fn ray_crossing_count(point: Point, polygon: &[Point]) -> usize {
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    edges
+        .filter(|&(&a, &b)| {
+            if (a.y > point.y) == (b.y > point.y) {
+                return false;
+            }
+
+            // The edge spans the ray's line; it crosses the ray if the point is on its left
+            // when following the edge downwards, i.e. the crossing is at a larger `x`.
+            let (top, bottom) = if a.y < b.y { (a, b) } else { (b, a) };
+            orientation(top, bottom, point) == Orientation::Clockwise
+        })
+        .count()
+}
+
+/// Calculates the z component of the cross product of two offsets.
+fn cross(a: Point, b: Point) -> i128 {
+    a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_intersection() {
+        let p = |x, y| Point::new(x, y);
+        let at = |x: i128, y: i128, d: i128| {
+            SegmentIntersection::Point(Rational::new(x, d), Rational::new(y, d))
+        };
+
+        // Crossing at a fraction, and touching at an endpoint.
+        assert_eq!(
+            segment_intersection([p(0, 0), p(1, 1)], [p(0, 1), p(1, 0)]),
+            at(1, 1, 2)
+        );
+        assert_eq!(
+            segment_intersection([p(0, 0), p(2, 0)], [p(2, 0), p(2, 5)]),
+            at(2, 0, 1)
+        );
+        assert_eq!(
+            segment_intersection([p(0, 0), p(2, 0)], [p(3, -1), p(3, 1)]),
+            SegmentIntersection::None
+        );
+
+        // Parallel, collinear and degenerate segments.
+        assert_eq!(
+            segment_intersection([p(0, 0), p(2, 0)], [p(0, 1), p(2, 1)]),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            segment_intersection([p(4, 0), p(0, 0)], [p(3, 0), p(9, 0)]),
+            SegmentIntersection::Overlap(p(3, 0), p(4, 0))
+        );
+        assert_eq!(
+            segment_intersection([p(0, 0), p(0, 2)], [p(0, 2), p(0, 3)]),
+            at(0, 2, 1)
+        );
+        assert_eq!(
+            segment_intersection([p(1, 1), p(1, 1)], [p(0, 0), p(2, 2)]),
+            at(1, 1, 1)
+        );
+        assert_eq!(
+            segment_intersection([p(1, 1), p(1, 1)], [p(0, 0), p(2, 0)]),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn test_ray_crossing_count() {
+        // A U shape, whose notch the ray runs along and whose vertices it grazes.
+        let polygon = [
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 2),
+            Point::new(4, 2),
+            Point::new(4, 0),
+            Point::new(6, 0),
+            Point::new(6, 4),
+            Point::new(0, 4),
+        ];
+        let inside = |x, y| ray_crossing_count(Point::new(x, y), &polygon) % 2 == 1;
+        assert!(inside(1, 1));
+        assert!(!inside(3, 1));
+        assert!(inside(5, 1));
+        assert!(inside(1, 2));
+        assert!(inside(3, 3));
+        assert!(!inside(-1, 2));
+        assert!(!inside(3, -1));
+        assert!(!inside(7, 3));
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A state that evolves in discrete steps, such as a guard walking or sand falling.
+pub trait Simulate {
+    /// Advances the state by one step, or returns `false` if it has come to an end.
+    fn step(&mut self) -> bool;
+}
+
+/// Runs a [`Simulate`] state, counting the steps taken.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{Simulate, Simulation};
+///
+/// /// The Collatz sequence, which ends at `1`.
+/// struct Collatz(u64);
+///
+/// impl Simulate for Collatz {
+///     fn step(&mut self) -> bool {
+///         match self.0 {
+///             1 => return false,
+///             n if n % 2 == 0 => self.0 = n / 2,
+///             n => self.0 = 3 * n + 1,
+///         }
+///         true
+///     }
+/// }
+///
+/// let mut simulation = Simulation::new(Collatz(6));
+/// assert!(simulation.run_until(|state| state.0 > 10));
+/// assert_eq!((simulation.state().0, simulation.steps()), (16, 4));
+///
+/// assert_eq!(simulation.run(100), 4);
+/// assert_eq!((simulation.state().0, simulation.steps()), (1, 8));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Simulation<S> {
+    state: S,
+    steps: u64,
+}
+
+/// A copy of a simulation's state to return to later; see [`Simulation::snapshot`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Snapshot<S> {
+    state: S,
+    steps: u64,
+}
+
+/// A repeating sequence of states, found by [`Simulation::find_cycle`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cycle {
+    /// The number of steps after which the state first takes part in the cycle.
+    pub start: u64,
+    /// The number of steps until a state repeats.
+    pub length: u64,
+}
+
+impl<S> Simulation<S> {
+    /// Starts a simulation of the state with no steps taken.
+    pub fn new(state: S) -> Self {
+        Self { state, steps: 0 }
+    }
+
+    /// Gets the current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Gets the current state mutably, e.g. to inject an event between steps.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Ends the simulation and returns its state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Gets the number of steps taken.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Copies the current state and step count.
+    pub fn snapshot(&self) -> Snapshot<S>
+    where
+        S: Clone,
+    {
+        Snapshot {
+            state: self.state.clone(),
+            steps: self.steps,
+        }
+    }
+
+    /// Rolls the state and step count back to the snapshot.
+    pub fn restore(&mut self, snapshot: &Snapshot<S>)
+    where
+        S: Clone,
+    {
+        self.state.clone_from(&snapshot.state);
+        self.steps = snapshot.steps;
+    }
+}
+
+impl<S: Simulate> Simulation<S> {
+    /// Advances the state by one step, or returns `false` if it has come to an end.
+    pub fn step(&mut self) -> bool {
+        let advanced = self.state.step();
+        self.steps += u64::from(advanced);
+        advanced
+    }
+
+    /// Takes up to the given number of steps, returning how many were taken before the
+    /// simulation came to an end.
+    pub fn run(&mut self, steps: u64) -> u64 {
+        let start = self.steps;
+        while self.steps - start < steps && self.step() {}
+        self.steps - start
+    }
+
+    /// Steps until the state matches the predicate, which is checked before every step.
+    ///
+    /// Returns `false` if the simulation came to an end first. The predicate also serves
+    /// as a hook to observe every state, e.g. to draw a frame.
+    pub fn run_until<P>(&mut self, mut predicate: P) -> bool
+    where
+        P: FnMut(&S) -> bool,
+    {
+        loop {
+            if predicate(&self.state) {
+                return true;
+            }
+            if !self.step() {
+                return false;
+            }
+        }
+    }
+
+    /// Steps until a state repeats one seen since the call, which shows that the simulation
+    /// cycles from then on.
+    ///
+    /// Afterwards, the simulation is at the first repeated state, `start + length` steps in.
+    /// Returns `None` if the simulation came to an end instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_utils::{Simulate, Simulation};
+    ///
+    /// #[derive(Clone, Eq, PartialEq, Hash)]
+    /// struct Squares(u64);
+    ///
+    /// impl Simulate for Squares {
+    ///     fn step(&mut self) -> bool {
+    ///         self.0 = (self.0 * self.0 + 1) % 10;
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// // 0 → 1 → 2 → 5 → 6 → 7 → 0 → …
+    /// let mut simulation = Simulation::new(Squares(3));
+    /// let cycle = simulation.find_cycle().unwrap();
+    /// assert_eq!((cycle.start, cycle.length), (1, 6));
+    /// assert_eq!(cycle.equivalent_step(1_000_000_000), 4);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn find_cycle(&mut self) -> Option<Cycle>
+    where
+        S: Clone + Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(&start) = seen.get(&self.state) {
+                return Some(Cycle {
+                    start,
+                    length: self.steps - start,
+                });
+            }
+            seen.insert(self.state.clone(), self.steps);
+            if !self.step() {
+                return None;
+            }
+        }
+    }
+}
+
+impl<S> Snapshot<S> {
+    /// Gets the copied state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Gets the number of steps taken when the snapshot was made.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+}
+
+impl Cycle {
+    /// Maps a step count to the earliest one that leads to the same state.
+    pub fn equivalent_step(&self, step: u64) -> u64 {
+        if step < self.start {
+            return step;
+        }
+        self.start + (step - self.start) % self.length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A number wandering around a rho-shaped sequence.
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    struct Rho(u64);
+
+    impl Simulate for Rho {
+        fn step(&mut self) -> bool {
+            self.0 = (self.0 * self.0 + 3) % 1009;
+            true
+        }
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let mut simulation = Simulation::new(Rho(2));
+        simulation.run(5);
+        let snapshot = simulation.snapshot();
+        let state = simulation.state().clone();
+
+        simulation.run(20);
+        assert_ne!(simulation.state(), &state);
+        simulation.restore(&snapshot);
+        assert_eq!((simulation.state(), simulation.steps()), (&state, 5));
+        assert_eq!((snapshot.state(), snapshot.steps()), (&state, 5));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_cycle_skips_ahead() {
+        let mut simulation = Simulation::new(Rho(2));
+        let cycle = simulation.find_cycle().expect("the sequence is finite");
+        assert!(cycle.length > 0);
+
+        for target in [0, cycle.start, cycle.start + 3 * cycle.length + 1, 10_000] {
+            let mut direct = Simulation::new(Rho(2));
+            direct.run(target);
+
+            let mut skipped = Simulation::new(Rho(2));
+            skipped.run(cycle.equivalent_step(target));
+            assert_eq!(skipped.state(), direct.state(), "after {target} steps");
+        }
+    }
+}
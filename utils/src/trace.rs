@@ -0,0 +1,33 @@
+//! Spans around the phases of a solution, for profiling with the `tracing` feature.
+//!
+//! Without the feature, the guards are empty and entering a span compiles to nothing, so the
+//! days can mark their phases unconditionally.
+
+/// An entered span, exited when the guard is dropped.
+#[must_use = "the span is exited when the guard is dropped"]
+pub struct Entered {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Enters the span of parsing the input.
+#[inline]
+pub fn parse() -> Entered {
+    Entered {
+        #[cfg(feature = "tracing")]
+        _span: tracing::info_span!("parse").entered(),
+    }
+}
+
+/// Enters the span of solving a part of a day, named `part1` or `part2`.
+#[inline]
+#[allow(unused_variables)]
+pub fn part(year: u16, day: u8, part: u8) -> Entered {
+    Entered {
+        #[cfg(feature = "tracing")]
+        _span: match part {
+            1 => tracing::info_span!("part1", year, day).entered(),
+            _ => tracing::info_span!("part2", year, day).entered(),
+        },
+    }
+}
@@ -0,0 +1,310 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A set of byte strings with associated values, stored as a tree of their shared prefixes.
+///
+/// Looking up which keys start a text only walks the text once, no matter how many keys
+/// there are, which makes it a good fit for matching many short patterns.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::Trie;
+///
+/// let trie: Trie<u32> = [("b", 1), ("bw", 2), ("bwu", 3), ("r", 4)].into_iter().collect();
+/// assert_eq!(trie.get("bw"), Some(&2));
+///
+/// let matches: Vec<_> = trie.prefixes("bwurrg".bytes()).collect();
+/// assert_eq!(matches, [(1, &1), (2, &2), (3, &3)]);
+/// assert_eq!(trie.shortest_match("rrg".bytes()), Some((1, &4)));
+/// assert_eq!(trie.longest_match("bwurrg".bytes()), Some((3, &3)));
+/// assert_eq!(trie.longest_match("g".bytes()), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trie<V = ()> {
+    /// The nodes of the tree, with the root, standing for the empty prefix, at index `0`.
+    nodes: Vec<TrieNode<V>>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct TrieNode<V> {
+    /// The next byte of each longer prefix with the index of its node, ordered by byte.
+    children: Vec<(u8, usize)>,
+    /// The value if the prefix is a key itself.
+    value: Option<V>,
+}
+
+impl<V> Default for TrieNode<V> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            value: None,
+        }
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+}
+
+impl<V> Trie<V> {
+    /// Creates an empty trie.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of keys.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the trie contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts the key with the value, returning the value previously stored for it.
+    pub fn insert(&mut self, key: impl AsRef<[u8]>, value: V) -> Option<V> {
+        let mut node = 0;
+        for &byte in key.as_ref() {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&byte, |&(b, _)| b)
+            {
+                Ok(index) => self.nodes[node].children[index].1,
+                Err(index) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[node].children.insert(index, (byte, child));
+                    child
+                }
+            };
+        }
+
+        let previous = self.nodes[node].value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Gets the value stored for the key.
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&V> {
+        let node = key
+            .as_ref()
+            .iter()
+            .try_fold(0, |node, &byte| self.child(node, byte))?;
+        self.nodes[node].value.as_ref()
+    }
+
+    /// Returns `true` if the trie contains the key.
+    pub fn contains_key(&self, key: impl AsRef<[u8]>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Iterates the keys the bytes start with, from shortest to longest, as the key's length
+    /// and value.
+    ///
+    /// Only as many bytes are consumed as needed, so the bytes may well be an endless or
+    /// reversed iterator.
+    pub fn prefixes<I>(&self, bytes: I) -> Prefixes<'_, V, I::IntoIter>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        Prefixes {
+            trie: self,
+            bytes: bytes.into_iter(),
+            node: Some(0),
+            depth: 0,
+            pending: true,
+        }
+    }
+
+    /// Finds the shortest key the bytes start with, as its length and value.
+    pub fn shortest_match<I>(&self, bytes: I) -> Option<(usize, &V)>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.prefixes(bytes).next()
+    }
+
+    /// Finds the longest key the bytes start with, as its length and value.
+    pub fn longest_match<I>(&self, bytes: I) -> Option<(usize, &V)>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.prefixes(bytes).last()
+    }
+
+    /// Iterates the keys in byte order.
+    pub fn keys(&self) -> Keys<'_, V> {
+        Keys {
+            trie: self,
+            stack: vec![(0, Vec::new())],
+        }
+    }
+
+    fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        let index = children.binary_search_by_key(&byte, |&(b, _)| b).ok()?;
+        Some(children[index].1)
+    }
+}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for Trie<V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<K: AsRef<[u8]>, V> Extend<(K, V)> for Trie<V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An iterator over the keys a byte string starts with; see [`Trie::prefixes`].
+#[derive(Debug, Clone)]
+pub struct Prefixes<'a, V, I> {
+    trie: &'a Trie<V>,
+    bytes: I,
+    /// The node of the bytes consumed so far, or `None` once no key can match anymore.
+    node: Option<usize>,
+    depth: usize,
+    /// Whether the current node's value is yet to be yielded.
+    pending: bool,
+}
+
+impl<'a, V, I: Iterator<Item = u8>> Iterator for Prefixes<'a, V, I> {
+    type Item = (usize, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.node?;
+            if self.pending {
+                self.pending = false;
+                if let Some(value) = &self.trie.nodes[node].value {
+                    return Some((self.depth, value));
+                }
+            }
+
+            self.node = self
+                .bytes
+                .next()
+                .and_then(|byte| self.trie.child(node, byte));
+            self.depth += 1;
+            self.pending = true;
+        }
+    }
+}
+
+/// An iterator over the keys of a trie in byte order; see [`Trie::keys`].
+#[derive(Debug, Clone)]
+pub struct Keys<'a, V> {
+    trie: &'a Trie<V>,
+    /// The nodes yet to visit with their keys, the next one on top.
+    stack: Vec<(usize, Vec<u8>)>,
+}
+
+impl<V> Iterator for Keys<'_, V> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, key)) = self.stack.pop() {
+            let node = &self.trie.nodes[node];
+            self.stack
+                .extend(node.children.iter().rev().map(|&(byte, child)| {
+                    let mut key = key.clone();
+                    key.push(byte);
+                    (child, key)
+                }));
+            if node.value.is_some() {
+                return Some(key);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert_eq!(trie.insert("one", 1), None);
+        assert_eq!(trie.insert("on", 2), None);
+        assert_eq!(trie.insert("one", 3), Some(1));
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains_key("on"));
+        assert!(!trie.contains_key("o"));
+        assert!(!trie.contains_key("ones"));
+
+        trie.insert("", 0);
+        assert_eq!(trie.shortest_match("xyz".bytes()), Some((0, &0)));
+    }
+
+    #[test]
+    fn test_keys() {
+        let trie: Trie = ["bwu", "r", "b", "", "bw"]
+            .map(|key| (key, ()))
+            .into_iter()
+            .collect();
+        let keys: Vec<_> = trie.keys().collect();
+        assert_eq!(keys, [&b""[..], b"b", b"bw", b"bwu", b"r"]);
+    }
+
+    #[test]
+    fn test_reversed_walk() {
+        let trie: Trie<u32> = ["eno", "owt", "eerht"].into_iter().zip(1..).collect();
+        let line = "xtwone";
+        assert_eq!(trie.shortest_match(line.bytes().rev()), Some((3, &1)));
+        assert_eq!(trie.shortest_match(line[..5].bytes().rev()), None);
+        assert_eq!(trie.shortest_match(line[..4].bytes().rev()), Some((3, &2)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_prefixes_match_brute_force() {
+        use crate::property::{check, Rng};
+
+        check(
+            300,
+            |rng| {
+                let word = |rng: &mut Rng| -> Vec<u8> {
+                    (0..rng.index(0..5))
+                        .map(|_| b'a' + rng.below(3) as u8)
+                        .collect()
+                };
+                let keys: Vec<_> = (0..rng.index(0..10)).map(|_| word(rng)).collect();
+                (keys, word(rng))
+            },
+            |(keys, text)| {
+                let trie: Trie<Vec<u8>> = keys.iter().map(|key| (key, key.clone())).collect();
+                let mut expected: Vec<_> =
+                    keys.iter().filter(|key| text.starts_with(key)).collect();
+                expected.sort_by_key(|key| key.len());
+                expected.dedup();
+
+                let found: Vec<_> = trie.prefixes(text.iter().copied()).collect();
+                found.len() == expected.len()
+                    && found
+                        .iter()
+                        .zip(&expected)
+                        .all(|(&(len, value), key)| len == key.len() && value == *key)
+            },
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+/// A three-dimensional integer vector.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::Vec3;
+///
+/// let a: Vec3 = "1,0,1".parse().unwrap();
+/// let b = Vec3::new(0, 0, 2);
+/// assert_eq!(a + b, Vec3::new(1, 0, 3));
+/// assert_eq!(a.manhattan(b), 2);
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// An axis-aligned, three-dimensional box; both corners are inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{BoundingBox3, Vec3};
+///
+/// let brick: BoundingBox3 = "1,0,1~1,2,1".parse().unwrap();
+/// assert_eq!(brick.volume(), 3);
+/// assert!(brick.contains(Vec3::new(1, 1, 1)));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BoundingBox3 {
+    /// The corner with the smallest coordinates.
+    pub min: Vec3,
+    /// The corner with the largest coordinates.
+    pub max: Vec3,
+}
+
+/// A rotation by multiples of 90° about the axes.
+///
+/// As a matrix, each row and column has a single non-zero entry of `1` or `-1`, and the
+/// determinant is `1`; mirror images are excluded. [`Rotation3::all`] lists the 24 such
+/// rotations, i.e. every way to orient something that may face and point up in any direction.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::{Rotation3, Vec3};
+///
+/// let v = Vec3::new(1, 2, 3);
+/// let orientations = Rotation3::all().map(|rotation| rotation * v);
+/// assert!(orientations.contains(&Vec3::new(-2, 1, 3)));
+/// assert!(!orientations.contains(&Vec3::new(2, 1, 3)));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rotation3 {
+    rows: [Vec3; 3],
+}
+
+impl Vec3 {
+    /// The vector `(0, 0, 0)`.
+    pub const ZERO: Vec3 = Vec3::new(0, 0, 0);
+
+    /// The unit vector along the x axis.
+    pub const UNIT_X: Vec3 = Vec3::new(1, 0, 0);
+
+    /// The unit vector along the y axis.
+    pub const UNIT_Y: Vec3 = Vec3::new(0, 1, 0);
+
+    /// The unit vector along the z axis.
+    pub const UNIT_Z: Vec3 = Vec3::new(0, 0, 1);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Gets the component-wise minimum of two vectors.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Gets the component-wise maximum of two vectors.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Calculates the taxicab/Manhattan distance to another vector.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Calculates the dot product.
+    pub fn dot(self, other: Self) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Calculates the cross product.
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl Rotation3 {
+    /// The rotation leaving every vector as it is.
+    pub const IDENTITY: Rotation3 = Rotation3 {
+        rows: [Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z],
+    };
+
+    /// Gets the 24 rotations, starting with the [identity](Self::IDENTITY).
+    pub fn all() -> [Rotation3; 24] {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        const AXES: [Vec3; 3] = [Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z];
+
+        let mut rotations = [Self::IDENTITY; 24];
+        let mut count = 0;
+        for [a, b, c] in PERMUTATIONS {
+            for signs in 0..8 {
+                let sign = |bit: i64| if signs & (1 << bit) == 0 { 1 } else { -1 };
+                let rotation = Self {
+                    rows: [AXES[a] * sign(0), AXES[b] * sign(1), AXES[c] * sign(2)],
+                };
+                if rotation.determinant() == 1 {
+                    rotations[count] = rotation;
+                    count += 1;
+                }
+            }
+        }
+        debug_assert_eq!(count, 24);
+        rotations
+    }
+
+    /// Gets the rotation undoing this one.
+    pub fn inverse(self) -> Self {
+        let [a, b, c] = self.rows;
+        Self {
+            rows: [
+                Vec3::new(a.x, b.x, c.x),
+                Vec3::new(a.y, b.y, c.y),
+                Vec3::new(a.z, b.z, c.z),
+            ],
+        }
+    }
+
+    fn determinant(&self) -> i64 {
+        let [a, b, c] = self.rows;
+        a.dot(b.cross(c))
+    }
+}
+
+impl BoundingBox3 {
+    /// Creates a box spanning the two (inclusive) corners, in any order.
+    pub fn new(a: Vec3, b: Vec3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Creates the smallest box containing all points, or `None` if there are none.
+    pub fn enclosing(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |bounds, point| {
+            bounds.union(&Self::new(point, point))
+        }))
+    }
+
+    /// Creates the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Gets the positions both boxes share, or `None` if they don't [intersect](Self::intersects).
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        })
+    }
+
+    /// Gets the number of integer positions inside the box.
+    pub fn volume(&self) -> u64 {
+        let size = self.max - self.min + Vec3::new(1, 1, 1);
+        size.x as u64 * size.y as u64 * size.z as u64
+    }
+
+    /// Determines whether the position lies inside the box.
+    pub fn contains(&self, position: Vec3) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y)
+            && (self.min.z..=self.max.z).contains(&position.z)
+    }
+
+    /// Determines whether the two boxes share at least one position.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.overlaps_xy(other) && self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// Determines whether the projections of both boxes onto the x-y plane overlap.
+    pub fn overlaps_xy(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Returns the box moved by the offset.
+    pub fn translate(&self, offset: Vec3) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Iterates all integer positions inside the box.
+    pub fn positions(&self) -> impl Iterator<Item = Vec3> + '_ {
+        (self.min.z..=self.max.z).flat_map(move |z| {
+            (self.min.y..=self.max.y)
+                .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Vec3::new(x, y, z)))
+        })
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Vec3> for Rotation3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let [a, b, c] = self.rows;
+        Vec3::new(a.dot(rhs), b.dot(rhs), c.dot(rhs))
+    }
+}
+
+impl Mul for Rotation3 {
+    type Output = Rotation3;
+
+    /// Combines the rotations, such that `(a * b) * v` equals `a * (b * v)`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let columns = rhs.inverse().rows;
+        Self {
+            rows: self.rows.map(|row| {
+                Vec3::new(
+                    row.dot(columns[0]),
+                    row.dot(columns[1]),
+                    row.dot(columns[2]),
+                )
+            }),
+        }
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Display for Vec3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for BoundingBox3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}~{}", self.min, self.max)
+    }
+}
+
+impl FromStr for Vec3 {
+    type Err = ParseVec3Error;
+
+    /// Parses a comma-separated triple such as `1,0,-1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split(',').map(|c| c.trim().parse::<i64>());
+        let mut next = || {
+            components
+                .next()
+                .ok_or(ParseVec3Error("too few components"))?
+                .map_err(|_| ParseVec3Error("invalid component"))
+        };
+
+        let vec = Vec3::new(next()?, next()?, next()?);
+        if components.next().is_some() {
+            return Err(ParseVec3Error("too many components"));
+        }
+
+        Ok(vec)
+    }
+}
+
+impl FromStr for BoundingBox3 {
+    type Err = ParseVec3Error;
+
+    /// Parses two corners separated by a tilde, such as `1,0,1~1,2,1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .trim()
+            .split_once('~')
+            .ok_or(ParseVec3Error("missing corner separator"))?;
+        Ok(Self::new(a.parse()?, b.parse()?))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseVec3Error(&'static str);
+
+impl Display for ParseVec3Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Failed to parse vector: {}", self.0)
+    }
+}
+
+impl Error for ParseVec3Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vec3() {
+        assert_eq!("1,-2,3".parse(), Ok(Vec3::new(1, -2, 3)));
+        assert_eq!(
+            "1,2".parse::<Vec3>(),
+            Err(ParseVec3Error("too few components"))
+        );
+        assert_eq!(
+            "1,2,3,4".parse::<Vec3>(),
+            Err(ParseVec3Error("too many components"))
+        );
+    }
+
+    #[test]
+    fn test_cross() {
+        assert_eq!(Vec3::UNIT_X.cross(Vec3::UNIT_Y), Vec3::UNIT_Z);
+        assert_eq!(Vec3::UNIT_Y.cross(Vec3::UNIT_X), -Vec3::UNIT_Z);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let a: BoundingBox3 = "0,0,2~2,0,2".parse().expect("failed to parse box");
+        let b: BoundingBox3 = "0,2,3~2,2,3".parse().expect("failed to parse box");
+        let c: BoundingBox3 = "1,0,1~1,2,1".parse().expect("failed to parse box");
+        assert!(!a.overlaps_xy(&b));
+        assert!(a.overlaps_xy(&c));
+        assert!(!a.intersects(&c));
+        assert!(a.intersects(&c.translate(Vec3::UNIT_Z)));
+        assert_eq!(c.positions().count() as u64, c.volume());
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(
+            a.intersection(&c.translate(Vec3::UNIT_Z)),
+            Some(BoundingBox3::new(Vec3::new(1, 0, 2), Vec3::new(1, 0, 2)))
+        );
+    }
+
+    #[test]
+    fn test_enclosing() {
+        let points = [Vec3::new(1, -2, 3), Vec3::new(-1, 5, 0), Vec3::new(0, 0, 7)];
+        let bounds = BoundingBox3::enclosing(points).expect("there are points");
+        assert_eq!(
+            bounds,
+            "-1,-2,0~1,5,7".parse().expect("failed to parse box")
+        );
+        assert_eq!(BoundingBox3::enclosing([]), None);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation3::all();
+        assert_eq!(rotations[0], Rotation3::IDENTITY);
+
+        // All rotations are distinct and form a group.
+        let v = Vec3::new(1, 2, 3);
+        for (i, &a) in rotations.iter().enumerate() {
+            assert!(rotations[..i].iter().all(|&b| a * v != b * v));
+            assert_eq!(a * (a.inverse() * v), v);
+            for b in rotations {
+                assert!(rotations.contains(&(a * b)));
+                assert_eq!((a * b) * v, a * (b * v));
+            }
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! A small framework for puzzles built around instruction machines.
+//!
+//! A [`Machine`] executes a program of words, where each instruction is an opcode followed by
+//! a fixed number of operands. The opcodes are defined as closures on a [`MachineBuilder`];
+//! each one operates on the machine [`State`] and tells the machine how to continue via [`Flow`].
+//!
+//! # Examples
+//!
+//! A machine that adds its two inputs:
+//!
+//! ```
+//! use aoc_utils::vm::{Flow, MachineBuilder, Status};
+//!
+//! let builder = MachineBuilder::new(2)
+//!     // inp r: read the next input into register `r`
+//!     .opcode(0, "inp", 1, |state, operands| match state.input.pop_front() {
+//!         Some(value) => {
+//!             state.registers[operands[0] as usize] = value;
+//!             Flow::Next
+//!         }
+//!         None => Flow::Await,
+//!     })
+//!     // add a b: add register `b` to register `a`
+//!     .opcode(1, "add", 2, |state, operands| {
+//!         state.registers[operands[0] as usize] += state.registers[operands[1] as usize];
+//!         Flow::Next
+//!     })
+//!     // out r: output register `r`
+//!     .opcode(2, "out", 1, |state, operands| {
+//!         state.output.push(state.registers[operands[0] as usize]);
+//!         Flow::Next
+//!     });
+//!
+//! let mut machine = builder.build(vec![0, 0, 0, 1, 1, 0, 1, 2, 0]);
+//! machine.push_input(20);
+//! assert_eq!(machine.run(), Ok(Status::AwaitingInput));
+//!
+//! machine.push_input(22);
+//! assert_eq!(machine.run(), Ok(Status::Halted));
+//! assert_eq!(machine.take_output(), [42]);
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::vec;
+use std::vec::Vec;
+
+/// How the machine continues after executing an instruction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Flow {
+    /// Continue with the instruction following this one.
+    Next,
+    /// Continue at the specified instruction pointer.
+    Jump(usize),
+    /// Stop the machine.
+    Halt,
+    /// Repeat this instruction once more input is available.
+    Await,
+}
+
+/// The status of a machine after executing instructions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Status {
+    /// The machine can execute further instructions.
+    Running,
+    /// The machine halted, either explicitly or by running past the end of the program.
+    Halted,
+    /// The machine waits for input before it can continue.
+    AwaitingInput,
+}
+
+/// The registers, program and I/O channels of a machine.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct State<W> {
+    pub registers: Vec<W>,
+    pub program: Vec<W>,
+    /// The instruction pointer, indexing into the program.
+    pub ip: usize,
+    /// The values not yet read by the program.
+    pub input: VecDeque<W>,
+    /// The values written by the program.
+    pub output: Vec<W>,
+}
+
+/// A decoded instruction, e.g. for tracing the execution.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Instruction<W> {
+    /// The position of the opcode in the program.
+    pub ip: usize,
+    pub name: &'static str,
+    pub operands: Vec<W>,
+}
+
+impl<W> Display for Instruction<W>
+where
+    W: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>4}: {}", self.ip, self.name)?;
+        for (i, operand) in self.operands.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{operand}")?;
+        }
+        Ok(())
+    }
+}
+
+type Handler<W> = Rc<dyn Fn(&mut State<W>, &[W]) -> Flow>;
+
+#[derive(Clone)]
+struct Opcode<W> {
+    name: &'static str,
+    arity: usize,
+    handler: Handler<W>,
+}
+
+/// Defines the opcodes of a machine and builds machines for programs.
+#[derive(Clone)]
+pub struct MachineBuilder<W> {
+    registers: usize,
+    opcodes: HashMap<W, Opcode<W>>,
+}
+
+impl<W> MachineBuilder<W>
+where
+    W: Copy + Default + Eq + Hash,
+{
+    /// Creates a builder for machines with the specified number of registers.
+    pub fn new(registers: usize) -> Self {
+        Self {
+            registers,
+            opcodes: HashMap::new(),
+        }
+    }
+
+    /// Defines an opcode followed by `arity` operands.
+    ///
+    /// The handler receives the machine state and the operands. The instruction pointer
+    /// still points at the opcode while the handler executes.
+    pub fn opcode<F>(mut self, opcode: W, name: &'static str, arity: usize, handler: F) -> Self
+    where
+        F: Fn(&mut State<W>, &[W]) -> Flow + 'static,
+    {
+        self.opcodes.insert(
+            opcode,
+            Opcode {
+                name,
+                arity,
+                handler: Rc::new(handler),
+            },
+        );
+        self
+    }
+
+    /// Builds a machine with zeroed registers for the program.
+    pub fn build(&self, program: Vec<W>) -> Machine<W> {
+        Machine {
+            state: State {
+                registers: vec![W::default(); self.registers],
+                program,
+                ip: 0,
+                input: VecDeque::new(),
+                output: Vec::new(),
+            },
+            opcodes: Rc::new(self.opcodes.clone()),
+            halted: false,
+        }
+    }
+}
+
+/// A machine executing a program with the opcodes defined by a [`MachineBuilder`].
+#[derive(Clone)]
+pub struct Machine<W> {
+    state: State<W>,
+    opcodes: Rc<HashMap<W, Opcode<W>>>,
+    halted: bool,
+}
+
+impl<W> Machine<W>
+where
+    W: Copy + Eq + Hash,
+{
+    pub fn state(&self) -> &State<W> {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut State<W> {
+        &mut self.state
+    }
+
+    /// Returns `true` if the machine halted.
+    pub fn is_halted(&self) -> bool {
+        self.halted || self.state.ip >= self.state.program.len()
+    }
+
+    /// Appends a value to the input channel.
+    pub fn push_input(&mut self, value: W) {
+        self.state.input.push_back(value);
+    }
+
+    /// Removes and returns all values written to the output channel.
+    pub fn take_output(&mut self) -> Vec<W> {
+        std::mem::take(&mut self.state.output)
+    }
+
+    /// Decodes the instruction at the instruction pointer without executing it.
+    ///
+    /// Returns `None` if the machine halted.
+    pub fn current(&self) -> Option<Result<Instruction<W>, VmError<W>>> {
+        if self.is_halted() {
+            return None;
+        }
+
+        Some(self.decode().map(|(opcode, operands)| Instruction {
+            ip: self.state.ip,
+            name: opcode.name,
+            operands: operands.to_vec(),
+        }))
+    }
+
+    /// Executes a single instruction.
+    pub fn step(&mut self) -> Result<Status, VmError<W>> {
+        if self.is_halted() {
+            return Ok(Status::Halted);
+        }
+
+        let (opcode, operands) = self.decode()?;
+        let (handler, arity) = (Rc::clone(&opcode.handler), opcode.arity);
+        let operands = operands.to_vec();
+
+        match handler(&mut self.state, &operands) {
+            Flow::Next => self.state.ip += 1 + arity,
+            Flow::Jump(ip) => self.state.ip = ip,
+            Flow::Halt => self.halted = true,
+            Flow::Await => return Ok(Status::AwaitingInput),
+        }
+
+        Ok(if self.is_halted() {
+            Status::Halted
+        } else {
+            Status::Running
+        })
+    }
+
+    /// Executes instructions until the machine halts or waits for input.
+    pub fn run(&mut self) -> Result<Status, VmError<W>> {
+        loop {
+            match self.step()? {
+                Status::Running => {}
+                status => return Ok(status),
+            }
+        }
+    }
+
+    fn decode(&self) -> Result<(&Opcode<W>, &[W]), VmError<W>> {
+        let ip = self.state.ip;
+        let code = self.state.program[ip];
+        let opcode = self
+            .opcodes
+            .get(&code)
+            .ok_or(VmError::UnknownOpcode { ip, opcode: code })?;
+        let operands = self
+            .state
+            .program
+            .get(ip + 1..ip + 1 + opcode.arity)
+            .ok_or(VmError::TruncatedInstruction { ip })?;
+        Ok((opcode, operands))
+    }
+}
+
+impl<W> Debug for Machine<W>
+where
+    W: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Machine")
+            .field("state", &self.state)
+            .field("halted", &self.halted)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VmError<W> {
+    /// The word at the instruction pointer is not a defined opcode.
+    UnknownOpcode { ip: usize, opcode: W },
+    /// The program ends before all operands of the instruction.
+    TruncatedInstruction { ip: usize },
+}
+
+impl<W> Display for VmError<W>
+where
+    W: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VmError::UnknownOpcode { ip, opcode } => {
+                write!(f, "Unknown opcode {opcode:?} at position {ip}")
+            }
+            VmError::TruncatedInstruction { ip } => {
+                write!(f, "Missing operands for the instruction at position {ip}")
+            }
+        }
+    }
+}
+
+impl<W> Error for VmError<W> where W: Debug {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    /// A machine counting register 0 down to zero, outputting each value.
+    fn countdown() -> MachineBuilder<i64> {
+        MachineBuilder::new(1)
+            // set v: set the register to `v`
+            .opcode(0, "set", 1, |state, operands| {
+                state.registers[0] = operands[0];
+                Flow::Next
+            })
+            // out: output the register
+            .opcode(1, "out", 0, |state, _| {
+                state.output.push(state.registers[0]);
+                Flow::Next
+            })
+            // dec: decrement the register
+            .opcode(2, "dec", 0, |state, _| {
+                state.registers[0] -= 1;
+                Flow::Next
+            })
+            // jnz t: jump to `t` if the register is not zero
+            .opcode(3, "jnz", 1, |state, operands| {
+                if state.registers[0] != 0 {
+                    Flow::Jump(operands[0] as usize)
+                } else {
+                    Flow::Next
+                }
+            })
+            .opcode(9, "hlt", 0, |_, _| Flow::Halt)
+    }
+
+    #[test]
+    fn test_run() {
+        let mut machine = countdown().build(vec![0, 3, 1, 2, 3, 2, 9, 1]);
+        assert_eq!(machine.run(), Ok(Status::Halted));
+        assert_eq!(machine.take_output(), [3, 2, 1]);
+        assert!(machine.is_halted());
+        assert_eq!(machine.step(), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn test_step() {
+        let mut machine = countdown().build(vec![0, 1, 1, 2, 3, 2]);
+        let trace: Vec<_> = std::iter::from_fn(|| {
+            let instruction = machine.current()?.expect("valid instruction");
+            machine.step().expect("valid instruction");
+            Some(instruction.to_string())
+        })
+        .collect();
+        assert_eq!(
+            trace,
+            ["   0: set 1", "   2: out", "   3: dec", "   4: jnz 2"]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut machine = countdown().build(vec![7]);
+        assert_eq!(
+            machine.run(),
+            Err(VmError::UnknownOpcode { ip: 0, opcode: 7 })
+        );
+
+        let mut machine = countdown().build(vec![1, 0]);
+        assert_eq!(machine.run(), Err(VmError::TruncatedInstruction { ip: 1 }));
+    }
+}
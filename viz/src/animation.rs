@@ -0,0 +1,145 @@
+use crate::Canvas;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::ResetColor;
+use crossterm::terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute};
+use std::io::{self, Stdout};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Limits the frame rate of an animation by sleeping until the next frame is due.
+#[derive(Debug, Clone)]
+pub struct FrameLimiter {
+    frame_time: Duration,
+    next_frame: Option<Instant>,
+}
+
+impl FrameLimiter {
+    /// Creates a limiter for the specified number of frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame rate is zero.
+    pub fn new(frames_per_second: u32) -> Self {
+        assert!(frames_per_second > 0, "the frame rate must not be zero");
+        Self {
+            frame_time: Duration::from_secs(1) / frames_per_second,
+            next_frame: None,
+        }
+    }
+
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Waits until the next frame is due.
+    ///
+    /// Frames that took longer than the frame time aren't caught up on; the next frame
+    /// is then scheduled relative to now.
+    pub fn wait(&mut self) {
+        let now = Instant::now();
+        let due = match self.next_frame {
+            Some(due) if due > now => {
+                thread::sleep(due - now);
+                due
+            }
+            _ => now,
+        };
+        self.next_frame = Some(due + self.frame_time);
+    }
+}
+
+/// The terminal in the alternate screen, showing an animation.
+///
+/// Entering the screen hides the cursor and switches to raw mode, so that key presses don't
+/// garble the frames; dropping it restores the previous terminal contents. Pressing `q`, `Esc`
+/// or `Ctrl+C` stops the animation, failing the next call with [`io::ErrorKind::Interrupted`].
+pub struct Screen {
+    out: Stdout,
+    limiter: FrameLimiter,
+}
+
+impl Screen {
+    /// Switches to the alternate screen, presenting frames at the specified rate.
+    pub fn enter(frames_per_second: u32) -> io::Result<Self> {
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        if let Err(e) = execute!(
+            out,
+            EnterAlternateScreen,
+            cursor::Hide,
+            terminal::Clear(ClearType::All)
+        ) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Self {
+            out,
+            limiter: FrameLimiter::new(frames_per_second),
+        })
+    }
+
+    /// Waits for the next frame to be due, then presents the canvas.
+    pub fn present(&mut self, canvas: &mut Canvas) -> io::Result<()> {
+        self.limiter.wait();
+        poll_quit(Duration::ZERO)?;
+        canvas.present(&mut self.out)
+    }
+
+    /// Keeps the last frame on screen for the specified time, or until the animation is stopped.
+    pub fn hold(&mut self, duration: Duration) -> io::Result<()> {
+        let end = Instant::now() + duration;
+        loop {
+            let remaining = end.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(());
+            }
+            poll_quit(remaining)?;
+        }
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(self.out, ResetColor, cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Reads the pending key presses, waiting up to the timeout for the first one, and fails if
+/// any of them stops the animation.
+fn poll_quit(timeout: Duration) -> io::Result<()> {
+    let mut timeout = timeout;
+    while event::poll(timeout)? {
+        timeout = Duration::ZERO;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        let interrupt = key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL;
+        let quit = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc);
+        if key.kind == KeyEventKind::Press && (interrupt || quit) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "the animation was stopped",
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_limiter() {
+        let mut limiter = FrameLimiter::new(100);
+        assert_eq!(limiter.frame_time(), Duration::from_millis(10));
+
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.wait();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+}
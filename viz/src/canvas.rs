@@ -0,0 +1,214 @@
+use crate::Color;
+use aoc_utils::{Grid, Point};
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Colors, Print, ResetColor, SetColors};
+use std::io::{self, Write};
+
+/// A character drawn in a color.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cell {
+    pub symbol: char,
+    pub foreground: Color,
+    pub background: Color,
+}
+
+impl Cell {
+    /// Creates a cell in the default colors.
+    pub const fn new(symbol: char) -> Self {
+        Self {
+            symbol,
+            foreground: Color::Default,
+            background: Color::Default,
+        }
+    }
+
+    pub const fn with_foreground(mut self, color: Color) -> Self {
+        self.foreground = color;
+        self
+    }
+
+    pub const fn with_background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    fn colors(&self) -> Colors {
+        Colors::new(self.foreground.into(), self.background.into())
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+/// A double-buffered drawing surface for the terminal.
+///
+/// Drawing happens on the back buffer; [`present`](Canvas::present) writes the cells that
+/// differ from the previously presented frame and swaps the buffers.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_viz::{Canvas, Cell, Color};
+///
+/// let mut canvas = Canvas::new(3, 1);
+/// canvas.set(1, 0, Cell::new('#').with_foreground(Color::Red));
+///
+/// let mut out = Vec::new();
+/// canvas.present(&mut out).unwrap();
+/// assert!(String::from_utf8(out).unwrap().contains("\x1b[38;5;1;49m#"));
+///
+/// // Unchanged frames don't write any cells.
+/// let mut out = Vec::new();
+/// canvas.present(&mut out).unwrap();
+/// assert_eq!(out, b"\x1b[0m");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    front: Vec<Cell>,
+    back: Vec<Cell>,
+    /// Whether the next frame must be written completely, e.g. because the terminal was cleared.
+    invalidated: bool,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            front: vec![Cell::default(); width * height],
+            back: vec![Cell::default(); width * height],
+            invalidated: true,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets a cell of the frame being drawn.
+    pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
+        (x < self.width && y < self.height).then(|| &self.back[x + y * self.width])
+    }
+
+    /// Sets a cell of the frame being drawn; cells outside the canvas are ignored.
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        if x < self.width && y < self.height {
+            self.back[x + y * self.width] = cell;
+        }
+    }
+
+    /// Resets all cells of the frame being drawn.
+    pub fn clear(&mut self) {
+        self.back.fill(Cell::default());
+    }
+
+    /// Forces the next [`present`](Canvas::present) to write every cell.
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    /// Draws a line of text, clipped at the edge of the canvas.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str, color: Color) {
+        for (i, symbol) in text.chars().enumerate() {
+            self.set(x + i, y, Cell::new(symbol).with_foreground(color));
+        }
+    }
+
+    /// Draws a grid with its top-left corner at `(x, y)`, clipped at the edge of the canvas.
+    pub fn draw_grid<T, F>(&mut self, x: usize, y: usize, grid: &Grid<T>, mut cell: F)
+    where
+        F: FnMut(Point, &T) -> Cell,
+    {
+        for (point, value) in grid.iter() {
+            let cell = cell(point, value);
+            self.set(x + point.x as usize, y + point.y as usize, cell);
+        }
+    }
+
+    /// Writes the changes of the drawn frame to the terminal and makes it the presented frame.
+    ///
+    /// The frame being drawn keeps its contents, so the next frame can be drawn incrementally.
+    pub fn present<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        // The frame is queued up in memory to write it to the terminal at once.
+        let mut buffer = Vec::new();
+        let mut cursor = None;
+        let mut colors = None;
+
+        for (i, (cell, previous)) in self.back.iter().zip(&self.front).enumerate() {
+            if !self.invalidated && cell == previous {
+                continue;
+            }
+
+            let (x, y) = (i % self.width, i / self.width);
+            if cursor != Some((x, y)) {
+                queue!(buffer, MoveTo(x as u16, y as u16))?;
+            }
+            if colors != Some(cell.colors()) {
+                queue!(buffer, SetColors(cell.colors()))?;
+                colors = Some(cell.colors());
+            }
+
+            queue!(buffer, Print(cell.symbol))?;
+            cursor = Some((x + 1, y));
+        }
+
+        queue!(buffer, ResetColor)?;
+        out.write_all(&buffer)?;
+        out.flush()?;
+
+        self.front.clone_from(&self.back);
+        self.invalidated = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn present(canvas: &mut Canvas) -> String {
+        let mut out = Vec::new();
+        canvas.present(&mut out).expect("failed to write");
+        String::from_utf8(out).expect("invalid UTF-8")
+    }
+
+    #[test]
+    fn test_present_changes() {
+        let mut canvas = Canvas::new(4, 2);
+        canvas.draw_text(0, 0, "ab", Color::Default);
+        assert_eq!(
+            present(&mut canvas),
+            "\x1b[1;1H\x1b[39;49mab  \x1b[2;1H    \x1b[0m"
+        );
+
+        canvas.set(3, 1, Cell::new('#').with_background(Color::Blue));
+        canvas.set(9, 9, Cell::new('?'));
+        assert_eq!(present(&mut canvas), "\x1b[2;4H\x1b[39;48;5;4m#\x1b[0m");
+
+        canvas.invalidate();
+        assert_eq!(present(&mut canvas).matches('#').count(), 1);
+        assert_eq!(canvas.get(0, 0), Some(&Cell::new('a')));
+    }
+
+    #[test]
+    fn test_draw_grid() {
+        let grid = Grid::parse("#.\n.#", |c| Some(c == '#')).expect("invalid grid");
+        let mut canvas = Canvas::new(3, 3);
+        canvas.draw_grid(1, 1, &grid, |_, &wall| {
+            Cell::new(if wall { '#' } else { '.' })
+        });
+        assert_eq!(canvas.get(1, 1), Some(&Cell::new('#')));
+        assert_eq!(canvas.get(2, 1), Some(&Cell::new('.')));
+        assert_eq!(canvas.get(0, 0), Some(&Cell::new(' ')));
+    }
+}
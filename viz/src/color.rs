@@ -0,0 +1,131 @@
+/// A terminal color.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Color {
+    /// The terminal's default foreground or background color.
+    #[default]
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    /// A 24-bit color.
+    Rgb(u8, u8, u8),
+}
+
+/// Maps the colors to the standard terminal colors, which crossterm calls dark.
+impl From<Color> for crossterm::style::Color {
+    fn from(color: Color) -> Self {
+        use crossterm::style::Color as Term;
+        match color {
+            Color::Default => Term::Reset,
+            Color::Black => Term::Black,
+            Color::Red => Term::DarkRed,
+            Color::Green => Term::DarkGreen,
+            Color::Yellow => Term::DarkYellow,
+            Color::Blue => Term::DarkBlue,
+            Color::Magenta => Term::DarkMagenta,
+            Color::Cyan => Term::DarkCyan,
+            Color::White => Term::Grey,
+            // The bright variant of black.
+            Color::Gray => Term::DarkGrey,
+            Color::Rgb(r, g, b) => Term::Rgb { r, g, b },
+        }
+    }
+}
+
+/// Maps values in `0.0..=1.0` to colors by interpolating between color stops.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_viz::{Color, ColorMap};
+///
+/// let map = ColorMap::new(vec![(0, 0, 0), (255, 255, 255)]);
+/// assert_eq!(map.map(0.0), Color::Rgb(0, 0, 0));
+/// assert_eq!(map.map(0.5), Color::Rgb(128, 128, 128));
+/// assert_eq!(map.map(2.0), Color::Rgb(255, 255, 255));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ColorMap {
+    stops: Vec<(u8, u8, u8)>,
+}
+
+impl ColorMap {
+    /// Creates a color map from evenly spaced color stops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no stops are given.
+    pub fn new(stops: Vec<(u8, u8, u8)>) -> Self {
+        assert!(!stops.is_empty(), "a color map requires at least one stop");
+        Self { stops }
+    }
+
+    /// A map from dark red over orange and yellow to white.
+    pub fn heat() -> Self {
+        Self::new(vec![
+            (64, 0, 0),
+            (200, 40, 0),
+            (255, 160, 0),
+            (255, 255, 80),
+            (255, 255, 255),
+        ])
+    }
+
+    /// A map from dark gray to white.
+    pub fn grayscale() -> Self {
+        Self::new(vec![(40, 40, 40), (255, 255, 255)])
+    }
+
+    /// Gets the color for the value, clamping it to `0.0..=1.0`.
+    pub fn map(&self, value: f64) -> Color {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+
+        let segments = self.stops.len() - 1;
+        let position = value * segments as f64;
+        let index = (position.floor() as usize).min(segments.saturating_sub(1));
+        let (from, to) = (self.stops[index], self.stops[(index + 1).min(segments)]);
+        let t = position - index as f64;
+
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_color() {
+        use crossterm::style::Color as Term;
+        assert_eq!(Term::from(Color::Default), Term::Reset);
+        assert_eq!(Term::from(Color::Red), Term::DarkRed);
+        assert_eq!(Term::from(Color::Gray), Term::DarkGrey);
+        assert_eq!(
+            Term::from(Color::Rgb(1, 2, 3)),
+            Term::Rgb { r: 1, g: 2, b: 3 }
+        );
+    }
+
+    #[test]
+    fn test_color_map() {
+        let map = ColorMap::new(vec![(0, 0, 0), (100, 0, 0), (100, 100, 0)]);
+        assert_eq!(map.map(-1.0), Color::Rgb(0, 0, 0));
+        assert_eq!(map.map(0.25), Color::Rgb(50, 0, 0));
+        assert_eq!(map.map(0.5), Color::Rgb(100, 0, 0));
+        assert_eq!(map.map(1.0), Color::Rgb(100, 100, 0));
+
+        let single = ColorMap::new(vec![(1, 2, 3)]);
+        assert_eq!(single.map(0.7), Color::Rgb(1, 2, 3));
+    }
+}
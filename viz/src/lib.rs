@@ -0,0 +1,28 @@
+//! Terminal visualizations for puzzle solutions.
+//!
+//! A [`Canvas`] is drawn frame by frame and presented on a [`Screen`], which limits the frame
+//! rate and restores the terminal when the animation ends:
+//!
+//! ```no_run
+//! use aoc_viz::{Canvas, Cell, Color, Screen};
+//!
+//! let mut screen = Screen::enter(30).expect("failed to enter the screen");
+//! let mut canvas = Canvas::new(10, 1);
+//! for x in 0..10 {
+//!     canvas.set(x, 0, Cell::new('#').with_foreground(Color::Green));
+//!     screen.present(&mut canvas).expect("failed to draw");
+//! }
+//! ```
+
+mod animation;
+mod canvas;
+mod color;
+
+pub use animation::{FrameLimiter, Screen};
+pub use canvas::{Canvas, Cell};
+pub use color::{Color, ColorMap};
+
+/// Returns `true` if the `--animate` command-line option is given.
+pub fn animate_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--animate")
+}